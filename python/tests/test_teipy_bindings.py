@@ -0,0 +1,241 @@
+"""Behavioural tests for the :mod:`tei_rapporteur` document bindings.
+
+Each test drives the module as Python callers do, covering value semantics,
+pickling, in-place editing, the optional pandas export, parser limits, file
+I/O, and the type stub. The tests take no fixtures, so ``tei-py/tests/python_suite.rs``
+can run them against the compiled module without building a wheel.
+"""
+
+import ast
+import copy
+import json
+import pathlib
+import pickle
+import sys
+import tempfile
+import types
+from concurrent.futures import ThreadPoolExecutor
+
+import tei_rapporteur as tr
+
+ROOT = pathlib.Path(__file__).resolve().parents[2]
+STUB_PATHS = (
+    ROOT / "tei-py" / "tei_rapporteur.pyi",
+    ROOT / "tei_rapporteur" / "_header.pyi",
+)
+DUNDERS = {
+    "__eq__",
+    "__hash__",
+    "__len__",
+    "__repr__",
+    "__getnewargs__",
+    "__getstate__",
+    "__setstate__",
+}
+
+
+def _raises(call, exception) -> bool:
+    try:
+        call()
+    except exception:
+        return True
+    return False
+
+
+def test_document_method_encodes_msgpack() -> None:
+    """Check that ``Document.to_msgpack`` matches the module-level encoder."""
+
+    document = tr.Document("Wolf 359", [tr.Utterance(["Day 1."], speaker="Eiffel")])
+    payload = document.to_msgpack()
+    assert isinstance(payload, bytes)
+    assert payload == tr.to_msgpack(document)
+    assert tr.from_msgpack(payload).to_dict() == document.to_dict()
+
+
+def test_documents_behave_as_python_values() -> None:
+    """Compare documents by content and keep mutable documents unhashable."""
+
+    first = tr.Document("Wolf 359", [tr.P(["Previously."]), tr.Utterance(["Day 1."])])
+    second = tr.from_msgpack(first.to_msgpack())
+    other = tr.Document("Wolf 359")
+    assert first == second and first != other and first != "Wolf 359"
+    assert _raises(lambda: hash(first), TypeError), "documents should be unhashable"
+    assert (len(first), len(other)) == (2, 0)
+    assert repr(first) == "<Document 'Wolf 359' blocks=2>", repr(first)
+
+
+def test_documents_survive_pickling() -> None:
+    """Round-trip documents through ``pickle`` and ``copy.deepcopy``."""
+
+    document = tr.Document("Wolf 359", [tr.Utterance(["Day 1."], speaker="Eiffel", id="u1")])
+    restored = pickle.loads(pickle.dumps(document))
+    assert restored == document and restored is not document
+    assert restored.blocks[0].value.speaker == "Eiffel"
+    assert copy.deepcopy(document) == document
+
+
+def test_documents_are_edited_in_place() -> None:
+    """Edit titles and blocks in place, including by negative index."""
+
+    document = tr.Document("Wolf 359")
+    document.set_title("Wolf 359: Day 1")
+    document.append_utterance("Eiffel", "Day 1 of the mission.")
+    document.append_utterance(None, "Static.")
+    document.append_block(tr.P(["Recorded aboard the Hephaestus."]))
+    block = document.blocks[0]
+    block.set_id("u1")
+    assert document.blocks[0].value.id is None
+    document.set_block(0, block)
+    document.set_block(-1, tr.P(["Recorded aboard the Hephaestus."], id="p1"))
+
+    assert document.title == "Wolf 359: Day 1"
+    assert [block.kind for block in document.blocks] == ["u", "u", "p"]
+    first = document.blocks[0].value
+    assert (first.speaker, first.id) == ("Eiffel", "u1")
+    assert document.blocks[2].value.id == "p1"
+
+    for call, exception in [
+        (lambda: document.set_title("  "), tr.DocumentTitleError),
+        (lambda: document.append_utterance("Eiffel", "  "), tr.BodyContentError),
+        (lambda: document.set_block(3, block), IndexError),
+        (lambda: document.set_block(-4, block), IndexError),
+    ]:
+        assert _raises(call, exception), f"expected {exception.__name__}"
+    assert len(document) == 3 and document.title == "Wolf 359: Day 1"
+
+
+def test_documents_export_utterances_to_pandas() -> None:
+    """Build data frames through pandas and explain when it is missing."""
+
+    document = tr.Document("Wolf 359", [tr.P(["Previously."])])
+    document.append_utterance("Eiffel", "Day 1.")
+    document.append_utterance(None, "Static.")
+
+    original = sys.modules.get("pandas")
+    try:
+        sys.modules["pandas"] = None
+        try:
+            document.to_dataframe()
+        except ImportError as error:
+            assert "tei-rapporteur[pandas]" in str(error), str(error)
+        else:
+            raise AssertionError("exported without pandas")
+
+        pandas = types.ModuleType("pandas")
+        pandas.DataFrame = lambda columns: columns
+        sys.modules["pandas"] = pandas
+        frame = document.to_dataframe()
+    finally:
+        if original is None:
+            del sys.modules["pandas"]
+        else:
+            sys.modules["pandas"] = original
+    assert frame == {
+        "speaker": ["Eiffel", None],
+        "start": [None, None],
+        "end": [None, None],
+        "text": ["Day 1.", "Static."],
+        "id": [None, None],
+    }, frame
+
+
+def test_python_dicts_round_trip_documents() -> None:
+    """Round-trip documents through plain ``dict`` values and JSON."""
+
+    document = tr.Document("Wolf 359", [tr.P(["Previously."], id="p1")])
+    data = document.to_dict()
+    assert data["body"] == [{"type": "p", "id": "p1", "content": ["Previously."]}]
+    assert tr.Document.from_dict(json.loads(json.dumps(data))).to_dict() == data
+    for invalid in ({"header": {}, "body": []}, {"header": data["header"], "body": [{1, 2}]}):
+        assert _raises(lambda: tr.Document.from_dict(invalid), (TypeError, ValueError)), invalid
+
+
+def test_python_threads_parse_and_emit_concurrently() -> None:
+    """Parse and emit documents from several threads at once."""
+
+    titles = [f"Episode {number}" for number in range(16)]
+    payloads = [tr.Document(title, [tr.P([title])]).to_xml() for title in titles]
+    with ThreadPoolExecutor(max_workers=4) as pool:
+        documents = list(pool.map(tr.parse_xml, payloads))
+        emitted = list(pool.map(lambda document: document.to_xml(), documents))
+    assert [document.title for document in documents] == titles
+    assert emitted == payloads
+
+
+def test_parse_options_bound_untrusted_markup() -> None:
+    """Enforce parser resource limits passed as ``ParseOptions``."""
+
+    xml = tr.Document("Wolf 359", [tr.Utterance(["Day 1."], speaker="Eiffel")]).to_xml()
+    defaults = tr.ParseOptions()
+    assert (defaults.max_depth, defaults.max_entity_expansions, defaults.max_document_size) == (
+        256,
+        1_000_000,
+        None,
+    )
+    assert tr.parse_xml(xml, tr.ParseOptions(max_depth=None)).title == "Wolf 359"
+
+    for options in (tr.ParseOptions(max_document_size=16), tr.ParseOptions(max_depth=2)):
+        try:
+            tr.parse_xml(xml, options)
+        except tr.LimitExceededError as error:
+            assert error.code == "TEI-LIMIT"
+        else:
+            raise AssertionError("limit not enforced")
+
+
+def test_python_documents_load_and_save_files() -> None:
+    """Save and load documents by ``str`` and ``pathlib`` paths."""
+
+    document = tr.Document("Wolf 359", [tr.Utterance(["Day 1."], speaker="Eiffel")])
+    with tempfile.TemporaryDirectory() as directory:
+        target = pathlib.Path(directory) / "wolf-359.xml"
+        document.save(target)
+        assert target.read_text(encoding="utf-8").startswith("<?xml")
+        assert tr.Document.from_file(target) == document
+        document.save(str(target), declaration=False)
+        assert target.read_text(encoding="utf-8").startswith("<TEI")
+        assert tr.Document.from_file(str(target)) == document
+        try:
+            tr.Document.from_file(pathlib.Path(directory) / "missing.xml")
+        except tr.FileError as error:
+            assert error.code == "TEI-IO"
+        else:
+            raise AssertionError("missing files must fail")
+
+
+def _declared(body) -> set[str]:
+    names = set()
+    for node in body:
+        if isinstance(node, (ast.ClassDef, ast.FunctionDef)):
+            names.add(node.name)
+        elif isinstance(node, ast.AnnAssign):
+            names.add(node.target.id)
+        elif isinstance(node, ast.Assign):
+            names.update(target.id for target in node.targets)
+        elif isinstance(node, ast.ImportFrom) and node.module == "tei_rapporteur._header":
+            names.update(alias.asname for alias in node.names if alias.asname == alias.name)
+    return names
+
+
+def test_stub_covers_the_compiled_module() -> None:
+    """Check that the type stub declares every public class and method."""
+
+    main, header = (ast.parse(path.read_text(encoding="utf-8")).body for path in STUB_PATHS)
+    top_level = _declared(main)
+    classes = {
+        node.name: _declared(node.body)
+        for node in main + header
+        if isinstance(node, ast.ClassDef)
+    }
+    assert _declared(header) <= top_level, "stub must re-export every header class"
+
+    missing = [name for name in tr.__all__ if name not in top_level]
+    assert not missing, f"stub lacks {missing}"
+    for name, members in classes.items():
+        cls = getattr(tr, name)
+        if issubclass(cls, BaseException):
+            continue
+        exported = {key for key in vars(cls) if not key.startswith("_") or key in DUNDERS}
+        assert (
+            members - {"__init__"} == exported
+        ), f"{name}: stub {sorted(members)} vs module {sorted(exported)}"
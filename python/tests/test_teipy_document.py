@@ -29,3 +29,18 @@ def test_document_round_trip() -> None:
     assert (
         actual_markup == expected_markup
     ), f"emit_title_markup should wrap the title, found {actual_markup!r}"
+
+
+def test_errors_carry_stable_codes() -> None:
+    """Check that validation failures expose the stable ``[CODE]`` prefix."""
+
+    try:
+        tr.Document("   ")
+    except ValueError as error:
+        message = str(error)
+    else:  # pragma: no cover - the constructor must reject blank titles
+        raise AssertionError("blank titles should be rejected")
+
+    assert message.startswith(
+        "[TEI-TITLE-EMPTY] "
+    ), f"error should lead with its code, found {message!r}"
@@ -0,0 +1,193 @@
+//! Speaker anonymisation with deterministic, seeded pseudonyms.
+//!
+//! Research datasets must not expose speaker identities, yet utterances still
+//! need to line up with the cast list. [`Anonymiser`] derives each pseudonym
+//! from a seed and the original name only, so the same speaker receives the
+//! same pseudonym in every document processed with the same seed.
+
+use crate::{ProfileDesc, Speaker, SpeakerName, TeiDocument};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Replaces speaker names with stable pseudonyms.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::Anonymiser;
+///
+/// let anonymiser = Anonymiser::new(2024);
+/// let pseudonym = anonymiser.pseudonym("Keisha");
+///
+/// assert!(pseudonym.starts_with("speaker-"));
+/// assert_eq!(pseudonym, Anonymiser::new(2024).pseudonym("Keisha"));
+/// assert_ne!(pseudonym, Anonymiser::new(7).pseudonym("Keisha"));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Anonymiser {
+    seed: u64,
+}
+
+impl Anonymiser {
+    /// Creates an anonymiser; reuse the seed across a corpus to keep
+    /// pseudonyms consistent between documents.
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Returns the pseudonym for a speaker name or reference.
+    ///
+    /// Surrounding whitespace and the `#` of a pointer are ignored, so
+    /// `" Keisha "`, `"#Keisha"` and `"Keisha"` map to the same pseudonym.
+    #[must_use]
+    pub fn pseudonym(&self, name: &str) -> String {
+        let hash = bare_name(name)
+            .bytes()
+            .fold(FNV_OFFSET_BASIS ^ self.seed, |state, byte| {
+                (state ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            });
+
+        format!("speaker-{:012x}", mix(hash) >> 16)
+    }
+
+    /// Replaces speaker names in the profile description and speaker
+    /// references on utterances with their pseudonyms.
+    ///
+    /// Identical names map to identical pseudonyms, so references between the
+    /// cast list and the body stay intact. References written as pointers,
+    /// such as `#Keisha`, keep their `#`.
+    pub fn anonymise(&self, document: &mut TeiDocument) {
+        let cast = document
+            .header
+            .profile_desc_mut()
+            .map(ProfileDesc::speakers_mut)
+            .unwrap_or_default();
+        for speaker in cast {
+            if let Ok(pseudonym) = SpeakerName::new(self.pseudonym(speaker.as_str())) {
+                *speaker = pseudonym;
+            }
+        }
+
+        for utterance in document.text.body_mut().utterances_mut() {
+            let pseudonym = utterance
+                .speaker()
+                .and_then(|speaker| Speaker::new(self.reference(speaker.as_str())).ok());
+            if let Some(speaker) = pseudonym {
+                utterance.replace_speaker(speaker);
+            }
+        }
+    }
+
+    /// Returns the pseudonym replacing a speaker reference, keeping the `#`
+    /// of a pointer.
+    fn reference(self, reference: &str) -> String {
+        let pseudonym = self.pseudonym(reference);
+        if reference.trim().starts_with('#') {
+            format!("#{pseudonym}")
+        } else {
+            pseudonym
+        }
+    }
+}
+
+/// Returns `name` without surrounding whitespace or a pointer's leading `#`.
+fn bare_name(name: &str) -> &str {
+    let trimmed = name.trim();
+    trimmed.strip_prefix('#').unwrap_or(trimmed)
+}
+
+/// Finalises a hash so that nearby inputs produce unrelated pseudonyms.
+const fn mix(value: u64) -> u64 {
+    let mut mixed = value;
+    mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    mixed ^ (mixed >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FileDesc, ProfileDesc, TeiHeader, TeiText, Utterance};
+
+    fn document() -> TeiDocument {
+        let mut profile = ProfileDesc::new();
+        profile
+            .add_speaker("Keisha")
+            .unwrap_or_else(|error| panic!("valid speaker: {error}"));
+        let file_desc = FileDesc::from_title_str("Oral History 12")
+            .unwrap_or_else(|error| panic!("valid title: {error}"));
+        let mut text = TeiText::empty();
+        text.push_utterance(
+            Utterance::from_text_segments(Some("Keisha"), ["I grew up by the docks."])
+                .unwrap_or_else(|error| panic!("valid utterance: {error}")),
+        )
+        .push_utterance(
+            Utterance::from_text_segments(None::<&str>, ["[inaudible]"])
+                .unwrap_or_else(|error| panic!("valid utterance: {error}")),
+        );
+
+        TeiDocument::new(TeiHeader::new(file_desc).with_profile_desc(profile), text)
+    }
+
+    #[test]
+    fn replaces_names_consistently_in_header_and_body() {
+        let anonymiser = Anonymiser::new(42);
+        let expected = anonymiser.pseudonym("Keisha");
+        let mut transcript = document();
+
+        anonymiser.anonymise(&mut transcript);
+
+        let header_names: Vec<_> = transcript
+            .header()
+            .profile_desc()
+            .map(|profile| profile.speakers().iter().map(SpeakerName::as_str).collect())
+            .unwrap_or_default();
+        let body_references: Vec<_> = transcript
+            .text()
+            .body()
+            .utterances()
+            .map(|utterance| utterance.speaker().map(Speaker::as_str))
+            .collect();
+
+        assert_eq!(header_names, [expected.as_str()]);
+        assert_eq!(body_references, [Some(expected.as_str()), None]);
+    }
+
+    #[test]
+    fn keeps_pointer_references_resolving_to_the_cast() {
+        let anonymiser = Anonymiser::new(42);
+        let mut transcript = document();
+        transcript.text.body_mut().push_utterance(
+            Utterance::from_text_segments(Some("#Keisha"), ["Mostly fishing boats."])
+                .unwrap_or_else(|error| panic!("valid utterance: {error}")),
+        );
+        assert!(transcript.validate().codes().is_empty());
+
+        anonymiser.anonymise(&mut transcript);
+
+        let pointer = transcript
+            .text()
+            .body()
+            .utterances()
+            .last()
+            .and_then(|utterance| utterance.speaker().map(Speaker::as_str))
+            .map(ToOwned::to_owned);
+        assert_eq!(
+            pointer,
+            Some(format!("#{}", anonymiser.pseudonym("Keisha")))
+        );
+        assert!(transcript.validate().codes().is_empty());
+    }
+
+    #[test]
+    fn pseudonyms_differ_between_names() {
+        let anonymiser = Anonymiser::new(42);
+
+        assert_ne!(
+            anonymiser.pseudonym("Keisha"),
+            anonymiser.pseudonym("Kiesha")
+        );
+    }
+}
@@ -0,0 +1,38 @@
+//! Constructors for errors raised while writing other formats.
+
+use super::TeiError;
+use crate::SourceLocation;
+
+impl TeiError {
+    /// Builds an export error for output in `format`, pointing at the
+    /// element that could not be written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::{SourceLocation, TeiError, TeiPath};
+    ///
+    /// let path = TeiPath::root().child("text").child("body").nth_child("u", 2);
+    /// let error = TeiError::export_at(
+    ///     "SRT",
+    ///     "utterance has no start time",
+    ///     SourceLocation::new().with_path(path),
+    /// );
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "SRT export error: utterance has no start time (/TEI/text/body/u[2])",
+    /// );
+    /// ```
+    #[must_use]
+    pub fn export_at(
+        format: &'static str,
+        message: impl Into<String>,
+        location: SourceLocation,
+    ) -> Self {
+        Self::Export {
+            format,
+            message: message.into(),
+            location: Some(location),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Constructors for errors raised while importing other transcript formats.
+
+use super::TeiError;
+use crate::SourceLocation;
+
+impl TeiError {
+    /// Builds an import error for input in `format`, pointing at a source
+    /// location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::{SourceLocation, TeiError};
+    ///
+    /// let error = TeiError::import_at(
+    ///     "WebVTT",
+    ///     "malformed timestamp `00:01`",
+    ///     SourceLocation::new().with_line_column(3, 1),
+    /// );
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "WebVTT import error: malformed timestamp `00:01` (line 3, column 1)",
+    /// );
+    /// ```
+    #[must_use]
+    pub fn import_at(
+        format: &'static str,
+        message: impl Into<String>,
+        location: SourceLocation,
+    ) -> Self {
+        Self::Import {
+            format,
+            message: message.into(),
+            location: Some(location),
+        }
+    }
+}
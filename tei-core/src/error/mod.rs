@@ -0,0 +1,385 @@
+//! The crate-wide error type.
+//!
+//! [`TeiError`] wraps the validation errors raised by each part of the data
+//! model, plus the XML, JSON, `MessagePack`, import, export and I/O failures
+//! reported by the serialisation layers, so callers handle a single type whichever
+//! layer failed.
+
+use std::error::Error as StdError;
+use std::io;
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use crate::{
+    BodyContentError, DocumentTitleError, ErrorCode, HeaderValidationError,
+    IdentifierValidationError, ResourceLimit, SourceLocation, SpeakerValidationError,
+};
+
+mod export;
+mod import;
+mod source;
+
+pub use source::ErrorSource;
+
+/// Errors raised by TEI core data model operations.
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum TeiError {
+    /// Wrapper around [`DocumentTitleError`] values.
+    #[error(transparent)]
+    DocumentTitle(#[from] DocumentTitleError),
+    /// Wrapper around [`HeaderValidationError`] values.
+    #[error(transparent)]
+    Header(#[from] HeaderValidationError),
+    /// Wrapper around [`BodyContentError`] values.
+    #[error(transparent)]
+    Body(#[from] BodyContentError),
+    /// Wrapper around [`IdentifierValidationError`] values.
+    #[error(transparent)]
+    Identifier(#[from] IdentifierValidationError),
+    /// Wrapper around [`SpeakerValidationError`] values.
+    #[error(transparent)]
+    Speaker(#[from] SpeakerValidationError),
+    /// XML parsing or serialisation failed.
+    #[error(
+        "XML processing error: {message}{}",
+        .location.as_ref().map_or_else(String::new, |known| format!(" ({known})"))
+    )]
+    Xml {
+        /// Message describing the failure emitted by the XML layer.
+        message: String,
+        /// Where in the source text the failure was detected, when known.
+        location: Option<SourceLocation>,
+        /// Error reported by the XML library, when one was available.
+        source: Option<ErrorSource>,
+    },
+    /// JSON parsing or serialisation failed.
+    #[error("JSON processing error: {message}")]
+    Json {
+        /// Message describing the failure, including the line and column
+        /// when the input was malformed.
+        message: String,
+    },
+    /// `MessagePack` encoding or decoding failed.
+    #[error("MessagePack processing error: {message}")]
+    MessagePack {
+        /// Message describing the failure.
+        message: String,
+    },
+    /// A transcript in another format could not be imported.
+    #[error(
+        "{format} import error: {message}{}",
+        .location.as_ref().map_or_else(String::new, |known| format!(" ({known})"))
+    )]
+    Import {
+        /// Name of the format being imported, such as `WebVTT`.
+        format: &'static str,
+        /// Message describing the failure.
+        message: String,
+        /// Where in the source text the failure was detected, when known.
+        location: Option<SourceLocation>,
+    },
+    /// A document could not be written in another format.
+    #[error(
+        "{format} export error: {message}{}",
+        .location.as_ref().map_or_else(String::new, |known| format!(" ({known})"))
+    )]
+    Export {
+        /// Name of the format being written, such as `SRT`.
+        format: &'static str,
+        /// Message describing the failure.
+        message: String,
+        /// The element that could not be exported, when known.
+        location: Option<SourceLocation>,
+    },
+    /// Reading or writing a file failed.
+    #[error("I/O error on {}: {message}", .path.display())]
+    Io {
+        /// File the operation was working on.
+        path: PathBuf,
+        /// Category of the underlying [`io::Error`].
+        kind: io::ErrorKind,
+        /// Message reported by the operating system.
+        message: String,
+    },
+    /// The input is in a character encoding the parser cannot read.
+    #[error("unsupported character encoding `{encoding}`; re-save the file as UTF-8 or UTF-16")]
+    UnsupportedEncoding {
+        /// Encoding name as declared by the input.
+        encoding: String,
+    },
+    /// The input exceeded a parser resource limit.
+    #[error(
+        "{limit} limit of {maximum}{} exceeded{}",
+        .limit.unit(),
+        .location.as_ref().map_or_else(String::new, |known| format!(" ({known})"))
+    )]
+    LimitExceeded {
+        /// Limit that was exceeded.
+        limit: ResourceLimit,
+        /// Configured maximum.
+        maximum: usize,
+        /// Where in the source text the limit was crossed, when known.
+        location: Option<SourceLocation>,
+    },
+}
+
+impl TeiError {
+    /// Builds an XML processing error with the provided message.
+    #[must_use]
+    pub fn xml(message: impl Into<String>) -> Self {
+        Self::Xml {
+            message: message.into(),
+            location: None,
+            source: None,
+        }
+    }
+
+    /// Builds an XML processing error pointing at a source location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::{SourceLocation, TeiError};
+    ///
+    /// let error = TeiError::xml_at("unexpected end tag", SourceLocation::new().with_offset(12));
+    /// assert_eq!(error.location().and_then(SourceLocation::offset), Some(12));
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "XML processing error: unexpected end tag (byte 12)",
+    /// );
+    /// ```
+    #[must_use]
+    pub fn xml_at(message: impl Into<String>, location: SourceLocation) -> Self {
+        Self::Xml {
+            message: message.into(),
+            location: Some(location),
+            source: None,
+        }
+    }
+
+    /// Records the library error behind an XML processing error, exposing it
+    /// through [`std::error::Error::source`].
+    ///
+    /// Other variants are returned unchanged.
+    #[must_use]
+    pub fn with_source(mut self, error: impl StdError + Send + Sync + 'static) -> Self {
+        if let Self::Xml { source, .. } = &mut self {
+            *source = Some(ErrorSource::new(error));
+        }
+        self
+    }
+
+    /// Builds an I/O error for an operation on `path`.
+    ///
+    /// The underlying [`io::Error`] is reduced to its kind and message so the
+    /// error stays cloneable and comparable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use tei_core::TeiError;
+    ///
+    /// let source = io::Error::new(io::ErrorKind::NotFound, "no such file");
+    /// let error = TeiError::io("episode.xml", &source);
+    /// assert_eq!(error.to_string(), "I/O error on episode.xml: no such file");
+    /// ```
+    #[must_use]
+    pub fn io(path: impl Into<PathBuf>, error: &io::Error) -> Self {
+        Self::Io {
+            path: path.into(),
+            kind: error.kind(),
+            message: error.to_string(),
+        }
+    }
+
+    /// Builds a JSON processing error with the provided message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::TeiError;
+    ///
+    /// let error = TeiError::json("expected `,` or `}` at line 1 column 9");
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "JSON processing error: expected `,` or `}` at line 1 column 9",
+    /// );
+    /// ```
+    #[must_use]
+    pub fn json(message: impl Into<String>) -> Self {
+        Self::Json {
+            message: message.into(),
+        }
+    }
+
+    /// Builds a `MessagePack` processing error with the provided message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::TeiError;
+    ///
+    /// let error = TeiError::message_pack("unexpected end of file");
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "MessagePack processing error: unexpected end of file",
+    /// );
+    /// ```
+    #[must_use]
+    pub fn message_pack(message: impl Into<String>) -> Self {
+        Self::MessagePack {
+            message: message.into(),
+        }
+    }
+
+    /// Builds an error for input declared in an encoding the parser cannot
+    /// read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::TeiError;
+    ///
+    /// let error = TeiError::unsupported_encoding("Shift_JIS");
+    /// assert!(error.to_string().starts_with("unsupported character encoding `Shift_JIS`"));
+    /// ```
+    #[must_use]
+    pub fn unsupported_encoding(encoding: impl Into<String>) -> Self {
+        Self::UnsupportedEncoding {
+            encoding: encoding.into(),
+        }
+    }
+
+    /// Builds an error for input that exceeded a parser resource limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::{ResourceLimit, SourceLocation, TeiError};
+    ///
+    /// let error = TeiError::limit_exceeded(ResourceLimit::Depth, 64, None);
+    /// assert_eq!(error.to_string(), "nesting depth limit of 64 elements exceeded");
+    ///
+    /// let located = TeiError::limit_exceeded(
+    ///     ResourceLimit::EntityExpansions,
+    ///     10,
+    ///     Some(SourceLocation::new().with_offset(300)),
+    /// );
+    /// assert_eq!(
+    ///     located.to_string(),
+    ///     "entity expansion limit of 10 references exceeded (byte 300)",
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn limit_exceeded(
+        limit: ResourceLimit,
+        maximum: usize,
+        location: Option<SourceLocation>,
+    ) -> Self {
+        Self::LimitExceeded {
+            limit,
+            maximum,
+            location,
+        }
+    }
+
+    /// Returns the source location recorded on XML, import, export and limit
+    /// errors.
+    #[must_use]
+    pub const fn location(&self) -> Option<&SourceLocation> {
+        match self {
+            Self::Xml { location, .. }
+            | Self::Import { location, .. }
+            | Self::Export { location, .. }
+            | Self::LimitExceeded { location, .. } => location.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl ErrorCode for TeiError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::DocumentTitle(error) => error.code(),
+            Self::Header(error) => error.code(),
+            Self::Body(error) => error.code(),
+            Self::Identifier(error) => error.code(),
+            Self::Speaker(error) => error.code(),
+            Self::Xml { .. } => "TEI-XML",
+            Self::Json { .. } => "TEI-JSON",
+            Self::MessagePack { .. } => "TEI-MSGPACK",
+            Self::Import { .. } => "TEI-IMPORT",
+            Self::Export { .. } => "TEI-EXPORT",
+            Self::Io { .. } => "TEI-IO",
+            Self::UnsupportedEncoding { .. } => "TEI-ENCODING",
+            Self::LimitExceeded { .. } => "TEI-LIMIT",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_document_title_error_into_tei_error() {
+        let error: TeiError = DocumentTitleError::Empty.into();
+        assert!(matches!(
+            error,
+            TeiError::DocumentTitle(DocumentTitleError::Empty)
+        ));
+    }
+
+    #[test]
+    fn converts_body_content_error_into_tei_error() {
+        let error: TeiError = BodyContentError::EmptySpeaker.into();
+        assert!(matches!(
+            error,
+            TeiError::Body(BodyContentError::EmptySpeaker)
+        ));
+    }
+
+    #[test]
+    fn converts_header_validation_error_into_tei_error() {
+        let error: TeiError = HeaderValidationError::EmptyField { field: "header" }.into();
+
+        assert!(matches!(
+            error,
+            TeiError::Header(HeaderValidationError::EmptyField { field: "header" })
+        ));
+    }
+
+    #[test]
+    fn converts_identifier_validation_error_into_tei_error() {
+        let error: TeiError = IdentifierValidationError::Empty.into();
+
+        assert!(matches!(
+            error,
+            TeiError::Identifier(IdentifierValidationError::Empty)
+        ));
+    }
+
+    #[test]
+    fn converts_speaker_validation_error_into_tei_error() {
+        let error: TeiError = SpeakerValidationError::Empty.into();
+
+        assert!(matches!(
+            error,
+            TeiError::Speaker(SpeakerValidationError::Empty)
+        ));
+    }
+
+    #[test]
+    fn constructs_xml_error_from_message() {
+        let error = TeiError::xml("missing header");
+        let TeiError::Xml { message, .. } = error else {
+            panic!("expected XML error variant");
+        };
+
+        assert_eq!(message, "missing header");
+    }
+}
@@ -0,0 +1,70 @@
+//! The library error kept behind a [`TeiError`](crate::TeiError).
+
+use std::error::Error as StdError;
+use std::fmt;
+use std::sync::Arc;
+
+/// Shared handle to the library error behind a [`TeiError`](crate::TeiError).
+///
+/// Library errors are rarely cloneable or comparable, so the original is kept
+/// behind an [`Arc`] and compared by message, which lets
+/// [`TeiError`](crate::TeiError) remain `Clone` and `Eq`. Use
+/// [`ErrorSource::get`] to downcast to the concrete type.
+///
+/// # Examples
+///
+/// ```
+/// use std::error::Error;
+/// use std::io;
+///
+/// use tei_core::{ErrorSource, TeiError};
+///
+/// let error = TeiError::xml("unreadable").with_source(io::Error::other("disk on fire"));
+/// let source = error
+///     .source()
+///     .and_then(|cause| cause.downcast_ref::<ErrorSource>())
+///     .and_then(|cause| cause.get().downcast_ref::<io::Error>());
+/// assert_eq!(source.map(ToString::to_string).as_deref(), Some("disk on fire"));
+/// ```
+#[derive(Clone)]
+pub struct ErrorSource(Arc<dyn StdError + Send + Sync + 'static>);
+
+impl ErrorSource {
+    /// Wraps a library error.
+    #[must_use]
+    pub fn new(error: impl StdError + Send + Sync + 'static) -> Self {
+        Self(Arc::new(error))
+    }
+
+    /// Returns the original error.
+    #[must_use]
+    pub fn get(&self) -> &(dyn StdError + Send + Sync + 'static) {
+        self.0.as_ref()
+    }
+}
+
+impl fmt::Debug for ErrorSource {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, formatter)
+    }
+}
+
+impl fmt::Display for ErrorSource {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, formatter)
+    }
+}
+
+impl StdError for ErrorSource {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.0.source()
+    }
+}
+
+impl PartialEq for ErrorSource {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_string() == other.0.to_string()
+    }
+}
+
+impl Eq for ErrorSource {}
@@ -0,0 +1,224 @@
+//! Stable, machine-readable codes attached to every TEI error variant.
+//!
+//! Log aggregation and user interfaces should not depend on message wording,
+//! which may be refined over time. Each error type implements [`ErrorCode`] so
+//! callers can branch on a short identifier such as `TEI-TITLE-EMPTY` and opt
+//! into code-prefixed rendering through [`ErrorCode::display_with_code`].
+
+use std::fmt;
+
+/// Exposes a stable identifier for an error variant.
+///
+/// Codes are part of the public contract: once published they are never
+/// reassigned to a different failure, even when the human-readable message
+/// changes.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{DocumentTitleError, ErrorCode};
+///
+/// let error = DocumentTitleError::Empty;
+/// assert_eq!(error.code(), "TEI-TITLE-EMPTY");
+/// assert_eq!(
+///     error.display_with_code().to_string(),
+///     "[TEI-TITLE-EMPTY] document title may not be empty",
+/// );
+/// ```
+pub trait ErrorCode: fmt::Display {
+    /// Returns the stable code identifying the error variant.
+    fn code(&self) -> &'static str;
+
+    /// Returns a [`fmt::Display`] adapter that prefixes the message with the
+    /// error code.
+    ///
+    /// The plain [`fmt::Display`] implementation is left untouched so existing
+    /// message matching keeps working; callers opt into the coded form when
+    /// writing logs or rendering diagnostics.
+    fn display_with_code(&self) -> CodedDisplay<'_, Self>
+    where
+        Self: Sized,
+    {
+        CodedDisplay { error: self }
+    }
+}
+
+/// Renders an error as `[CODE] message`.
+///
+/// Produced by [`ErrorCode::display_with_code`].
+#[derive(Clone, Copy, Debug)]
+pub struct CodedDisplay<'a, E> {
+    error: &'a E,
+}
+
+impl<E> fmt::Display for CodedDisplay<'_, E>
+where
+    E: ErrorCode,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "[{}] {}", self.error.code(), self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BodyContentError, Container, DocumentTitleError, HeaderValidationError,
+        IdentifierValidationError, IdnoType, SourceLocation, SpeakerValidationError, TeiError,
+        TitleType,
+    };
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(TeiError::from(DocumentTitleError::Empty), "TEI-TITLE-EMPTY")]
+    #[case(
+        TeiError::from(DocumentTitleError::MissingMain),
+        "TEI-TITLE-MISSING-MAIN"
+    )]
+    #[case(
+        TeiError::from(DocumentTitleError::Duplicate { title_type: TitleType::Sub }),
+        "TEI-TITLE-DUPLICATE"
+    )]
+    #[case(
+        TeiError::from(DocumentTitleError::ControlCharacter { code_point: 0x07 }),
+        "TEI-TITLE-CONTROL-CHARACTER"
+    )]
+    #[case(
+        TeiError::from(DocumentTitleError::TooLong { max_length: 4, length: 8 }),
+        "TEI-TITLE-TOO-LONG"
+    )]
+    #[case(
+        TeiError::from(HeaderValidationError::EmptyField { field: "speaker" }),
+        "TEI-HEADER-EMPTY-FIELD"
+    )]
+    #[case(
+        TeiError::from(HeaderValidationError::InvalidIdno {
+            idno_type: IdnoType::Doi,
+            value: "not-a-doi".to_owned(),
+        }),
+        "TEI-HEADER-INVALID-IDNO"
+    )]
+    #[case(
+        TeiError::from(HeaderValidationError::InvalidDate {
+            value: "May 2024".to_owned(),
+        }),
+        "TEI-HEADER-INVALID-DATE"
+    )]
+    #[case(
+        TeiError::from(HeaderValidationError::UnresolvedCategory {
+            reference: "#news".to_owned(),
+        }),
+        "TEI-HEADER-UNRESOLVED-CATEGORY"
+    )]
+    #[case(
+        TeiError::from(HeaderValidationError::InvalidMimeType {
+            value: "mpeg".to_owned(),
+        }),
+        "TEI-HEADER-INVALID-MIME-TYPE"
+    )]
+    #[case(
+        TeiError::from(HeaderValidationError::InvalidDuration {
+            value: "an hour".to_owned(),
+        }),
+        "TEI-HEADER-INVALID-DURATION"
+    )]
+    #[case(
+        TeiError::from(HeaderValidationError::NonPositiveNumber { field: "episode number" }),
+        "TEI-HEADER-NON-POSITIVE-NUMBER"
+    )]
+    #[case(
+        TeiError::from(HeaderValidationError::ExtentMismatch {
+            measure: "utterances",
+            recorded: 3,
+            actual: 2,
+        }),
+        "TEI-HEADER-EXTENT-MISMATCH"
+    )]
+    #[case(
+        TeiError::from(BodyContentError::EmptyContent { container: Container::Paragraph }),
+        "TEI-BODY-EMPTY-CONTENT"
+    )]
+    #[case(
+        TeiError::from(BodyContentError::EmptySegment { container: Container::Utterance }),
+        "TEI-BODY-EMPTY-SEGMENT"
+    )]
+    #[case(
+        TeiError::from(BodyContentError::EmptySpeaker),
+        "TEI-BODY-EMPTY-SPEAKER"
+    )]
+    #[case(
+        TeiError::from(BodyContentError::EmptyIdentifier { container: Container::Paragraph }),
+        "TEI-BODY-EMPTY-IDENTIFIER"
+    )]
+    #[case(
+        TeiError::from(BodyContentError::InvalidIdentifier { container: Container::Utterance }),
+        "TEI-BODY-INVALID-IDENTIFIER"
+    )]
+    #[case(
+        TeiError::from(BodyContentError::DuplicateIdentifier { id: "u1".to_owned() }),
+        "TEI-BODY-DUPLICATE-IDENTIFIER"
+    )]
+    #[case(
+        TeiError::from(BodyContentError::UnknownSpeaker { speaker: "hera".to_owned() }),
+        "TEI-BODY-UNKNOWN-SPEAKER"
+    )]
+    #[case(
+        TeiError::from(BodyContentError::InvalidPauseDuration { value: "long".to_owned() }),
+        "TEI-BODY-INVALID-PAUSE-DURATION"
+    )]
+    #[case(
+        TeiError::from(BodyContentError::PausesExceedDuration),
+        "TEI-BODY-PAUSES-EXCEED-DURATION"
+    )]
+    #[case(
+        TeiError::from(BodyContentError::EmptyRawMarkup),
+        "TEI-BODY-EMPTY-RAW-MARKUP"
+    )]
+    #[case(TeiError::from(IdentifierValidationError::Empty), "TEI-ID-EMPTY")]
+    #[case(
+        TeiError::from(IdentifierValidationError::ContainsWhitespace),
+        "TEI-ID-WHITESPACE"
+    )]
+    #[case(TeiError::from(SpeakerValidationError::Empty), "TEI-SPEAKER-EMPTY")]
+    #[case(TeiError::xml("broken"), "TEI-XML")]
+    #[case(TeiError::json("broken"), "TEI-JSON")]
+    #[case(TeiError::message_pack("broken"), "TEI-MSGPACK")]
+    #[case(
+        TeiError::import_at("WebVTT", "broken", SourceLocation::new()),
+        "TEI-IMPORT"
+    )]
+    #[case(
+        TeiError::export_at("SRT", "broken", SourceLocation::new()),
+        "TEI-EXPORT"
+    )]
+    #[case(
+        TeiError::io("episode.xml", &std::io::Error::from(std::io::ErrorKind::NotFound)),
+        "TEI-IO"
+    )]
+    #[case(TeiError::unsupported_encoding("Shift_JIS"), "TEI-ENCODING")]
+    #[case(
+        TeiError::limit_exceeded(crate::ResourceLimit::Depth, 64, None),
+        "TEI-LIMIT"
+    )]
+    fn tei_error_exposes_stable_codes(#[case] error: TeiError, #[case] expected: &str) {
+        assert_eq!(error.code(), expected);
+    }
+
+    #[test]
+    fn coded_display_prefixes_message() {
+        let error = TeiError::xml("missing header");
+
+        assert_eq!(
+            error.display_with_code().to_string(),
+            "[TEI-XML] XML processing error: missing header"
+        );
+    }
+
+    #[test]
+    fn plain_display_omits_code() {
+        let error = TeiError::from(SpeakerValidationError::Empty);
+
+        assert_eq!(error.to_string(), "speaker references must not be empty");
+    }
+}
@@ -0,0 +1,162 @@
+//! Escaping for text and attribute values written as XML.
+//!
+//! `tei-xml` re-exports these helpers as `escape_xml_text` and
+//! `escape_xml_attr`, and the importers and exporters here that write markup
+//! by hand use them too, so every writer escapes the same characters.
+//! [`first_forbidden`] finds the characters XML 1.0 forbids, which no amount
+//! of escaping can carry.
+
+/// Encodes text for inclusion in XML content.
+///
+/// The helper escapes markup-significant characters to keep the resulting
+/// document well-formed. It intentionally mirrors the narrow surface required
+/// for text nodes; use [`attribute`] for attribute values.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::escape;
+///
+/// assert_eq!(escape::text("R&D <Test>"), "R&amp;D &lt;Test&gt;");
+/// ```
+#[must_use]
+pub fn text(input: &str) -> String {
+    if !input
+        .chars()
+        .any(|character| matches!(character, '&' | '<' | '>' | '"' | '\''))
+    {
+        return input.to_owned();
+    }
+
+    let mut escaped = String::with_capacity(input.len());
+
+    for character in input.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            other => escaped.push(other),
+        }
+    }
+
+    escaped
+}
+
+/// Encodes text for inclusion in a quoted XML attribute value.
+///
+/// Besides the characters [`text`] escapes, tabs, line feeds and carriage
+/// returns become character references. Parsers replace literal whitespace
+/// in attribute values with spaces, so only references survive a round trip.
+/// The result is safe inside either single or double quotes.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::escape;
+///
+/// assert_eq!(
+///     escape::attribute("Eiffel & \"Hera\"\n"),
+///     "Eiffel &amp; &quot;Hera&quot;&#10;"
+/// );
+/// ```
+#[must_use]
+pub fn attribute(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+
+    for character in input.chars() {
+        match attribute_reference(character) {
+            Some(reference) => escaped.push_str(reference),
+            None => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+/// Returns the reference [`attribute`] writes for `character`, if any.
+#[must_use]
+pub const fn attribute_reference(character: char) -> Option<&'static str> {
+    match character {
+        '&' => Some("&amp;"),
+        '<' => Some("&lt;"),
+        '>' => Some("&gt;"),
+        '"' => Some("&quot;"),
+        '\'' => Some("&apos;"),
+        '\t' => Some("&#9;"),
+        '\n' => Some("&#10;"),
+        '\r' => Some("&#13;"),
+        _ => None,
+    }
+}
+
+/// Returns the first character in `value` that XML 1.0 forbids.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::escape;
+///
+/// assert_eq!(escape::first_forbidden("Day 1.\u{7}"), Some('\u{7}'));
+/// assert_eq!(escape::first_forbidden("Day 1.\n"), None);
+/// ```
+#[must_use]
+pub fn first_forbidden(value: &str) -> Option<char> {
+    value
+        .chars()
+        .find(|character| is_forbidden_xml_char(*character))
+}
+
+fn is_forbidden_xml_char(character: char) -> bool {
+    let codepoint = u32::from(character);
+    is_surrogate(codepoint)
+        || is_forbidden_control_char(codepoint)
+        || is_noncharacter(codepoint)
+        || !is_in_xml_allowed_range(codepoint)
+}
+
+fn is_surrogate(codepoint: u32) -> bool {
+    (0xD800..=0xDFFF).contains(&codepoint)
+}
+
+const fn is_forbidden_control_char(codepoint: u32) -> bool {
+    codepoint < 0x20 && !is_allowed_control_char(codepoint)
+}
+
+const fn is_allowed_control_char(codepoint: u32) -> bool {
+    matches!(codepoint, 0x9 | 0xA | 0xD)
+}
+
+fn is_noncharacter(codepoint: u32) -> bool {
+    // Noncharacters (FFFE/FFFF, FDD0-FDEF, and last two of each plane)
+    codepoint == 0xFFFE
+        || codepoint == 0xFFFF
+        || (0xFDD0..=0xFDEF).contains(&codepoint)
+        || (codepoint >= 0x1_0000 && codepoint & 0xFFFE == 0xFFFE)
+}
+
+fn is_in_xml_allowed_range(codepoint: u32) -> bool {
+    // XML 1.0 permits: #x9 | #xA | #xD | [#x20-#xD7FF] | [#xE000-#xFFFD] | [#x10000-#x10FFFF]
+    matches!(codepoint, 0x9 | 0xA | 0xD)
+        || (0x20..=0xD7FF).contains(&codepoint)
+        || (0xE000..=0xFFFD).contains(&codepoint)
+        || (0x1_0000..=0x10_FFFF).contains(&codepoint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_forbidden_characters() {
+        assert!(first_forbidden("Valid").is_none());
+        assert_eq!(first_forbidden("\u{0}broken"), Some('\u{0}'));
+    }
+
+    #[test]
+    fn attribute_escaping_keeps_whitespace_as_references() {
+        assert_eq!(attribute("a\tb\r\nc'"), "a&#9;b&#13;&#10;c&apos;");
+        assert_eq!(text("a\tb"), "a\tb");
+    }
+}
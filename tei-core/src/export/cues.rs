@@ -0,0 +1,346 @@
+//! Timed, wrapped cues shared by the subtitle exporters.
+
+use std::time::Duration;
+
+use super::CueOptions;
+use crate::{Inline, SourceLocation, TeiDocument, TeiError, TeiPath, Timeline, Utterance};
+
+/// What a subtitle format can express.
+pub(super) struct Dialect {
+    /// Name used in error messages.
+    pub(super) format: &'static str,
+    /// Whether `&`, `<`, and `>` in text must be escaped.
+    pub(super) escape: bool,
+    /// How styling is written.
+    pub(super) markup: Markup,
+    /// Whether the speaker is written as a `Name:` label on the first cue
+    /// of each utterance.
+    pub(super) speaker_labels: bool,
+}
+
+/// The markup a format uses for styling.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum Markup {
+    /// `<i>`, `<b>`, and `<u>` tags only.
+    BasicTags,
+    /// Cue tags including `<lang>`.
+    Tags,
+    /// TTML `<span>`s referring to the styles the document declares, or
+    /// carrying `xml:lang`.
+    Spans,
+}
+
+/// Styling that survives into cue markup.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Style {
+    Tag(&'static str),
+    Lang(String),
+}
+
+impl Style {
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::Tag(name) => name,
+            Self::Lang(_) => "lang",
+        }
+    }
+
+    /// Returns the markup opening the style.
+    fn opening(&self, markup: Markup) -> String {
+        match (self, markup == Markup::Spans) {
+            (Self::Tag(name), false) => format!("<{name}>"),
+            (Self::Lang(tag), false) => format!("<lang {tag}>"),
+            (Self::Tag(name), true) => {
+                let style = match *name {
+                    "i" => "italic",
+                    "b" => "bold",
+                    _ => "underline",
+                };
+                format!("<span style=\"{style}\">")
+            }
+            (Self::Lang(tag), true) => {
+                format!("<span xml:lang=\"{}\">", crate::escape::attribute(tag))
+            }
+        }
+    }
+
+    fn closing(&self, markup: Markup) -> String {
+        if markup == Markup::Spans {
+            "</span>".to_owned()
+        } else {
+            format!("</{}>", self.name())
+        }
+    }
+}
+
+/// A run of text sharing one set of styles.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Fragment {
+    styles: Vec<Style>,
+    text: String,
+}
+
+/// Fragments written without spaces between them.
+type Word = Vec<Fragment>;
+
+/// A cue ready to be written.
+pub(super) struct Cue {
+    pub(super) start: Duration,
+    pub(super) end: Duration,
+    pub(super) speaker: Option<String>,
+    pub(super) lines: Vec<String>,
+    /// Path of the utterance the cue was cut from.
+    pub(super) path: TeiPath,
+}
+
+/// Splits the utterances of `document` into timed cues.
+pub(super) fn cues(
+    document: &TeiDocument,
+    dialect: &Dialect,
+    options: CueOptions,
+) -> Result<Vec<Cue>, TeiError> {
+    let timeline = document.text().timeline();
+    let mut cues = Vec::new();
+    for (index, utterance) in document.text().body().utterances().enumerate() {
+        let path = TeiPath::root()
+            .child("text")
+            .child("body")
+            .nth_child("u", index + 1);
+        let (start, end) = timing(utterance, timeline, dialect.format, &path)?;
+
+        let mut words = Vec::new();
+        let speaker = utterance.speaker().map(|name| name.as_str().to_owned());
+        if let Some(name) = speaker.as_deref().filter(|_| dialect.speaker_labels) {
+            words.push(vec![Fragment {
+                styles: Vec::new(),
+                text: format!("{name}:"),
+            }]);
+        }
+        let labels = words.len();
+        collect_words(utterance.content(), &mut Vec::new(), &mut words, true);
+        if words.len() == labels {
+            continue;
+        }
+
+        let lines = wrap(&words, options.max_line_length());
+        let chunks: Vec<_> = lines.chunks(options.max_lines()).collect();
+        let span = end.saturating_sub(start);
+        let total: usize = lines.iter().map(|line| line.1).sum();
+        let mut elapsed = 0;
+        for chunk in chunks {
+            let length: usize = chunk.iter().map(|line| line.1).sum();
+            cues.push(Cue {
+                start: start + share(span, elapsed, total),
+                end: start + share(span, elapsed + length, total),
+                speaker: speaker.clone(),
+                lines: chunk.iter().map(|line| render(&line.0, dialect)).collect(),
+                path: path.clone(),
+            });
+            elapsed += length;
+        }
+    }
+    Ok(cues)
+}
+
+/// Resolves the start and end of the utterance at `path`, reporting missing
+/// or inconsistent timing as an export error for `format`.
+pub(super) fn timing(
+    utterance: &Utterance,
+    timeline: Option<&Timeline>,
+    format: &'static str,
+    path: &TeiPath,
+) -> Result<(Duration, Duration), TeiError> {
+    let resolve = |pointer: Option<&str>, attribute: &str| {
+        let error = |message: String| {
+            TeiError::export_at(
+                format,
+                message,
+                SourceLocation::new().with_path(path.clone().attribute(attribute)),
+            )
+        };
+        let Some(value) = pointer else {
+            return Err(error(format!("utterance has no {attribute} time")));
+        };
+        timeline
+            .and_then(|known| known.offset(value))
+            .ok_or_else(|| error(format!("`{value}` is not a point on the timeline")))
+    };
+    let start = resolve(utterance.start(), "start")?;
+    let end = resolve(utterance.end(), "end")?;
+    if end < start {
+        return Err(TeiError::export_at(
+            format,
+            "utterance ends before it starts",
+            SourceLocation::new().with_path(path.clone()),
+        ));
+    }
+    Ok((start, end))
+}
+
+/// Appends the words of `content` to `words`, styled by `styles`.
+///
+/// `separated` reports whether whitespace preceded the content, so text
+/// following a styled run without a space stays in the same word.
+fn collect_words(
+    content: &[Inline],
+    styles: &mut Vec<Style>,
+    words: &mut Vec<Word>,
+    mut separated: bool,
+) -> bool {
+    for inline in content {
+        match inline {
+            Inline::Text(text) => separated = push_words(text, styles, words, separated),
+            Inline::Hi(hi) => {
+                let tag = match hi.rend() {
+                    Some("italic") => Some(Style::Tag("i")),
+                    Some("bold") => Some(Style::Tag("b")),
+                    Some("underline") => Some(Style::Tag("u")),
+                    _ => None,
+                };
+                let lang = hi.lang().map(|lang| Style::Lang(lang.as_str().to_owned()));
+                let added: Vec<_> = tag.into_iter().chain(lang).collect();
+                let depth = styles.len();
+                styles.extend(added);
+                separated = collect_words(hi.content(), styles, words, separated);
+                styles.truncate(depth);
+            }
+            Inline::Unclear(unclear) => {
+                separated = collect_words(unclear.content(), styles, words, separated);
+            }
+            Inline::Quote(quote) => {
+                separated = collect_words(quote.content(), styles, words, separated);
+            }
+            Inline::Pause(_) => separated = true,
+            Inline::Note(_) | Inline::Raw(_) => {}
+        }
+    }
+    separated
+}
+
+fn push_words(text: &str, styles: &[Style], words: &mut Vec<Word>, mut separated: bool) -> bool {
+    for (index, piece) in text.split(char::is_whitespace).enumerate() {
+        separated |= index > 0;
+        if piece.is_empty() {
+            continue;
+        }
+        let fragment = Fragment {
+            styles: styles.to_vec(),
+            text: piece.to_owned(),
+        };
+        match words.last_mut() {
+            Some(word) if !separated => word.push(fragment),
+            _ => words.push(vec![fragment]),
+        }
+        separated = false;
+    }
+    separated
+}
+
+/// Greedily wraps `words` into lines of at most `limit` characters,
+/// returning each line with its length.
+fn wrap(words: &[Word], limit: usize) -> Vec<(Vec<&Word>, usize)> {
+    let mut lines: Vec<(Vec<&Word>, usize)> = Vec::new();
+    for word in words {
+        let length = word
+            .iter()
+            .map(|fragment| fragment.text.chars().count())
+            .sum::<usize>();
+        match lines.last_mut() {
+            Some((line, used)) if *used + 1 + length <= limit => {
+                line.push(word);
+                *used += 1 + length;
+            }
+            _ => lines.push((vec![word], length)),
+        }
+    }
+    lines
+}
+
+/// Writes a line, opening and closing tags so it stands on its own.
+fn render(words: &[&Word], dialect: &Dialect) -> String {
+    let mut rendered = String::new();
+    let mut open: Vec<&Style> = Vec::new();
+    for (index, word) in words.iter().enumerate() {
+        for (position, fragment) in word.iter().enumerate() {
+            let wanted: Vec<&Style> = fragment
+                .styles
+                .iter()
+                .filter(|style| {
+                    dialect.markup != Markup::BasicTags || matches!(style, Style::Tag(_))
+                })
+                .collect();
+            close_unwanted(&mut rendered, &mut open, &wanted, dialect.markup);
+            if index > 0 && position == 0 {
+                rendered.push(' ');
+            }
+            open_wanted(&mut rendered, &mut open, &wanted, dialect.markup);
+            push_text(&mut rendered, &fragment.text, dialect.escape);
+        }
+    }
+    close_unwanted(&mut rendered, &mut open, &[], dialect.markup);
+    rendered
+}
+
+/// Closes the tags in `open` that `wanted` does not share.
+fn close_unwanted(
+    rendered: &mut String,
+    open: &mut Vec<&Style>,
+    wanted: &[&Style],
+    markup: Markup,
+) {
+    let kept = open
+        .iter()
+        .zip(wanted)
+        .take_while(|(current, next)| current == next)
+        .count();
+    for style in open.drain(kept..).rev() {
+        rendered.push_str(&style.closing(markup));
+    }
+}
+
+/// Opens the tags of `wanted` beyond those already `open`, which must share
+/// them as a prefix.
+fn open_wanted<'a>(
+    rendered: &mut String,
+    open: &mut Vec<&'a Style>,
+    wanted: &[&'a Style],
+    markup: Markup,
+) {
+    for style in wanted.get(open.len()..).unwrap_or_default() {
+        rendered.push_str(&style.opening(markup));
+        open.push(style);
+    }
+}
+
+/// Appends `text`, escaping the characters cue markup reserves when asked.
+pub(super) fn push_text(rendered: &mut String, text: &str, escape: bool) {
+    if !escape {
+        rendered.push_str(text);
+        return;
+    }
+    for character in text.chars() {
+        match character {
+            '&' => rendered.push_str("&amp;"),
+            '<' => rendered.push_str("&lt;"),
+            '>' => rendered.push_str("&gt;"),
+            other => rendered.push(other),
+        }
+    }
+}
+
+/// Returns `part / whole` of `span`, rounded down to the millisecond unless
+/// it is the whole span.
+fn share(span: Duration, part: usize, whole: usize) -> Duration {
+    if part >= whole {
+        return span;
+    }
+    let (Ok(part_wide), Ok(whole_wide)) = (u128::try_from(part), u128::try_from(whole)) else {
+        return span;
+    };
+    let millis = span
+        .as_millis()
+        .saturating_mul(part_wide)
+        .checked_div(whole_wide)
+        .unwrap_or_default();
+    Duration::from_millis(u64::try_from(millis).unwrap_or(u64::MAX))
+}
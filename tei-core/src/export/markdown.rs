@@ -0,0 +1,304 @@
+//! Markdown for publishing transcripts on the web.
+//!
+//! The title becomes the only heading, since the body is a flat run of
+//! paragraphs and utterances without divisions. Speakers are set in bold,
+//! `<hi>` runs become emphasis, and `<note>`s become footnotes listed after
+//! the text. A block holding nothing but a `<quote>` is set as a blockquote;
+//! a quotation inside running text keeps its place between quotation marks.
+
+use crate::{BodyBlock, Inline, TeiDocument, TimestampFormat};
+
+/// Characters escaped wherever they appear in text.
+const SPECIAL: [char; 10] = ['\\', '`', '*', '_', '[', ']', '<', '>', '#', '|'];
+
+/// Settings for [`markdown`].
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::TimestampFormat;
+/// use tei_core::export::MarkdownOptions;
+///
+/// let options = MarkdownOptions::new().with_timestamps(Some(TimestampFormat::default()));
+/// assert!(options.timestamps().is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct MarkdownOptions {
+    timestamps: Option<TimestampFormat>,
+}
+
+impl MarkdownOptions {
+    /// Creates options that leave times out.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { timestamps: None }
+    }
+
+    /// Opens each timed utterance with its start time as inline code,
+    /// written with `format`, or leaves times out when `None`.
+    #[must_use]
+    pub const fn with_timestamps(mut self, format: Option<TimestampFormat>) -> Self {
+        self.timestamps = format;
+        self
+    }
+
+    /// Returns the format used for utterance start times, if any.
+    #[must_use]
+    pub const fn timestamps(&self) -> Option<TimestampFormat> {
+        self.timestamps
+    }
+}
+
+/// Writes `document` as Markdown, one paragraph per block.
+///
+/// Text is escaped so that characters such as `*` and `[` stay literal, and
+/// raw markup is left out.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::export::{self, MarkdownOptions};
+/// use tei_core::{Hi, Inline, Note, TeiDocument, Utterance};
+///
+/// let mut document = TeiDocument::from_title_str("Wolf 359")?;
+/// let utterance = Utterance::from_inline(
+///     Some("Eiffel"),
+///     [
+///         Inline::text("Day 1 of the "),
+///         Inline::Hi(Hi::with_rend("italic", [Inline::text("mission")])),
+///         Inline::text("."),
+///         Inline::Note(Note::new([Inline::text("Recorded in orbit.")])),
+///     ],
+/// )?;
+/// document.text_mut().body_mut().push_utterance(utterance);
+///
+/// assert_eq!(
+///     export::markdown(&document, MarkdownOptions::new()),
+///     "# Wolf 359\n\n**Eiffel:** Day 1 of the *mission*.[^1]\n\n[^1]: Recorded in orbit.\n",
+/// );
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+#[must_use]
+pub fn markdown(document: &TeiDocument, options: MarkdownOptions) -> String {
+    let timeline = document.text().timeline();
+    let mut notes = Vec::new();
+    let mut sections = vec![format!("# {}", escape(document.title().as_str()))];
+    for block in document.text().body().blocks() {
+        let (label, content) = match block {
+            BodyBlock::Paragraph(paragraph) => (String::new(), paragraph.content()),
+            BodyBlock::Utterance(utterance) => {
+                let start = options.timestamps().and_then(|format| {
+                    let offset = utterance
+                        .start()
+                        .and_then(|point| timeline.and_then(|known| known.offset(point)))?;
+                    Some(format!("`{}`", format.format(offset)))
+                });
+                let speaker = utterance
+                    .speaker()
+                    .map(|speaker| format!("**{}:**", escape(speaker.as_str())));
+                let parts: Vec<String> = start.into_iter().chain(speaker).collect();
+                (parts.join(" "), utterance.content())
+            }
+            BodyBlock::Raw(_) => continue,
+        };
+        if let Some(section) = render_block(&label, content, &mut notes) {
+            sections.push(section);
+        }
+    }
+    if !notes.is_empty() {
+        let definitions: Vec<String> = notes
+            .iter()
+            .enumerate()
+            .map(|(index, note)| format!("[^{}]: {note}", index + 1))
+            .collect();
+        sections.push(definitions.join("\n"));
+    }
+    sections.join("\n\n") + "\n"
+}
+
+/// Renders one block, or `None` when it holds no visible text.
+fn render_block(label: &str, content: &[Inline], notes: &mut Vec<String>) -> Option<String> {
+    if let Some(quoted) = sole_quote(content) {
+        let text = collapse(&render(quoted, notes));
+        if text.is_empty() {
+            return None;
+        }
+        let quote = format!("> {text}");
+        return Some(if label.is_empty() {
+            quote
+        } else {
+            format!("{label}\n\n{quote}")
+        });
+    }
+    let text = collapse(&render(content, notes));
+    match (label.is_empty(), text.is_empty()) {
+        (_, true) => None,
+        (true, false) => Some(escape_line_start(&text)),
+        (false, false) => Some(format!("{label} {text}")),
+    }
+}
+
+/// Returns the content of the only `<quote>` in `content`, when nothing but
+/// whitespace surrounds it.
+fn sole_quote(content: &[Inline]) -> Option<&[Inline]> {
+    let mut visible = content
+        .iter()
+        .filter(|inline| !matches!(inline, Inline::Text(text) if text.trim().is_empty()));
+    match (visible.next(), visible.next()) {
+        (Some(Inline::Quote(quote)), None) => Some(quote.content()),
+        _ => None,
+    }
+}
+
+fn render(content: &[Inline], notes: &mut Vec<String>) -> String {
+    let mut text = String::new();
+    for inline in content {
+        match inline {
+            Inline::Text(value) => text.push_str(&escape(value)),
+            Inline::Hi(hi) => {
+                let marker = if hi.rend() == Some("bold") { "**" } else { "*" };
+                wrap(&mut text, &render(hi.content(), notes), marker, marker);
+            }
+            Inline::Unclear(unclear) => text.push_str(&render(unclear.content(), notes)),
+            Inline::Quote(quote) => {
+                wrap(
+                    &mut text,
+                    &render(quote.content(), notes),
+                    "\u{201c}",
+                    "\u{201d}",
+                );
+            }
+            Inline::Note(note) => {
+                let body = collapse(&render(note.content(), notes));
+                if !body.is_empty() {
+                    notes.push(body);
+                    text.truncate(text.trim_end().len());
+                    text.push_str("[^");
+                    text.push_str(&notes.len().to_string());
+                    text.push(']');
+                }
+            }
+            Inline::Pause(_) | Inline::Raw(_) => text.push(' '),
+        }
+    }
+    text
+}
+
+/// Appends `inner` between `open` and `close`, keeping its outer whitespace
+/// outside the markers so emphasis is not left dangling.
+fn wrap(text: &mut String, inner: &str, open: &str, close: &str) {
+    let core = collapse(inner);
+    if core.is_empty() {
+        text.push_str(inner);
+        return;
+    }
+    if inner.starts_with(char::is_whitespace) {
+        text.push(' ');
+    }
+    text.push_str(open);
+    text.push_str(&core);
+    text.push_str(close);
+    if inner.ends_with(char::is_whitespace) {
+        text.push(' ');
+    }
+}
+
+fn collapse(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        if SPECIAL.contains(&character) {
+            escaped.push('\\');
+        }
+        escaped.push(character);
+    }
+    escaped
+}
+
+/// Escapes a list marker opening an unlabelled paragraph, such as `- ` or
+/// `1. `, so the line stays a paragraph.
+fn escape_line_start(line: &str) -> String {
+    let rest = line.trim_start_matches(|c: char| c.is_ascii_digit());
+    let number = line.len() - rest.len();
+    let marker = if number > 0 {
+        rest.starts_with(['.', ')'])
+    } else {
+        rest.starts_with(['-', '+'])
+    };
+    if !marker {
+        return line.to_owned();
+    }
+    let (head, tail) = line.split_at(number);
+    format!("{head}\\{tail}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Hi, Note, P, Quote, Utterance};
+
+    #[test]
+    fn writes_blockquotes_footnotes_and_escapes() {
+        let mut note = Note::new([Inline::text("Signal lost.")]);
+        note.set_place("foot");
+        let mut document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        let body = document.text_mut().body_mut();
+        body.push_paragraph(
+            P::from_text_segments(["1. Previously *on* Wolf 359"]).expect("valid paragraph"),
+        );
+        body.push_utterance(
+            Utterance::from_inline(
+                Some("Hera"),
+                [Inline::Quote(Quote::new([Inline::text(
+                    "Is anyone out there?",
+                )]))],
+            )
+            .expect("valid utterance"),
+        );
+        body.push_utterance(
+            Utterance::from_inline(
+                Some("Eiffel"),
+                [
+                    Inline::text("She said "),
+                    Inline::Quote(Quote::new([Inline::text("hello")])),
+                    Inline::Hi(Hi::with_rend("bold", [Inline::text(" twice ")])),
+                    Inline::text("today. "),
+                    Inline::Note(note),
+                ],
+            )
+            .expect("valid utterance"),
+        );
+
+        assert_eq!(
+            markdown(&document, MarkdownOptions::new()),
+            "# Wolf 359\n\n\
+             1\\. Previously \\*on\\* Wolf 359\n\n\
+             **Hera:**\n\n> Is anyone out there?\n\n\
+             **Eiffel:** She said \u{201c}hello\u{201d} **twice** today.[^1]\n\n\
+             [^1]: Signal lost.\n"
+        );
+    }
+
+    #[test]
+    fn opens_timed_utterances_with_their_start() {
+        let mut timeline = crate::Timeline::new();
+        let start = timeline.add_offset(std::time::Duration::from_secs(65));
+        let end = timeline.add_offset(std::time::Duration::from_secs(70));
+        let mut utterance =
+            Utterance::from_text_segments(Some("Eiffel"), ["Day 1."]).expect("valid utterance");
+        utterance.set_timing(&start, &end);
+        let mut document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        document.text_mut().set_timeline(timeline);
+        document.text_mut().body_mut().push_utterance(utterance);
+
+        let options = MarkdownOptions::new().with_timestamps(Some(TimestampFormat::default()));
+
+        assert_eq!(
+            markdown(&document, options),
+            "# Wolf 359\n\n`01:05` **Eiffel:** Day 1.\n"
+        );
+    }
+}
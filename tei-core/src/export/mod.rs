@@ -0,0 +1,121 @@
+//! Conversion of timed TEI documents into other transcript formats.
+//!
+//! The subtitle exporters walk the utterances of a document in order and
+//! write one cue per utterance, timed by the `@start` and `@end` points of
+//! the [`Timeline`](crate::Timeline). Text that does not fit in the lines
+//! allowed by [`CueOptions`] is split across several cues, sharing the
+//! utterance's time in proportion to their length. Paragraphs and raw
+//! markup have no timing and are left out.
+//!
+//! [`ttml`] writes the same cues as TTML for broadcast captioning.
+//!
+//! [`plain_text`] writes the whole body as speaker-labelled lines instead,
+//! for pipelines that read transcripts rather than subtitles, and
+//! [`markdown`] writes it for publishing on show websites.
+//! [`utterance_rows`] lists the utterances as flat records for data frames
+//! and spreadsheets.
+
+mod cues;
+mod markdown;
+mod plain;
+mod rows;
+pub mod srt;
+pub mod textgrid;
+pub mod ttml;
+pub mod webvtt;
+
+pub use markdown::{MarkdownOptions, markdown};
+pub use plain::{PlainTextOptions, plain_text};
+pub use rows::{UtteranceRow, utterance_rows};
+
+use crate::TimestampFormat;
+
+/// Layout limits and the clock applied to every cue.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::TimestampFormat;
+/// use tei_core::export::CueOptions;
+///
+/// let options = CueOptions::new()
+///     .with_max_line_length(32)
+///     .with_max_lines(1)
+///     .with_timestamps(Some(TimestampFormat::default().with_fraction_digits(2)));
+/// assert_eq!((options.max_line_length(), options.max_lines()), (32, 1));
+/// assert!(options.timestamps().is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CueOptions {
+    max_line_length: usize,
+    max_lines: usize,
+    timestamps: Option<TimestampFormat>,
+}
+
+impl CueOptions {
+    /// Characters per line allowed by default, following common broadcast
+    /// subtitle guidelines.
+    pub const DEFAULT_MAX_LINE_LENGTH: usize = 42;
+
+    /// Lines per cue allowed by default.
+    pub const DEFAULT_MAX_LINES: usize = 2;
+
+    /// Creates options with the default limits and each format's own clock.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            max_line_length: Self::DEFAULT_MAX_LINE_LENGTH,
+            max_lines: Self::DEFAULT_MAX_LINES,
+            timestamps: None,
+        }
+    }
+
+    /// Sets how many characters a line may hold before wrapping; a word
+    /// longer than the limit gets a line of its own. Zero is treated as one.
+    #[must_use]
+    pub const fn with_max_line_length(mut self, length: usize) -> Self {
+        self.max_line_length = if length == 0 { 1 } else { length };
+        self
+    }
+
+    /// Returns how many characters a line may hold.
+    #[must_use]
+    pub const fn max_line_length(&self) -> usize {
+        self.max_line_length
+    }
+
+    /// Sets how many lines a cue may hold before the text moves to a new
+    /// cue. Zero is treated as one.
+    #[must_use]
+    pub const fn with_max_lines(mut self, lines: usize) -> Self {
+        self.max_lines = if lines == 0 { 1 } else { lines };
+        self
+    }
+
+    /// Returns how many lines a cue may hold.
+    #[must_use]
+    pub const fn max_lines(&self) -> usize {
+        self.max_lines
+    }
+
+    /// Writes cue times with `format`, or with the clock the format's
+    /// specification expects when `None`. The override is written as given,
+    /// so a format a player cannot read yields a file it rejects.
+    #[must_use]
+    pub const fn with_timestamps(mut self, format: Option<TimestampFormat>) -> Self {
+        self.timestamps = format;
+        self
+    }
+
+    /// Returns the clock that overrides each format's own, if any.
+    #[must_use]
+    pub const fn timestamps(&self) -> Option<TimestampFormat> {
+        self.timestamps
+    }
+}
+
+impl Default for CueOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
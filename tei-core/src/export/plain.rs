@@ -0,0 +1,212 @@
+//! Speaker-labelled plain text, one block per line.
+
+use crate::header::parse_xsd_duration;
+use crate::text::format_seconds;
+use crate::{BodyBlock, Inline, TeiDocument, TimestampFormat};
+
+/// Settings for [`plain_text`].
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::TimestampFormat;
+/// use tei_core::export::PlainTextOptions;
+///
+/// let options = PlainTextOptions::new()
+///     .with_timestamps(Some(TimestampFormat::default()))
+///     .with_pauses(true);
+/// assert!(options.timestamps().is_some() && options.pauses());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PlainTextOptions {
+    timestamps: Option<TimestampFormat>,
+    pauses: bool,
+}
+
+impl PlainTextOptions {
+    /// Creates options that write neither timestamps nor pauses.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            timestamps: None,
+            pauses: false,
+        }
+    }
+
+    /// Opens each timed utterance with its start time in brackets, written
+    /// with `format`, or leaves times out when `None`.
+    #[must_use]
+    pub const fn with_timestamps(mut self, format: Option<TimestampFormat>) -> Self {
+        self.timestamps = format;
+        self
+    }
+
+    /// Returns how start times are written, if at all.
+    #[must_use]
+    pub const fn timestamps(&self) -> Option<TimestampFormat> {
+        self.timestamps
+    }
+
+    /// Writes each `<pause/>` as `[pause]`, or `[pause 1.5s]` when its
+    /// duration is known, instead of leaving it out.
+    #[must_use]
+    pub const fn with_pauses(mut self, pauses: bool) -> Self {
+        self.pauses = pauses;
+        self
+    }
+
+    /// Returns whether pauses are written.
+    #[must_use]
+    pub const fn pauses(&self) -> bool {
+        self.pauses
+    }
+}
+
+/// Writes `document` as plain text, one paragraph or utterance per line.
+///
+/// Utterances open with their speaker, as in `Eiffel: Day 1.`, and styling
+/// is dropped, so the text suits tools that expect a transcript rather than
+/// markup. Runs of whitespace collapse to single spaces, and notes and raw
+/// markup are left out.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::export::{self, PlainTextOptions};
+/// use tei_core::{Inline, TeiDocument, Utterance};
+///
+/// let mut document = TeiDocument::from_title_str("Wolf 359")?;
+/// let utterance = Utterance::from_inline(
+///     Some("Eiffel"),
+///     [Inline::text("Day 1 of the"), Inline::pause(), Inline::text("mission.")],
+/// )?;
+/// document.text_mut().body_mut().push_utterance(utterance);
+///
+/// let options = PlainTextOptions::new().with_pauses(true);
+/// assert_eq!(
+///     export::plain_text(&document, options),
+///     "Eiffel: Day 1 of the [pause] mission.\n",
+/// );
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+#[must_use]
+pub fn plain_text(document: &TeiDocument, options: PlainTextOptions) -> String {
+    let timeline = document.text().timeline();
+    let mut lines = Vec::new();
+    for block in document.text().body().blocks() {
+        let (prefix, content) = match block {
+            BodyBlock::Paragraph(paragraph) => (String::new(), paragraph.content()),
+            BodyBlock::Utterance(utterance) => {
+                let start = options.timestamps().and_then(|format| {
+                    let offset = utterance
+                        .start()
+                        .and_then(|point| timeline.and_then(|known| known.offset(point)))?;
+                    Some(format!("[{}] ", format.format(offset)))
+                });
+                let label = utterance
+                    .speaker()
+                    .map(|speaker| format!("{}: ", speaker.as_str()));
+                (
+                    [start, label].into_iter().flatten().collect(),
+                    utterance.content(),
+                )
+            }
+            BodyBlock::Raw(_) => continue,
+        };
+        let text = line(content, options);
+        if !text.is_empty() {
+            lines.push([prefix, text, "\n".to_owned()].concat());
+        }
+    }
+    lines.concat()
+}
+
+/// Renders `content` as one line of text with runs of whitespace collapsed.
+pub(super) fn line(content: &[Inline], options: PlainTextOptions) -> String {
+    let mut text = String::new();
+    push_inline(&mut text, content, options);
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn push_inline(text: &mut String, content: &[Inline], options: PlainTextOptions) {
+    for inline in content {
+        match inline {
+            Inline::Text(value) => text.push_str(value),
+            Inline::Hi(hi) => push_inline(text, hi.content(), options),
+            Inline::Unclear(unclear) => push_inline(text, unclear.content(), options),
+            Inline::Quote(quote) => push_inline(text, quote.content(), options),
+            Inline::Pause(pause) if options.pauses() => {
+                match pause.duration().and_then(parse_xsd_duration) {
+                    Some(duration) => {
+                        text.push_str(" [pause ");
+                        text.push_str(&format_seconds(duration));
+                        text.push_str("s] ");
+                    }
+                    None => text.push_str(" [pause] "),
+                }
+            }
+            Inline::Pause(_) | Inline::Note(_) | Inline::Raw(_) => text.push(' '),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{Hi, P, Pause, Timeline, Utterance};
+
+    fn document() -> TeiDocument {
+        let mut timeline = Timeline::new();
+        let start = timeline.add_offset(Duration::from_millis(65_500));
+        let end = timeline.add_offset(Duration::from_secs(70));
+        let mut pause = Pause::new();
+        pause.set_duration("PT1.5S");
+        let mut utterance = Utterance::from_inline(
+            Some("Eiffel"),
+            [
+                Inline::text("Day 1 of\n the"),
+                Inline::Pause(pause),
+                Inline::Hi(Hi::new([Inline::text("mission")])),
+                Inline::text("."),
+            ],
+        )
+        .expect("valid utterance");
+        utterance.set_timing(&start, &end);
+
+        let mut document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        document.text_mut().set_timeline(timeline);
+        let body = document.text_mut().body_mut();
+        body.push_paragraph(P::from_text_segments(["Previously."]).expect("valid paragraph"));
+        body.push_utterance(utterance);
+        body.push_utterance(
+            Utterance::from_text_segments(None::<&str>, ["Static."]).expect("valid utterance"),
+        );
+        document
+    }
+
+    #[test]
+    fn writes_labelled_lines() {
+        let text = plain_text(&document(), PlainTextOptions::new());
+
+        assert_eq!(
+            text,
+            "Previously.\nEiffel: Day 1 of the mission.\nStatic.\n"
+        );
+    }
+
+    #[test]
+    fn writes_timestamps_and_pauses_when_asked() {
+        let options = PlainTextOptions::new()
+            .with_timestamps(Some(TimestampFormat::default()))
+            .with_pauses(true);
+
+        let text = plain_text(&document(), options);
+
+        assert_eq!(
+            text,
+            "Previously.\n[01:05] Eiffel: Day 1 of the [pause 1.5s] mission.\nStatic.\n"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+//! Utterances as flat records for tabular tools.
+
+use std::time::Duration;
+
+use super::PlainTextOptions;
+use super::plain::line;
+use crate::{BodyBlock, TeiDocument};
+
+/// One utterance reduced to the columns analysts tabulate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UtteranceRow {
+    speaker: Option<String>,
+    start: Option<Duration>,
+    end: Option<Duration>,
+    text: String,
+    id: Option<String>,
+}
+
+impl UtteranceRow {
+    /// Returns the speaker reference in `@who`.
+    #[must_use]
+    pub fn speaker(&self) -> Option<&str> {
+        self.speaker.as_deref()
+    }
+
+    /// Returns the start offset, when `@start` resolves on the timeline.
+    #[must_use]
+    pub const fn start(&self) -> Option<Duration> {
+        self.start
+    }
+
+    /// Returns the end offset, when `@end` resolves on the timeline.
+    #[must_use]
+    pub const fn end(&self) -> Option<Duration> {
+        self.end
+    }
+
+    /// Returns the text without markup, as [`plain_text`](super::plain_text)
+    /// writes it.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the `xml:id`.
+    #[must_use]
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+/// Lists the utterances of `document` in order, one row each.
+///
+/// Paragraphs and raw markup are left out. Times that are missing, or that
+/// point at no entry of the timeline, are `None` rather than errors, so
+/// untimed transcripts still tabulate.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::export;
+/// use tei_core::{TeiDocument, Utterance};
+///
+/// let mut document = TeiDocument::from_title_str("Wolf 359")?;
+/// let utterance = Utterance::from_text_segments(Some("Eiffel"), ["Day 1."])?;
+/// document.text_mut().body_mut().push_utterance(utterance);
+///
+/// let rows = export::utterance_rows(&document);
+/// assert_eq!((rows[0].speaker(), rows[0].text()), (Some("Eiffel"), "Day 1."));
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+#[must_use]
+pub fn utterance_rows(document: &TeiDocument) -> Vec<UtteranceRow> {
+    let timeline = document.text().timeline();
+    let offset = |point: Option<&str>| point.and_then(|known| timeline?.offset(known));
+    document
+        .text()
+        .body()
+        .blocks()
+        .filter_map(|block| match block {
+            BodyBlock::Utterance(utterance) => Some(UtteranceRow {
+                speaker: utterance
+                    .speaker()
+                    .map(|speaker| speaker.as_str().to_owned()),
+                start: offset(utterance.start()),
+                end: offset(utterance.end()),
+                text: line(utterance.content(), PlainTextOptions::new()),
+                id: utterance.id().map(ToString::to_string),
+            }),
+            BodyBlock::Paragraph(_) | BodyBlock::Raw(_) => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Hi, Inline, P, Timeline, Utterance};
+
+    #[test]
+    fn lists_timed_and_untimed_utterances() {
+        let mut timeline = Timeline::new();
+        let start = timeline.add_offset(Duration::from_millis(1_500));
+        let end = timeline.add_offset(Duration::from_secs(4));
+        let mut timed = Utterance::from_inline(
+            Some("Eiffel"),
+            [
+                Inline::text("Day 1 of the "),
+                Inline::Hi(Hi::new([Inline::text("mission")])),
+                Inline::text("."),
+            ],
+        )
+        .expect("valid utterance");
+        timed.set_timing(&start, &end);
+        timed.set_id("u1").expect("valid identifier");
+
+        let mut document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        document.text_mut().set_timeline(timeline);
+        let body = document.text_mut().body_mut();
+        body.push_paragraph(P::from_text_segments(["Previously."]).expect("valid paragraph"));
+        body.push_utterance(timed);
+        body.push_utterance(
+            Utterance::from_text_segments(None::<&str>, ["Static."]).expect("valid utterance"),
+        );
+
+        let rows = utterance_rows(&document);
+
+        assert_eq!(rows.len(), 2);
+        let first = rows.first().expect("timed row");
+        assert_eq!(
+            (first.speaker(), first.text(), first.id()),
+            (Some("Eiffel"), "Day 1 of the mission.", Some("u1"))
+        );
+        assert_eq!(
+            (first.start(), first.end()),
+            (
+                Some(Duration::from_millis(1_500)),
+                Some(Duration::from_secs(4))
+            )
+        );
+        let second = rows.get(1).expect("untimed row");
+        assert_eq!(
+            (
+                second.speaker(),
+                second.start(),
+                second.end(),
+                second.text()
+            ),
+            (None, None, None, "Static.")
+        );
+    }
+}
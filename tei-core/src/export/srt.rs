@@ -0,0 +1,117 @@
+//! Timed utterances as SRT subtitles.
+//!
+//! SRT has no markup for speakers, so the first cue of each utterance opens
+//! with a label such as `Hera:`, which [`import::srt`](crate::import::srt)
+//! reads back. Cues split from one utterance carry no label, letting the
+//! importer merge them again. `<hi>` with `rend` set to `italic`, `bold`, or
+//! `underline` becomes `<i>`, `<b>`, or `<u>`; other styling is dropped.
+
+use super::CueOptions;
+use super::cues::{self, Dialect, Markup};
+use crate::{TeiDocument, TeiError, TimestampFormat, TimestampStyle};
+
+const DIALECT: Dialect = Dialect {
+    format: "SRT",
+    escape: false,
+    markup: Markup::BasicTags,
+    speaker_labels: true,
+};
+
+/// Writes the utterances of `document` as SRT using the default
+/// [`CueOptions`].
+///
+/// # Errors
+///
+/// Returns [`TeiError::Export`] when an utterance lacks `@start` or `@end`,
+/// points at a time missing from the timeline, or ends before it starts.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::export::srt;
+/// use tei_core::import;
+///
+/// let source = "1\n00:00:01,000 --> 00:00:03,500\nHERA: Hello, <i>Eiffel</i>.\n";
+/// let document = import::srt::parse(source, "Wolf 359")?;
+///
+/// assert_eq!(srt::render(&document)?, format!("{source}\n"));
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn render(document: &TeiDocument) -> Result<String, TeiError> {
+    render_with_options(document, CueOptions::new())
+}
+
+/// Writes the utterances of `document` as SRT, wrapping and splitting cues
+/// to fit `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`render`].
+pub fn render_with_options(
+    document: &TeiDocument,
+    options: CueOptions,
+) -> Result<String, TeiError> {
+    let clock = options.timestamps().unwrap_or_else(|| {
+        TimestampFormat::default()
+            .with_style(TimestampStyle::HoursMinutesSeconds)
+            .with_decimal_separator(',')
+            .with_fraction_digits(3)
+    });
+    let blocks: Vec<String> = cues::cues(document, &DIALECT, options)?
+        .iter()
+        .enumerate()
+        .map(|(index, cue)| {
+            format!(
+                "{}\n{} --> {}\n{}\n\n",
+                index + 1,
+                clock.format(cue.start),
+                clock.format(cue.end),
+                cue.lines.join("\n"),
+            )
+        })
+        .collect();
+    Ok(blocks.concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import;
+
+    #[test]
+    fn labels_only_the_first_cue_of_an_utterance() {
+        let source = "1\n00:00:01,000 --> 00:00:05,000\n\
+                      EIFFEL: Day 1 of the mission and nothing has gone wrong yet.\n";
+        let document = import::srt::parse(source, "Wolf 359").expect("source imports");
+        let options = CueOptions::new().with_max_line_length(24).with_max_lines(1);
+
+        let output = render_with_options(&document, options).expect("renders");
+
+        assert_eq!(
+            output,
+            "1\n00:00:01,000 --> 00:00:02,379\nEIFFEL: Day 1 of the\n\n\
+             2\n00:00:02,379 --> 00:00:03,965\nmission and nothing has\n\n\
+             3\n00:00:03,965 --> 00:00:05,000\ngone wrong yet.\n\n"
+        );
+        let reimported = import::srt::parse(&output, "Wolf 359").expect("output imports");
+        assert_eq!(reimported.text().body().utterances().count(), 1);
+    }
+
+    #[test]
+    fn reports_points_missing_from_the_timeline() {
+        let source = "1\n00:00:01,000 --> 00:00:02,000\nHello.\n";
+        let mut document = import::srt::parse(source, "Wolf 359").expect("source imports");
+        document.text_mut().clear_timeline();
+
+        let error = render(&document).expect_err("unresolved timing must fail");
+
+        assert!(
+            matches!(error, TeiError::Export { format: "SRT", .. }),
+            "{error:?}"
+        );
+        assert!(
+            error.to_string().contains("`#T1` is not a point"),
+            "{error}"
+        );
+    }
+}
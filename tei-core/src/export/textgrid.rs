@@ -0,0 +1,244 @@
+//! Timed utterances as a Praat `TextGrid`.
+//!
+//! Each speaker gets an interval tier of their own, in the order they first
+//! speak, holding one interval per utterance; utterances without a speaker
+//! share a tier named `transcript`. Praat expects every tier to cover the
+//! whole recording, so the gaps between utterances are filled with blank
+//! intervals, which [`import::textgrid`](crate::import::textgrid) skips when
+//! reading the grid back. Text is written without markup, as in
+//! [`plain_text`](super::plain_text).
+
+use std::time::Duration;
+
+use super::PlainTextOptions;
+use super::cues::timing;
+use super::plain::line;
+use crate::text::format_seconds;
+use crate::{SourceLocation, TeiDocument, TeiError, TeiPath};
+
+const FORMAT: &str = "TextGrid";
+
+/// Name of the tier holding utterances without a speaker.
+const UNLABELLED_TIER: &str = "transcript";
+
+/// One utterance placed on a tier.
+struct Interval {
+    start: Duration,
+    end: Duration,
+    text: String,
+    path: TeiPath,
+}
+
+/// Writes the utterances of `document` as a `TextGrid` in Praat's long text
+/// format.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Export`] when an utterance lacks `@start` or `@end`,
+/// points at a time missing from the timeline, ends before it starts, or
+/// overlaps an earlier utterance by the same speaker, which one tier cannot
+/// hold.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::export::textgrid;
+/// use tei_core::import;
+///
+/// let document = import::webvtt::parse(
+///     "WEBVTT\n\n00:01.000 --> 00:02.500\n<v Eiffel>Day 1.\n",
+///     "Wolf 359",
+/// )?;
+/// let grid = textgrid::render(&document)?;
+///
+/// assert!(grid.contains("name = \"Eiffel\""));
+/// assert!(grid.contains("text = \"Day 1.\""));
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn render(document: &TeiDocument) -> Result<String, TeiError> {
+    let timeline = document.text().timeline();
+    let mut tiers: Vec<(String, Vec<Interval>)> = Vec::new();
+    for (index, utterance) in document.text().body().utterances().enumerate() {
+        let path = TeiPath::root()
+            .child("text")
+            .child("body")
+            .nth_child("u", index + 1);
+        let (start, end) = timing(utterance, timeline, FORMAT, &path)?;
+        let text = line(utterance.content(), PlainTextOptions::new());
+        if text.is_empty() {
+            continue;
+        }
+        let name = utterance
+            .speaker()
+            .map_or(UNLABELLED_TIER, |speaker| speaker.as_str());
+        let interval = Interval {
+            start,
+            end,
+            text,
+            path,
+        };
+        match tiers.iter_mut().find(|(tier, _)| tier == name) {
+            Some((_, intervals)) => intervals.push(interval),
+            None => tiers.push((name.to_owned(), vec![interval])),
+        }
+    }
+
+    let xmax = tiers
+        .iter()
+        .flat_map(|(_, intervals)| intervals.iter().map(|interval| interval.end))
+        .max()
+        .unwrap_or_default();
+    let mut lines = vec![
+        "File type = \"ooTextFile\"".to_owned(),
+        "Object class = \"TextGrid\"".to_owned(),
+        String::new(),
+        "xmin = 0".to_owned(),
+        format!("xmax = {}", format_seconds(xmax)),
+        "tiers? <exists>".to_owned(),
+        format!("size = {}", tiers.len()),
+        "item []:".to_owned(),
+    ];
+    for (number, (name, mut intervals)) in tiers.into_iter().enumerate() {
+        intervals.sort_by_key(|interval| interval.start);
+        let filled = fill_gaps(intervals, xmax)?;
+        lines.extend([
+            format!("    item [{}]:", number + 1),
+            "        class = \"IntervalTier\"".to_owned(),
+            format!("        name = {}", quote(&name)),
+            "        xmin = 0".to_owned(),
+            format!("        xmax = {}", format_seconds(xmax)),
+            format!("        intervals: size = {}", filled.len()),
+        ]);
+        for (position, (start, end, text)) in filled.iter().enumerate() {
+            lines.extend([
+                format!("        intervals [{}]:", position + 1),
+                format!("            xmin = {}", format_seconds(*start)),
+                format!("            xmax = {}", format_seconds(*end)),
+                format!("            text = {}", quote(text)),
+            ]);
+        }
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Lays sorted `intervals` end to end from zero to `xmax`, with blank
+/// intervals in the gaps.
+fn fill_gaps(
+    intervals: Vec<Interval>,
+    xmax: Duration,
+) -> Result<Vec<(Duration, Duration, String)>, TeiError> {
+    let mut filled = Vec::with_capacity(intervals.len() * 2 + 1);
+    let mut cursor = Duration::ZERO;
+    for interval in intervals {
+        if interval.start < cursor {
+            return Err(TeiError::export_at(
+                FORMAT,
+                "utterance overlaps an earlier one by the same speaker",
+                SourceLocation::new().with_path(interval.path),
+            ));
+        }
+        if interval.start > cursor {
+            filled.push((cursor, interval.start, String::new()));
+        }
+        filled.push((interval.start, interval.end, interval.text));
+        cursor = interval.end;
+    }
+    if cursor < xmax || filled.is_empty() {
+        filled.push((cursor, xmax, String::new()));
+    }
+    Ok(filled)
+}
+
+/// Quotes `text` as a `TextGrid` string, doubling quote marks.
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Timeline, Utterance, import};
+
+    fn timed(
+        speaker: Option<&str>,
+        text: &str,
+        timeline: &mut Timeline,
+        span: (u64, u64),
+    ) -> Utterance {
+        let start = timeline.add_offset(Duration::from_millis(span.0));
+        let end = timeline.add_offset(Duration::from_millis(span.1));
+        let mut utterance =
+            Utterance::from_text_segments(speaker, [text]).expect("valid utterance");
+        utterance.set_timing(&start, &end);
+        utterance
+    }
+
+    #[test]
+    fn writes_a_tier_per_speaker_and_reads_it_back() {
+        let mut timeline = Timeline::new();
+        let first = timed(Some("Eiffel"), "Day 1.", &mut timeline, (500, 1_500));
+        let second = timed(
+            Some("Hera"),
+            "Hello, \"Eiffel\".",
+            &mut timeline,
+            (1_500, 3_000),
+        );
+        let third = timed(Some("Eiffel"), "Hi.", &mut timeline, (3_000, 3_250));
+        let mut document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        document.text_mut().set_timeline(timeline);
+        let body = document.text_mut().body_mut();
+        body.push_utterance(first);
+        body.push_utterance(second);
+        body.push_utterance(third);
+
+        let grid = render(&document).expect("document should export");
+
+        let hera = [
+            "    item [2]:",
+            "        class = \"IntervalTier\"",
+            "        name = \"Hera\"",
+            "        xmin = 0",
+            "        xmax = 3.25",
+            "        intervals: size = 3",
+            "        intervals [1]:",
+            "            xmin = 0",
+            "            xmax = 1.5",
+            "            text = \"\"",
+            "        intervals [2]:",
+            "            xmin = 1.5",
+            "            xmax = 3",
+            "            text = \"Hello, \"\"Eiffel\"\".\"",
+        ]
+        .join("\n");
+        assert!(grid.contains(&hera), "{grid}");
+        let restored = import::textgrid::parse(&grid, "Wolf 359").expect("grid should import");
+        let texts: Vec<_> = restored
+            .text()
+            .body()
+            .utterances()
+            .map(|utterance| line(utterance.content(), PlainTextOptions::new()))
+            .collect();
+        assert_eq!(texts, ["Day 1.", "Hello, \"Eiffel\".", "Hi."]);
+    }
+
+    #[test]
+    fn rejects_overlapping_utterances_by_one_speaker() {
+        let mut timeline = Timeline::new();
+        let first = timed(Some("Eiffel"), "Day 1.", &mut timeline, (0, 2_000));
+        let second = timed(Some("Eiffel"), "Again.", &mut timeline, (1_000, 3_000));
+        let mut document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        document.text_mut().set_timeline(timeline);
+        document.text_mut().body_mut().extend([
+            crate::BodyBlock::Utterance(first),
+            crate::BodyBlock::Utterance(second),
+        ]);
+
+        let error = render(&document).expect_err("overlap must be rejected");
+
+        let located = error.location().and_then(SourceLocation::path);
+        assert_eq!(
+            located.map(ToString::to_string).as_deref(),
+            Some("/TEI/text/body/u[2]")
+        );
+    }
+}
@@ -0,0 +1,309 @@
+//! Timed utterances as Timed Text Markup Language (TTML 1, also known as
+//! DFXP) captions.
+//!
+//! Each cue becomes a `<p>` in a single `<div>`, its lines separated by
+//! `<br/>`. Speakers are declared once as `<ttm:agent>`s in the head and
+//! referred to from each paragraph through `ttm:agent`. The head also
+//! declares a default style, one style per `<hi>` rendering, and a bottom
+//! region, as stubs for captioning workflows to restyle: `<hi>` with `rend`
+//! set to `italic`, `bold`, or `underline` becomes a `<span>` using the
+//! matching style, and a declared `xml:lang` becomes a `<span>` with
+//! `xml:lang`.
+//!
+//! Text is escaped as the XML emitter escapes it, and characters XML 1.0
+//! forbids are rejected with the path of the markup holding them.
+
+use super::CueOptions;
+use super::cues::{self, Cue, Dialect, Markup};
+use crate::escape;
+use crate::{SourceLocation, TeiDocument, TeiError, TeiPath, TimestampFormat, TimestampStyle};
+
+const DIALECT: Dialect = Dialect {
+    format: "TTML",
+    escape: true,
+    markup: Markup::Spans,
+    speaker_labels: false,
+};
+
+/// The styling and layout declared in every document's head.
+const STUBS: [&str; 9] = [
+    "    <styling>",
+    "      <style xml:id=\"default\" tts:color=\"white\" tts:textAlign=\"center\"/>",
+    "      <style xml:id=\"italic\" tts:fontStyle=\"italic\"/>",
+    "      <style xml:id=\"bold\" tts:fontWeight=\"bold\"/>",
+    "      <style xml:id=\"underline\" tts:textDecoration=\"underline\"/>",
+    "    </styling>",
+    "    <layout>",
+    "      <region xml:id=\"bottom\" tts:origin=\"10% 80%\" tts:extent=\"80% 15%\" \
+     tts:displayAlign=\"after\"/>",
+    "    </layout>",
+];
+
+/// Writes the utterances of `document` as TTML using the default
+/// [`CueOptions`].
+///
+/// # Errors
+///
+/// Returns [`TeiError::Export`] when an utterance lacks `@start` or `@end`,
+/// points at a time missing from the timeline, or ends before it starts, or
+/// when the text holds a character XML 1.0 forbids.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::export::ttml;
+/// use tei_core::import;
+///
+/// let source = "WEBVTT\n\n00:01.000 --> 00:03.500\n<v Eiffel>Day 1 of the <i>mission</i>.\n";
+/// let document = import::webvtt::parse(source, "Wolf 359")?;
+/// let captions = ttml::render(&document)?;
+///
+/// assert!(captions.contains(
+///     "<p begin=\"00:00:01.000\" end=\"00:00:03.500\" ttm:agent=\"speaker1\">\
+///      Day 1 of the <span style=\"italic\">mission</span>.</p>"
+/// ));
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn render(document: &TeiDocument) -> Result<String, TeiError> {
+    render_with_options(document, CueOptions::new())
+}
+
+/// Writes the utterances of `document` as TTML, wrapping and splitting cues
+/// to fit `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`render`].
+pub fn render_with_options(
+    document: &TeiDocument,
+    options: CueOptions,
+) -> Result<String, TeiError> {
+    let clock = options.timestamps().unwrap_or_else(|| {
+        TimestampFormat::default()
+            .with_style(TimestampStyle::HoursMinutesSeconds)
+            .with_fraction_digits(3)
+    });
+    let cues = cues::cues(document, &DIALECT, options)?;
+    let mut speakers: Vec<(&str, String)> = Vec::new();
+    let mut paragraphs = Vec::with_capacity(cues.len());
+    for cue in &cues {
+        let agent = agent(&mut speakers, cue)?;
+        paragraphs.push(format!(
+            "      <p begin=\"{}\" end=\"{}\"{agent}>{}</p>",
+            clock.format(cue.start),
+            clock.format(cue.end),
+            checked(&cue.lines.join("<br/>"), cue.path.clone())?,
+        ));
+    }
+
+    let header = TeiPath::root().child("teiHeader");
+    let language = checked(
+        document
+            .header()
+            .profile_desc()
+            .and_then(|profile| profile.languages().first())
+            .map_or("", |language| language.as_str()),
+        header.clone().child("profileDesc").child("lang"),
+    )?;
+    let title = checked(
+        document.title().as_str(),
+        header.child("fileDesc").child("title"),
+    )?;
+    let mut lines = vec![
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>".to_owned(),
+        format!(
+            "<tt xmlns=\"http://www.w3.org/ns/ttml\" \
+             xmlns:tts=\"http://www.w3.org/ns/ttml#styling\" \
+             xmlns:ttm=\"http://www.w3.org/ns/ttml#metadata\" xml:lang=\"{}\">",
+            escape::attribute(language)
+        ),
+        "  <head>".to_owned(),
+        "    <metadata>".to_owned(),
+        format!("      <ttm:title>{}</ttm:title>", escape::text(title)),
+    ];
+    for (index, (_, name)) in speakers.iter().enumerate() {
+        lines.push(format!(
+            "      <ttm:agent xml:id=\"speaker{}\" type=\"person\">\
+             <ttm:name type=\"full\">{}</ttm:name></ttm:agent>",
+            index + 1,
+            name
+        ));
+    }
+    lines.push("    </metadata>".to_owned());
+    lines.extend(STUBS.iter().map(|line| (*line).to_owned()));
+    lines.extend([
+        "  </head>".to_owned(),
+        "  <body region=\"bottom\" style=\"default\">".to_owned(),
+        "    <div>".to_owned(),
+    ]);
+    lines.extend(paragraphs);
+    lines.extend([
+        "    </div>".to_owned(),
+        "  </body>".to_owned(),
+        "</tt>".to_owned(),
+    ]);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Returns the `ttm:agent` attribute for the speaker of `cue`, declaring the
+/// speaker in `speakers` on first use.
+fn agent<'a>(speakers: &mut Vec<(&'a str, String)>, cue: &'a Cue) -> Result<String, TeiError> {
+    let Some(name) = cue.speaker.as_deref() else {
+        return Ok(String::new());
+    };
+    let known = speakers.iter().position(|(known, _)| *known == name);
+    let number = if let Some(number) = known {
+        number
+    } else {
+        let path = cue.path.clone().attribute("who");
+        speakers.push((name, escape::text(checked(name, path)?)));
+        speakers.len() - 1
+    };
+    Ok(format!(" ttm:agent=\"speaker{}\"", number + 1))
+}
+
+/// Returns `value`, or an error at `path` when it holds a character XML 1.0
+/// forbids.
+fn checked(value: &str, path: TeiPath) -> Result<&str, TeiError> {
+    escape::first_forbidden(value).map_or(Ok(value), |character| {
+        Err(TeiError::export_at(
+            DIALECT.format,
+            format!(
+                "document contains XML 1.0 forbidden character U+{:04X}",
+                u32::from(character)
+            ),
+            SourceLocation::new().with_path(path),
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Hi, Inline, LanguageTag, Timeline, Utterance};
+    use rstest::rstest;
+    use std::time::Duration;
+
+    #[test]
+    fn declares_speakers_and_styles_once() {
+        let mut timeline = Timeline::new();
+        let mut french = Hi::new([Inline::text("très")]);
+        french.set_lang(LanguageTag::new("fr").expect("valid language"));
+        let mut first = Utterance::from_inline(
+            Some("Hera"),
+            [
+                Inline::text("Fish & "),
+                Inline::Hi(french),
+                Inline::text(" bon, Eiffel."),
+            ],
+        )
+        .expect("valid utterance");
+        first.set_timing(
+            &timeline.add_offset(Duration::ZERO),
+            &timeline.add_offset(Duration::from_millis(1_500)),
+        );
+        let mut second = Utterance::from_inline(
+            Some("Eiffel"),
+            [
+                Inline::text("A "),
+                Inline::Hi(Hi::with_rend("bold", [Inline::text("very")])),
+                Inline::text(" long reply that needs a second line."),
+            ],
+        )
+        .expect("valid utterance");
+        second.set_timing(
+            &timeline.add_offset(Duration::from_secs(2)),
+            &timeline.add_offset(Duration::from_secs(4)),
+        );
+        let mut document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        document.text_mut().set_timeline(timeline);
+        let body = document.text_mut().body_mut();
+        body.push_utterance(first);
+        body.push_utterance(second);
+
+        let captions = render_with_options(&document, CueOptions::new().with_max_line_length(32))
+            .expect("document should export");
+
+        assert!(
+            captions.contains("<ttm:title>Wolf 359</ttm:title>"),
+            "{captions}"
+        );
+        assert!(
+            captions.contains(
+                "<ttm:agent xml:id=\"speaker2\" type=\"person\">\
+             <ttm:name type=\"full\">Eiffel</ttm:name></ttm:agent>"
+            ),
+            "{captions}"
+        );
+        assert!(
+            captions.contains(
+                "<p begin=\"00:00:00.000\" end=\"00:00:01.500\" ttm:agent=\"speaker1\">\
+             Fish &amp; <span xml:lang=\"fr\">très</span> bon, Eiffel.</p>"
+            ),
+            "{captions}"
+        );
+        assert!(
+            captions.contains(
+                "ttm:agent=\"speaker2\">A <span style=\"bold\">very</span> long reply that needs a\
+             <br/>second line.</p>"
+            ),
+            "{captions}"
+        );
+        assert_eq!(captions.matches("<ttm:agent ").count(), 2);
+    }
+
+    fn one_second(title: &str, speaker: &str, line: &str) -> TeiDocument {
+        let mut timeline = Timeline::new();
+        let mut utterance =
+            Utterance::from_text_segments(Some(speaker), [line]).expect("valid utterance");
+        utterance.set_timing(
+            &timeline.add_offset(Duration::ZERO),
+            &timeline.add_offset(Duration::from_secs(1)),
+        );
+        let mut document = TeiDocument::from_title_str(title).expect("valid title");
+        document.text_mut().set_timeline(timeline);
+        document.text_mut().body_mut().push_utterance(utterance);
+        document
+    }
+
+    #[rstest]
+    #[case("Wolf 359\u{7}", "Eiffel", "Day 1.", "/TEI/teiHeader/fileDesc/title")]
+    #[case("Wolf 359", "Eiff\u{1}el", "Day 1.", "/TEI/text/body/u[1]/@who")]
+    #[case("Wolf 359", "Eiffel", "Day\u{0} 1.", "/TEI/text/body/u[1]")]
+    fn rejects_forbidden_characters(
+        #[case] title: &str,
+        #[case] speaker: &str,
+        #[case] line: &str,
+        #[case] expected: &str,
+    ) {
+        let document = one_second(title, speaker, line);
+
+        let error = render(&document).expect_err("forbidden characters must fail");
+
+        assert!(error.to_string().contains("forbidden character"), "{error}");
+        assert_eq!(
+            error
+                .location()
+                .and_then(SourceLocation::path)
+                .map(ToString::to_string)
+                .as_deref(),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn escapes_quotes_in_titles_and_speakers() {
+        let document = one_second("Eiffel's log", "\"Doug\"", "Day 1.");
+
+        let captions = render(&document).expect("document should export");
+
+        assert!(
+            captions.contains("<ttm:title>Eiffel&apos;s log</ttm:title>"),
+            "{captions}"
+        );
+        assert!(
+            captions.contains("<ttm:name type=\"full\">&quot;Doug&quot;</ttm:name>"),
+            "{captions}"
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! Timed utterances as `WebVTT` subtitles.
+//!
+//! Each cue opens with a voice span such as `<v Hera>` naming the speaker.
+//! `<hi>` with `rend` set to `italic`, `bold`, or `underline` becomes `<i>`,
+//! `<b>`, or `<u>`, and a declared `xml:lang` becomes `<lang>`.
+
+use super::CueOptions;
+use super::cues::{self, Dialect, Markup};
+use crate::{TeiDocument, TeiError, TimestampFormat, TimestampStyle};
+
+const DIALECT: Dialect = Dialect {
+    format: "WebVTT",
+    escape: true,
+    markup: Markup::Tags,
+    speaker_labels: false,
+};
+
+/// Writes the utterances of `document` as `WebVTT` using the default
+/// [`CueOptions`].
+///
+/// # Errors
+///
+/// Returns [`TeiError::Export`] when an utterance lacks `@start` or `@end`,
+/// points at a time missing from the timeline, or ends before it starts.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::export::webvtt;
+/// use tei_core::import;
+///
+/// let source = "WEBVTT\n\n00:01.000 --> 00:03.500\n<v Eiffel>Day 1 of the <i>mission</i>.\n";
+/// let document = import::webvtt::parse(source, "Wolf 359")?;
+///
+/// assert_eq!(
+///     webvtt::render(&document)?,
+///     "WEBVTT\n\n00:00:01.000 --> 00:00:03.500\n<v Eiffel>Day 1 of the <i>mission</i>.\n",
+/// );
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn render(document: &TeiDocument) -> Result<String, TeiError> {
+    render_with_options(document, CueOptions::new())
+}
+
+/// Writes the utterances of `document` as `WebVTT`, wrapping and splitting
+/// cues to fit `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`render`].
+pub fn render_with_options(
+    document: &TeiDocument,
+    options: CueOptions,
+) -> Result<String, TeiError> {
+    let clock = options.timestamps().unwrap_or_else(|| {
+        TimestampFormat::default()
+            .with_style(TimestampStyle::HoursMinutesSeconds)
+            .with_fraction_digits(3)
+    });
+    let mut output = String::from("WEBVTT\n");
+    for cue in cues::cues(document, &DIALECT, options)? {
+        output.push('\n');
+        output.push_str(&clock.format(cue.start));
+        output.push_str(" --> ");
+        output.push_str(&clock.format(cue.end));
+        output.push('\n');
+        if let Some(speaker) = &cue.speaker {
+            output.push_str("<v ");
+            cues::push_text(&mut output, speaker, true);
+            output.push('>');
+        }
+        output.push_str(&cue.lines.join("\n"));
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Hi, Inline, LanguageTag, Timeline, Utterance};
+    use std::time::Duration;
+
+    fn document(utterance: Utterance) -> TeiDocument {
+        let mut document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        let mut timeline = Timeline::new();
+        let start = timeline.add_offset(Duration::from_secs(2));
+        let end = timeline.add_offset(Duration::from_secs(6));
+        let mut timed = utterance;
+        timed.set_timing(&start, &end);
+        document.text_mut().set_timeline(timeline);
+        document.text_mut().push_utterance(timed);
+        document
+    }
+
+    #[test]
+    fn splits_long_utterances_across_cues() {
+        let utterance = Utterance::from_text_segments(
+            Some("Hera"),
+            ["Hello, Eiffel. The air scrubbers are failing again."],
+        )
+        .expect("valid utterance");
+        let options = CueOptions::new().with_max_line_length(20).with_max_lines(1);
+
+        let output = render_with_options(&document(utterance), options).expect("renders");
+
+        assert_eq!(
+            output,
+            "WEBVTT\n\n\
+             00:00:02.000 --> 00:00:03.469\n<v Hera>Hello, Eiffel. The\n\n\
+             00:00:03.469 --> 00:00:04.857\n<v Hera>air scrubbers are\n\n\
+             00:00:04.857 --> 00:00:06.000\n<v Hera>failing again.\n"
+        );
+    }
+
+    #[test]
+    fn writes_times_with_the_chosen_clock() {
+        let utterance =
+            Utterance::from_text_segments(Some("Hera"), ["Hello."]).expect("valid utterance");
+        let clock = TimestampFormat::default()
+            .with_style(TimestampStyle::HoursMinutesSeconds)
+            .with_fraction_digits(1);
+        let options = CueOptions::new().with_timestamps(Some(clock));
+
+        let output = render_with_options(&document(utterance), options).expect("renders");
+
+        assert_eq!(
+            output,
+            "WEBVTT\n\n00:00:02.0 --> 00:00:06.0\n<v Hera>Hello.\n"
+        );
+    }
+
+    #[test]
+    fn keeps_styling_and_escapes_text() {
+        let mut french = Hi::with_rend("italic", [Inline::text("très")]);
+        french.set_lang(LanguageTag::new("fr").expect("valid language"));
+        let utterance = Utterance::from_inline(
+            None::<&str>,
+            [
+                Inline::text("Fish & chips are "),
+                Inline::Hi(french),
+                Inline::text("<good>"),
+            ],
+        )
+        .expect("valid utterance");
+
+        let output = render(&document(utterance)).expect("renders");
+
+        assert!(
+            output.ends_with("\nFish &amp; chips are <i><lang fr>très</lang></i>&lt;good&gt;\n"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn reports_utterances_without_timing() {
+        let mut document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        document.text_mut().push_utterance(
+            Utterance::from_text_segments(Some("Hera"), ["Hello."]).expect("valid utterance"),
+        );
+
+        let error = render(&document).expect_err("untimed utterances must fail");
+
+        assert_eq!(
+            error.to_string(),
+            "WebVTT export error: utterance has no start time (/TEI/text/body/u[1]/@start)"
+        );
+    }
+}
@@ -0,0 +1,98 @@
+//! ISO 8601 calendar date validation shared by header sections.
+//!
+//! TEI `@when` attributes accept `YYYY`, `YYYY-MM`, or `YYYY-MM-DD`; the
+//! validator checks month ranges and month lengths, including leap years.
+
+use super::{HeaderValidationError, normalise_optional_text};
+
+/// Normalises an optional ISO date, rejecting malformed non-blank values.
+pub(super) fn optional_iso_date(
+    value: impl Into<String>,
+) -> Result<Option<String>, HeaderValidationError> {
+    normalise_optional_text(value)
+        .map(|date| {
+            if is_iso_date(&date) {
+                Ok(date)
+            } else {
+                Err(HeaderValidationError::InvalidDate { value: date })
+            }
+        })
+        .transpose()
+}
+
+fn is_iso_date(value: &str) -> bool {
+    let mut parts = value.split('-');
+    let Some(year) = parts.next().and_then(|part| parse_fixed(part, 4)) else {
+        return false;
+    };
+    let Some(month_part) = parts.next() else {
+        return true;
+    };
+    let Some(month) = parse_fixed(month_part, 2).filter(|month| (1..=12).contains(month)) else {
+        return false;
+    };
+    let Some(day_part) = parts.next() else {
+        return true;
+    };
+    let Some(day) = parse_fixed(day_part, 2) else {
+        return false;
+    };
+
+    parts.next().is_none() && (1..=days_in_month(year, month)).contains(&day)
+}
+
+fn parse_fixed(part: &str, width: usize) -> Option<u32> {
+    if part.len() != width || !part.chars().all(|ch| ch.is_ascii_digit()) {
+        return None;
+    }
+
+    part.parse().ok()
+}
+
+const fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+const fn is_leap_year(year: u32) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("2024", true)]
+    #[case("2024-05", true)]
+    #[case("2024-02-29", true)]
+    #[case("2000-02-29", true)]
+    #[case("1900-02-29", false)]
+    #[case("2024-13", false)]
+    #[case("2024-04-31", false)]
+    #[case("24-05-01", false)]
+    #[case("2024-5-1", false)]
+    #[case("2024-05-01T10:00", false)]
+    #[case("May 2024", false)]
+    fn validates_iso_dates(#[case] value: &str, #[case] valid: bool) {
+        assert_eq!(is_iso_date(value), valid, "{value}");
+    }
+
+    #[test]
+    fn blank_dates_are_absent() {
+        assert_eq!(optional_iso_date("  "), Ok(None));
+    }
+
+    #[test]
+    fn dates_are_trimmed() {
+        assert_eq!(
+            optional_iso_date(" 2024-05-01 "),
+            Ok(Some("2024-05-01".to_owned()))
+        );
+    }
+}
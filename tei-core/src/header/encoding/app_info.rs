@@ -0,0 +1,198 @@
+//! Application details (`<appInfo>`) recording the software that produced a
+//! transcript.
+//!
+//! Each `<application>` carries the required `@ident` and `@version`
+//! attributes, an optional ISO `@when` date, and free-text `<desc>` notes so
+//! pipelines such as a speech recogniser followed by `tei-rapporteur` can be
+//! identified machine-readably.
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::{
+    HeaderValidationError, normalise_optional_text, optional_iso_date, required_text,
+};
+
+/// Applications that processed the document, serialised as `<appInfo>`.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{AppInfo, Application};
+///
+/// let mut app_info = AppInfo::new();
+/// app_info.add_application(
+///     Application::new("whisper", "large-v3")?
+///         .with_date("2024-05-01")?
+///         .with_note("Automatic transcription"),
+/// );
+/// app_info.add_application(Application::new("tei-rapporteur", "0.3")?);
+///
+/// assert_eq!(app_info.applications().len(), 2);
+/// # Ok::<(), tei_core::HeaderValidationError>(())
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "appInfo")]
+pub struct AppInfo {
+    #[serde(rename = "application", skip_serializing_if = "Vec::is_empty", default)]
+    applications: Vec<Application>,
+}
+
+impl AppInfo {
+    /// Creates an empty application list.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records an application in pipeline order.
+    pub fn add_application(&mut self, application: Application) {
+        self.applications.push(application);
+    }
+
+    /// Returns the recorded applications.
+    #[must_use]
+    pub const fn applications(&self) -> &[Application] {
+        self.applications.as_slice()
+    }
+
+    /// Reports whether no applications were recorded.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.applications.is_empty()
+    }
+}
+
+/// A single software component, serialised as `<application>`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "application", try_from = "RawApplication")]
+pub struct Application {
+    #[serde(rename = "@ident")]
+    ident: String,
+    #[serde(rename = "@version")]
+    version: String,
+    #[serde(rename = "@when", skip_serializing_if = "Option::is_none")]
+    date: Option<String>,
+    #[serde(rename = "desc", skip_serializing_if = "Vec::is_empty")]
+    notes: Vec<String>,
+}
+
+impl Application {
+    /// Builds an application record from its identifier and version.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when either value trims
+    /// to an empty string.
+    pub fn new(
+        ident: impl Into<String>,
+        version: impl Into<String>,
+    ) -> Result<Self, HeaderValidationError> {
+        Ok(Self {
+            ident: required_text(ident, "application ident")?,
+            version: required_text(version, "application version")?,
+            date: None,
+            notes: Vec::new(),
+        })
+    }
+
+    /// Records when the application ran; blank values clear the date.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::InvalidDate`] when the value is not an
+    /// ISO 8601 calendar date.
+    pub fn with_date(mut self, date: impl Into<String>) -> Result<Self, HeaderValidationError> {
+        self.date = optional_iso_date(date)?;
+        Ok(self)
+    }
+
+    /// Appends a descriptive note; blank notes are ignored.
+    #[must_use]
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.extend(normalise_optional_text(note));
+        self
+    }
+
+    /// Returns the application identifier.
+    #[must_use]
+    pub const fn ident(&self) -> &str {
+        self.ident.as_str()
+    }
+
+    /// Returns the application version.
+    #[must_use]
+    pub const fn version(&self) -> &str {
+        self.version.as_str()
+    }
+
+    /// Returns the run date when recorded.
+    #[must_use]
+    pub fn date(&self) -> Option<&str> {
+        self.date.as_deref()
+    }
+
+    /// Returns the descriptive notes.
+    #[must_use]
+    pub const fn notes(&self) -> &[String] {
+        self.notes.as_slice()
+    }
+}
+
+#[derive(Deserialize)]
+struct RawApplication {
+    #[serde(rename = "@ident", default)]
+    ident: String,
+    #[serde(rename = "@version", default)]
+    version: String,
+    #[serde(rename = "@when", default)]
+    date: Option<String>,
+    #[serde(rename = "desc", default)]
+    notes: Vec<String>,
+}
+
+impl TryFrom<RawApplication> for Application {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawApplication) -> Result<Self, Self::Error> {
+        let application =
+            Self::new(raw.ident, raw.version)?.with_date(raw.date.unwrap_or_default())?;
+
+        Ok(raw.notes.into_iter().fold(application, Self::with_note))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json as json;
+
+    #[test]
+    fn application_requires_version() {
+        let result = Application::new("whisper", "  ");
+
+        assert_eq!(
+            result,
+            Err(HeaderValidationError::EmptyField {
+                field: "application version",
+            })
+        );
+    }
+
+    #[test]
+    fn blank_notes_are_ignored() {
+        let application = Application::new("whisper", "large-v3")
+            .unwrap_or_else(|error| panic!("application should be valid: {error}"))
+            .with_note("   ");
+
+        assert!(application.notes().is_empty());
+    }
+
+    #[test]
+    fn deserialisation_rejects_invalid_dates() {
+        let result = json::from_str::<Application>(
+            r#"{"@ident":"whisper","@version":"3","@when":"yesterday"}"#,
+        );
+
+        assert!(result.is_err(), "invalid dates should not deserialise");
+    }
+}
@@ -0,0 +1,297 @@
+//! Classification declarations (`<classDecl>`) defining local taxonomies.
+//!
+//! Taxonomies and their (possibly nested) categories carry `xml:id` values so
+//! `<catRef>` pointers in the text classification can be checked. Only local
+//! `#id` pointers are resolved; pointers to external vocabularies are left
+//! alone.
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::{CatRef, HeaderValidationError, normalise_optional_text};
+use crate::text::XmlId;
+
+/// Taxonomies declared for the document.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{CatRef, Category, ClassDecl, Taxonomy, XmlId};
+///
+/// let mut genre = Taxonomy::new(XmlId::new("genre")?);
+/// genre.add_category(Category::new(XmlId::new("drama")?).with_description("Audio drama"));
+/// let mut class_decl = ClassDecl::new();
+/// class_decl.add_taxonomy(genre);
+///
+/// assert!(class_decl.check_cat_ref(&CatRef::new(["#drama"])?.with_scheme("#genre")).is_ok());
+/// assert!(class_decl.check_cat_ref(&CatRef::new(["#news"])?).is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "classDecl")]
+pub struct ClassDecl {
+    #[serde(rename = "taxonomy", skip_serializing_if = "Vec::is_empty", default)]
+    taxonomies: Vec<Taxonomy>,
+}
+
+impl ClassDecl {
+    /// Creates a declaration without taxonomies.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a taxonomy.
+    pub fn add_taxonomy(&mut self, taxonomy: Taxonomy) {
+        self.taxonomies.push(taxonomy);
+    }
+
+    /// Returns the declared taxonomies.
+    #[must_use]
+    pub const fn taxonomies(&self) -> &[Taxonomy] {
+        self.taxonomies.as_slice()
+    }
+
+    /// Finds a taxonomy by identifier, accepting a leading `#`.
+    #[must_use]
+    pub fn taxonomy(&self, reference: &str) -> Option<&Taxonomy> {
+        let id = reference.strip_prefix('#').unwrap_or(reference);
+
+        self.taxonomies
+            .iter()
+            .find(|taxonomy| taxonomy.id().as_str() == id)
+    }
+
+    /// Finds a category in any taxonomy, accepting a leading `#`.
+    #[must_use]
+    pub fn category(&self, reference: &str) -> Option<&Category> {
+        self.taxonomies
+            .iter()
+            .find_map(|taxonomy| taxonomy.category(reference))
+    }
+
+    /// Checks that the local pointers in a category reference resolve.
+    ///
+    /// A local `@scheme` must name a declared taxonomy, and local targets must
+    /// then name categories within it. Without a local scheme, targets may
+    /// name a category in any taxonomy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::UnresolvedCategory`] naming the first
+    /// pointer that does not resolve.
+    pub fn check_cat_ref(&self, cat_ref: &CatRef) -> Result<(), HeaderValidationError> {
+        let scheme = cat_ref.scheme().filter(|scheme| scheme.starts_with('#'));
+        let scoped = scheme.map(|reference| self.taxonomy(reference).ok_or(reference));
+        let unresolved = match scoped {
+            Some(Err(reference)) => Some(reference),
+            Some(Ok(taxonomy)) => first_unresolved(cat_ref, |target| taxonomy.category(target)),
+            None => first_unresolved(cat_ref, |target| self.category(target)),
+        };
+
+        unresolved.map_or(Ok(()), |reference| {
+            Err(HeaderValidationError::UnresolvedCategory {
+                reference: reference.to_owned(),
+            })
+        })
+    }
+}
+
+fn first_unresolved<'a, 'c>(
+    cat_ref: &'a CatRef,
+    lookup: impl Fn(&str) -> Option<&'c Category>,
+) -> Option<&'a str> {
+    cat_ref
+        .targets()
+        .iter()
+        .map(String::as_str)
+        .filter(|target| target.starts_with('#'))
+        .find(|target| lookup(target).is_none())
+}
+
+/// A named classification scheme, serialised as `<taxonomy xml:id="…">`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "taxonomy")]
+pub struct Taxonomy {
+    #[serde(rename = "@xml:id", alias = "@id")]
+    id: XmlId,
+    #[serde(rename = "category", skip_serializing_if = "Vec::is_empty", default)]
+    categories: Vec<Category>,
+}
+
+impl Taxonomy {
+    /// Creates an empty taxonomy.
+    #[must_use]
+    pub const fn new(id: XmlId) -> Self {
+        Self {
+            id,
+            categories: Vec::new(),
+        }
+    }
+
+    /// Appends a top-level category.
+    pub fn add_category(&mut self, category: Category) {
+        self.categories.push(category);
+    }
+
+    /// Returns the taxonomy identifier.
+    #[must_use]
+    pub const fn id(&self) -> &XmlId {
+        &self.id
+    }
+
+    /// Returns the top-level categories.
+    #[must_use]
+    pub const fn categories(&self) -> &[Category] {
+        self.categories.as_slice()
+    }
+
+    /// Finds a category at any depth, accepting a leading `#`.
+    #[must_use]
+    pub fn category(&self, reference: &str) -> Option<&Category> {
+        let id = reference.strip_prefix('#').unwrap_or(reference);
+
+        self.categories
+            .iter()
+            .find_map(|category| category.find(id))
+    }
+}
+
+/// A taxonomy entry, serialised as `<category xml:id="…">`.
+///
+/// Categories may nest to express hierarchies such as genre and sub-genre.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "category")]
+pub struct Category {
+    #[serde(rename = "@xml:id", alias = "@id")]
+    id: XmlId,
+    #[serde(rename = "catDesc", skip_serializing_if = "Option::is_none", default)]
+    description: Option<String>,
+    #[serde(rename = "category", skip_serializing_if = "Vec::is_empty", default)]
+    categories: Vec<Self>,
+}
+
+impl Category {
+    /// Creates a category without a description.
+    #[must_use]
+    pub const fn new(id: XmlId) -> Self {
+        Self {
+            id,
+            description: None,
+            categories: Vec::new(),
+        }
+    }
+
+    /// Describes the category; blank values clear the description.
+    #[must_use]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = normalise_optional_text(description);
+        self
+    }
+
+    /// Appends a nested sub-category.
+    pub fn add_category(&mut self, category: Self) {
+        self.categories.push(category);
+    }
+
+    /// Returns the category identifier.
+    #[must_use]
+    pub const fn id(&self) -> &XmlId {
+        &self.id
+    }
+
+    /// Returns the description when present.
+    #[must_use]
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the nested sub-categories.
+    #[must_use]
+    pub const fn categories(&self) -> &[Self] {
+        self.categories.as_slice()
+    }
+
+    fn find(&self, id: &str) -> Option<&Self> {
+        if self.id.as_str() == id {
+            return Some(self);
+        }
+
+        self.categories
+            .iter()
+            .find_map(|category| category.find(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(value: &str) -> XmlId {
+        XmlId::new(value).unwrap_or_else(|error| panic!("valid identifier: {error}"))
+    }
+
+    fn cat_ref(targets: &str) -> CatRef {
+        CatRef::new([targets]).unwrap_or_else(|error| panic!("valid catRef: {error}"))
+    }
+
+    fn class_decl() -> ClassDecl {
+        let mut drama = Category::new(id("drama"));
+        drama.add_category(Category::new(id("horror")));
+        let mut genre = Taxonomy::new(id("genre"));
+        genre.add_category(drama);
+        let mut format = Taxonomy::new(id("format"));
+        format.add_category(Category::new(id("interview")));
+        let mut declaration = ClassDecl::new();
+        declaration.add_taxonomy(genre);
+        declaration.add_taxonomy(format);
+        declaration
+    }
+
+    #[test]
+    fn resolves_nested_categories() {
+        let declaration = class_decl();
+
+        assert!(
+            declaration
+                .check_cat_ref(&cat_ref("#drama #horror"))
+                .is_ok()
+        );
+        assert!(
+            declaration
+                .check_cat_ref(&cat_ref("#horror").with_scheme("#genre"))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn rejects_categories_outside_the_scheme() {
+        let result = class_decl().check_cat_ref(&cat_ref("#interview").with_scheme("#genre"));
+
+        assert_eq!(
+            result,
+            Err(HeaderValidationError::UnresolvedCategory {
+                reference: "#interview".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_schemes() {
+        let result = class_decl().check_cat_ref(&cat_ref("#drama").with_scheme("#mood"));
+
+        assert_eq!(
+            result,
+            Err(HeaderValidationError::UnresolvedCategory {
+                reference: "#mood".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn ignores_external_pointers() {
+        let external =
+            cat_ref("https://example.org/genres#drama").with_scheme("https://example.org/genres");
+
+        assert!(ClassDecl::new().check_cat_ref(&external).is_ok());
+    }
+}
@@ -0,0 +1,360 @@
+//! Editorial declarations (`<editorialDecl>`) describing transcription policy.
+//!
+//! Corpus documentation records how text was normalised, how quotations and
+//! end-of-line hyphens were treated, and what interpretive markup was added.
+//! The first three policies carry the typed attributes TEI defines for them;
+//! interpretation has no such attribute, so it is described in prose only.
+//! Every policy may add `<p>` notes, and blank notes are ignored.
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::{HeaderValidationError, normalise_optional_text, required_text};
+
+/// Editorial policies applied while producing the transcript.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{
+///     EditorialDecl, Hyphenation, HyphenationEol, Normalization, NormalizationMethod,
+/// };
+///
+/// let editorial = EditorialDecl::new()
+///     .with_normalization(
+///         Normalization::new(NormalizationMethod::Silent)
+///             .with_note("Filler words were removed."),
+///     )
+///     .with_hyphenation(Hyphenation::new(HyphenationEol::None));
+///
+/// assert_eq!(
+///     editorial.normalization().map(Normalization::method),
+///     Some(NormalizationMethod::Silent),
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "editorialDecl")]
+pub struct EditorialDecl {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    normalization: Option<Normalization>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    quotation: Option<Quotation>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    hyphenation: Option<Hyphenation>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    interpretation: Option<Interpretation>,
+}
+
+impl EditorialDecl {
+    /// Creates a declaration without any policies.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares the normalisation policy.
+    #[must_use]
+    pub fn with_normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = Some(normalization);
+        self
+    }
+
+    /// Declares the quotation policy.
+    #[must_use]
+    pub fn with_quotation(mut self, quotation: Quotation) -> Self {
+        self.quotation = Some(quotation);
+        self
+    }
+
+    /// Declares the hyphenation policy.
+    #[must_use]
+    pub fn with_hyphenation(mut self, hyphenation: Hyphenation) -> Self {
+        self.hyphenation = Some(hyphenation);
+        self
+    }
+
+    /// Declares the interpretation policy.
+    #[must_use]
+    pub fn with_interpretation(mut self, interpretation: Interpretation) -> Self {
+        self.interpretation = Some(interpretation);
+        self
+    }
+
+    /// Returns the normalisation policy when declared.
+    #[must_use]
+    pub const fn normalization(&self) -> Option<&Normalization> {
+        self.normalization.as_ref()
+    }
+
+    /// Returns the quotation policy when declared.
+    #[must_use]
+    pub const fn quotation(&self) -> Option<&Quotation> {
+        self.quotation.as_ref()
+    }
+
+    /// Returns the hyphenation policy when declared.
+    #[must_use]
+    pub const fn hyphenation(&self) -> Option<&Hyphenation> {
+        self.hyphenation.as_ref()
+    }
+
+    /// Returns the interpretation policy when declared.
+    #[must_use]
+    pub const fn interpretation(&self) -> Option<&Interpretation> {
+        self.interpretation.as_ref()
+    }
+}
+
+/// How normalised readings are indicated (`@method`).
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NormalizationMethod {
+    /// Changes were made without recording the original form.
+    Silent,
+    /// Changes are recorded in the markup.
+    Markup,
+}
+
+/// How quotation marks were treated (`@marks`).
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuotationMarks {
+    /// All quotation marks were removed.
+    None,
+    /// Some quotation marks were retained.
+    Some,
+    /// All quotation marks were retained.
+    All,
+}
+
+/// Which end-of-line hyphens were retained (`@eol`).
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HyphenationEol {
+    /// All end-of-line hyphens were retained.
+    All,
+    /// Some end-of-line hyphens were retained.
+    Some,
+    /// Only hard hyphens were retained.
+    Hard,
+    /// No end-of-line hyphens were retained.
+    None,
+}
+
+/// Normalisation policy, serialised as `<normalization method="…">`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Normalization {
+    #[serde(rename = "@method")]
+    method: NormalizationMethod,
+    #[serde(rename = "p", skip_serializing_if = "Vec::is_empty", default)]
+    notes: Vec<String>,
+}
+
+impl Normalization {
+    /// Declares the normalisation method.
+    #[must_use]
+    pub const fn new(method: NormalizationMethod) -> Self {
+        Self {
+            method,
+            notes: Vec::new(),
+        }
+    }
+
+    /// Appends an explanatory note; blank notes are ignored.
+    #[must_use]
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.extend(normalise_optional_text(note));
+        self
+    }
+
+    /// Returns the normalisation method.
+    #[must_use]
+    pub const fn method(&self) -> NormalizationMethod {
+        self.method
+    }
+
+    /// Returns the explanatory notes.
+    #[must_use]
+    pub const fn notes(&self) -> &[String] {
+        self.notes.as_slice()
+    }
+}
+
+/// Quotation policy, serialised as `<quotation marks="…">`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Quotation {
+    #[serde(rename = "@marks")]
+    marks: QuotationMarks,
+    #[serde(rename = "p", skip_serializing_if = "Vec::is_empty", default)]
+    notes: Vec<String>,
+}
+
+impl Quotation {
+    /// Declares how quotation marks were treated.
+    #[must_use]
+    pub const fn new(marks: QuotationMarks) -> Self {
+        Self {
+            marks,
+            notes: Vec::new(),
+        }
+    }
+
+    /// Appends an explanatory note; blank notes are ignored.
+    #[must_use]
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.extend(normalise_optional_text(note));
+        self
+    }
+
+    /// Returns the quotation mark policy.
+    #[must_use]
+    pub const fn marks(&self) -> QuotationMarks {
+        self.marks
+    }
+
+    /// Returns the explanatory notes.
+    #[must_use]
+    pub const fn notes(&self) -> &[String] {
+        self.notes.as_slice()
+    }
+}
+
+/// Hyphenation policy, serialised as `<hyphenation eol="…">`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Hyphenation {
+    #[serde(rename = "@eol")]
+    eol: HyphenationEol,
+    #[serde(rename = "p", skip_serializing_if = "Vec::is_empty", default)]
+    notes: Vec<String>,
+}
+
+impl Hyphenation {
+    /// Declares which end-of-line hyphens were retained.
+    #[must_use]
+    pub const fn new(eol: HyphenationEol) -> Self {
+        Self {
+            eol,
+            notes: Vec::new(),
+        }
+    }
+
+    /// Appends an explanatory note; blank notes are ignored.
+    #[must_use]
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.extend(normalise_optional_text(note));
+        self
+    }
+
+    /// Returns the end-of-line hyphen policy.
+    #[must_use]
+    pub const fn eol(&self) -> HyphenationEol {
+        self.eol
+    }
+
+    /// Returns the explanatory notes.
+    #[must_use]
+    pub const fn notes(&self) -> &[String] {
+        self.notes.as_slice()
+    }
+}
+
+/// Interpretation policy, serialised as `<interpretation>` with `<p>` notes.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(try_from = "RawInterpretation")]
+pub struct Interpretation {
+    #[serde(rename = "p")]
+    notes: Vec<String>,
+}
+
+impl Interpretation {
+    /// Describes the interpretive markup applied to the transcript.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the description
+    /// trims to an empty string.
+    pub fn new(note: impl Into<String>) -> Result<Self, HeaderValidationError> {
+        Ok(Self {
+            notes: vec![required_text(note, "interpretation")?],
+        })
+    }
+
+    /// Appends a further note; blank notes are ignored.
+    #[must_use]
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.extend(normalise_optional_text(note));
+        self
+    }
+
+    /// Returns the interpretation notes.
+    #[must_use]
+    pub const fn notes(&self) -> &[String] {
+        self.notes.as_slice()
+    }
+}
+
+#[derive(Deserialize)]
+struct RawInterpretation {
+    #[serde(rename = "p", default)]
+    notes: Vec<String>,
+}
+
+impl TryFrom<RawInterpretation> for Interpretation {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawInterpretation) -> Result<Self, Self::Error> {
+        let notes: Vec<String> = raw
+            .notes
+            .into_iter()
+            .filter_map(normalise_optional_text)
+            .collect();
+        if notes.is_empty() {
+            return Err(HeaderValidationError::EmptyField {
+                field: "interpretation",
+            });
+        }
+
+        Ok(Self { notes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json as json;
+
+    #[test]
+    fn interpretation_requires_a_note() {
+        let result = Interpretation::new("  ");
+
+        assert_eq!(
+            result,
+            Err(HeaderValidationError::EmptyField {
+                field: "interpretation",
+            })
+        );
+    }
+
+    #[test]
+    fn blank_policy_notes_are_ignored() {
+        let quotation = Quotation::new(QuotationMarks::All).with_note("\t");
+
+        assert!(quotation.notes().is_empty());
+    }
+
+    #[test]
+    fn deserialisation_rejects_blank_interpretations() {
+        let result = json::from_str::<Interpretation>(r#"{"p":["  "]}"#);
+
+        assert!(
+            result.is_err(),
+            "blank interpretation should not deserialise"
+        );
+    }
+
+    #[test]
+    fn deserialisation_rejects_unknown_methods() {
+        let result = json::from_str::<Normalization>(r#"{"@method":"loud"}"#);
+
+        assert!(result.is_err(), "unknown methods should not deserialise");
+    }
+}
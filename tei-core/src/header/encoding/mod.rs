@@ -7,6 +7,25 @@ use std::fmt;
 use super::{HeaderValidationError, normalise_optional_text};
 use serde::{Deserialize, Serialize};
 
+mod app_info;
+mod class;
+mod editorial;
+mod project;
+mod refs;
+mod sampling;
+mod tags;
+
+pub use app_info::{AppInfo, Application};
+pub use class::{Category, ClassDecl, Taxonomy};
+pub use editorial::{
+    EditorialDecl, Hyphenation, HyphenationEol, Interpretation, Normalization, NormalizationMethod,
+    Quotation, QuotationMarks,
+};
+pub use project::ProjectDesc;
+pub use refs::{CiteStructure, RefsDecl};
+pub use sampling::SamplingDecl;
+pub use tags::{Rendition, RenditionScheme, TagsDecl};
+
 /// Aggregates encoding metadata such as annotation systems.
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename = "encodingDesc")]
@@ -17,6 +36,32 @@ pub struct EncodingDesc {
         default
     )]
     annotation_systems: Vec<AnnotationSystem>,
+    #[serde(rename = "appInfo", skip_serializing_if = "Option::is_none", default)]
+    app_info: Option<AppInfo>,
+    #[serde(
+        rename = "editorialDecl",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    editorial: Option<EditorialDecl>,
+    #[serde(
+        rename = "projectDesc",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    project: Option<ProjectDesc>,
+    #[serde(
+        rename = "samplingDecl",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    sampling: Option<SamplingDecl>,
+    #[serde(rename = "tagsDecl", skip_serializing_if = "Option::is_none", default)]
+    tags: Option<TagsDecl>,
+    #[serde(rename = "classDecl", skip_serializing_if = "Option::is_none", default)]
+    class: Option<ClassDecl>,
+    #[serde(rename = "refsDecl", skip_serializing_if = "Option::is_none", default)]
+    refs: Option<RefsDecl>,
 }
 
 impl EncodingDesc {
@@ -37,10 +82,108 @@ impl EncodingDesc {
         self.annotation_systems.as_slice()
     }
 
-    /// Reports whether any annotation systems were registered.
+    /// Records the applications that produced the document.
+    #[must_use]
+    pub fn with_app_info(mut self, app_info: AppInfo) -> Self {
+        self.app_info = Some(app_info);
+        self
+    }
+
+    /// Returns the application details when present.
+    #[must_use]
+    pub const fn app_info(&self) -> Option<&AppInfo> {
+        self.app_info.as_ref()
+    }
+
+    /// Declares the editorial policies applied to the transcript.
+    #[must_use]
+    pub fn with_editorial_decl(mut self, editorial: EditorialDecl) -> Self {
+        self.editorial = Some(editorial);
+        self
+    }
+
+    /// Returns the editorial declaration when present.
+    #[must_use]
+    pub const fn editorial_decl(&self) -> Option<&EditorialDecl> {
+        self.editorial.as_ref()
+    }
+
+    /// Describes the project the transcript belongs to.
+    #[must_use]
+    pub fn with_project_desc(mut self, project: ProjectDesc) -> Self {
+        self.project = Some(project);
+        self
+    }
+
+    /// Returns the project description when present.
+    #[must_use]
+    pub const fn project_desc(&self) -> Option<&ProjectDesc> {
+        self.project.as_ref()
+    }
+
+    /// Declares how the source material was sampled for transcription.
+    #[must_use]
+    pub fn with_sampling_decl(mut self, sampling: SamplingDecl) -> Self {
+        self.sampling = Some(sampling);
+        self
+    }
+
+    /// Returns the sampling declaration when present.
+    #[must_use]
+    pub const fn sampling_decl(&self) -> Option<&SamplingDecl> {
+        self.sampling.as_ref()
+    }
+
+    /// Declares default renderings for tagged content.
+    #[must_use]
+    pub fn with_tags_decl(mut self, tags: TagsDecl) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    /// Returns the tagging declaration when present.
+    #[must_use]
+    pub const fn tags_decl(&self) -> Option<&TagsDecl> {
+        self.tags.as_ref()
+    }
+
+    /// Declares the taxonomies used by category references.
+    #[must_use]
+    pub fn with_class_decl(mut self, class: ClassDecl) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    /// Returns the classification declaration when present.
+    #[must_use]
+    pub const fn class_decl(&self) -> Option<&ClassDecl> {
+        self.class.as_ref()
+    }
+
+    /// Declares how canonical references map to document structure.
+    #[must_use]
+    pub fn with_refs_decl(mut self, refs: RefsDecl) -> Self {
+        self.refs = Some(refs);
+        self
+    }
+
+    /// Returns the reference declaration when present.
+    #[must_use]
+    pub const fn refs_decl(&self) -> Option<&RefsDecl> {
+        self.refs.as_ref()
+    }
+
+    /// Reports whether the description records no encoding metadata.
     #[must_use]
     pub const fn is_empty(&self) -> bool {
         self.annotation_systems.is_empty()
+            && self.app_info.is_none()
+            && self.editorial.is_none()
+            && self.project.is_none()
+            && self.sampling.is_none()
+            && self.tags.is_none()
+            && self.class.is_none()
+            && self.refs.is_none()
     }
 
     /// Finds an annotation system by identifier.
@@ -0,0 +1,102 @@
+//! Project description (`<projectDesc>`) for the corpus a transcript belongs
+//! to.
+//!
+//! Holds one or more paragraphs with inline markup, reusing the body's
+//! validated [`P`] type so project documentation follows the same content
+//! rules as transcript prose.
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::HeaderValidationError;
+use crate::text::P;
+
+/// Project documentation serialised as `<projectDesc><p>…</p></projectDesc>`.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{P, ProjectDesc};
+///
+/// let project = ProjectDesc::new([P::from_text_segments([
+///     "Transcripts collected for the Wolf 359 fan archive.",
+/// ])?])?;
+///
+/// assert_eq!(project.paragraphs().len(), 1);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "projectDesc", try_from = "RawProjectDesc")]
+pub struct ProjectDesc {
+    #[serde(rename = "p")]
+    paragraphs: Vec<P>,
+}
+
+impl ProjectDesc {
+    /// Builds a project description from one or more paragraphs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when no paragraphs are
+    /// supplied.
+    pub fn new(paragraphs: impl IntoIterator<Item = P>) -> Result<Self, HeaderValidationError> {
+        let collected: Vec<P> = paragraphs.into_iter().collect();
+
+        if collected.is_empty() {
+            return Err(HeaderValidationError::EmptyField {
+                field: "project description",
+            });
+        }
+
+        Ok(Self {
+            paragraphs: collected,
+        })
+    }
+
+    /// Appends a paragraph.
+    pub fn push_paragraph(&mut self, paragraph: P) {
+        self.paragraphs.push(paragraph);
+    }
+
+    /// Returns the paragraphs in document order.
+    #[must_use]
+    pub const fn paragraphs(&self) -> &[P] {
+        self.paragraphs.as_slice()
+    }
+}
+
+#[derive(Deserialize)]
+struct RawProjectDesc {
+    #[serde(rename = "p", default)]
+    paragraphs: Vec<P>,
+}
+
+impl TryFrom<RawProjectDesc> for ProjectDesc {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawProjectDesc) -> Result<Self, Self::Error> {
+        Self::new(raw.paragraphs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json as json;
+
+    #[test]
+    fn rejects_descriptions_without_paragraphs() {
+        assert_eq!(
+            ProjectDesc::new(Vec::new()),
+            Err(HeaderValidationError::EmptyField {
+                field: "project description",
+            })
+        );
+    }
+
+    #[test]
+    fn deserialisation_rejects_empty_descriptions() {
+        let result = json::from_str::<ProjectDesc>(r#"{"p":[]}"#);
+
+        assert!(result.is_err(), "empty description should not deserialise");
+    }
+}
@@ -0,0 +1,215 @@
+//! Reference declarations (`<refsDecl>`) describing canonical citations.
+//!
+//! A `<citeStructure>` names a citation unit, the path expression selecting matching
+//! elements, and the expression that yields each unit's value. Nested
+//! structures describe deeper levels, joined by `@delim`, so an
+//! episode-and-turn scheme produces stable citations such as `12.34`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::{HeaderValidationError, normalise_optional_text, required_text};
+
+/// Canonical reference declaration for the document.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{CiteStructure, RefsDecl};
+///
+/// let turn = CiteStructure::new("turn", "text/body/u", "position()")?.with_delim(".");
+/// let episode = CiteStructure::new("episode", "/TEI", "@n")?.with_child(turn);
+/// let refs = RefsDecl::new().with_cite_structure(episode);
+///
+/// assert_eq!(refs.citation(&["12", "34"]), Some("12.34".to_owned()));
+/// assert_eq!(refs.citation(&["12", "34", "5"]), None);
+/// # Ok::<(), tei_core::HeaderValidationError>(())
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "refsDecl")]
+pub struct RefsDecl {
+    #[serde(
+        rename = "citeStructure",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    cite_structure: Option<CiteStructure>,
+}
+
+impl RefsDecl {
+    /// Creates an empty reference declaration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares the top-level citation structure.
+    #[must_use]
+    pub fn with_cite_structure(mut self, cite_structure: CiteStructure) -> Self {
+        self.cite_structure = Some(cite_structure);
+        self
+    }
+
+    /// Returns the top-level citation structure when declared.
+    #[must_use]
+    pub const fn cite_structure(&self) -> Option<&CiteStructure> {
+        self.cite_structure.as_ref()
+    }
+
+    /// Formats a citation from one value per structural level.
+    ///
+    /// Levels are followed through the first nested structure at each depth,
+    /// and each nested value is prefixed with that level's delimiter.
+    /// Returns `None` when no structure is declared, no values are supplied,
+    /// or more values are given than the structure has levels.
+    #[must_use]
+    pub fn citation<S: AsRef<str>>(&self, values: &[S]) -> Option<String> {
+        let (first, rest) = values.split_first()?;
+        let mut level = self.cite_structure.as_ref()?;
+        let mut citation = first.as_ref().to_owned();
+
+        for value in rest {
+            level = level.children.first()?;
+            citation.push_str(level.delim().unwrap_or_default());
+            citation.push_str(value.as_ref());
+        }
+
+        Some(citation)
+    }
+}
+
+/// One level of a citation scheme, serialised as `<citeStructure>`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "citeStructure", try_from = "RawCiteStructure")]
+pub struct CiteStructure {
+    #[serde(rename = "@unit")]
+    unit: String,
+    #[serde(rename = "@match")]
+    match_pattern: String,
+    #[serde(rename = "@use")]
+    use_expression: String,
+    #[serde(rename = "@delim", skip_serializing_if = "Option::is_none")]
+    delim: Option<String>,
+    #[serde(rename = "citeStructure", skip_serializing_if = "Vec::is_empty")]
+    children: Vec<Self>,
+}
+
+impl CiteStructure {
+    /// Builds a citation level from its unit name, match path, and value
+    /// expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when any argument trims
+    /// to an empty string.
+    pub fn new(
+        unit: impl Into<String>,
+        match_pattern: impl Into<String>,
+        use_expression: impl Into<String>,
+    ) -> Result<Self, HeaderValidationError> {
+        Ok(Self {
+            unit: required_text(unit, "citation unit")?,
+            match_pattern: required_text(match_pattern, "citation match")?,
+            use_expression: required_text(use_expression, "citation use")?,
+            delim: None,
+            children: Vec::new(),
+        })
+    }
+
+    /// Sets the separator written before this level's value; blank values
+    /// clear it.
+    #[must_use]
+    pub fn with_delim(mut self, delim: impl Into<String>) -> Self {
+        self.delim = normalise_optional_text(delim);
+        self
+    }
+
+    /// Appends a nested citation level.
+    #[must_use]
+    pub fn with_child(mut self, child: Self) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Returns the citation unit name.
+    #[must_use]
+    pub const fn unit(&self) -> &str {
+        self.unit.as_str()
+    }
+
+    /// Returns the path expression selecting elements at this level.
+    #[must_use]
+    pub const fn match_pattern(&self) -> &str {
+        self.match_pattern.as_str()
+    }
+
+    /// Returns the expression yielding each unit's value.
+    #[must_use]
+    pub const fn use_expression(&self) -> &str {
+        self.use_expression.as_str()
+    }
+
+    /// Returns the separator when declared.
+    #[must_use]
+    pub fn delim(&self) -> Option<&str> {
+        self.delim.as_deref()
+    }
+
+    /// Returns the nested citation levels.
+    #[must_use]
+    pub const fn children(&self) -> &[Self] {
+        self.children.as_slice()
+    }
+}
+
+#[derive(Deserialize)]
+struct RawCiteStructure {
+    #[serde(rename = "@unit", default)]
+    unit: String,
+    #[serde(rename = "@match", default)]
+    match_pattern: String,
+    #[serde(rename = "@use", default)]
+    use_expression: String,
+    #[serde(rename = "@delim", default)]
+    delim: Option<String>,
+    #[serde(rename = "citeStructure", default)]
+    children: Vec<CiteStructure>,
+}
+
+impl TryFrom<RawCiteStructure> for CiteStructure {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawCiteStructure) -> Result<Self, Self::Error> {
+        let mut structure = Self::new(raw.unit, raw.match_pattern, raw.use_expression)?
+            .with_delim(raw.delim.unwrap_or_default());
+        structure.children = raw.children;
+        Ok(structure)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json as json;
+
+    #[test]
+    fn cite_structure_requires_use_expression() {
+        assert_eq!(
+            CiteStructure::new("turn", "//u", " "),
+            Err(HeaderValidationError::EmptyField {
+                field: "citation use",
+            })
+        );
+    }
+
+    #[test]
+    fn citation_requires_a_declared_structure() {
+        assert!(RefsDecl::new().citation(&["1"]).is_none());
+    }
+
+    #[test]
+    fn deserialisation_rejects_blank_units() {
+        let result = json::from_str::<CiteStructure>(r#"{"@unit":"","@match":"//u","@use":"@n"}"#);
+
+        assert!(result.is_err(), "blank units should not deserialise");
+    }
+}
@@ -0,0 +1,102 @@
+//! Sampling declaration (`<samplingDecl>`) for partially transcribed sources.
+//!
+//! Corpora often transcribe only portions of each episode, such as the first
+//! ten minutes or every interview segment. The declaration explains that
+//! strategy in one or more paragraphs, reusing the body's validated [`P`]
+//! type.
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::HeaderValidationError;
+use crate::text::P;
+
+/// Sampling strategy serialised as `<samplingDecl><p>…</p></samplingDecl>`.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{P, SamplingDecl};
+///
+/// let sampling = SamplingDecl::new([P::from_text_segments([
+///     "Only the first ten minutes of each episode were transcribed.",
+/// ])?])?;
+///
+/// assert_eq!(sampling.paragraphs().len(), 1);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "samplingDecl", try_from = "RawSamplingDecl")]
+pub struct SamplingDecl {
+    #[serde(rename = "p")]
+    paragraphs: Vec<P>,
+}
+
+impl SamplingDecl {
+    /// Builds a sampling declaration from one or more paragraphs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when no paragraphs are
+    /// supplied.
+    pub fn new(paragraphs: impl IntoIterator<Item = P>) -> Result<Self, HeaderValidationError> {
+        let collected: Vec<P> = paragraphs.into_iter().collect();
+
+        if collected.is_empty() {
+            return Err(HeaderValidationError::EmptyField {
+                field: "sampling declaration",
+            });
+        }
+
+        Ok(Self {
+            paragraphs: collected,
+        })
+    }
+
+    /// Appends a paragraph.
+    pub fn push_paragraph(&mut self, paragraph: P) {
+        self.paragraphs.push(paragraph);
+    }
+
+    /// Returns the paragraphs in document order.
+    #[must_use]
+    pub const fn paragraphs(&self) -> &[P] {
+        self.paragraphs.as_slice()
+    }
+}
+
+#[derive(Deserialize)]
+struct RawSamplingDecl {
+    #[serde(rename = "p", default)]
+    paragraphs: Vec<P>,
+}
+
+impl TryFrom<RawSamplingDecl> for SamplingDecl {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawSamplingDecl) -> Result<Self, Self::Error> {
+        Self::new(raw.paragraphs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json as json;
+
+    #[test]
+    fn rejects_declarations_without_paragraphs() {
+        assert_eq!(
+            SamplingDecl::new(Vec::new()),
+            Err(HeaderValidationError::EmptyField {
+                field: "sampling declaration",
+            })
+        );
+    }
+
+    #[test]
+    fn deserialisation_rejects_empty_declarations() {
+        let result = json::from_str::<SamplingDecl>(r#"{"p":[]}"#);
+
+        assert!(result.is_err(), "empty declaration should not deserialise");
+    }
+}
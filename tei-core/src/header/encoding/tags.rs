@@ -0,0 +1,228 @@
+//! Tagging declarations (`<tagsDecl>`) carrying default renderings.
+//!
+//! Each `<rendition>` pairs an `xml:id` with styling instructions, usually
+//! CSS. Exporters resolve inline `@rend` hints such as `<hi rend="stress">`
+//! against the identifiers, and fall back to renditions whose `@selector`
+//! names an element when no explicit hint is present.
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::{HeaderValidationError, normalise_optional_text, required_text};
+use crate::text::XmlId;
+
+/// Rendition defaults declared for the document.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{Rendition, RenditionScheme, TagsDecl, XmlId};
+///
+/// let mut tags = TagsDecl::new();
+/// tags.add_rendition(
+///     Rendition::new(XmlId::new("stress")?, "font-weight: bold;")?
+///         .with_scheme(RenditionScheme::Css),
+/// );
+/// tags.add_rendition(
+///     Rendition::new(XmlId::new("hi-default")?, "font-style: italic;")?.with_selector("hi"),
+/// );
+///
+/// assert_eq!(
+///     tags.rendition("stress").map(Rendition::content),
+///     Some("font-weight: bold;"),
+/// );
+/// assert_eq!(
+///     tags.default_rendition("hi").map(Rendition::content),
+///     Some("font-style: italic;"),
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "tagsDecl")]
+pub struct TagsDecl {
+    #[serde(rename = "rendition", skip_serializing_if = "Vec::is_empty", default)]
+    renditions: Vec<Rendition>,
+}
+
+impl TagsDecl {
+    /// Creates a declaration without renditions.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a rendition.
+    pub fn add_rendition(&mut self, rendition: Rendition) {
+        self.renditions.push(rendition);
+    }
+
+    /// Returns the registered renditions.
+    #[must_use]
+    pub const fn renditions(&self) -> &[Rendition] {
+        self.renditions.as_slice()
+    }
+
+    /// Finds the rendition referenced by a `@rend` value.
+    ///
+    /// A leading `#` is accepted so `@rendition` pointers resolve too.
+    #[must_use]
+    pub fn rendition(&self, reference: &str) -> Option<&Rendition> {
+        let id = reference.strip_prefix('#').unwrap_or(reference);
+
+        self.renditions
+            .iter()
+            .find(|rendition| rendition.id().as_str() == id)
+    }
+
+    /// Finds the default rendition declared for an element name.
+    #[must_use]
+    pub fn default_rendition(&self, element: &str) -> Option<&Rendition> {
+        self.renditions
+            .iter()
+            .find(|rendition| rendition.selector() == Some(element))
+    }
+}
+
+/// Notation used by a rendition's content (`@scheme`).
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RenditionScheme {
+    /// Cascading Style Sheets declarations.
+    Css,
+    /// Extensible Stylesheet Language formatting objects.
+    Xslfo,
+    /// Informal prose description.
+    Free,
+    /// Any other notation.
+    Other,
+}
+
+/// A named rendering, serialised as `<rendition xml:id="…">…</rendition>`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "rendition", try_from = "RawRendition")]
+pub struct Rendition {
+    #[serde(rename = "@xml:id")]
+    id: XmlId,
+    #[serde(rename = "@scheme", skip_serializing_if = "Option::is_none")]
+    scheme: Option<RenditionScheme>,
+    #[serde(rename = "@selector", skip_serializing_if = "Option::is_none")]
+    selector: Option<String>,
+    #[serde(rename = "$text")]
+    content: String,
+}
+
+impl Rendition {
+    /// Builds a rendition from its identifier and styling content.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the content trims to
+    /// an empty string.
+    pub fn new(id: XmlId, content: impl Into<String>) -> Result<Self, HeaderValidationError> {
+        Ok(Self {
+            id,
+            scheme: None,
+            selector: None,
+            content: required_text(content, "rendition")?,
+        })
+    }
+
+    /// Declares the notation used by the content.
+    #[must_use]
+    pub const fn with_scheme(mut self, scheme: RenditionScheme) -> Self {
+        self.scheme = Some(scheme);
+        self
+    }
+
+    /// Applies the rendition by default to matching elements; blank selectors
+    /// clear it.
+    #[must_use]
+    pub fn with_selector(mut self, selector: impl Into<String>) -> Self {
+        self.selector = normalise_optional_text(selector);
+        self
+    }
+
+    /// Returns the rendition identifier.
+    #[must_use]
+    pub const fn id(&self) -> &XmlId {
+        &self.id
+    }
+
+    /// Returns the content notation when declared.
+    #[must_use]
+    pub const fn scheme(&self) -> Option<RenditionScheme> {
+        self.scheme
+    }
+
+    /// Returns the default selector when declared.
+    #[must_use]
+    pub fn selector(&self) -> Option<&str> {
+        self.selector.as_deref()
+    }
+
+    /// Returns the styling content.
+    #[must_use]
+    pub const fn content(&self) -> &str {
+        self.content.as_str()
+    }
+}
+
+#[derive(Deserialize)]
+struct RawRendition {
+    #[serde(rename = "@xml:id", alias = "@id")]
+    id: XmlId,
+    #[serde(rename = "@scheme", default)]
+    scheme: Option<RenditionScheme>,
+    #[serde(rename = "@selector", default)]
+    selector: Option<String>,
+    #[serde(rename = "$text", default)]
+    content: String,
+}
+
+impl TryFrom<RawRendition> for Rendition {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawRendition) -> Result<Self, Self::Error> {
+        let mut rendition =
+            Self::new(raw.id, raw.content)?.with_selector(raw.selector.unwrap_or_default());
+        rendition.scheme = raw.scheme;
+        Ok(rendition)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json as json;
+
+    fn id(value: &str) -> XmlId {
+        XmlId::new(value).unwrap_or_else(|error| panic!("valid identifier: {error}"))
+    }
+
+    #[test]
+    fn rendition_requires_content() {
+        assert_eq!(
+            Rendition::new(id("stress"), "  "),
+            Err(HeaderValidationError::EmptyField { field: "rendition" })
+        );
+    }
+
+    #[test]
+    fn resolves_pointer_references() {
+        let mut tags = TagsDecl::new();
+        tags.add_rendition(
+            Rendition::new(id("stress"), "font-weight: bold;")
+                .unwrap_or_else(|error| panic!("rendition should be valid: {error}")),
+        );
+
+        assert!(tags.rendition("#stress").is_some());
+        assert!(tags.rendition("shout").is_none());
+        assert!(tags.default_rendition("hi").is_none());
+    }
+
+    #[test]
+    fn deserialisation_rejects_blank_content() {
+        let result = json::from_str::<Rendition>(r#"{"@xml:id":"stress","$text":"  "}"#);
+
+        assert!(result.is_err(), "blank renditions should not deserialise");
+    }
+}
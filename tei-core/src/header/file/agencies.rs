@@ -0,0 +1,115 @@
+//! Sponsor, funder, and principal names recorded in the file description.
+//!
+//! Institutional archives record who sponsored, funded, and led a recording
+//! project. The names are plain text, so construction and deserialisation
+//! apply the usual trimming and non-empty rules with field-specific messages.
+
+use serde::Deserialize;
+use serde::de::{self, Deserializer};
+
+use super::FileDesc;
+use crate::header::{HeaderValidationError, required_text};
+
+impl FileDesc {
+    /// Records an organisation sponsoring the recording project.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the name trims to an
+    /// empty string.
+    pub fn add_sponsor(&mut self, sponsor: impl Into<String>) -> Result<(), HeaderValidationError> {
+        self.sponsors.push(required_text(sponsor, "sponsor")?);
+        Ok(())
+    }
+
+    /// Records an organisation funding the recording project.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the name trims to an
+    /// empty string.
+    pub fn add_funder(&mut self, funder: impl Into<String>) -> Result<(), HeaderValidationError> {
+        self.funders.push(required_text(funder, "funder")?);
+        Ok(())
+    }
+
+    /// Records a principal researcher leading the recording project.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the name trims to an
+    /// empty string.
+    pub fn add_principal(
+        &mut self,
+        principal: impl Into<String>,
+    ) -> Result<(), HeaderValidationError> {
+        self.principals.push(required_text(principal, "principal")?);
+        Ok(())
+    }
+
+    /// Returns the recorded sponsors.
+    #[must_use]
+    pub const fn sponsors(&self) -> &[String] {
+        self.sponsors.as_slice()
+    }
+
+    /// Returns the recorded funders.
+    #[must_use]
+    pub const fn funders(&self) -> &[String] {
+        self.funders.as_slice()
+    }
+
+    /// Returns the recorded principal researchers.
+    #[must_use]
+    pub const fn principals(&self) -> &[String] {
+        self.principals.as_slice()
+    }
+}
+
+pub(super) fn sponsors<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    validated_names(deserializer, "sponsor")
+}
+
+pub(super) fn funders<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    validated_names(deserializer, "funder")
+}
+
+pub(super) fn principals<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    validated_names(deserializer, "principal")
+}
+
+fn validated_names<'de, D>(deserializer: D, field: &'static str) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<String>::deserialize(deserializer)?
+        .into_iter()
+        .map(|name| required_text(name, field).map_err(de::Error::custom))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_blank_funders() {
+        let mut file_desc = FileDesc::from_title_str("Voices of the Valley")
+            .unwrap_or_else(|error| panic!("valid title: {error}"));
+
+        assert_eq!(
+            file_desc.add_funder("   "),
+            Err(HeaderValidationError::EmptyField { field: "funder" })
+        );
+        assert!(file_desc.funders().is_empty());
+    }
+}
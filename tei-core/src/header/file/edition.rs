@@ -0,0 +1,111 @@
+//! Edition statement (`<editionStmt>`) distinguishing re-released transcripts.
+//!
+//! Corrected releases carry an edition label such as "Second edition" along
+//! with optional notes describing what changed since the previous release.
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::{HeaderValidationError, normalise_optional_text, required_text};
+
+/// Edition label and change notes, serialised as
+/// `<editionStmt><edition>…</edition><note>…</note></editionStmt>`.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::EditionStmt;
+///
+/// let mut edition = EditionStmt::new("Second edition")?;
+/// edition.add_note("Corrected speaker attributions in act two");
+/// edition.add_note("   ");
+///
+/// assert_eq!(edition.edition(), "Second edition");
+/// assert_eq!(edition.notes(), ["Corrected speaker attributions in act two"]);
+/// # Ok::<(), tei_core::HeaderValidationError>(())
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "editionStmt", try_from = "RawEditionStmt")]
+pub struct EditionStmt {
+    edition: String,
+    #[serde(rename = "note", skip_serializing_if = "Vec::is_empty")]
+    notes: Vec<String>,
+}
+
+impl EditionStmt {
+    /// Builds an edition statement from its label.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the label trims to
+    /// an empty string.
+    pub fn new(edition: impl Into<String>) -> Result<Self, HeaderValidationError> {
+        Ok(Self {
+            edition: required_text(edition, "edition")?,
+            notes: Vec::new(),
+        })
+    }
+
+    /// Records a note about the edition; blank notes are ignored.
+    pub fn add_note(&mut self, note: impl Into<String>) {
+        self.notes.extend(normalise_optional_text(note));
+    }
+
+    /// Returns the edition label.
+    #[must_use]
+    pub const fn edition(&self) -> &str {
+        self.edition.as_str()
+    }
+
+    /// Returns the recorded notes in document order.
+    #[must_use]
+    pub const fn notes(&self) -> &[String] {
+        self.notes.as_slice()
+    }
+}
+
+#[derive(Deserialize)]
+struct RawEditionStmt {
+    #[serde(default)]
+    edition: String,
+    #[serde(rename = "note", default)]
+    notes: Vec<String>,
+}
+
+impl TryFrom<RawEditionStmt> for EditionStmt {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawEditionStmt) -> Result<Self, Self::Error> {
+        let mut statement = Self::new(raw.edition)?;
+        for note in raw.notes {
+            statement.add_note(note);
+        }
+        Ok(statement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json as json;
+
+    #[test]
+    fn edition_requires_label() {
+        let result = EditionStmt::new("\t");
+
+        assert_eq!(
+            result,
+            Err(HeaderValidationError::EmptyField { field: "edition" })
+        );
+    }
+
+    #[test]
+    fn deserialisation_trims_and_drops_blank_notes() {
+        let edition = json::from_str::<EditionStmt>(
+            r#"{"edition":" Second edition ","note":["  ","Fixed timestamps "]}"#,
+        )
+        .unwrap_or_else(|error| panic!("edition should deserialise: {error}"));
+
+        assert_eq!(edition.edition(), "Second edition");
+        assert_eq!(edition.notes(), ["Fixed timestamps"]);
+    }
+}
@@ -0,0 +1,185 @@
+//! Extent metadata (`<extent>`) recording audio duration and transcript size.
+//!
+//! Each figure is emitted as a `<measure>` element whose `@unit` names the
+//! quantity, so `<measure unit="words" quantity="1200"/>` records the word
+//! count. Durations are stored with whole-second precision.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Size of the transcript and the audio it was taken from.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use tei_core::Extent;
+///
+/// let extent = Extent::new()
+///     .with_duration(Duration::from_secs(2712))
+///     .with_utterance_count(48)
+///     .with_word_count(5310);
+///
+/// assert_eq!(extent.duration(), Some(Duration::from_secs(2712)));
+/// assert_eq!(extent.utterance_count(), Some(48));
+/// assert_eq!(extent.word_count(), Some(5310));
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(from = "ExtentElement", into = "ExtentElement")]
+pub struct Extent {
+    duration_secs: Option<u64>,
+    utterances: Option<usize>,
+    words: Option<usize>,
+}
+
+impl Extent {
+    /// Creates an extent without any measurements.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the audio duration, truncated to whole seconds.
+    #[must_use]
+    pub const fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration_secs = Some(duration.as_secs());
+        self
+    }
+
+    /// Records the number of utterances in the body.
+    #[must_use]
+    pub const fn with_utterance_count(mut self, utterances: usize) -> Self {
+        self.utterances = Some(utterances);
+        self
+    }
+
+    /// Records the number of words in the body.
+    #[must_use]
+    pub const fn with_word_count(mut self, words: usize) -> Self {
+        self.words = Some(words);
+        self
+    }
+
+    /// Returns the audio duration when recorded.
+    #[must_use]
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration_secs.map(Duration::from_secs)
+    }
+
+    /// Returns the utterance count when recorded.
+    #[must_use]
+    pub const fn utterance_count(&self) -> Option<usize> {
+        self.utterances
+    }
+
+    /// Returns the word count when recorded.
+    #[must_use]
+    pub const fn word_count(&self) -> Option<usize> {
+        self.words
+    }
+}
+
+/// Wire representation of `<extent>` as a list of measures.
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "extent")]
+struct ExtentElement {
+    #[serde(rename = "measure", default)]
+    measures: Vec<Measure>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct Measure {
+    #[serde(rename = "@unit")]
+    unit: MeasureUnit,
+    #[serde(rename = "@quantity")]
+    quantity: u64,
+}
+
+#[derive(Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum MeasureUnit {
+    Seconds,
+    Utterances,
+    Words,
+}
+
+impl From<ExtentElement> for Extent {
+    fn from(element: ExtentElement) -> Self {
+        let mut extent = Self::default();
+
+        for Measure { unit, quantity } in element.measures {
+            match unit {
+                MeasureUnit::Seconds => extent.duration_secs = Some(quantity),
+                MeasureUnit::Utterances => extent.utterances = Some(saturating_usize(quantity)),
+                MeasureUnit::Words => extent.words = Some(saturating_usize(quantity)),
+            }
+        }
+
+        extent
+    }
+}
+
+impl From<Extent> for ExtentElement {
+    fn from(extent: Extent) -> Self {
+        let measures = [
+            (MeasureUnit::Seconds, extent.duration_secs),
+            (
+                MeasureUnit::Utterances,
+                extent.utterances.map(saturating_u64),
+            ),
+            (MeasureUnit::Words, extent.words.map(saturating_u64)),
+        ]
+        .into_iter()
+        .filter_map(|(unit, quantity)| {
+            quantity.map(|value| Measure {
+                unit,
+                quantity: value,
+            })
+        })
+        .collect();
+
+        Self { measures }
+    }
+}
+
+fn saturating_usize(value: u64) -> usize {
+    usize::try_from(value).unwrap_or(usize::MAX)
+}
+
+fn saturating_u64(value: usize) -> u64 {
+    u64::try_from(value).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json as json;
+
+    #[test]
+    fn duration_is_truncated_to_whole_seconds() {
+        let extent = Extent::new().with_duration(Duration::from_millis(90_750));
+
+        assert_eq!(extent.duration(), Some(Duration::from_secs(90)));
+    }
+
+    #[test]
+    fn omits_unrecorded_measures() {
+        let extent = Extent::new().with_word_count(12);
+        let value = json::to_value(extent)
+            .unwrap_or_else(|error| panic!("extent should serialise: {error}"));
+
+        assert_eq!(
+            value,
+            json::json!({"measure": [{"@unit": "words", "@quantity": 12}]})
+        );
+    }
+
+    #[test]
+    fn deserialisation_rejects_unknown_units() {
+        let result = json::from_str::<Extent>(r#"{"measure":[{"@unit":"pages","@quantity":3}]}"#);
+
+        assert!(result.is_err(), "unknown units should not deserialise");
+    }
+}
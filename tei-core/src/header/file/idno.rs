@@ -0,0 +1,235 @@
+//! Typed identifiers (`<idno>`) linking a transcript to external systems.
+//!
+//! Podcast GUIDs are opaque, so they only need visible text, whereas DOIs and
+//! URLs are checked against their expected shape before they are accepted.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::{HeaderValidationError, required_text};
+
+/// Kind of identifier, serialised as `@type`.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::IdnoType;
+///
+/// assert_eq!(IdnoType::Doi.as_str(), "doi");
+/// assert_eq!(IdnoType::Url.to_string(), "url");
+/// ```
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdnoType {
+    /// An opaque globally unique identifier, such as a podcast episode GUID.
+    Guid,
+    /// A Digital Object Identifier such as `10.5281/zenodo.1234`.
+    Doi,
+    /// An absolute `http` or `https` URL, such as a feed address.
+    Url,
+}
+
+impl IdnoType {
+    /// Returns the serialised `@type` value.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Guid => "guid",
+            Self::Doi => "doi",
+            Self::Url => "url",
+        }
+    }
+
+    fn accepts(self, value: &str) -> bool {
+        match self {
+            Self::Guid => true,
+            Self::Doi => is_doi(value),
+            Self::Url => is_http_url(value),
+        }
+    }
+}
+
+impl fmt::Display for IdnoType {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// Identifier with an optional type, serialised as `<idno type="…">…</idno>`.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{Idno, IdnoType};
+///
+/// let doi = Idno::new(IdnoType::Doi, "10.5281/zenodo.1234")?;
+/// assert_eq!(doi.idno_type(), Some(IdnoType::Doi));
+/// assert!(Idno::new(IdnoType::Url, "feeds.example.org/rss").is_err());
+/// # Ok::<(), tei_core::HeaderValidationError>(())
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "idno", try_from = "RawIdno")]
+pub struct Idno {
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    idno_type: Option<IdnoType>,
+    #[serde(rename = "$text")]
+    value: String,
+}
+
+impl Idno {
+    /// Builds a typed identifier, validating the value against its type.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the value trims to
+    /// an empty string, or [`HeaderValidationError::InvalidIdno`] when a DOI or
+    /// URL is malformed.
+    pub fn new(
+        idno_type: IdnoType,
+        value: impl Into<String>,
+    ) -> Result<Self, HeaderValidationError> {
+        Self::from_parts(Some(idno_type), value)
+    }
+
+    /// Builds an identifier without a type; only visible text is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the value trims to
+    /// an empty string.
+    pub fn untyped(value: impl Into<String>) -> Result<Self, HeaderValidationError> {
+        Self::from_parts(None, value)
+    }
+
+    /// Returns the identifier type when recorded.
+    #[must_use]
+    pub const fn idno_type(&self) -> Option<IdnoType> {
+        self.idno_type
+    }
+
+    /// Returns the identifier value.
+    #[must_use]
+    pub const fn value(&self) -> &str {
+        self.value.as_str()
+    }
+
+    fn from_parts(
+        idno_type: Option<IdnoType>,
+        value: impl Into<String>,
+    ) -> Result<Self, HeaderValidationError> {
+        let trimmed = required_text(value, "idno")?;
+
+        if let Some(kind) = idno_type
+            && !kind.accepts(&trimmed)
+        {
+            return Err(HeaderValidationError::InvalidIdno {
+                idno_type: kind,
+                value: trimmed,
+            });
+        }
+
+        Ok(Self {
+            idno_type,
+            value: trimmed,
+        })
+    }
+}
+
+fn is_doi(value: &str) -> bool {
+    let Some((prefix, suffix)) = value.split_once('/') else {
+        return false;
+    };
+    let Some(registrant) = prefix.strip_prefix("10.") else {
+        return false;
+    };
+
+    !registrant.is_empty()
+        && registrant
+            .split('.')
+            .all(|part| !part.is_empty() && part.chars().all(|ch| ch.is_ascii_digit()))
+        && !suffix.is_empty()
+        && !suffix.chars().any(char::is_whitespace)
+}
+
+fn is_http_url(value: &str) -> bool {
+    let Some(rest) = value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"))
+    else {
+        return false;
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+
+    !host.is_empty() && !value.chars().any(char::is_whitespace)
+}
+
+#[derive(Deserialize)]
+struct RawIdno {
+    #[serde(rename = "@type", default)]
+    idno_type: Option<IdnoType>,
+    #[serde(rename = "$text", default)]
+    value: String,
+}
+
+impl TryFrom<RawIdno> for Idno {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawIdno) -> Result<Self, Self::Error> {
+        Self::from_parts(raw.idno_type, raw.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json as json;
+
+    #[rstest]
+    #[case(IdnoType::Doi, "10.5281/zenodo.1234")]
+    #[case(IdnoType::Doi, "10.1000.10/abc(def)")]
+    #[case(IdnoType::Url, "https://feeds.example.org/rss")]
+    #[case(IdnoType::Url, "http://example.org")]
+    #[case(IdnoType::Guid, "c0a8ef2e-episode-12")]
+    fn accepts_well_formed_values(#[case] idno_type: IdnoType, #[case] value: &str) {
+        let idno = Idno::new(idno_type, value)
+            .unwrap_or_else(|error| panic!("`{value}` should be valid: {error}"));
+
+        assert_eq!(idno.value(), value);
+    }
+
+    #[rstest]
+    #[case(IdnoType::Doi, "doi:10.5281/zenodo.1234")]
+    #[case(IdnoType::Doi, "10.abc/zenodo")]
+    #[case(IdnoType::Doi, "10.5281/")]
+    #[case(IdnoType::Url, "ftp://example.org")]
+    #[case(IdnoType::Url, "https:///feed")]
+    #[case(IdnoType::Url, "https://example.org/a feed")]
+    fn rejects_malformed_values(#[case] idno_type: IdnoType, #[case] value: &str) {
+        let result = Idno::new(idno_type, value);
+
+        assert_eq!(
+            result,
+            Err(HeaderValidationError::InvalidIdno {
+                idno_type,
+                value: value.to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_blank_values() {
+        assert_eq!(
+            Idno::untyped("  "),
+            Err(HeaderValidationError::EmptyField { field: "idno" })
+        );
+    }
+
+    #[test]
+    fn deserialisation_validates_typed_values() {
+        let result = json::from_str::<Idno>(r#"{"@type":"doi","$text":"not-a-doi"}"#);
+
+        assert!(result.is_err(), "malformed DOI should not deserialise");
+    }
+}
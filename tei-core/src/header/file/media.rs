@@ -0,0 +1,285 @@
+//! Source media (`<media>`) linking a transcript to its audio file.
+//!
+//! Media records carry the file URL, its MIME type, and optionally the running
+//! time as a W3C `xsd:duration` (for example `PT1H2M3S`). An optional `xml:id`
+//! lets timeline anchors point at the recording they are measured against.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::{HeaderValidationError, required_text};
+use crate::text::XmlId;
+
+/// A media file the transcript was made from.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use tei_core::{Media, XmlId};
+///
+/// let media = Media::new("https://example.org/wolf-359/ep12.mp3", "audio/mpeg")?
+///     .with_duration(Duration::from_secs(3723))
+///     .with_id(XmlId::new("ep12-audio")?);
+///
+/// assert_eq!(media.mime_type(), "audio/mpeg");
+/// assert_eq!(media.duration(), Some(Duration::from_secs(3723)));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "media", try_from = "RawMedia", into = "RawMedia")]
+pub struct Media {
+    id: Option<XmlId>,
+    url: String,
+    mime_type: String,
+    duration: Option<Duration>,
+}
+
+impl Media {
+    /// Builds a media record from its URL and MIME type.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the URL is blank, or
+    /// [`HeaderValidationError::InvalidMimeType`] when the MIME type is not of
+    /// the form `type/subtype`.
+    pub fn new(
+        url: impl Into<String>,
+        mime_type: impl Into<String>,
+    ) -> Result<Self, HeaderValidationError> {
+        let url_text = required_text(url, "media url")?;
+        let mime = required_text(mime_type, "media type")?;
+
+        if !is_mime_type(&mime) {
+            return Err(HeaderValidationError::InvalidMimeType { value: mime });
+        }
+
+        Ok(Self {
+            id: None,
+            url: url_text,
+            mime_type: mime,
+            duration: None,
+        })
+    }
+
+    /// Assigns the identifier timeline anchors use to reference the media.
+    #[must_use]
+    pub fn with_id(mut self, id: XmlId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Records the media running time.
+    #[must_use]
+    pub const fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Returns the identifier when assigned.
+    #[must_use]
+    pub const fn id(&self) -> Option<&XmlId> {
+        self.id.as_ref()
+    }
+
+    /// Returns the media URL.
+    #[must_use]
+    pub const fn url(&self) -> &str {
+        self.url.as_str()
+    }
+
+    /// Returns the MIME type.
+    #[must_use]
+    pub const fn mime_type(&self) -> &str {
+        self.mime_type.as_str()
+    }
+
+    /// Returns the running time when recorded.
+    #[must_use]
+    pub const fn duration(&self) -> Option<Duration> {
+        self.duration
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "media")]
+struct RawMedia {
+    #[serde(
+        rename = "@xml:id",
+        alias = "@id",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    id: Option<XmlId>,
+    #[serde(rename = "@url", default)]
+    url: String,
+    #[serde(rename = "@mimeType", default)]
+    mime_type: String,
+    #[serde(rename = "@dur", skip_serializing_if = "Option::is_none", default)]
+    duration: Option<String>,
+}
+
+impl TryFrom<RawMedia> for Media {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawMedia) -> Result<Self, Self::Error> {
+        let mut media = Self::new(raw.url, raw.mime_type)?;
+        media.id = raw.id;
+        media.duration = raw
+            .duration
+            .map(|value| {
+                parse_xsd_duration(&value).ok_or(HeaderValidationError::InvalidDuration { value })
+            })
+            .transpose()?;
+        Ok(media)
+    }
+}
+
+impl From<Media> for RawMedia {
+    fn from(media: Media) -> Self {
+        Self {
+            id: media.id,
+            url: media.url,
+            mime_type: media.mime_type,
+            duration: media.duration.map(format_xsd_duration),
+        }
+    }
+}
+
+fn is_mime_type(value: &str) -> bool {
+    let token = |part: &str| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || "!#$&^_.+-".contains(ch))
+    };
+
+    value
+        .split_once('/')
+        .is_some_and(|(kind, subtype)| token(kind) && token(subtype))
+}
+
+/// Parses the day and time components of an `xsd:duration`.
+///
+/// Years and months are rejected because their length in seconds is
+/// ambiguous.
+pub(crate) fn parse_xsd_duration(value: &str) -> Option<Duration> {
+    let rest = value.trim().strip_prefix('P')?;
+    let (date_part, time_part) = match rest.split_once('T') {
+        Some((_, "")) => return None,
+        Some((date, time)) => (date, time),
+        None if rest.is_empty() => return None,
+        None => (rest, ""),
+    };
+
+    let days = Duration::from_secs(parse_days(date_part)?.checked_mul(86_400)?);
+    days.checked_add(parse_time(time_part)?)
+}
+
+fn parse_days(date_part: &str) -> Option<u64> {
+    match date_part.strip_suffix('D') {
+        Some(count) => count.parse().ok(),
+        None if date_part.is_empty() => Some(0),
+        None => None,
+    }
+}
+
+fn parse_time(time_part: &str) -> Option<Duration> {
+    let (hours, after_hours) = take_component(time_part, 'H')?;
+    let (minutes, after_minutes) = take_component(after_hours, 'M')?;
+    let seconds = match after_minutes.strip_suffix('S') {
+        Some(amount) => parse_seconds(amount)?,
+        None if after_minutes.is_empty() => Duration::ZERO,
+        None => return None,
+    };
+    let whole = hours
+        .checked_mul(3_600)?
+        .checked_add(minutes.checked_mul(60)?)?;
+
+    Duration::from_secs(whole).checked_add(seconds)
+}
+
+fn take_component(input: &str, designator: char) -> Option<(u64, &str)> {
+    match input.split_once(designator) {
+        Some((count, rest)) => Some((count.parse().ok()?, rest)),
+        None => Some((0, input)),
+    }
+}
+
+fn parse_seconds(amount: &str) -> Option<Duration> {
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if fraction.len() > 9 || !fraction.chars().all(|ch| ch.is_ascii_digit()) {
+        return None;
+    }
+
+    let padded = format!("{fraction:0<9}");
+    Some(Duration::new(whole.parse().ok()?, padded.parse().ok()?))
+}
+
+fn format_xsd_duration(duration: Duration) -> String {
+    let total = duration.as_secs();
+    let hours = total.div_euclid(3_600);
+    let minutes = total.rem_euclid(3_600).div_euclid(60);
+    let seconds = total.rem_euclid(60);
+    let digits = format!("{:09}", duration.subsec_nanos());
+    let fraction = digits.trim_end_matches('0');
+    let hours_part = if hours > 0 {
+        format!("{hours}H")
+    } else {
+        String::new()
+    };
+    let minutes_part = if minutes > 0 {
+        format!("{minutes}M")
+    } else {
+        String::new()
+    };
+    let fraction_part = if fraction.is_empty() {
+        String::new()
+    } else {
+        format!(".{fraction}")
+    };
+
+    format!("PT{hours_part}{minutes_part}{seconds}{fraction_part}S")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("PT1H2M3S", Some(Duration::from_secs(3_723)))]
+    #[case("PT45M", Some(Duration::from_mins(45)))]
+    #[case("PT1.25S", Some(Duration::from_millis(1_250)))]
+    #[case("P1DT1S", Some(Duration::from_secs(86_401)))]
+    #[case("P1D", Some(Duration::from_hours(24)))]
+    #[case("PT", None)]
+    #[case("P", None)]
+    #[case("P1M", None)]
+    #[case("1H", None)]
+    #[case("PT1.5M", None)]
+    fn parses_xsd_durations(#[case] value: &str, #[case] expected: Option<Duration>) {
+        assert_eq!(parse_xsd_duration(value), expected, "{value}");
+    }
+
+    #[rstest]
+    #[case(Duration::from_secs(3_723), "PT1H2M3S")]
+    #[case(Duration::from_mins(1), "PT1M0S")]
+    #[case(Duration::from_millis(1_250), "PT1.25S")]
+    #[case(Duration::ZERO, "PT0S")]
+    fn formats_xsd_durations(#[case] duration: Duration, #[case] expected: &str) {
+        assert_eq!(format_xsd_duration(duration), expected);
+    }
+
+    #[test]
+    fn rejects_malformed_mime_types() {
+        assert_eq!(
+            Media::new("ep12.mp3", "mpeg"),
+            Err(HeaderValidationError::InvalidMimeType {
+                value: "mpeg".to_owned(),
+            })
+        );
+    }
+}
@@ -0,0 +1,348 @@
+//! Bibliographic file description (`<fileDesc>`) for TEI headers.
+//! Validates the titles and normalises the optional synopsis text.
+
+mod agencies;
+mod edition;
+mod extent;
+mod idno;
+mod media;
+mod notes;
+mod publication;
+mod resp;
+mod series;
+mod source;
+mod titles;
+
+pub use edition::EditionStmt;
+pub use extent::Extent;
+pub use idno::{Idno, IdnoType};
+pub use media::Media;
+pub(crate) use media::parse_xsd_duration;
+pub use notes::NotesStmt;
+pub use publication::{Availability, Licence, PublicationStmt};
+pub use resp::RespStmt;
+pub use series::SeriesStmt;
+pub use source::SourceDesc;
+
+use crate::title::{DocumentTitle, DocumentTitleError, TitleType};
+
+use serde::{Deserialize, Serialize};
+
+use super::normalise_optional_text;
+use titles::FileTitles;
+
+/// Bibliographic metadata describing the TEI file.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "fileDesc")]
+pub struct FileDesc {
+    #[serde(rename = "title")]
+    titles: FileTitles,
+    #[serde(rename = "respStmt", skip_serializing_if = "Vec::is_empty", default)]
+    resp_stmts: Vec<RespStmt>,
+    #[serde(
+        rename = "sponsor",
+        skip_serializing_if = "Vec::is_empty",
+        default,
+        deserialize_with = "agencies::sponsors"
+    )]
+    sponsors: Vec<String>,
+    #[serde(
+        rename = "funder",
+        skip_serializing_if = "Vec::is_empty",
+        default,
+        deserialize_with = "agencies::funders"
+    )]
+    funders: Vec<String>,
+    #[serde(
+        rename = "principal",
+        skip_serializing_if = "Vec::is_empty",
+        default,
+        deserialize_with = "agencies::principals"
+    )]
+    principals: Vec<String>,
+    #[serde(
+        rename = "editionStmt",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    edition: Option<EditionStmt>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    extent: Option<Extent>,
+    #[serde(
+        rename = "publicationStmt",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    publication: Option<PublicationStmt>,
+    #[serde(rename = "idno", skip_serializing_if = "Vec::is_empty", default)]
+    idnos: Vec<Idno>,
+    #[serde(
+        rename = "seriesStmt",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    series: Option<SeriesStmt>,
+    #[serde(rename = "notesStmt", skip_serializing_if = "Option::is_none", default)]
+    notes: Option<NotesStmt>,
+    #[serde(
+        rename = "sourceDesc",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    source: Option<SourceDesc>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    synopsis: Option<String>,
+}
+
+impl FileDesc {
+    /// Builds a file description from a validated title.
+    #[must_use]
+    pub const fn new(title: DocumentTitle) -> Self {
+        Self {
+            titles: FileTitles::new(title),
+            resp_stmts: Vec::new(),
+            sponsors: Vec::new(),
+            funders: Vec::new(),
+            principals: Vec::new(),
+            edition: None,
+            extent: None,
+            publication: None,
+            idnos: Vec::new(),
+            series: None,
+            notes: None,
+            source: None,
+            synopsis: None,
+        }
+    }
+
+    /// Validates a raw title before creating the file description.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DocumentTitleError::Empty`] when the supplied title trims to an
+    /// empty string.
+    pub fn from_title_str(value: &str) -> Result<Self, DocumentTitleError> {
+        DocumentTitle::new(value).map(Self::new)
+    }
+
+    /// Credits a contributor, such as a transcriber or editor.
+    pub fn add_resp_stmt(&mut self, resp_stmt: RespStmt) {
+        self.resp_stmts.push(resp_stmt);
+    }
+
+    /// Attaches the edition statement for corrected re-releases.
+    #[must_use]
+    pub fn with_edition_stmt(mut self, edition: EditionStmt) -> Self {
+        self.edition = Some(edition);
+        self
+    }
+
+    /// Attaches the extent measurements.
+    #[must_use]
+    pub const fn with_extent(mut self, extent: Extent) -> Self {
+        self.set_extent(extent);
+        self
+    }
+
+    /// Replaces the extent measurements.
+    pub const fn set_extent(&mut self, extent: Extent) {
+        self.extent = Some(extent);
+    }
+
+    /// Attaches the publication statement.
+    #[must_use]
+    pub fn with_publication_stmt(mut self, publication: PublicationStmt) -> Self {
+        self.publication = Some(publication);
+        self
+    }
+
+    /// Records an identifier for the transcript, such as its DOI.
+    pub fn add_idno(&mut self, idno: Idno) {
+        self.idnos.push(idno);
+    }
+
+    /// Assigns a series statement holding just the series title.
+    ///
+    /// Blank titles clear the series statement. Use
+    /// [`FileDesc::with_series_stmt`] to record an identifier or episode
+    /// number as well.
+    #[must_use]
+    pub fn with_series(mut self, series: impl Into<String>) -> Self {
+        self.series = SeriesStmt::new(series).ok();
+        self
+    }
+
+    /// Attaches a structured series statement.
+    #[must_use]
+    pub fn with_series_stmt(mut self, series: SeriesStmt) -> Self {
+        self.series = Some(series);
+        self
+    }
+
+    /// Attaches free-form header notes.
+    #[must_use]
+    pub fn with_notes_stmt(mut self, notes: NotesStmt) -> Self {
+        self.notes = Some(notes);
+        self
+    }
+
+    /// Assigns an optional synopsis.
+    #[must_use]
+    pub fn with_synopsis(mut self, synopsis: impl Into<String>) -> Self {
+        self.synopsis = normalise_optional_text(synopsis);
+        self
+    }
+
+    /// Assigns a subtitle, serialised as `<title type="sub">`.
+    #[must_use]
+    pub fn with_subtitle(mut self, subtitle: DocumentTitle) -> Self {
+        self.titles.set_subtitle(subtitle);
+        self
+    }
+
+    /// Replaces the main title, keeping any subtitle and alternatives.
+    pub fn set_title(&mut self, title: DocumentTitle) {
+        self.titles.set_main(title);
+    }
+
+    /// Records an alternative title, serialised as `<title type="alt">`.
+    pub fn add_alternative_title(&mut self, title: DocumentTitle) {
+        self.titles.push_alternative(title);
+    }
+
+    /// Returns the main document title.
+    #[must_use]
+    pub const fn title(&self) -> &DocumentTitle {
+        self.titles.main()
+    }
+
+    /// Returns the subtitle when present.
+    #[must_use]
+    pub const fn subtitle(&self) -> Option<&DocumentTitle> {
+        self.titles.subtitle()
+    }
+
+    /// Returns the recorded alternative titles.
+    #[must_use]
+    pub const fn alternative_titles(&self) -> &[DocumentTitle] {
+        self.titles.alternatives()
+    }
+
+    /// Iterates over every title alongside its type, main title first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::{DocumentTitle, FileDesc, TitleType};
+    ///
+    /// let mut file_desc = FileDesc::from_title_str("Wolf 359")?
+    ///     .with_subtitle(DocumentTitle::new("Box of Sand")?);
+    /// file_desc.add_alternative_title(DocumentTitle::new("Hephaestus Logs")?);
+    ///
+    /// let titles: Vec<_> = file_desc
+    ///     .titles()
+    ///     .map(|(title_type, title)| (title_type, title.as_str()))
+    ///     .collect();
+    /// assert_eq!(
+    ///     titles,
+    ///     [
+    ///         (TitleType::Main, "Wolf 359"),
+    ///         (TitleType::Sub, "Box of Sand"),
+    ///         (TitleType::Alt, "Hephaestus Logs"),
+    ///     ]
+    /// );
+    /// # Ok::<(), tei_core::DocumentTitleError>(())
+    /// ```
+    pub fn titles(&self) -> impl Iterator<Item = (TitleType, &DocumentTitle)> {
+        self.titles.iter()
+    }
+
+    /// Returns the credited contributors.
+    #[must_use]
+    pub const fn resp_stmts(&self) -> &[RespStmt] {
+        self.resp_stmts.as_slice()
+    }
+
+    /// Returns the edition statement when present.
+    #[must_use]
+    pub const fn edition_stmt(&self) -> Option<&EditionStmt> {
+        self.edition.as_ref()
+    }
+
+    /// Returns the extent measurements when present.
+    #[must_use]
+    pub const fn extent(&self) -> Option<&Extent> {
+        self.extent.as_ref()
+    }
+
+    /// Returns the publication statement when present.
+    #[must_use]
+    pub const fn publication_stmt(&self) -> Option<&PublicationStmt> {
+        self.publication.as_ref()
+    }
+
+    /// Returns the identifiers recorded for the transcript.
+    #[must_use]
+    pub const fn idnos(&self) -> &[Idno] {
+        self.idnos.as_slice()
+    }
+
+    /// Returns the series title when present.
+    #[must_use]
+    pub fn series(&self) -> Option<&str> {
+        self.series.as_ref().map(SeriesStmt::title)
+    }
+
+    /// Returns the series statement when present.
+    #[must_use]
+    pub const fn series_stmt(&self) -> Option<&SeriesStmt> {
+        self.series.as_ref()
+    }
+
+    /// Returns the notes statement when present.
+    #[must_use]
+    pub const fn notes_stmt(&self) -> Option<&NotesStmt> {
+        self.notes.as_ref()
+    }
+
+    /// Returns the synopsis when present.
+    #[must_use]
+    pub fn synopsis(&self) -> Option<&str> {
+        self.synopsis.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_desc_carries_optional_metadata() {
+        let file_desc = FileDesc::from_title_str("Wolf 359")
+            .unwrap_or_else(|error| panic!("valid title: {error}"))
+            .with_series("Kakos Industries")
+            .with_synopsis("Drama podcast");
+
+        assert_eq!(file_desc.series(), Some("Kakos Industries"));
+        assert_eq!(file_desc.synopsis(), Some("Drama podcast"));
+    }
+
+    #[test]
+    fn title_keeps_returning_the_main_title() {
+        let subtitle =
+            DocumentTitle::new("Box of Sand").unwrap_or_else(|error| panic!("valid: {error}"));
+        let mut file_desc = FileDesc::from_title_str("Wolf 359")
+            .unwrap_or_else(|error| panic!("valid title: {error}"))
+            .with_subtitle(subtitle);
+        file_desc.add_alternative_title(
+            DocumentTitle::new("Hephaestus Logs").unwrap_or_else(|error| panic!("valid: {error}")),
+        );
+
+        assert_eq!(file_desc.title().as_str(), "Wolf 359");
+        assert_eq!(
+            file_desc.subtitle().map(DocumentTitle::as_str),
+            Some("Box of Sand")
+        );
+        assert_eq!(file_desc.alternative_titles().len(), 1);
+    }
+}
@@ -0,0 +1,107 @@
+//! Notes statement (`<notesStmt>`) holding free-form header notes.
+//!
+//! Editorial caveats about the source audio belong here rather than in the
+//! synopsis. TEI requires at least one `<note>`, and every note must contain
+//! visible text.
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::{HeaderValidationError, required_text};
+
+/// Free-form notes about the transcript.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::NotesStmt;
+///
+/// let mut notes = NotesStmt::new(["Audio drops out between 12:03 and 12:41."])?;
+/// notes.add_note("Music cues are not transcribed.")?;
+///
+/// assert_eq!(notes.notes().len(), 2);
+/// # Ok::<(), tei_core::HeaderValidationError>(())
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "notesStmt", try_from = "RawNotesStmt")]
+pub struct NotesStmt {
+    #[serde(rename = "note")]
+    notes: Vec<String>,
+}
+
+impl NotesStmt {
+    /// Builds a notes statement from one or more notes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when no notes are
+    /// supplied or any note trims to an empty string.
+    pub fn new<S>(notes: impl IntoIterator<Item = S>) -> Result<Self, HeaderValidationError>
+    where
+        S: Into<String>,
+    {
+        let validated = notes
+            .into_iter()
+            .map(|note| required_text(note, "note"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if validated.is_empty() {
+            return Err(HeaderValidationError::EmptyField { field: "notes" });
+        }
+
+        Ok(Self { notes: validated })
+    }
+
+    /// Appends a note.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the note trims to an
+    /// empty string.
+    pub fn add_note(&mut self, note: impl Into<String>) -> Result<(), HeaderValidationError> {
+        self.notes.push(required_text(note, "note")?);
+        Ok(())
+    }
+
+    /// Returns the recorded notes in document order.
+    #[must_use]
+    pub const fn notes(&self) -> &[String] {
+        self.notes.as_slice()
+    }
+}
+
+#[derive(Deserialize)]
+struct RawNotesStmt {
+    #[serde(rename = "note", default)]
+    notes: Vec<String>,
+}
+
+impl TryFrom<RawNotesStmt> for NotesStmt {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawNotesStmt) -> Result<Self, Self::Error> {
+        Self::new(raw.notes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json as json;
+
+    #[rstest]
+    #[case(Vec::new(), "notes")]
+    #[case(vec!["Recorded live", "  "], "note")]
+    fn rejects_missing_or_blank_notes(#[case] notes: Vec<&str>, #[case] field: &'static str) {
+        let result = NotesStmt::new(notes);
+
+        assert_eq!(result, Err(HeaderValidationError::EmptyField { field }));
+    }
+
+    #[test]
+    fn deserialisation_rejects_blank_notes() {
+        let result = json::from_str::<NotesStmt>(r#"{"note":["\t"]}"#);
+
+        assert!(result.is_err(), "blank notes should not deserialise");
+    }
+}
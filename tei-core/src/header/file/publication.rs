@@ -0,0 +1,254 @@
+//! Publication statement (`<publicationStmt>`) recording who released the
+//! transcript, when, and under which licence.
+//!
+//! Archives cannot publish documents without rights metadata, so every text
+//! field is trimmed and blank values are rejected during construction and
+//! deserialisation alike.
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::{HeaderValidationError, normalise_optional_text, required_text};
+
+/// Publication details for the transcript.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{Availability, Licence, PublicationStmt};
+///
+/// let licence = Licence::new("CC BY 4.0", "https://creativecommons.org/licenses/by/4.0/")?;
+/// let publication = PublicationStmt::new("Night Vale Presents")?
+///     .with_date("2024-05-01")
+///     .with_availability(Availability::new().with_licence(licence));
+///
+/// assert_eq!(publication.publisher(), "Night Vale Presents");
+/// assert_eq!(publication.date(), Some("2024-05-01"));
+/// # Ok::<(), tei_core::HeaderValidationError>(())
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "publicationStmt", try_from = "RawPublicationStmt")]
+pub struct PublicationStmt {
+    publisher: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    availability: Option<Availability>,
+}
+
+impl PublicationStmt {
+    /// Builds a publication statement for the named publisher.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the publisher trims
+    /// to an empty string.
+    pub fn new(publisher: impl Into<String>) -> Result<Self, HeaderValidationError> {
+        Ok(Self {
+            publisher: required_text(publisher, "publisher")?,
+            date: None,
+            availability: None,
+        })
+    }
+
+    /// Assigns the publication date; blank values clear it.
+    #[must_use]
+    pub fn with_date(mut self, date: impl Into<String>) -> Self {
+        self.date = normalise_optional_text(date);
+        self
+    }
+
+    /// Attaches availability and licensing details.
+    #[must_use]
+    pub fn with_availability(mut self, availability: Availability) -> Self {
+        self.availability = Some(availability);
+        self
+    }
+
+    /// Returns the publisher name.
+    #[must_use]
+    pub const fn publisher(&self) -> &str {
+        self.publisher.as_str()
+    }
+
+    /// Returns the publication date when present.
+    #[must_use]
+    pub fn date(&self) -> Option<&str> {
+        self.date.as_deref()
+    }
+
+    /// Returns the availability statement when present.
+    #[must_use]
+    pub const fn availability(&self) -> Option<&Availability> {
+        self.availability.as_ref()
+    }
+}
+
+#[derive(Deserialize)]
+struct RawPublicationStmt {
+    #[serde(default)]
+    publisher: String,
+    #[serde(default)]
+    date: Option<String>,
+    #[serde(default)]
+    availability: Option<Availability>,
+}
+
+impl TryFrom<RawPublicationStmt> for PublicationStmt {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawPublicationStmt) -> Result<Self, Self::Error> {
+        let mut statement = Self::new(raw.publisher)?;
+        statement.date = raw.date.and_then(normalise_optional_text);
+        statement.availability = raw.availability;
+        Ok(statement)
+    }
+}
+
+/// Access conditions for the transcript, serialised as `<availability>`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "availability")]
+pub struct Availability {
+    #[serde(rename = "licence", skip_serializing_if = "Option::is_none", default)]
+    licence: Option<Licence>,
+}
+
+impl Availability {
+    /// Creates an availability statement without a licence.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attaches the licence governing reuse.
+    #[must_use]
+    pub fn with_licence(mut self, licence: Licence) -> Self {
+        self.licence = Some(licence);
+        self
+    }
+
+    /// Returns the licence when present.
+    #[must_use]
+    pub const fn licence(&self) -> Option<&Licence> {
+        self.licence.as_ref()
+    }
+}
+
+/// Licence text and/or URL, serialised as `<licence target="…">…</licence>`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "licence", try_from = "RawLicence")]
+pub struct Licence {
+    #[serde(rename = "@target", skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(rename = "$text", skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+}
+
+impl Licence {
+    /// Builds a licence from its descriptive text and URL.
+    ///
+    /// Either value may be blank, but not both.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when both the text and
+    /// URL trim to empty strings.
+    pub fn new(
+        text: impl Into<String>,
+        url: impl Into<String>,
+    ) -> Result<Self, HeaderValidationError> {
+        Self::from_parts(normalise_optional_text(text), normalise_optional_text(url))
+    }
+
+    /// Returns the licence text when present.
+    #[must_use]
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Returns the licence URL when present.
+    #[must_use]
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    fn from_parts(
+        text: Option<String>,
+        url: Option<String>,
+    ) -> Result<Self, HeaderValidationError> {
+        if text.is_none() && url.is_none() {
+            return Err(HeaderValidationError::EmptyField { field: "licence" });
+        }
+
+        Ok(Self { url, text })
+    }
+}
+
+#[derive(Deserialize)]
+struct RawLicence {
+    #[serde(rename = "@target", default)]
+    url: Option<String>,
+    #[serde(rename = "$text", default)]
+    text: Option<String>,
+}
+
+impl TryFrom<RawLicence> for Licence {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawLicence) -> Result<Self, Self::Error> {
+        Self::from_parts(
+            raw.text.and_then(normalise_optional_text),
+            raw.url.and_then(normalise_optional_text),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json as json;
+
+    #[test]
+    fn publication_requires_publisher() {
+        let result = PublicationStmt::new("   ");
+
+        assert_eq!(
+            result,
+            Err(HeaderValidationError::EmptyField { field: "publisher" })
+        );
+    }
+
+    #[test]
+    fn licence_requires_text_or_url() {
+        let result = Licence::new(" ", "\t");
+
+        assert_eq!(
+            result,
+            Err(HeaderValidationError::EmptyField { field: "licence" })
+        );
+    }
+
+    #[test]
+    fn licence_accepts_url_only() {
+        let licence = Licence::new("", " https://example.org/licence ")
+            .unwrap_or_else(|error| panic!("URL-only licence should be valid: {error}"));
+
+        assert_eq!(licence.url(), Some("https://example.org/licence"));
+        assert!(licence.text().is_none());
+    }
+
+    #[test]
+    fn deserialisation_rejects_blank_publisher() {
+        let result = json::from_str::<PublicationStmt>(r#"{"publisher":"   "}"#);
+
+        assert!(result.is_err(), "blank publisher should not deserialise");
+    }
+
+    #[test]
+    fn deserialisation_normalises_blank_dates() {
+        let publication =
+            json::from_str::<PublicationStmt>(r#"{"publisher":"Rusty Quill","date":"  "}"#)
+                .unwrap_or_else(|error| panic!("publication should deserialise: {error}"));
+
+        assert!(publication.date().is_none());
+    }
+}
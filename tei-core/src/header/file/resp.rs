@@ -0,0 +1,101 @@
+//! Responsibility statements (`<respStmt>`) crediting contributors.
+//!
+//! Each statement pairs a role such as "transcriber" with the responsible
+//! party, reusing [`ResponsibleParty`] so names follow the same trimming and
+//! non-empty rules as revision notes.
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::{HeaderValidationError, ResponsibleParty, required_text};
+
+/// Credits a contributor with a role, serialised as
+/// `<respStmt><resp>…</resp><name>…</name></respStmt>`.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::RespStmt;
+///
+/// let credit = RespStmt::new(" transcriber ", "Jane Doe")?;
+/// assert_eq!(credit.role(), "transcriber");
+/// assert_eq!(credit.name().as_str(), "Jane Doe");
+/// # Ok::<(), tei_core::HeaderValidationError>(())
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "respStmt", try_from = "RawRespStmt")]
+pub struct RespStmt {
+    #[serde(rename = "resp")]
+    role: String,
+    name: ResponsibleParty,
+}
+
+impl RespStmt {
+    /// Validates the role and name before building the statement.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when either the role or
+    /// the name trims to an empty string.
+    pub fn new(
+        role: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Result<Self, HeaderValidationError> {
+        Ok(Self {
+            role: required_text(role, "responsibility role")?,
+            name: ResponsibleParty::new(name)?,
+        })
+    }
+
+    /// Returns the contributor's role.
+    #[must_use]
+    pub const fn role(&self) -> &str {
+        self.role.as_str()
+    }
+
+    /// Returns the credited party.
+    #[must_use]
+    pub const fn name(&self) -> &ResponsibleParty {
+        &self.name
+    }
+}
+
+#[derive(Deserialize)]
+struct RawRespStmt {
+    #[serde(rename = "resp", default)]
+    role: String,
+    #[serde(default)]
+    name: String,
+}
+
+impl TryFrom<RawRespStmt> for RespStmt {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawRespStmt) -> Result<Self, Self::Error> {
+        Self::new(raw.role, raw.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json as json;
+
+    #[rstest]
+    #[case("   ", "Jane Doe", "responsibility role")]
+    #[case("transcriber", "  ", "revision responsibility")]
+    fn rejects_blank_parts(#[case] role: &str, #[case] name: &str, #[case] field: &'static str) {
+        let Err(error) = RespStmt::new(role, name) else {
+            panic!("blank responsibility statements must be rejected");
+        };
+
+        assert_eq!(error, HeaderValidationError::EmptyField { field });
+    }
+
+    #[test]
+    fn deserialisation_rejects_blank_roles() {
+        let result = json::from_str::<RespStmt>(r#"{"resp":" ","name":"Jane Doe"}"#);
+
+        assert!(result.is_err(), "blank role should not deserialise");
+    }
+}
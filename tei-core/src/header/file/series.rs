@@ -0,0 +1,231 @@
+//! Series statement (`<seriesStmt>`) placing an episode within its series.
+//!
+//! Records the series title alongside any identifiers and the season and
+//! episode numbers, serialised as `<idno>` and `<biblScope>` children. Season
+//! and episode numbers are positive integers; zero is rejected.
+
+use serde::{Deserialize, Serialize};
+
+use super::Idno;
+use crate::header::{HeaderValidationError, required_text};
+
+/// Series metadata for the transcript.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{Idno, IdnoType, SeriesStmt};
+///
+/// let mut series = SeriesStmt::new("Welcome to Night Vale")?
+///     .with_season_number(2)?
+///     .with_episode_number(42)?;
+/// series.add_idno(Idno::new(IdnoType::Url, "https://feeds.example.org/night-vale")?);
+///
+/// assert_eq!(series.title(), "Welcome to Night Vale");
+/// assert_eq!(series.idnos().len(), 1);
+/// assert_eq!(series.season_number(), Some(2));
+/// assert_eq!(series.episode_number(), Some(42));
+/// assert!(SeriesStmt::new("Welcome to Night Vale")?.with_episode_number(0).is_err());
+/// # Ok::<(), tei_core::HeaderValidationError>(())
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(
+    rename = "seriesStmt",
+    try_from = "RawSeriesStmt",
+    into = "RawSeriesStmt"
+)]
+pub struct SeriesStmt {
+    title: String,
+    idnos: Vec<Idno>,
+    season_number: Option<u32>,
+    episode_number: Option<u32>,
+}
+
+impl SeriesStmt {
+    /// Builds a series statement from its title.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the title trims to
+    /// an empty string.
+    pub fn new(title: impl Into<String>) -> Result<Self, HeaderValidationError> {
+        Ok(Self {
+            title: required_text(title, "series title")?,
+            idnos: Vec::new(),
+            season_number: None,
+            episode_number: None,
+        })
+    }
+
+    /// Records an identifier for the series, such as its feed URL.
+    pub fn add_idno(&mut self, idno: Idno) {
+        self.idnos.push(idno);
+    }
+
+    /// Assigns the season number.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::NonPositiveNumber`] when the number is
+    /// zero.
+    pub fn with_season_number(mut self, season: u32) -> Result<Self, HeaderValidationError> {
+        self.season_number = Some(positive(season, "season number")?);
+        Ok(self)
+    }
+
+    /// Assigns the episode number within the series or season.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::NonPositiveNumber`] when the number is
+    /// zero.
+    pub fn with_episode_number(mut self, episode: u32) -> Result<Self, HeaderValidationError> {
+        self.episode_number = Some(positive(episode, "episode number")?);
+        Ok(self)
+    }
+
+    /// Returns the series title.
+    #[must_use]
+    pub const fn title(&self) -> &str {
+        self.title.as_str()
+    }
+
+    /// Returns the identifiers recorded for the series.
+    #[must_use]
+    pub const fn idnos(&self) -> &[Idno] {
+        self.idnos.as_slice()
+    }
+
+    /// Returns the season number when present.
+    #[must_use]
+    pub const fn season_number(&self) -> Option<u32> {
+        self.season_number
+    }
+
+    /// Returns the episode number when present.
+    #[must_use]
+    pub const fn episode_number(&self) -> Option<u32> {
+        self.episode_number
+    }
+}
+
+const fn positive(number: u32, field: &'static str) -> Result<u32, HeaderValidationError> {
+    if number == 0 {
+        return Err(HeaderValidationError::NonPositiveNumber { field });
+    }
+
+    Ok(number)
+}
+
+/// Numbered scope serialised as `<biblScope unit="…">N</biblScope>`.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct BiblScope {
+    #[serde(rename = "@unit")]
+    unit: ScopeUnit,
+    #[serde(rename = "$text")]
+    number: u32,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum ScopeUnit {
+    Season,
+    Episode,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "seriesStmt")]
+struct RawSeriesStmt {
+    #[serde(default)]
+    title: String,
+    #[serde(rename = "idno", skip_serializing_if = "Vec::is_empty", default)]
+    idnos: Vec<Idno>,
+    #[serde(rename = "biblScope", skip_serializing_if = "Vec::is_empty", default)]
+    scopes: Vec<BiblScope>,
+}
+
+impl TryFrom<RawSeriesStmt> for SeriesStmt {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawSeriesStmt) -> Result<Self, Self::Error> {
+        let mut statement = Self::new(raw.title)?;
+        statement.idnos = raw.idnos;
+
+        raw.scopes
+            .into_iter()
+            .try_fold(statement, |current, scope| match scope.unit {
+                ScopeUnit::Season => current.with_season_number(scope.number),
+                ScopeUnit::Episode => current.with_episode_number(scope.number),
+            })
+    }
+}
+
+impl From<SeriesStmt> for RawSeriesStmt {
+    fn from(statement: SeriesStmt) -> Self {
+        let season = statement.season_number.map(|number| BiblScope {
+            unit: ScopeUnit::Season,
+            number,
+        });
+        let episode = statement.episode_number.map(|number| BiblScope {
+            unit: ScopeUnit::Episode,
+            number,
+        });
+
+        Self {
+            title: statement.title,
+            idnos: statement.idnos,
+            scopes: season.into_iter().chain(episode).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json as json;
+
+    #[test]
+    fn series_requires_title() {
+        let result = SeriesStmt::new("  ");
+
+        assert_eq!(
+            result,
+            Err(HeaderValidationError::EmptyField {
+                field: "series title"
+            })
+        );
+    }
+
+    #[test]
+    fn deserialisation_reads_episode_scope() {
+        let series = json::from_str::<SeriesStmt>(
+            r#"{"title":"The Magnus Archives","biblScope":[{"@unit":"episode","$text":7}]}"#,
+        )
+        .unwrap_or_else(|error| panic!("series should deserialise: {error}"));
+
+        assert!(series.idnos().is_empty());
+        assert_eq!(series.episode_number(), Some(7));
+        assert!(series.season_number().is_none());
+    }
+
+    #[test]
+    fn rejects_zero_season_numbers() {
+        let result = SeriesStmt::new("Archive 81").and_then(|series| series.with_season_number(0));
+
+        assert_eq!(
+            result,
+            Err(HeaderValidationError::NonPositiveNumber {
+                field: "season number",
+            })
+        );
+    }
+
+    #[test]
+    fn deserialisation_rejects_zero_episodes() {
+        let result = json::from_str::<SeriesStmt>(
+            r#"{"title":"Archive 81","biblScope":[{"@unit":"episode","$text":0}]}"#,
+        );
+
+        assert!(result.is_err(), "episode zero should not deserialise");
+    }
+}
@@ -0,0 +1,122 @@
+//! Source description (`<sourceDesc>`) tying the transcript to its recording.
+//!
+//! Each attached [`Media`] is wrapped in its own `<recording>` inside a
+//! `<recordingStmt>`, following the TEI encoding for transcribed speech.
+
+use serde::{Deserialize, Serialize};
+
+use super::{FileDesc, Media};
+
+/// Recordings the transcript was made from.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{Media, SourceDesc};
+///
+/// let mut source = SourceDesc::new();
+/// source.add_media(Media::new("https://example.org/ep12.mp3", "audio/mpeg")?);
+///
+/// assert_eq!(source.media().len(), 1);
+/// # Ok::<(), tei_core::HeaderValidationError>(())
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "sourceDesc", from = "RawSourceDesc", into = "RawSourceDesc")]
+pub struct SourceDesc {
+    media: Vec<Media>,
+}
+
+impl SourceDesc {
+    /// Creates a source description without media.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attaches a media file.
+    pub fn add_media(&mut self, media: Media) {
+        self.media.push(media);
+    }
+
+    /// Returns the attached media in document order.
+    #[must_use]
+    pub const fn media(&self) -> &[Media] {
+        self.media.as_slice()
+    }
+
+    /// Finds a media file by its `xml:id`, accepting a leading `#`.
+    #[must_use]
+    pub fn find_media(&self, reference: &str) -> Option<&Media> {
+        let id = reference.strip_prefix('#').unwrap_or(reference);
+
+        self.media
+            .iter()
+            .find(|media| media.id().is_some_and(|media_id| media_id.as_str() == id))
+    }
+}
+
+impl FileDesc {
+    /// Attaches the source description.
+    #[must_use]
+    pub fn with_source_desc(mut self, source: SourceDesc) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Returns the source description when present.
+    #[must_use]
+    pub const fn source_desc(&self) -> Option<&SourceDesc> {
+        self.source.as_ref()
+    }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(rename = "sourceDesc")]
+struct RawSourceDesc {
+    #[serde(
+        rename = "recordingStmt",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    recording_stmt: Option<RecordingStmt>,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+struct RecordingStmt {
+    #[serde(rename = "recording", default)]
+    recordings: Vec<Recording>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct Recording {
+    #[serde(rename = "media", skip_serializing_if = "Vec::is_empty", default)]
+    media: Vec<Media>,
+}
+
+impl From<RawSourceDesc> for SourceDesc {
+    fn from(raw: RawSourceDesc) -> Self {
+        let media = raw
+            .recording_stmt
+            .unwrap_or_default()
+            .recordings
+            .into_iter()
+            .flat_map(|recording| recording.media)
+            .collect();
+
+        Self { media }
+    }
+}
+
+impl From<SourceDesc> for RawSourceDesc {
+    fn from(source: SourceDesc) -> Self {
+        let recordings: Vec<Recording> = source
+            .media
+            .into_iter()
+            .map(|media| Recording { media: vec![media] })
+            .collect();
+
+        Self {
+            recording_stmt: (!recordings.is_empty()).then_some(RecordingStmt { recordings }),
+        }
+    }
+}
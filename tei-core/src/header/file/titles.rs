@@ -0,0 +1,201 @@
+//! Typed `<title>` elements recorded in the file description.
+//!
+//! TEI allows several titles with distinct `@type` values. The main title is
+//! emitted without `@type` so minimal documents keep their canonical form,
+//! while subtitles and alternative titles carry `type="sub"` and `type="alt"`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::title::{DocumentTitle, DocumentTitleError, TitleType};
+
+/// Validated set of titles owned by a file description.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(try_from = "Vec<TitleElement>", into = "Vec<TitleElement>")]
+pub(super) struct FileTitles {
+    main: DocumentTitle,
+    subtitle: Option<DocumentTitle>,
+    alternatives: Vec<DocumentTitle>,
+}
+
+impl FileTitles {
+    pub(super) const fn new(main: DocumentTitle) -> Self {
+        Self {
+            main,
+            subtitle: None,
+            alternatives: Vec::new(),
+        }
+    }
+
+    pub(super) const fn main(&self) -> &DocumentTitle {
+        &self.main
+    }
+
+    pub(super) const fn subtitle(&self) -> Option<&DocumentTitle> {
+        self.subtitle.as_ref()
+    }
+
+    pub(super) fn set_main(&mut self, main: DocumentTitle) {
+        self.main = main;
+    }
+
+    pub(super) fn set_subtitle(&mut self, subtitle: DocumentTitle) {
+        self.subtitle = Some(subtitle);
+    }
+
+    pub(super) const fn alternatives(&self) -> &[DocumentTitle] {
+        self.alternatives.as_slice()
+    }
+
+    pub(super) fn push_alternative(&mut self, title: DocumentTitle) {
+        self.alternatives.push(title);
+    }
+
+    pub(super) fn iter(&self) -> impl Iterator<Item = (TitleType, &DocumentTitle)> {
+        std::iter::once((TitleType::Main, &self.main))
+            .chain(self.subtitle.iter().map(|title| (TitleType::Sub, title)))
+            .chain(
+                self.alternatives
+                    .iter()
+                    .map(|title| (TitleType::Alt, title)),
+            )
+    }
+
+    fn record(
+        main: &mut Option<DocumentTitle>,
+        subtitle: &mut Option<DocumentTitle>,
+        alternatives: &mut Vec<DocumentTitle>,
+        element: TitleElement,
+    ) -> Result<(), DocumentTitleError> {
+        let title_type = element.title_type.unwrap_or(TitleType::Main);
+        let title = DocumentTitle::new(element.text)?;
+
+        match title_type {
+            TitleType::Main => set_once(main, title, title_type),
+            TitleType::Sub => set_once(subtitle, title, title_type),
+            TitleType::Alt => {
+                alternatives.push(title);
+                Ok(())
+            }
+        }
+    }
+}
+
+fn set_once(
+    slot: &mut Option<DocumentTitle>,
+    title: DocumentTitle,
+    title_type: TitleType,
+) -> Result<(), DocumentTitleError> {
+    if slot.is_some() {
+        return Err(DocumentTitleError::Duplicate { title_type });
+    }
+
+    *slot = Some(title);
+    Ok(())
+}
+
+impl TryFrom<Vec<TitleElement>> for FileTitles {
+    type Error = DocumentTitleError;
+
+    fn try_from(elements: Vec<TitleElement>) -> Result<Self, Self::Error> {
+        let mut main = None;
+        let mut subtitle = None;
+        let mut alternatives = Vec::new();
+
+        for element in elements {
+            Self::record(&mut main, &mut subtitle, &mut alternatives, element)?;
+        }
+
+        let main_title = main.ok_or(DocumentTitleError::MissingMain)?;
+
+        Ok(Self {
+            main: main_title,
+            subtitle,
+            alternatives,
+        })
+    }
+}
+
+impl From<FileTitles> for Vec<TitleElement> {
+    fn from(titles: FileTitles) -> Self {
+        titles
+            .iter()
+            .map(|(title_type, title)| TitleElement {
+                title_type: (title_type != TitleType::Main).then_some(title_type),
+                text: title.as_str().to_owned(),
+            })
+            .collect()
+    }
+}
+
+/// Wire representation of a single `<title>` element.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub(super) struct TitleElement {
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none", default)]
+    title_type: Option<TitleType>,
+    #[serde(rename = "$text", default)]
+    text: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn element(title_type: Option<TitleType>, text: &str) -> TitleElement {
+        TitleElement {
+            title_type,
+            text: text.to_owned(),
+        }
+    }
+
+    #[test]
+    fn untyped_title_becomes_main() {
+        let titles = FileTitles::try_from(vec![
+            element(Some(TitleType::Alt), "Working Title"),
+            element(None, "Wolf 359"),
+        ])
+        .unwrap_or_else(|error| panic!("titles should validate: {error}"));
+
+        assert_eq!(titles.main().as_str(), "Wolf 359");
+        assert_eq!(
+            titles
+                .alternatives()
+                .iter()
+                .map(DocumentTitle::as_str)
+                .collect::<Vec<_>>(),
+            ["Working Title"],
+        );
+    }
+
+    #[test]
+    fn rejects_missing_main_title() {
+        let result = FileTitles::try_from(vec![element(Some(TitleType::Sub), "Subtitle")]);
+
+        assert_eq!(result, Err(DocumentTitleError::MissingMain));
+    }
+
+    #[test]
+    fn rejects_duplicate_subtitles() {
+        let result = FileTitles::try_from(vec![
+            element(None, "Main"),
+            element(Some(TitleType::Sub), "First"),
+            element(Some(TitleType::Sub), "Second"),
+        ]);
+
+        assert_eq!(
+            result,
+            Err(DocumentTitleError::Duplicate {
+                title_type: TitleType::Sub,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_blank_typed_titles() {
+        let result = FileTitles::try_from(vec![
+            element(None, "Main"),
+            element(Some(TitleType::Alt), "   "),
+        ]);
+
+        assert_eq!(result, Err(DocumentTitleError::Empty));
+    }
+}
@@ -7,15 +7,33 @@
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::ErrorCode;
+
+mod date;
 mod encoding;
 mod file;
 mod profile;
 mod revision;
+mod xeno;
 
-pub use encoding::{AnnotationSystem, AnnotationSystemId, EncodingDesc};
-pub use file::FileDesc;
-pub use profile::{LanguageTag, ProfileDesc, SpeakerName};
+use date::optional_iso_date;
+pub use encoding::{
+    AnnotationSystem, AnnotationSystemId, AppInfo, Application, Category, CiteStructure, ClassDecl,
+    EditorialDecl, EncodingDesc, Hyphenation, HyphenationEol, Interpretation, Normalization,
+    NormalizationMethod, ProjectDesc, Quotation, QuotationMarks, RefsDecl, Rendition,
+    RenditionScheme, SamplingDecl, TagsDecl, Taxonomy,
+};
+pub(crate) use file::parse_xsd_duration;
+pub use file::{
+    Availability, EditionStmt, Extent, FileDesc, Idno, IdnoType, Licence, Media, NotesStmt,
+    PublicationStmt, RespStmt, SeriesStmt, SourceDesc,
+};
+pub use profile::{
+    Abstract, CatRef, Creation, Keywords, LanguageTag, ProfileDesc, SpeakerName, SpeakerRole,
+    TextClass,
+};
 pub use revision::{ResponsibleParty, RevisionChange, RevisionDesc};
+pub use xeno::XenoData;
 
 /// Error raised when TEI header metadata fails validation.
 #[derive(Clone, Debug, Error, Eq, PartialEq, Serialize)]
@@ -26,6 +44,69 @@ pub enum HeaderValidationError {
         /// Name of the empty field.
         field: &'static str,
     },
+    /// An identifier did not match the format required by its type.
+    #[error("{idno_type} identifier `{value}` is not valid")]
+    InvalidIdno {
+        /// Declared identifier type.
+        idno_type: IdnoType,
+        /// Rejected identifier value.
+        value: String,
+    },
+    /// A local category or taxonomy pointer did not resolve.
+    #[error("category reference `{reference}` does not match a declared taxonomy or category")]
+    UnresolvedCategory {
+        /// Pointer that failed to resolve.
+        reference: String,
+    },
+    /// A media type was not of the form `type/subtype`.
+    #[error("media type `{value}` must use the form type/subtype")]
+    InvalidMimeType {
+        /// Rejected media type.
+        value: String,
+    },
+    /// A duration was not a supported `xsd:duration` value.
+    #[error("duration `{value}` must be an xsd:duration such as PT1H2M3S")]
+    InvalidDuration {
+        /// Rejected duration value.
+        value: String,
+    },
+    /// A count that must be at least one was zero.
+    #[error("{field} must be a positive integer")]
+    NonPositiveNumber {
+        /// Name of the numbered field.
+        field: &'static str,
+    },
+    /// A date was not a valid ISO 8601 calendar date.
+    #[error("date `{value}` must use the form YYYY, YYYY-MM, or YYYY-MM-DD")]
+    InvalidDate {
+        /// Rejected date value.
+        value: String,
+    },
+    /// An extent measure disagreed with the body it describes.
+    #[error("extent records {recorded} {measure} but the body holds {actual}")]
+    ExtentMismatch {
+        /// Name of the stale measure, such as `utterances`.
+        measure: &'static str,
+        /// Count recorded in the header.
+        recorded: usize,
+        /// Count observed in the body.
+        actual: usize,
+    },
+}
+
+impl ErrorCode for HeaderValidationError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::EmptyField { .. } => "TEI-HEADER-EMPTY-FIELD",
+            Self::InvalidIdno { .. } => "TEI-HEADER-INVALID-IDNO",
+            Self::InvalidDate { .. } => "TEI-HEADER-INVALID-DATE",
+            Self::UnresolvedCategory { .. } => "TEI-HEADER-UNRESOLVED-CATEGORY",
+            Self::InvalidMimeType { .. } => "TEI-HEADER-INVALID-MIME-TYPE",
+            Self::InvalidDuration { .. } => "TEI-HEADER-INVALID-DURATION",
+            Self::NonPositiveNumber { .. } => "TEI-HEADER-NON-POSITIVE-NUMBER",
+            Self::ExtentMismatch { .. } => "TEI-HEADER-EXTENT-MISMATCH",
+        }
+    }
 }
 
 /// Metadata container for TEI header information.
@@ -46,6 +127,8 @@ pub struct TeiHeader {
         default
     )]
     encoding: Option<EncodingDesc>,
+    #[serde(rename = "xenoData", skip_serializing_if = "Vec::is_empty", default)]
+    xeno_data: Vec<XenoData>,
     #[serde(
         rename = "revisionDesc",
         skip_serializing_if = "Option::is_none",
@@ -62,6 +145,7 @@ impl TeiHeader {
             file: file_desc,
             profile: None,
             encoding: None,
+            xeno_data: Vec::new(),
             revision: None,
         }
     }
@@ -72,24 +156,64 @@ impl TeiHeader {
         &self.file
     }
 
+    /// Returns a mutable reference to the file description.
+    pub const fn file_desc_mut(&mut self) -> &mut FileDesc {
+        &mut self.file
+    }
+
     /// Returns the profile description when provided.
     #[must_use]
     pub const fn profile_desc(&self) -> Option<&ProfileDesc> {
         self.profile.as_ref()
     }
 
+    pub(crate) const fn profile_desc_mut(&mut self) -> Option<&mut ProfileDesc> {
+        self.profile.as_mut()
+    }
+
     /// Returns the encoding description when provided.
     #[must_use]
     pub const fn encoding_desc(&self) -> Option<&EncodingDesc> {
         self.encoding.as_ref()
     }
 
+    /// Returns the foreign metadata payloads in document order.
+    #[must_use]
+    pub const fn xeno_data(&self) -> &[XenoData] {
+        self.xeno_data.as_slice()
+    }
+
     /// Returns the revision description when provided.
     #[must_use]
     pub const fn revision_desc(&self) -> Option<&RevisionDesc> {
         self.revision.as_ref()
     }
 
+    /// Checks that category references resolve against the declared
+    /// taxonomies.
+    ///
+    /// Local `#id` pointers in `<catRef>` must name a taxonomy or category
+    /// declared in `<classDecl>`; pointers to external vocabularies are not
+    /// checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::UnresolvedCategory`] naming the first
+    /// pointer that does not resolve.
+    pub fn check_cat_refs(&self) -> Result<(), HeaderValidationError> {
+        let undeclared = ClassDecl::new();
+        let class_decl = self
+            .encoding_desc()
+            .and_then(EncodingDesc::class_decl)
+            .unwrap_or(&undeclared);
+
+        self.profile_desc()
+            .and_then(ProfileDesc::text_class)
+            .map_or(&[][..], TextClass::cat_refs)
+            .iter()
+            .try_for_each(|cat_ref| class_decl.check_cat_ref(cat_ref))
+    }
+
     /// Attaches a profile description.
     #[must_use]
     pub fn with_profile_desc(mut self, profile_desc: ProfileDesc) -> Self {
@@ -104,6 +228,13 @@ impl TeiHeader {
         self
     }
 
+    /// Appends a foreign metadata payload.
+    #[must_use]
+    pub fn with_xeno_data(mut self, xeno_data: XenoData) -> Self {
+        self.xeno_data.push(xeno_data);
+        self
+    }
+
     /// Attaches a revision description.
     #[must_use]
     pub fn with_revision_desc(mut self, revision_desc: RevisionDesc) -> Self {
@@ -123,6 +254,13 @@ fn normalise_optional_text(value: impl Into<String>) -> Option<String> {
     }
 }
 
+fn required_text(
+    value: impl Into<String>,
+    field: &'static str,
+) -> Result<String, HeaderValidationError> {
+    normalise_optional_text(value).ok_or(HeaderValidationError::EmptyField { field })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,96 @@
+//! Structured abstract (`<abstract>`) describing the transcript.
+//!
+//! Unlike the single-string synopsis, an abstract holds one or more full
+//! paragraphs with inline markup, reusing the body's validated [`P`] type.
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::HeaderValidationError;
+use crate::text::P;
+
+/// Paragraph-level summary serialised as `<abstract><p>…</p></abstract>`.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{Abstract, P};
+///
+/// let summary = Abstract::new([P::from_text_segments([
+///     "Cecil reports on the new dog park.",
+/// ])?])?;
+///
+/// assert_eq!(summary.paragraphs().len(), 1);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "abstract", try_from = "RawAbstract")]
+pub struct Abstract {
+    #[serde(rename = "p")]
+    paragraphs: Vec<P>,
+}
+
+impl Abstract {
+    /// Builds an abstract from one or more paragraphs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when no paragraphs are
+    /// supplied.
+    pub fn new(paragraphs: impl IntoIterator<Item = P>) -> Result<Self, HeaderValidationError> {
+        let collected: Vec<P> = paragraphs.into_iter().collect();
+
+        if collected.is_empty() {
+            return Err(HeaderValidationError::EmptyField { field: "abstract" });
+        }
+
+        Ok(Self {
+            paragraphs: collected,
+        })
+    }
+
+    /// Appends a paragraph.
+    pub fn push_paragraph(&mut self, paragraph: P) {
+        self.paragraphs.push(paragraph);
+    }
+
+    /// Returns the paragraphs in document order.
+    #[must_use]
+    pub const fn paragraphs(&self) -> &[P] {
+        self.paragraphs.as_slice()
+    }
+}
+
+#[derive(Deserialize)]
+struct RawAbstract {
+    #[serde(rename = "p", default)]
+    paragraphs: Vec<P>,
+}
+
+impl TryFrom<RawAbstract> for Abstract {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawAbstract) -> Result<Self, Self::Error> {
+        Self::new(raw.paragraphs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json as json;
+
+    #[test]
+    fn rejects_abstracts_without_paragraphs() {
+        assert_eq!(
+            Abstract::new(Vec::new()),
+            Err(HeaderValidationError::EmptyField { field: "abstract" })
+        );
+    }
+
+    #[test]
+    fn deserialisation_rejects_empty_abstracts() {
+        let result = json::from_str::<Abstract>(r#"{"p":[]}"#);
+
+        assert!(result.is_err(), "empty abstract should not deserialise");
+    }
+}
@@ -0,0 +1,121 @@
+//! Creation details (`<creation>`) for the transcript text.
+//!
+//! Records when and where the text itself was produced, which may differ from
+//! when the audio was recorded. Dates use the ISO 8601 calendar forms TEI
+//! accepts in `@when`: `YYYY`, `YYYY-MM`, or `YYYY-MM-DD`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::{HeaderValidationError, normalise_optional_text, optional_iso_date};
+
+/// Provenance of the transcript text.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::Creation;
+///
+/// let creation = Creation::new()
+///     .with_date("2024-02-29")?
+///     .with_place("Leeds");
+///
+/// assert_eq!(creation.date(), Some("2024-02-29"));
+/// assert_eq!(creation.place(), Some("Leeds"));
+/// assert!(Creation::new().with_date("2023-02-29").is_err());
+/// # Ok::<(), tei_core::HeaderValidationError>(())
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "creation", try_from = "RawCreation")]
+pub struct Creation {
+    #[serde(rename = "date", skip_serializing_if = "Option::is_none")]
+    date: Option<CreationDate>,
+    #[serde(rename = "placeName", skip_serializing_if = "Option::is_none")]
+    place: Option<String>,
+}
+
+impl Creation {
+    /// Creates an empty creation record.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the ISO 8601 creation date; blank values clear it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::InvalidDate`] when the value is not a
+    /// valid `YYYY`, `YYYY-MM`, or `YYYY-MM-DD` date.
+    pub fn with_date(mut self, date: impl Into<String>) -> Result<Self, HeaderValidationError> {
+        self.date = optional_iso_date(date)?.map(|when| CreationDate { when });
+        Ok(self)
+    }
+
+    /// Records where the text was created; blank values clear it.
+    #[must_use]
+    pub fn with_place(mut self, place: impl Into<String>) -> Self {
+        self.place = normalise_optional_text(place);
+        self
+    }
+
+    /// Returns the creation date when recorded.
+    #[must_use]
+    pub fn date(&self) -> Option<&str> {
+        self.date.as_ref().map(|date| date.when.as_str())
+    }
+
+    /// Returns the creation place when recorded.
+    #[must_use]
+    pub fn place(&self) -> Option<&str> {
+        self.place.as_deref()
+    }
+}
+
+/// Creation date serialised as `<date when="…"/>`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct CreationDate {
+    #[serde(rename = "@when")]
+    when: String,
+}
+
+#[derive(Deserialize)]
+struct RawCreation {
+    #[serde(rename = "date", default)]
+    date: Option<CreationDate>,
+    #[serde(rename = "placeName", default)]
+    place: Option<String>,
+}
+
+impl TryFrom<RawCreation> for Creation {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawCreation) -> Result<Self, Self::Error> {
+        let creation = Self::new().with_place(raw.place.unwrap_or_default());
+        match raw.date {
+            Some(date) => creation.with_date(date.when),
+            None => Ok(creation),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json as json;
+
+    #[test]
+    fn blank_dates_clear_the_value() {
+        let creation = Creation::new()
+            .with_date("   ")
+            .unwrap_or_else(|error| panic!("blank dates are allowed: {error}"));
+
+        assert!(creation.date().is_none());
+    }
+
+    #[test]
+    fn deserialisation_rejects_invalid_dates() {
+        let result = json::from_str::<Creation>(r#"{"date":{"@when":"2024-00-10"}}"#);
+
+        assert!(result.is_err(), "invalid dates should not deserialise");
+    }
+}
@@ -0,0 +1,329 @@
+//! Audience and linguistic profile metadata for TEI headers.
+//!
+//! Tracks speakers and languages while normalizing optional fields.
+
+mod abstract_text;
+mod creation;
+mod names;
+mod role;
+mod text_class;
+
+pub use abstract_text::Abstract;
+pub use creation::Creation;
+pub use names::{LanguageTag, SpeakerName};
+pub use role::SpeakerRole;
+pub use text_class::{CatRef, Keywords, TextClass};
+
+use super::{HeaderValidationError, normalise_optional_text};
+use serde::{Deserialize, Serialize};
+
+/// Audience and linguistic profile metadata.
+///
+/// Speakers may carry a [`SpeakerRole`], serialised as `@role` on their
+/// `<speaker>` element.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(
+    rename = "profileDesc",
+    from = "RawProfileDesc",
+    into = "RawProfileDesc"
+)]
+pub struct ProfileDesc {
+    abstract_text: Option<Abstract>,
+    creation: Option<Creation>,
+    synopsis: Option<String>,
+    speakers: Vec<SpeakerName>,
+    /// Roles aligned by index with `speakers`.
+    speaker_roles: Vec<Option<SpeakerRole>>,
+    languages: Vec<LanguageTag>,
+    text_class: Option<TextClass>,
+}
+
+impl ProfileDesc {
+    /// Creates an empty profile description.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assigns an optional synopsis.
+    #[must_use]
+    pub fn with_synopsis(mut self, synopsis: impl Into<String>) -> Self {
+        self.synopsis = normalise_optional_text(synopsis);
+        self
+    }
+
+    /// Attaches a paragraph-level abstract.
+    #[must_use]
+    pub fn with_abstract(mut self, abstract_text: Abstract) -> Self {
+        self.abstract_text = Some(abstract_text);
+        self
+    }
+
+    /// Attaches details of when and where the text was created.
+    #[must_use]
+    pub fn with_creation(mut self, creation: Creation) -> Self {
+        self.creation = Some(creation);
+        self
+    }
+
+    /// Attaches keywords and category references for the transcript.
+    #[must_use]
+    pub fn with_text_class(mut self, text_class: TextClass) -> Self {
+        self.text_class = Some(text_class);
+        self
+    }
+
+    /// Adds a speaker to the cast list.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the speaker name is
+    /// empty after trimming.
+    pub fn add_speaker(&mut self, speaker: impl Into<String>) -> Result<(), HeaderValidationError> {
+        let normalised_speaker = SpeakerName::new(speaker)?;
+        self.speakers.push(normalised_speaker);
+        self.speaker_roles.push(None);
+        Ok(())
+    }
+
+    /// Adds a speaker with a role to the cast list.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the speaker name is
+    /// empty after trimming.
+    pub fn add_speaker_with_role(
+        &mut self,
+        speaker: impl Into<String>,
+        role: SpeakerRole,
+    ) -> Result<(), HeaderValidationError> {
+        let normalised_speaker = SpeakerName::new(speaker)?;
+        self.speakers.push(normalised_speaker);
+        self.speaker_roles.push(Some(role));
+        Ok(())
+    }
+
+    /// Adds a language identifier to the profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the language tag is
+    /// empty after trimming.
+    pub fn add_language(
+        &mut self,
+        language: impl Into<String>,
+    ) -> Result<(), HeaderValidationError> {
+        let normalised_language = LanguageTag::new(language)?;
+        self.languages.push(normalised_language);
+        Ok(())
+    }
+
+    /// Returns the abstract when present.
+    #[must_use]
+    pub const fn abstract_text(&self) -> Option<&Abstract> {
+        self.abstract_text.as_ref()
+    }
+
+    /// Returns the creation details when present.
+    #[must_use]
+    pub const fn creation(&self) -> Option<&Creation> {
+        self.creation.as_ref()
+    }
+
+    /// Returns the synopsis when present.
+    #[must_use]
+    pub fn synopsis(&self) -> Option<&str> {
+        self.synopsis.as_deref()
+    }
+
+    /// Returns the registered speakers.
+    #[must_use]
+    pub const fn speakers(&self) -> &[SpeakerName] {
+        self.speakers.as_slice()
+    }
+
+    /// Returns the role recorded for a speaker.
+    #[must_use]
+    pub fn speaker_role(&self, speaker: &str) -> Option<&SpeakerRole> {
+        self.speakers
+            .iter()
+            .zip(&self.speaker_roles)
+            .find(|(name, _)| name.as_str() == speaker)
+            .and_then(|(_, role)| role.as_ref())
+    }
+
+    /// Returns the speakers recorded with the given role.
+    pub fn speakers_with_role<'a>(
+        &'a self,
+        role: &'a SpeakerRole,
+    ) -> impl Iterator<Item = &'a SpeakerName> + 'a {
+        self.speakers
+            .iter()
+            .zip(&self.speaker_roles)
+            .filter(move |(_, recorded)| recorded.as_ref() == Some(role))
+            .map(|(name, _)| name)
+    }
+
+    pub(crate) const fn speakers_mut(&mut self) -> &mut [SpeakerName] {
+        self.speakers.as_mut_slice()
+    }
+
+    /// Returns the number of speakers recorded.
+    #[must_use]
+    pub const fn len_speakers(&self) -> usize {
+        self.speakers.len()
+    }
+
+    /// Returns the recorded languages.
+    #[must_use]
+    pub const fn languages(&self) -> &[LanguageTag] {
+        self.languages.as_slice()
+    }
+
+    /// Returns the text classification when present.
+    #[must_use]
+    pub const fn text_class(&self) -> Option<&TextClass> {
+        self.text_class.as_ref()
+    }
+
+    /// Returns the number of language tags recorded.
+    #[must_use]
+    pub const fn len_languages(&self) -> usize {
+        self.languages.len()
+    }
+
+    /// Reports whether any metadata has been recorded.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.abstract_text.is_none()
+            && self.creation.is_none()
+            && self.synopsis.is_none()
+            && self.speakers.is_empty()
+            && self.languages.is_empty()
+            && self.text_class.is_none()
+    }
+}
+
+/// Wire form of [`ProfileDesc`], pairing each speaker with its role.
+#[derive(Default, Deserialize, Serialize)]
+#[serde(rename = "profileDesc")]
+struct RawProfileDesc {
+    #[serde(rename = "abstract", skip_serializing_if = "Option::is_none", default)]
+    abstract_text: Option<Abstract>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    creation: Option<Creation>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    synopsis: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default, rename = "speaker")]
+    speakers: Vec<SpeakerElement>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default, rename = "lang")]
+    languages: Vec<LanguageTag>,
+    #[serde(rename = "textClass", skip_serializing_if = "Option::is_none", default)]
+    text_class: Option<TextClass>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct SpeakerElement {
+    #[serde(rename = "@role", skip_serializing_if = "Option::is_none", default)]
+    role: Option<SpeakerRole>,
+    #[serde(rename = "$text")]
+    name: SpeakerName,
+}
+
+impl From<RawProfileDesc> for ProfileDesc {
+    fn from(raw: RawProfileDesc) -> Self {
+        let (speakers, speaker_roles) = raw
+            .speakers
+            .into_iter()
+            .map(|element| (element.name, element.role))
+            .unzip();
+
+        Self {
+            abstract_text: raw.abstract_text,
+            creation: raw.creation,
+            synopsis: raw.synopsis,
+            speakers,
+            speaker_roles,
+            languages: raw.languages,
+            text_class: raw.text_class,
+        }
+    }
+}
+
+impl From<ProfileDesc> for RawProfileDesc {
+    fn from(profile: ProfileDesc) -> Self {
+        let speakers = profile
+            .speakers
+            .into_iter()
+            .zip(profile.speaker_roles)
+            .map(|(name, role)| SpeakerElement { role, name })
+            .collect();
+
+        Self {
+            abstract_text: profile.abstract_text,
+            creation: profile.creation,
+            synopsis: profile.synopsis,
+            speakers,
+            languages: profile.languages,
+            text_class: profile.text_class,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_desc_tracks_speakers_and_languages() {
+        let mut profile = ProfileDesc::new();
+        profile
+            .add_speaker("Keisha")
+            .unwrap_or_else(|error| panic!("speaker recorded: {error}"));
+        profile
+            .add_language("en-GB")
+            .unwrap_or_else(|error| panic!("language recorded: {error}"));
+
+        assert_eq!(
+            profile
+                .speakers()
+                .iter()
+                .map(SpeakerName::as_str)
+                .collect::<Vec<_>>(),
+            ["Keisha"],
+        );
+        assert_eq!(profile.len_speakers(), 1);
+        assert_eq!(
+            profile
+                .languages()
+                .iter()
+                .map(LanguageTag::as_str)
+                .collect::<Vec<_>>(),
+            ["en-GB"],
+        );
+        assert_eq!(profile.len_languages(), 1);
+    }
+
+    #[test]
+    fn filters_speakers_by_role() {
+        let mut profile = ProfileDesc::new();
+        profile
+            .add_speaker_with_role("Cecil", SpeakerRole::Host)
+            .unwrap_or_else(|error| panic!("speaker recorded: {error}"));
+        profile
+            .add_speaker("Carlos")
+            .unwrap_or_else(|error| panic!("speaker recorded: {error}"));
+        profile
+            .add_speaker_with_role("Dana", SpeakerRole::Caller)
+            .unwrap_or_else(|error| panic!("speaker recorded: {error}"));
+
+        let callers: Vec<_> = profile
+            .speakers_with_role(&SpeakerRole::Caller)
+            .map(SpeakerName::as_str)
+            .collect();
+
+        assert_eq!(callers, ["Dana"]);
+        assert_eq!(profile.speaker_role("Cecil"), Some(&SpeakerRole::Host));
+        assert!(profile.speaker_role("Carlos").is_none());
+    }
+}
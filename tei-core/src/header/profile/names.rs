@@ -1,14 +1,16 @@
-//! Audience and linguistic profile metadata for TEI headers.
+//! Validated speaker and language values recorded in the profile description.
 //!
-//! Tracks speakers and languages while normalizing optional fields.
+//! Both wrappers trim their input and reject empty strings so cast lists and
+//! language declarations never carry blank entries.
 
 use std::fmt;
 use std::str::FromStr;
 
-use super::{HeaderValidationError, normalise_optional_text};
 use serde::{Deserialize, Serialize};
 
-/// Validated speaker name stored within [`ProfileDesc`].
+use crate::header::{HeaderValidationError, normalise_optional_text};
+
+/// Validated speaker name stored within [`ProfileDesc`](super::ProfileDesc).
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(try_from = "String", into = "String")]
 pub struct SpeakerName(String);
@@ -79,7 +81,7 @@ impl From<SpeakerName> for String {
     }
 }
 
-/// Validated language identifier stored within [`ProfileDesc`].
+/// Validated language identifier stored within [`ProfileDesc`](super::ProfileDesc).
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(try_from = "String", into = "String")]
 pub struct LanguageTag(String);
@@ -150,96 +152,6 @@ impl From<LanguageTag> for String {
     }
 }
 
-/// Audience and linguistic profile metadata.
-#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
-#[serde(rename = "profileDesc")]
-pub struct ProfileDesc {
-    #[serde(skip_serializing_if = "Option::is_none", default)]
-    synopsis: Option<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty", default, rename = "speaker")]
-    speakers: Vec<SpeakerName>,
-    #[serde(skip_serializing_if = "Vec::is_empty", default, rename = "lang")]
-    languages: Vec<LanguageTag>,
-}
-
-impl ProfileDesc {
-    /// Creates an empty profile description.
-    #[must_use]
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    /// Assigns an optional synopsis.
-    #[must_use]
-    pub fn with_synopsis(mut self, synopsis: impl Into<String>) -> Self {
-        self.synopsis = normalise_optional_text(synopsis);
-        self
-    }
-
-    /// Adds a speaker to the cast list.
-    ///
-    /// # Errors
-    ///
-    /// Returns [`HeaderValidationError::EmptyField`] when the speaker name is
-    /// empty after trimming.
-    pub fn add_speaker(&mut self, speaker: impl Into<String>) -> Result<(), HeaderValidationError> {
-        let normalised_speaker = SpeakerName::new(speaker)?;
-        self.speakers.push(normalised_speaker);
-        Ok(())
-    }
-
-    /// Adds a language identifier to the profile.
-    ///
-    /// # Errors
-    ///
-    /// Returns [`HeaderValidationError::EmptyField`] when the language tag is
-    /// empty after trimming.
-    pub fn add_language(
-        &mut self,
-        language: impl Into<String>,
-    ) -> Result<(), HeaderValidationError> {
-        let normalised_language = LanguageTag::new(language)?;
-        self.languages.push(normalised_language);
-        Ok(())
-    }
-
-    /// Returns the synopsis when present.
-    #[must_use]
-    pub fn synopsis(&self) -> Option<&str> {
-        self.synopsis.as_deref()
-    }
-
-    /// Returns the registered speakers.
-    #[must_use]
-    pub const fn speakers(&self) -> &[SpeakerName] {
-        self.speakers.as_slice()
-    }
-
-    /// Returns the number of speakers recorded.
-    #[must_use]
-    pub const fn len_speakers(&self) -> usize {
-        self.speakers.len()
-    }
-
-    /// Returns the recorded languages.
-    #[must_use]
-    pub const fn languages(&self) -> &[LanguageTag] {
-        self.languages.as_slice()
-    }
-
-    /// Returns the number of language tags recorded.
-    #[must_use]
-    pub const fn len_languages(&self) -> usize {
-        self.languages.len()
-    }
-
-    /// Reports whether any metadata has been recorded.
-    #[must_use]
-    pub const fn is_empty(&self) -> bool {
-        self.synopsis.is_none() && self.speakers.is_empty() && self.languages.is_empty()
-    }
-}
-
 fn build_validated_text(
     value: impl Into<String>,
     field: &'static str,
@@ -252,36 +164,6 @@ mod tests {
     use super::*;
     use serde_json as json;
 
-    #[test]
-    fn profile_desc_tracks_speakers_and_languages() {
-        let mut profile = ProfileDesc::new();
-        profile
-            .add_speaker("Keisha")
-            .unwrap_or_else(|error| panic!("speaker recorded: {error}"));
-        profile
-            .add_language("en-GB")
-            .unwrap_or_else(|error| panic!("language recorded: {error}"));
-
-        assert_eq!(
-            profile
-                .speakers()
-                .iter()
-                .map(SpeakerName::as_str)
-                .collect::<Vec<_>>(),
-            ["Keisha"],
-        );
-        assert_eq!(profile.len_speakers(), 1);
-        assert_eq!(
-            profile
-                .languages()
-                .iter()
-                .map(LanguageTag::as_str)
-                .collect::<Vec<_>>(),
-            ["en-GB"],
-        );
-        assert_eq!(profile.len_languages(), 1);
-    }
-
     #[test]
     fn speaker_name_deserialisation_rejects_empty() {
         let result = json::from_str::<SpeakerName>("\"   \"");
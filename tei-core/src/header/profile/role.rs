@@ -0,0 +1,136 @@
+//! Participant roles (`@role` on `<speaker>`) distinguishing hosts from
+//! guests.
+//!
+//! Well-known podcast roles have dedicated variants; anything else is kept
+//! verbatim in [`SpeakerRole::Other`] so custom labels survive a round trip.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::{HeaderValidationError, required_text};
+
+/// Role a speaker plays in an episode.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::SpeakerRole;
+///
+/// assert_eq!("co-host".parse::<SpeakerRole>()?, SpeakerRole::CoHost);
+/// assert_eq!(
+///     "producer".parse::<SpeakerRole>()?,
+///     SpeakerRole::Other("producer".to_owned()),
+/// );
+/// assert_eq!(SpeakerRole::Caller.as_str(), "caller");
+/// # Ok::<(), tei_core::HeaderValidationError>(())
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum SpeakerRole {
+    /// Presents the show.
+    Host,
+    /// Presents the show alongside the host.
+    CoHost,
+    /// Invited guest.
+    Guest,
+    /// Listener calling in.
+    Caller,
+    /// Narrates scripted segments.
+    Narrator,
+    /// Any other role, stored as written.
+    Other(String),
+}
+
+impl SpeakerRole {
+    /// Parses a role label, mapping well-known values to dedicated variants.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the label trims to
+    /// an empty string.
+    pub fn new(value: impl Into<String>) -> Result<Self, HeaderValidationError> {
+        let label = required_text(value, "speaker role")?;
+
+        Ok(match label.as_str() {
+            "host" => Self::Host,
+            "co-host" => Self::CoHost,
+            "guest" => Self::Guest,
+            "caller" => Self::Caller,
+            "narrator" => Self::Narrator,
+            _ => Self::Other(label),
+        })
+    }
+
+    /// Returns the label written to `@role`.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        match self {
+            Self::Host => "host",
+            Self::CoHost => "co-host",
+            Self::Guest => "guest",
+            Self::Caller => "caller",
+            Self::Narrator => "narrator",
+            Self::Other(label) => label.as_str(),
+        }
+    }
+}
+
+impl fmt::Display for SpeakerRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SpeakerRole {
+    type Err = HeaderValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for SpeakerRole {
+    type Error = HeaderValidationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<SpeakerRole> for String {
+    fn from(value: SpeakerRole) -> Self {
+        match value {
+            SpeakerRole::Other(label) => label,
+            known => known.as_str().to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(SpeakerRole::Host)]
+    #[case(SpeakerRole::CoHost)]
+    #[case(SpeakerRole::Guest)]
+    #[case(SpeakerRole::Caller)]
+    #[case(SpeakerRole::Narrator)]
+    #[case(SpeakerRole::Other("sound designer".to_owned()))]
+    fn labels_round_trip(#[case] role: SpeakerRole) {
+        assert_eq!(SpeakerRole::new(role.as_str()), Ok(role));
+    }
+
+    #[test]
+    fn rejects_blank_roles() {
+        assert_eq!(
+            SpeakerRole::new("  "),
+            Err(HeaderValidationError::EmptyField {
+                field: "speaker role",
+            })
+        );
+    }
+}
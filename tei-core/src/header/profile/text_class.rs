@@ -0,0 +1,278 @@
+//! Text classification (`<textClass>`) for topic tagging and discovery.
+//!
+//! Keywords carry free-text terms, optionally tied to a vocabulary through
+//! `@scheme`, while `<catRef>` points at categories declared in a taxonomy.
+
+use serde::{Deserialize, Serialize};
+
+use crate::header::{HeaderValidationError, normalise_optional_text, required_text};
+
+/// Keywords and category references describing the transcript's subject.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{CatRef, Keywords, TextClass};
+///
+/// let mut text_class = TextClass::new();
+/// text_class.add_keywords(Keywords::new(["Oral history", "Docks"])?.with_scheme("#lcsh"));
+/// text_class.add_cat_ref(CatRef::new(["#drama", "#comedy"])?.with_scheme("#genre"));
+///
+/// assert_eq!(text_class.keywords().len(), 1);
+/// assert_eq!(
+///     text_class.cat_refs().first().map(CatRef::targets),
+///     Some(["#drama".to_owned(), "#comedy".to_owned()].as_slice()),
+/// );
+/// # Ok::<(), tei_core::HeaderValidationError>(())
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "textClass")]
+pub struct TextClass {
+    #[serde(rename = "keywords", skip_serializing_if = "Vec::is_empty", default)]
+    keywords: Vec<Keywords>,
+    #[serde(rename = "catRef", skip_serializing_if = "Vec::is_empty", default)]
+    cat_refs: Vec<CatRef>,
+}
+
+impl TextClass {
+    /// Creates an empty classification.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a keyword list.
+    pub fn add_keywords(&mut self, keywords: Keywords) {
+        self.keywords.push(keywords);
+    }
+
+    /// Appends a category reference.
+    pub fn add_cat_ref(&mut self, cat_ref: CatRef) {
+        self.cat_refs.push(cat_ref);
+    }
+
+    /// Returns the recorded keyword lists.
+    #[must_use]
+    pub const fn keywords(&self) -> &[Keywords] {
+        self.keywords.as_slice()
+    }
+
+    /// Returns the recorded category references.
+    #[must_use]
+    pub const fn cat_refs(&self) -> &[CatRef] {
+        self.cat_refs.as_slice()
+    }
+
+    /// Reports whether any classification has been recorded.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.keywords.is_empty() && self.cat_refs.is_empty()
+    }
+}
+
+/// List of keyword terms, serialised as `<keywords><term>…</term></keywords>`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "keywords", try_from = "RawKeywords")]
+pub struct Keywords {
+    #[serde(rename = "@scheme", skip_serializing_if = "Option::is_none")]
+    scheme: Option<String>,
+    #[serde(rename = "term")]
+    terms: Vec<String>,
+}
+
+impl Keywords {
+    /// Builds a keyword list from one or more terms.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when no terms are
+    /// supplied or any term trims to an empty string.
+    pub fn new<S>(terms: impl IntoIterator<Item = S>) -> Result<Self, HeaderValidationError>
+    where
+        S: Into<String>,
+    {
+        let validated = terms
+            .into_iter()
+            .map(|term| required_text(term, "keyword"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if validated.is_empty() {
+            return Err(HeaderValidationError::EmptyField { field: "keywords" });
+        }
+
+        Ok(Self {
+            scheme: None,
+            terms: validated,
+        })
+    }
+
+    /// Names the vocabulary the terms come from; blank values clear it.
+    #[must_use]
+    pub fn with_scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.scheme = normalise_optional_text(scheme);
+        self
+    }
+
+    /// Returns the vocabulary reference when present.
+    #[must_use]
+    pub fn scheme(&self) -> Option<&str> {
+        self.scheme.as_deref()
+    }
+
+    /// Returns the keyword terms.
+    #[must_use]
+    pub const fn terms(&self) -> &[String] {
+        self.terms.as_slice()
+    }
+}
+
+#[derive(Deserialize)]
+struct RawKeywords {
+    #[serde(rename = "@scheme", default)]
+    scheme: Option<String>,
+    #[serde(rename = "term", default)]
+    terms: Vec<String>,
+}
+
+impl TryFrom<RawKeywords> for Keywords {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawKeywords) -> Result<Self, Self::Error> {
+        let keywords = Self::new(raw.terms)?;
+        Ok(match raw.scheme {
+            Some(scheme) => keywords.with_scheme(scheme),
+            None => keywords,
+        })
+    }
+}
+
+/// Reference to taxonomy categories, serialised as
+/// `<catRef scheme="…" target="#a #b"/>`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "catRef", try_from = "RawCatRef", into = "RawCatRef")]
+pub struct CatRef {
+    scheme: Option<String>,
+    targets: Vec<String>,
+}
+
+impl CatRef {
+    /// Builds a category reference from one or more category pointers.
+    ///
+    /// Whitespace-separated pointers within a single value are split apart,
+    /// matching the `@target` attribute syntax.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when no category
+    /// pointers remain after trimming.
+    pub fn new<S>(targets: impl IntoIterator<Item = S>) -> Result<Self, HeaderValidationError>
+    where
+        S: Into<String>,
+    {
+        let pointers: Vec<String> = targets
+            .into_iter()
+            .flat_map(|target| {
+                target
+                    .into()
+                    .split_whitespace()
+                    .map(str::to_owned)
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        if pointers.is_empty() {
+            return Err(HeaderValidationError::EmptyField {
+                field: "category reference",
+            });
+        }
+
+        Ok(Self {
+            scheme: None,
+            targets: pointers,
+        })
+    }
+
+    /// Names the taxonomy the categories belong to; blank values clear it.
+    #[must_use]
+    pub fn with_scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.scheme = normalise_optional_text(scheme);
+        self
+    }
+
+    /// Returns the taxonomy reference when present.
+    #[must_use]
+    pub fn scheme(&self) -> Option<&str> {
+        self.scheme.as_deref()
+    }
+
+    /// Returns the category pointers in document order.
+    #[must_use]
+    pub const fn targets(&self) -> &[String] {
+        self.targets.as_slice()
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "catRef")]
+struct RawCatRef {
+    #[serde(rename = "@scheme", skip_serializing_if = "Option::is_none", default)]
+    scheme: Option<String>,
+    #[serde(rename = "@target", default)]
+    target: String,
+}
+
+impl TryFrom<RawCatRef> for CatRef {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawCatRef) -> Result<Self, Self::Error> {
+        let cat_ref = Self::new([raw.target])?;
+        Ok(match raw.scheme {
+            Some(scheme) => cat_ref.with_scheme(scheme),
+            None => cat_ref,
+        })
+    }
+}
+
+impl From<CatRef> for RawCatRef {
+    fn from(cat_ref: CatRef) -> Self {
+        Self {
+            scheme: cat_ref.scheme,
+            target: cat_ref.targets.join(" "),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json as json;
+
+    #[rstest]
+    #[case(Vec::new(), "keywords")]
+    #[case(vec!["Docks", " "], "keyword")]
+    fn keywords_reject_missing_or_blank_terms(
+        #[case] terms: Vec<&str>,
+        #[case] field: &'static str,
+    ) {
+        assert_eq!(
+            Keywords::new(terms),
+            Err(HeaderValidationError::EmptyField { field })
+        );
+    }
+
+    #[test]
+    fn cat_ref_splits_whitespace_separated_targets() {
+        let cat_ref = CatRef::new([" #drama  #comedy "])
+            .unwrap_or_else(|error| panic!("valid category reference: {error}"));
+
+        assert_eq!(cat_ref.targets(), ["#drama", "#comedy"]);
+    }
+
+    #[test]
+    fn cat_ref_deserialisation_rejects_blank_targets() {
+        let result = json::from_str::<CatRef>(r##"{"@scheme":"#genre","@target":"  "}"##);
+
+        assert!(result.is_err(), "blank targets should not deserialise");
+    }
+}
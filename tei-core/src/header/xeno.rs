@@ -0,0 +1,119 @@
+//! Foreign metadata (`<xenoData>`) carried alongside the TEI header.
+//!
+//! Source feeds often hold metadata that has no TEI equivalent, such as a
+//! podcast RSS `<item>` or a JSON payload from a hosting platform. The payload
+//! is stored as opaque character data and emitted unchanged, so markup inside
+//! it is escaped on output rather than interpreted as TEI.
+
+use serde::{Deserialize, Serialize};
+
+use super::{HeaderValidationError, normalise_optional_text};
+
+/// Opaque non-TEI metadata, serialised as `<xenoData type="…">…</xenoData>`.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::XenoData;
+///
+/// let payload = r#"{"guid":"ep-12","explicit":false}"#;
+/// let xeno = XenoData::new(payload)?.with_type("application/json");
+///
+/// assert_eq!(xeno.payload(), payload);
+/// assert_eq!(xeno.data_type(), Some("application/json"));
+/// # Ok::<(), tei_core::HeaderValidationError>(())
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "xenoData", try_from = "RawXenoData")]
+pub struct XenoData {
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    data_type: Option<String>,
+    #[serde(rename = "$text")]
+    payload: String,
+}
+
+impl XenoData {
+    /// Wraps a foreign metadata payload without altering it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the payload contains
+    /// only whitespace.
+    pub fn new(payload: impl Into<String>) -> Result<Self, HeaderValidationError> {
+        let verbatim = payload.into();
+
+        if verbatim.trim().is_empty() {
+            return Err(HeaderValidationError::EmptyField { field: "xenoData" });
+        }
+
+        Ok(Self {
+            data_type: None,
+            payload: verbatim,
+        })
+    }
+
+    /// Labels the payload format, such as `rss` or a MIME type; blank values
+    /// clear it.
+    #[must_use]
+    pub fn with_type(mut self, data_type: impl Into<String>) -> Self {
+        self.data_type = normalise_optional_text(data_type);
+        self
+    }
+
+    /// Returns the payload format label when present.
+    #[must_use]
+    pub fn data_type(&self) -> Option<&str> {
+        self.data_type.as_deref()
+    }
+
+    /// Returns the payload exactly as supplied.
+    #[must_use]
+    pub const fn payload(&self) -> &str {
+        self.payload.as_str()
+    }
+}
+
+#[derive(Deserialize)]
+struct RawXenoData {
+    #[serde(rename = "@type", default)]
+    data_type: Option<String>,
+    #[serde(rename = "$text", default)]
+    payload: String,
+}
+
+impl TryFrom<RawXenoData> for XenoData {
+    type Error = HeaderValidationError;
+
+    fn try_from(raw: RawXenoData) -> Result<Self, Self::Error> {
+        Ok(Self::new(raw.payload)?.with_type(raw.data_type.unwrap_or_default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json as json;
+
+    #[test]
+    fn rejects_blank_payloads() {
+        assert_eq!(
+            XenoData::new(" \n "),
+            Err(HeaderValidationError::EmptyField { field: "xenoData" })
+        );
+    }
+
+    #[test]
+    fn keeps_surrounding_whitespace() {
+        let xeno = XenoData::new(" {} ")
+            .unwrap_or_else(|error| panic!("payload should be valid: {error}"));
+
+        assert_eq!(xeno.payload(), " {} ");
+    }
+
+    #[test]
+    fn deserialisation_rejects_missing_payloads() {
+        let result = json::from_str::<XenoData>(r#"{"@type":"rss"}"#);
+
+        assert!(result.is_err(), "missing payload should not deserialise");
+    }
+}
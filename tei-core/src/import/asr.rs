@@ -0,0 +1,298 @@
+//! Word-level speech recognition results gathered into utterances.
+//!
+//! Recognisers report each word with its timing, a confidence score, and,
+//! when diarisation was requested, a speaker. The importers read their
+//! format into [`Token`]s and hand them to [`transcript`], which first
+//! normalises them, trimming whitespace and splitting punctuation glued to
+//! the end of a word into a token of its own, so every format reaches the
+//! same shape. Words are then grouped into utterances at each change of
+//! speaker, or at the end of each sentence when no word names a speaker.
+
+use std::borrow::Cow;
+use std::mem;
+use std::ops::Range;
+use std::time::Duration;
+
+use super::TranscriptBuilder;
+use crate::{Certainty, Inline, SourceLocation, TeiDocument, TeiError, Unclear};
+
+/// Marks split off the end of a word during normalisation.
+const PUNCTUATION: [char; 9] = ['.', ',', '!', '?', ';', ':', '\u{2026}', '"', '\u{201d}'];
+
+/// Options shared by the speech recognition importers.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::import::AsrOptions;
+///
+/// let options = AsrOptions::new().with_unclear_below(0.5);
+/// assert_eq!(options.unclear_below(), 0.5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AsrOptions {
+    unclear_below: f64,
+}
+
+impl AsrOptions {
+    /// Confidence below which a word is marked unclear by default: the
+    /// point where [`Certainty::from_confidence`] stops reporting
+    /// [`Certainty::High`].
+    pub const DEFAULT_UNCLEAR_BELOW: f64 = 0.8;
+
+    /// Creates options with the default threshold.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            unclear_below: Self::DEFAULT_UNCLEAR_BELOW,
+        }
+    }
+
+    /// Sets the confidence below which words are wrapped in `<unclear>`.
+    /// Zero keeps every word as plain text.
+    #[must_use]
+    pub const fn with_unclear_below(mut self, threshold: f64) -> Self {
+        self.unclear_below = threshold;
+        self
+    }
+
+    /// Returns the confidence below which words are marked unclear.
+    #[must_use]
+    pub const fn unclear_below(&self) -> f64 {
+        self.unclear_below
+    }
+}
+
+impl Default for AsrOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A recognised word or a punctuation mark inferred by the recogniser.
+pub(super) enum Token<'a> {
+    Word(Word<'a>),
+    Punctuation(&'a str),
+}
+
+/// A recognised word with its speaker, timing, and confidence score.
+pub(super) struct Word<'a> {
+    pub(super) speaker: Option<Cow<'a, str>>,
+    pub(super) timing: Range<Duration>,
+    pub(super) text: &'a str,
+    pub(super) confidence: Option<f64>,
+}
+
+/// Builds a timed document titled `title` from recognised `tokens`.
+///
+/// Punctuation before the first word has nothing to attach to and is
+/// dropped.
+pub(super) fn transcript(
+    title: &str,
+    tokens: Vec<Token<'_>>,
+    options: AsrOptions,
+) -> Result<TeiDocument, TeiError> {
+    let normalised = normalise(tokens);
+    let diarised = normalised
+        .iter()
+        .any(|token| matches!(token, Token::Word(word) if word.speaker.is_some()));
+
+    let mut builder = TranscriptBuilder::new(title)?;
+    let mut turn: Option<Turn<'_>> = None;
+    for token in normalised {
+        let word = match token {
+            Token::Word(word) => word,
+            Token::Punctuation(mark) => {
+                if let Some(current) = turn.as_mut() {
+                    current.push_punctuation(mark);
+                }
+                continue;
+            }
+        };
+        let closed = turn.take_if(|current| {
+            if diarised {
+                current.speaker != word.speaker
+            } else {
+                current.ends_sentence()
+            }
+        });
+        if let Some(finished) = closed {
+            finished.push_to(&mut builder)?;
+        }
+        turn.get_or_insert_with(|| Turn::new(&word))
+            .push_word(&word, options.unclear_below());
+    }
+    if let Some(last) = turn {
+        last.push_to(&mut builder)?;
+    }
+    Ok(builder.finish())
+}
+
+/// Builds an import error for JSON that does not match `format`.
+pub(super) fn json_error(format: &'static str, error: &serde_json::Error) -> TeiError {
+    // serde_json appends the position to its messages; the location carries
+    // it instead.
+    let message = error.to_string();
+    let cause = message.split(" at line ").next().unwrap_or_default();
+    TeiError::import_at(
+        format,
+        cause,
+        SourceLocation::new().with_line_column(error.line(), error.column()),
+    )
+}
+
+/// Trims each word and moves punctuation at its end into a token of its
+/// own, dropping words left blank.
+fn normalise(tokens: Vec<Token<'_>>) -> Vec<Token<'_>> {
+    let mut normalised = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        let Token::Word(word) = token else {
+            normalised.push(token);
+            continue;
+        };
+        let text = word.text.trim();
+        let bare = text.trim_end_matches(PUNCTUATION);
+        let marks = text.get(bare.len()..).unwrap_or_default();
+        if !bare.is_empty() {
+            normalised.push(Token::Word(Word { text: bare, ..word }));
+        }
+        if !marks.is_empty() {
+            normalised.push(Token::Punctuation(marks));
+        }
+    }
+    normalised
+}
+
+/// The words of one utterance, gathered as inline content.
+struct Turn<'a> {
+    speaker: Option<Cow<'a, str>>,
+    timing: Range<Duration>,
+    content: Vec<Inline>,
+    text: String,
+    /// The open run of unclear words and its lowest score.
+    unclear: Option<(f64, String)>,
+}
+
+impl<'a> Turn<'a> {
+    fn new(first: &Word<'a>) -> Self {
+        Self {
+            speaker: first.speaker.clone(),
+            timing: first.timing.clone(),
+            content: Vec::new(),
+            text: String::new(),
+            unclear: None,
+        }
+    }
+
+    /// Appends `word`, marking it unclear when its confidence is below
+    /// `unclear_below`.
+    fn push_word(&mut self, word: &Word<'a>, unclear_below: f64) {
+        let started = !self.content.is_empty() || !self.text.is_empty() || self.unclear.is_some();
+        let separator = if started { " " } else { "" };
+        self.timing.end = self.timing.end.max(word.timing.end);
+        let doubtful = word.confidence.filter(|score| *score < unclear_below);
+        match (doubtful, self.unclear.as_mut()) {
+            (Some(score), Some((lowest, run))) => {
+                *lowest = lowest.min(score);
+                run.push(' ');
+                run.push_str(word.text);
+            }
+            (Some(score), None) => {
+                self.text.push_str(separator);
+                self.flush_text();
+                self.unclear = Some((score, word.text.to_owned()));
+            }
+            (None, _) => {
+                self.flush_unclear();
+                self.text.push_str(separator);
+                self.text.push_str(word.text);
+            }
+        }
+    }
+
+    fn push_punctuation(&mut self, mark: &str) {
+        self.flush_unclear();
+        self.text.push_str(mark);
+    }
+
+    fn ends_sentence(&self) -> bool {
+        self.unclear.is_none() && self.text.trim_end().ends_with(['.', '!', '?'])
+    }
+
+    fn flush_text(&mut self) {
+        if !self.text.is_empty() {
+            self.content.push(Inline::Text(mem::take(&mut self.text)));
+        }
+    }
+
+    fn flush_unclear(&mut self) {
+        if let Some((lowest, run)) = self.unclear.take() {
+            let mut unclear = Unclear::new([Inline::Text(run)]);
+            unclear.set_cert(Certainty::from_confidence(lowest));
+            self.content.push(Inline::Unclear(unclear));
+        }
+    }
+
+    fn push_to(mut self, builder: &mut TranscriptBuilder) -> Result<(), TeiError> {
+        self.flush_unclear();
+        self.flush_text();
+        builder.push_utterance(self.speaker.as_deref(), self.timing, self.content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, seconds: u64, confidence: f64) -> Token<'_> {
+        Token::Word(Word {
+            speaker: None,
+            timing: Duration::from_secs(seconds)..Duration::from_secs(seconds + 1),
+            text,
+            confidence: Some(confidence),
+        })
+    }
+
+    #[test]
+    fn keeps_glued_punctuation_outside_unclear_words() {
+        let tokens = vec![word(" Hello, ", 0, 0.9), word("Hephaestus?", 1, 0.3)];
+
+        let document = transcript("Wolf 359", tokens, AsrOptions::new()).expect("words import");
+
+        let mut unclear = Unclear::new([Inline::text("Hephaestus")]);
+        unclear.set_cert(Certainty::Low);
+        let utterance = document.text().body().utterances().next();
+        assert_eq!(
+            utterance.map(crate::Utterance::content),
+            Some(
+                [
+                    Inline::text("Hello, "),
+                    Inline::Unclear(unclear),
+                    Inline::text("?"),
+                ]
+                .as_slice()
+            )
+        );
+    }
+
+    #[test]
+    fn attaches_standalone_punctuation_and_drops_blank_words() {
+        let tokens = vec![
+            Token::Punctuation("\u{2014}"),
+            word("Day", 0, 0.9),
+            word(" ", 1, 0.9),
+            Token::Punctuation("."),
+            word("...", 2, 0.9),
+        ];
+
+        let document = transcript("Wolf 359", tokens, AsrOptions::new()).expect("words import");
+
+        let texts: Vec<_> = document
+            .text()
+            .body()
+            .utterances()
+            .map(|utterance| utterance.content().to_vec())
+            .collect();
+        assert_eq!(texts, [vec![Inline::text("Day....")]]);
+    }
+}
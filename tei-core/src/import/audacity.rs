@@ -0,0 +1,168 @@
+//! Audacity label tracks as timed utterances and milestones.
+//!
+//! Audacity exports a label track as text with one label per line: its start
+//! and end in decimal seconds and its text, separated by tabs. A label
+//! spanning time becomes an utterance, named after the speaker when its text
+//! opens with a label such as `Eiffel:`; a point label, whose start and end
+//! are equal, marks a moment and becomes a `<milestone>`. Lines opening with
+//! a backslash hold the frequency range of a spectral selection and are
+//! skipped.
+
+use super::TranscriptBuilder;
+use super::source::{self, error_at};
+use super::srt::SpeakerLabels;
+use crate::text::parse_seconds;
+use crate::{Inline, TeiDocument, TeiError};
+
+const FORMAT: &str = "Audacity";
+
+/// Converts an Audacity label track into a timed document titled `title`.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Import`] when a line lacks a tab-separated start and
+/// end, holds a malformed time, or ends before it starts. Returns
+/// [`TeiError::DocumentTitle`] when `title` is blank.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::import::audacity;
+///
+/// let labels = "0.500000\t2.000000\tEiffel: Day 1.\n3.000000\t3.000000\tStatic\n";
+/// let document = audacity::parse(labels, "Wolf 359")?;
+///
+/// let utterance = document.text().body().utterances().next().expect("one utterance");
+/// assert_eq!(utterance.speaker().map(|s| s.as_str()), Some("Eiffel"));
+/// assert_eq!(document.text().body().blocks().len(), 2);
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn parse(input: &str, title: &str) -> Result<TeiDocument, TeiError> {
+    let normalised = source::normalise(input);
+    let mut builder = TranscriptBuilder::new(title)?;
+    for (index, line) in normalised.split('\n').enumerate() {
+        let number = index + 1;
+        if line.trim().is_empty() || line.starts_with('\\') {
+            continue;
+        }
+        let mut fields = line.splitn(3, '\t');
+        let (Some(start_field), Some(end_field)) = (fields.next(), fields.next()) else {
+            return Err(error_at(
+                FORMAT,
+                "expected a start and end separated by a tab",
+                number,
+            ));
+        };
+        let start = seconds(start_field, number)?;
+        let end = seconds(end_field, number)?;
+        if end < start {
+            return Err(error_at(FORMAT, "label ends before it starts", number));
+        }
+        let text = fields.next().unwrap_or_default().trim();
+        if end == start {
+            builder.push_milestone(start, text);
+            continue;
+        }
+        if text.is_empty() {
+            continue;
+        }
+        let (speaker, said) = SpeakerLabels::Capitalised
+            .split(text)
+            .map_or((None, text), |(name, rest)| (Some(name), rest));
+        builder
+            .push_utterance(speaker, start..end, [Inline::text(said)])
+            .map_err(|failure| error_at(FORMAT, &failure.to_string(), number))?;
+    }
+    Ok(builder.finish())
+}
+
+fn seconds(field: &str, line: usize) -> Result<std::time::Duration, TeiError> {
+    let text = field.trim();
+    parse_seconds(text).ok_or_else(|| error_at(FORMAT, &format!("malformed time `{text}`"), line))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{BodyBlock, SourceLocation};
+    use rstest::rstest;
+
+    #[test]
+    fn reads_spans_as_utterances_and_points_as_milestones() {
+        let labels = "\u{feff}0.000000\t1.250000\tHera: Hello, Eiffel.\r\n\
+                      \\\t440.000000\t880.000000\r\n\
+                      1.250000\t3.000000\tDay 1 of the mission.\r\n\
+                      \r\n\
+                      4.500000\t4.500000\tStatic & \"noise\"\r\n";
+
+        let document = parse(labels, "Wolf 359").expect("labels should import");
+
+        let timeline = document.text().timeline().expect("timeline recorded");
+        let body = document.text().body();
+        let summary: Vec<_> = document
+            .text()
+            .body()
+            .utterances()
+            .map(|utterance| {
+                (
+                    utterance.speaker().map(crate::Speaker::as_str),
+                    utterance.end().and_then(|point| timeline.offset(point)),
+                    utterance.content().to_vec(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (
+                    Some("Hera"),
+                    Some(Duration::from_millis(1_250)),
+                    vec![Inline::text("Hello, Eiffel.")]
+                ),
+                (
+                    None,
+                    Some(Duration::from_secs(3)),
+                    vec![Inline::text("Day 1 of the mission.")]
+                ),
+            ]
+        );
+        let Some(BodyBlock::Raw(milestone)) = body.blocks().last() else {
+            panic!("expected a milestone, found {body:?}");
+        };
+        assert_eq!(
+            milestone.as_str(),
+            "<milestone unit=\"label\" n=\"Static &amp; &quot;noise&quot;\" synch=\"#T4\"/>"
+        );
+        assert_eq!(timeline.offset("#T4"), Some(Duration::from_millis(4_500)));
+    }
+
+    #[test]
+    fn keeps_tabs_in_milestone_labels() {
+        let document = parse("4.5\t4.5\tStatic\tnoise\n", "Wolf 359").expect("label imports");
+
+        let Some(BodyBlock::Raw(milestone)) = document.text().body().blocks().last() else {
+            panic!("expected a milestone");
+        };
+        assert!(
+            milestone.as_str().contains("n=\"Static&#9;noise\""),
+            "{}",
+            milestone.as_str()
+        );
+    }
+
+    #[rstest]
+    #[case("0.5 2.0 Day 1.\n", 1)]
+    #[case("0.5\t2.0\tDay 1.\n2.0\tsoon\tHi.\n", 2)]
+    #[case("\n\n2.0\t0.5\tDay 1.\n", 3)]
+    fn reports_malformed_labels_with_their_line(#[case] input: &str, #[case] line: usize) {
+        let error = parse(input, "Wolf 359").expect_err("input must be rejected");
+
+        assert!(
+            matches!(error, TeiError::Import { format: FORMAT, .. }),
+            "{error:?}"
+        );
+        assert_eq!(error.location().and_then(SourceLocation::line), Some(line));
+    }
+}
@@ -0,0 +1,394 @@
+//! Amazon Transcribe JSON as timed utterances.
+//!
+//! Transcribe reports each recognised word as a `pronunciation` item with
+//! its timing and a confidence score, and each punctuation mark as a
+//! `punctuation` item belonging to the word before it. Diarised jobs label
+//! every word with a speaker such as `spk_0`, either on the item itself or
+//! in `speaker_labels`; each change of speaker starts a new utterance and
+//! every label becomes one of the document's speakers. Without speaker
+//! labels, each sentence becomes an utterance.
+//!
+//! Words scored below [`AsrOptions::unclear_below`] are wrapped in
+//! `<unclear>`, with neighbouring words sharing one element whose
+//! [`Certainty`](crate::Certainty) follows the lowest score among them. Only
+//! the first alternative of each item is read. The importer is available
+//! with the default `json` feature.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use super::AsrOptions;
+use super::asr::{self, Token, Word};
+use crate::text::parse_seconds;
+use crate::{SourceLocation, TeiDocument, TeiError, TeiPath};
+
+const FORMAT: &str = "Amazon Transcribe";
+
+/// Converts an Amazon Transcribe job result into a timed document titled
+/// `title`, using the default [`AsrOptions`].
+///
+/// # Errors
+///
+/// Returns [`TeiError::Import`] when the input is not a Transcribe result
+/// or a word has missing or malformed timing or confidence. Returns
+/// [`TeiError::DocumentTitle`] when `title` is blank.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::import::aws_transcribe;
+/// use tei_core::{Certainty, Inline};
+///
+/// let result = r#"{"results": {"items": [
+///     {"type": "pronunciation", "start_time": "0.5", "end_time": "0.9",
+///      "speaker_label": "spk_0",
+///      "alternatives": [{"content": "Hephaestus", "confidence": "0.42"}]},
+///     {"type": "punctuation", "alternatives": [{"content": "."}]}
+/// ]}}"#;
+/// let document = aws_transcribe::parse(result, "Wolf 359")?;
+///
+/// let utterance = document.text().body().utterances().next().expect("one utterance");
+/// assert_eq!(utterance.speaker().map(|s| s.as_str()), Some("spk_0"));
+/// let Some(Inline::Unclear(unclear)) = utterance.content().first() else {
+///     panic!("the word should be unclear");
+/// };
+/// assert_eq!(unclear.cert(), Some(Certainty::Low));
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn parse(input: &str, title: &str) -> Result<TeiDocument, TeiError> {
+    parse_with_options(input, title, AsrOptions::new())
+}
+
+/// Converts an Amazon Transcribe job result into a timed document titled
+/// `title`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`parse`].
+pub fn parse_with_options(
+    input: &str,
+    title: &str,
+    options: AsrOptions,
+) -> Result<TeiDocument, TeiError> {
+    let job: Job = serde_json::from_str(input).map_err(|error| asr::json_error(FORMAT, &error))?;
+    let results = job.results;
+    let labels: HashMap<&str, &str> = results
+        .speaker_labels
+        .iter()
+        .flat_map(|labels| &labels.segments)
+        .flat_map(|segment| &segment.items)
+        .map(|item| (item.start_time.as_str(), item.speaker_label.as_str()))
+        .collect();
+
+    let mut tokens = Vec::with_capacity(results.items.len());
+    for (index, item) in results.items.iter().enumerate() {
+        let Some(alternative) = item.alternatives.first() else {
+            continue;
+        };
+        tokens.push(match item.kind {
+            ItemKind::Punctuation => Token::Punctuation(&alternative.content),
+            ItemKind::Pronunciation => Token::Word(read_word(item, alternative, index, &labels)?),
+        });
+    }
+    asr::transcript(title, tokens, options)
+}
+
+/// The parts of a Transcribe job result the importer reads.
+#[derive(Deserialize)]
+struct Job {
+    results: Results,
+}
+
+#[derive(Deserialize)]
+struct Results {
+    #[serde(default)]
+    items: Vec<Item>,
+    #[serde(default)]
+    speaker_labels: Option<SpeakerLabels>,
+}
+
+#[derive(Deserialize)]
+struct SpeakerLabels {
+    #[serde(default)]
+    segments: Vec<LabelledSegment>,
+}
+
+#[derive(Deserialize)]
+struct LabelledSegment {
+    #[serde(default)]
+    items: Vec<LabelledItem>,
+}
+
+#[derive(Deserialize)]
+struct LabelledItem {
+    start_time: String,
+    speaker_label: String,
+}
+
+#[derive(Deserialize)]
+struct Item {
+    #[serde(rename = "type")]
+    kind: ItemKind,
+    #[serde(default)]
+    start_time: Option<String>,
+    #[serde(default)]
+    end_time: Option<String>,
+    #[serde(default)]
+    speaker_label: Option<String>,
+    #[serde(default)]
+    alternatives: Vec<Alternative>,
+}
+
+#[derive(Clone, Copy, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum ItemKind {
+    Pronunciation,
+    Punctuation,
+}
+
+#[derive(Deserialize)]
+struct Alternative {
+    content: String,
+    #[serde(default)]
+    confidence: Option<String>,
+}
+
+/// Reads the pronunciation `item`, the `index`-th in the result, finding
+/// its speaker in `labels` when the item does not name one.
+fn read_word<'a>(
+    item: &'a Item,
+    alternative: &'a Alternative,
+    index: usize,
+    labels: &HashMap<&str, &'a str>,
+) -> Result<Word<'a>, TeiError> {
+    let path = TeiPath::new()
+        .child("results")
+        .nth_child("items", index + 1);
+    let error = |field: &str, message: String| {
+        TeiError::import_at(
+            FORMAT,
+            message,
+            SourceLocation::new().with_path(path.clone().child(field)),
+        )
+    };
+    let time = |value: Option<&str>, field: &str| {
+        let Some(text) = value else {
+            return Err(error(field, format!("word has no {field}")));
+        };
+        parse_seconds(text).ok_or_else(|| error(field, format!("malformed {field} `{text}`")))
+    };
+    let start = time(item.start_time.as_deref(), "start_time")?;
+    let end = time(item.end_time.as_deref(), "end_time")?;
+    if end < start {
+        return Err(error("end_time", "word ends before it starts".to_owned()));
+    }
+    let confidence = alternative
+        .confidence
+        .as_deref()
+        .map(|text| {
+            text.parse::<f64>()
+                .map_err(|_| error("confidence", format!("malformed confidence `{text}`")))
+        })
+        .transpose()?;
+
+    let start_text = item.start_time.as_deref().unwrap_or_default();
+    let speaker = item
+        .speaker_label
+        .as_deref()
+        .or_else(|| labels.get(start_text).copied());
+    Ok(Word {
+        speaker: speaker.map(Cow::Borrowed),
+        timing: start..end,
+        text: &alternative.content,
+        confidence,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{Certainty, Inline, SpeakerName, Unclear};
+    use rstest::rstest;
+
+    const DIARISED: &str = r#"{
+        "jobName": "wolf-359",
+        "results": {
+            "transcripts": [{"transcript": "Day one. Hello, Eiffel."}],
+            "speaker_labels": {"speakers": 2, "segments": [
+                {"speaker_label": "spk_0", "start_time": "1.0", "end_time": "1.8", "items": [
+                    {"speaker_label": "spk_0", "start_time": "1.0", "end_time": "1.4"},
+                    {"speaker_label": "spk_0", "start_time": "1.4", "end_time": "1.8"}
+                ]},
+                {"speaker_label": "spk_1", "start_time": "2.5", "end_time": "3.25", "items": [
+                    {"speaker_label": "spk_1", "start_time": "2.5", "end_time": "2.9"},
+                    {"speaker_label": "spk_1", "start_time": "2.9", "end_time": "3.25"}
+                ]}
+            ]},
+            "items": [
+                {"type": "pronunciation", "start_time": "1.0", "end_time": "1.4",
+                 "alternatives": [{"confidence": "0.99", "content": "Day"}]},
+                {"type": "pronunciation", "start_time": "1.4", "end_time": "1.8",
+                 "alternatives": [{"confidence": "0.97", "content": "one"}]},
+                {"type": "punctuation",
+                 "alternatives": [{"confidence": "0.0", "content": "."}]},
+                {"type": "pronunciation", "start_time": "2.5", "end_time": "2.9",
+                 "alternatives": [{"confidence": "0.95", "content": "Hello"}]},
+                {"type": "punctuation",
+                 "alternatives": [{"confidence": "0.0", "content": ","}]},
+                {"type": "pronunciation", "start_time": "2.9", "end_time": "3.25",
+                 "alternatives": [{"confidence": "0.61", "content": "Eiffel"}]},
+                {"type": "punctuation",
+                 "alternatives": [{"confidence": "0.0", "content": "."}]}
+            ]
+        },
+        "status": "COMPLETED"
+    }"#;
+
+    fn word(start: &str, end: &str, content: &str, confidence: &str) -> String {
+        format!(
+            r#"{{"type": "pronunciation", "start_time": "{start}", "end_time": "{end}",
+                "alternatives": [{{"confidence": "{confidence}", "content": "{content}"}}]}}"#
+        )
+    }
+
+    fn stop() -> String {
+        r#"{"type": "punctuation", "alternatives": [{"content": "."}]}"#.to_owned()
+    }
+
+    fn job(items: &[String]) -> String {
+        format!(r#"{{"results": {{"items": [{}]}}}}"#, items.join(","))
+    }
+
+    #[test]
+    fn reads_speakers_and_timing_from_speaker_labels() {
+        let document = parse(DIARISED, "Wolf 359").expect("result should import");
+
+        let speakers: Vec<_> = document
+            .header()
+            .profile_desc()
+            .map(|profile| profile.speakers().iter().map(SpeakerName::as_str).collect())
+            .unwrap_or_default();
+        assert_eq!(speakers, ["spk_0", "spk_1"]);
+        let timeline = document.text().timeline().expect("timeline recorded");
+        let timing: Vec<_> = document
+            .text()
+            .body()
+            .utterances()
+            .map(|utterance| {
+                let offset = |point: Option<&str>| point.and_then(|id| timeline.offset(id));
+                (offset(utterance.start()), offset(utterance.end()))
+            })
+            .collect();
+        assert_eq!(
+            timing,
+            [
+                (
+                    Some(Duration::from_secs(1)),
+                    Some(Duration::from_millis(1_800))
+                ),
+                (
+                    Some(Duration::from_millis(2_500)),
+                    Some(Duration::from_millis(3_250))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn marks_low_confidence_words_unclear() {
+        let document = parse(DIARISED, "Wolf 359").expect("result should import");
+
+        let second = document.text().body().utterances().nth(1);
+        let mut unclear = Unclear::new([Inline::text("Eiffel")]);
+        unclear.set_cert(Certainty::Medium);
+        assert_eq!(
+            second.map(crate::Utterance::content),
+            Some(
+                [
+                    Inline::text("Hello, "),
+                    Inline::Unclear(unclear),
+                    Inline::text("."),
+                ]
+                .as_slice()
+            )
+        );
+    }
+
+    #[test]
+    fn joins_neighbouring_unclear_words_at_the_lowest_certainty() {
+        let input = job(&[
+            word("0.0", "0.5", "Welcome", "0.99"),
+            word("0.5", "0.9", "to", "0.7"),
+            word("0.9", "1.5", "Hephaestus", "0.3"),
+        ]);
+
+        let document = parse(&input, "Wolf 359").expect("result should import");
+
+        let mut unclear = Unclear::new([Inline::text("to Hephaestus")]);
+        unclear.set_cert(Certainty::Low);
+        let utterance = document.text().body().utterances().next();
+        assert_eq!(
+            utterance.map(crate::Utterance::content),
+            Some([Inline::text("Welcome "), Inline::Unclear(unclear)].as_slice())
+        );
+    }
+
+    #[rstest]
+    #[case(AsrOptions::new(), 2)]
+    #[case(AsrOptions::new().with_unclear_below(0.0), 0)]
+    fn splits_undiarised_results_into_sentences(
+        #[case] options: AsrOptions,
+        #[case] unclear: usize,
+    ) {
+        let input = job(&[
+            word("0.0", "0.5", "Day", "0.4"),
+            stop(),
+            word("1.0", "1.5", "Still", "0.9"),
+            word("1.5", "2.0", "here", "0.6"),
+            stop(),
+        ]);
+
+        let document = parse_with_options(&input, "Wolf 359", options).expect("result imports");
+
+        let utterances: Vec<_> = document.text().body().utterances().collect();
+        assert_eq!(utterances.len(), 2);
+        assert!(
+            utterances
+                .iter()
+                .all(|utterance| utterance.speaker().is_none())
+        );
+        let marked = utterances
+            .iter()
+            .flat_map(|utterance| utterance.content())
+            .filter(|inline| matches!(inline, Inline::Unclear(_)))
+            .count();
+        assert_eq!(marked, unclear);
+    }
+
+    #[rstest]
+    #[case(job(&[word("0.5", "x", "Day", "0.9")]), "/results/items[1]/end_time")]
+    #[case(job(&[word("0.5", "0.1", "Day", "0.9")]), "/results/items[1]/end_time")]
+    #[case(job(&[stop(), word("0.5", "0.9", "Day", "high")]), "/results/items[2]/confidence")]
+    fn reports_malformed_words_with_their_path(#[case] input: String, #[case] path: &str) {
+        let error = parse(&input, "Wolf 359").expect_err("input must be rejected");
+
+        assert!(
+            matches!(error, TeiError::Import { format: FORMAT, .. }),
+            "{error:?}"
+        );
+        let located = error.location().and_then(SourceLocation::path);
+        assert_eq!(located.map(ToString::to_string).as_deref(), Some(path));
+    }
+
+    #[test]
+    fn reports_malformed_json_with_its_line() {
+        let error = parse("{\n  \"results\": [", "Wolf 359").expect_err("input must be rejected");
+
+        assert_eq!(error.location().and_then(SourceLocation::line), Some(2));
+        assert!(!error.to_string().contains("at line"), "{error}");
+    }
+}
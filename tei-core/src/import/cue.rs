@@ -0,0 +1,333 @@
+//! Subtitle cue text: voice spans, styling tags, and character references.
+
+use std::mem;
+
+use crate::{Hi, Inline, LanguageTag};
+
+/// Styling carried over from a cue tag.
+enum Style {
+    Rend(&'static str),
+    Lang(LanguageTag),
+}
+
+/// Cue text before it becomes validated inline content.
+enum Node {
+    Text(String),
+    Span(Style, Vec<Self>),
+}
+
+/// A tag still open while reading the cue text.
+struct Frame {
+    name: String,
+    style: Option<Style>,
+    nodes: Vec<Node>,
+}
+
+/// The voice segments of a cue under construction.
+#[derive(Default)]
+struct Segments {
+    speaker: Option<String>,
+    open: Vec<Frame>,
+    nodes: Vec<Node>,
+    done: Vec<(Option<String>, Vec<Inline>)>,
+}
+
+/// Splits cue text into one run of inline content per voice, paired with the
+/// voice's name.
+///
+/// Segments without visible text are dropped.
+pub(super) fn segments(payload: &str) -> Vec<(Option<String>, Vec<Inline>)> {
+    let mut segments = Segments::default();
+    let mut rest = payload;
+    while !rest.is_empty() {
+        let text_end = rest.find('<').unwrap_or(rest.len());
+        let (text, after) = rest.split_at(text_end);
+        segments.push_text(&decode(text));
+        let Some(tag) = after.strip_prefix('<') else {
+            break;
+        };
+        let Some((inner, remainder)) = tag.split_once('>') else {
+            segments.push_text(&decode(after));
+            break;
+        };
+        segments.apply_tag(inner);
+        rest = remainder;
+    }
+    segments.finish_voice();
+    segments.done
+}
+
+impl Segments {
+    fn current(&mut self) -> &mut Vec<Node> {
+        match self.open.last_mut() {
+            Some(frame) => &mut frame.nodes,
+            None => &mut self.nodes,
+        }
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if !text.is_empty() {
+            self.current().push(Node::Text(text.to_owned()));
+        }
+    }
+
+    fn apply_tag(&mut self, inner: &str) {
+        if let Some(closing) = inner.strip_prefix('/') {
+            if closing.trim() == "v" {
+                self.finish_voice();
+                self.speaker = None;
+            } else {
+                self.close(closing.trim());
+            }
+            return;
+        }
+        let (head, annotation) = inner.split_once(char::is_whitespace).unwrap_or((inner, ""));
+        let name = head.split('.').next().unwrap_or_default();
+        let style = match name {
+            "v" => {
+                self.finish_voice();
+                let speaker = decode(annotation.trim());
+                self.speaker = (!speaker.is_empty()).then_some(speaker);
+                return;
+            }
+            "i" => Some(Style::Rend("italic")),
+            "b" => Some(Style::Rend("bold")),
+            "u" => Some(Style::Rend("underline")),
+            "lang" => LanguageTag::new(annotation.trim()).ok().map(Style::Lang),
+            // Timestamp tags mark karaoke timing and never close.
+            _ if name.starts_with(|first: char| first.is_ascii_digit()) => return,
+            _ => None,
+        };
+        self.open.push(Frame {
+            name: name.to_owned(),
+            style,
+            nodes: Vec::new(),
+        });
+    }
+
+    /// Closes the innermost open tag called `name`, and any opened inside it.
+    fn close(&mut self, name: &str) {
+        if !self.open.iter().any(|frame| frame.name == name) {
+            return;
+        }
+        while let Some(frame) = self.open.pop() {
+            let matched = frame.name == name;
+            self.fold(frame);
+            if matched {
+                return;
+            }
+        }
+    }
+
+    fn fold(&mut self, frame: Frame) {
+        let parent = self.current();
+        match frame.style {
+            Some(style) => parent.push(Node::Span(style, frame.nodes)),
+            None => parent.extend(frame.nodes),
+        }
+    }
+
+    fn finish_voice(&mut self) {
+        while let Some(frame) = self.open.pop() {
+            self.fold(frame);
+        }
+        let mut nodes = normalise(mem::take(&mut self.nodes));
+        if nodes.is_empty() {
+            return;
+        }
+        trim_edges(&mut nodes);
+        self.done.push((
+            self.speaker.clone(),
+            nodes.into_iter().map(inline).collect(),
+        ));
+    }
+}
+
+/// Merges adjacent text, drops spans without visible text, and moves
+/// whitespace-only runs into neighbouring text so every run stays visible.
+fn normalise(nodes: Vec<Node>) -> Vec<Node> {
+    let mut out: Vec<Node> = Vec::new();
+    let mut pending = String::new();
+    for node in nodes {
+        match node {
+            Node::Text(text) if text.trim().is_empty() => {
+                if !attach_end(&mut out, &text) {
+                    pending.push_str(&text);
+                }
+            }
+            Node::Text(text) => {
+                let joined = mem::take(&mut pending) + &text;
+                if let Some(Node::Text(previous)) = out.last_mut() {
+                    previous.push_str(&joined);
+                } else {
+                    out.push(Node::Text(joined));
+                }
+            }
+            Node::Span(style, children) => {
+                let mut kept = normalise(children);
+                if kept.is_empty() {
+                    continue;
+                }
+                attach_start(&mut kept, &mem::take(&mut pending));
+                out.push(Node::Span(style, kept));
+            }
+        }
+    }
+    out
+}
+
+fn attach_end(nodes: &mut [Node], whitespace: &str) -> bool {
+    match nodes.last_mut() {
+        Some(Node::Text(text)) => {
+            text.push_str(whitespace);
+            true
+        }
+        Some(Node::Span(_, children)) => attach_end(children, whitespace),
+        None => false,
+    }
+}
+
+fn attach_start(nodes: &mut [Node], whitespace: &str) {
+    match nodes.first_mut() {
+        Some(Node::Text(text)) => text.insert_str(0, whitespace),
+        Some(Node::Span(_, children)) => attach_start(children, whitespace),
+        None => {}
+    }
+}
+
+fn trim_edges(nodes: &mut [Node]) {
+    if let Some(text) = edge_text(nodes, true) {
+        *text = text.trim_start().to_owned();
+    }
+    if let Some(text) = edge_text(nodes, false) {
+        *text = text.trim_end().to_owned();
+    }
+}
+
+fn edge_text(nodes: &mut [Node], first: bool) -> Option<&mut String> {
+    let node = if first {
+        nodes.first_mut()
+    } else {
+        nodes.last_mut()
+    };
+    match node? {
+        Node::Text(text) => Some(text),
+        Node::Span(_, children) => edge_text(children, first),
+    }
+}
+
+fn inline(node: Node) -> Inline {
+    match node {
+        Node::Text(text) => Inline::Text(text),
+        Node::Span(Style::Rend(rend), children) => {
+            Inline::Hi(Hi::with_rend(rend, children.into_iter().map(inline)))
+        }
+        Node::Span(Style::Lang(lang), children) => {
+            let mut hi = Hi::new(children.into_iter().map(inline));
+            hi.set_lang(lang);
+            Inline::Hi(hi)
+        }
+    }
+}
+
+/// Resolves the character references `WebVTT` defines, leaving unknown ones
+/// as written.
+fn decode(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        let (before, reference) = rest.split_at(start);
+        decoded.push_str(before);
+        let resolved = reference.split_once(';').and_then(|(name, after)| {
+            let character = match name {
+                "&amp" => '&',
+                "&lt" => '<',
+                "&gt" => '>',
+                "&quot" => '"',
+                "&apos" => '\'',
+                "&nbsp" => '\u{a0}',
+                "&lrm" => '\u{200e}',
+                "&rlm" => '\u{200f}',
+                _ => return None,
+            };
+            Some((character, after))
+        });
+        if let Some((character, after)) = resolved {
+            decoded.push(character);
+            rest = after;
+        } else {
+            decoded.push('&');
+            rest = reference.get(1..).unwrap_or_default();
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn text(value: &str) -> Inline {
+        Inline::text(value)
+    }
+
+    #[test]
+    fn splits_voices_into_segments() {
+        let segments = segments("<v Eiffel>Hello?</v> <v.loud Hera>Hi, Eiffel.");
+
+        assert_eq!(
+            segments,
+            vec![
+                (Some("Eiffel".to_owned()), vec![text("Hello?")]),
+                (Some("Hera".to_owned()), vec![text("Hi, Eiffel.")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn maps_styling_tags_to_hi() {
+        let segments = segments("A <i>very</i> <b.loud>bad</b> <lang fr>idée</lang>");
+
+        let mut french = Hi::new([text("idée")]);
+        french.set_lang(LanguageTag::new("fr").expect("valid language"));
+        assert_eq!(
+            segments,
+            vec![(
+                None,
+                vec![
+                    text("A "),
+                    Inline::Hi(Hi::with_rend("italic", [text("very ")])),
+                    Inline::Hi(Hi::with_rend("bold", [text("bad ")])),
+                    Inline::Hi(french),
+                ]
+            )]
+        );
+    }
+
+    #[test]
+    fn keeps_the_text_of_other_tags() {
+        let segments = segments("<c.yellow>Day</c> <00:00:01.500>1 <ruby>日<rt>ひ</rt></ruby>");
+
+        assert_eq!(segments, vec![(None, vec![text("Day 1 日ひ")])]);
+    }
+
+    #[test]
+    fn drops_segments_without_visible_text() {
+        let segments = segments(" <v Eiffel> <i> </i></v><v Hera>Here.");
+
+        assert_eq!(
+            segments,
+            vec![(Some("Hera".to_owned()), vec![text("Here.")])]
+        );
+    }
+
+    #[rstest]
+    #[case("Fish &amp; chips", "Fish & chips")]
+    #[case("&lt;beep&gt;", "<beep>")]
+    #[case("R&D &copy;", "R&D &copy;")]
+    fn decodes_character_references(#[case] payload: &str, #[case] expected: &str) {
+        assert_eq!(segments(payload), vec![(None, vec![text(expected)])]);
+    }
+}
@@ -0,0 +1,192 @@
+//! Descript transcript exports as utterances.
+//!
+//! The plain-text export writes one paragraph per speaker, as
+//! `Eiffel: Day 1.` or, with timecodes enabled, `[00:00:03] Eiffel: Day 1.`;
+//! lines without a label continue the paragraph above. Timed paragraphs
+//! last until the next one starts.
+//!
+//! The JSON export lists `utterances`, each with a `speaker` name, `start`
+//! and `end` times in seconds, and its `text`; it is read with the default
+//! `json` feature.
+
+use super::labelled;
+use crate::{TeiDocument, TeiError};
+
+const FORMAT: &str = "Descript";
+
+/// Converts a Descript plain-text export into a document titled `title`.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Import`] when a bracketed timecode is malformed.
+/// Returns [`TeiError::DocumentTitle`] when `title` is blank.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::import::descript;
+///
+/// let transcript = "[00:00:03] Eiffel: Day 1 of the mission.\n[00:00:07] Hera: Hello.\n";
+/// let document = descript::parse_text(transcript, "Wolf 359")?;
+///
+/// let speakers: Vec<_> = document
+///     .text()
+///     .body()
+///     .utterances()
+///     .filter_map(|utterance| utterance.speaker())
+///     .map(|speaker| speaker.as_str())
+///     .collect();
+/// assert_eq!(speakers, ["Eiffel", "Hera"]);
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn parse_text(input: &str, title: &str) -> Result<TeiDocument, TeiError> {
+    labelled::parse(FORMAT, input, title)
+}
+
+/// Converts a Descript JSON export into a timed document titled `title`.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Import`] when the input is not a Descript export or
+/// an utterance has a malformed time or ends before it starts. Returns
+/// [`TeiError::DocumentTitle`] when `title` is blank.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::import::descript;
+///
+/// let export = r#"{"utterances": [
+///     {"speaker": "Eiffel", "start": 3.0, "end": 5.25, "text": "Day 1."}
+/// ]}"#;
+/// let document = descript::parse_json(export, "Wolf 359")?;
+///
+/// assert_eq!(document.text().body().utterances().count(), 1);
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+#[cfg(feature = "json")]
+pub fn parse_json(input: &str, title: &str) -> Result<TeiDocument, TeiError> {
+    json::parse(input, title)
+}
+
+#[cfg(feature = "json")]
+mod json {
+    use serde::Deserialize;
+    use serde_json::Number;
+
+    use super::FORMAT;
+    use crate::import::{TranscriptBuilder, asr};
+    use crate::text::parse_seconds;
+    use crate::{Inline, SourceLocation, TeiDocument, TeiError, TeiPath};
+
+    #[derive(Deserialize)]
+    struct Export {
+        #[serde(default)]
+        utterances: Vec<Utterance>,
+    }
+
+    #[derive(Deserialize)]
+    struct Utterance {
+        #[serde(default)]
+        speaker: Option<String>,
+        start: Number,
+        end: Number,
+        text: String,
+    }
+
+    pub(super) fn parse(input: &str, title: &str) -> Result<TeiDocument, TeiError> {
+        let export: Export =
+            serde_json::from_str(input).map_err(|error| asr::json_error(FORMAT, &error))?;
+        let mut builder = TranscriptBuilder::new(title)?;
+        for (index, utterance) in export.utterances.iter().enumerate() {
+            if utterance.text.trim().is_empty() {
+                continue;
+            }
+            let path = TeiPath::new().nth_child("utterances", index + 1);
+            let error = |field: &str, message: String| {
+                TeiError::import_at(
+                    FORMAT,
+                    message,
+                    SourceLocation::new().with_path(path.clone().child(field)),
+                )
+            };
+            let time = |value: &Number, field: &str| {
+                parse_seconds(&value.to_string())
+                    .ok_or_else(|| error(field, format!("malformed {field} time `{value}`")))
+            };
+            let start = time(&utterance.start, "start")?;
+            let end = time(&utterance.end, "end")?;
+            if end < start {
+                return Err(error("end", "utterance ends before it starts".to_owned()));
+            }
+            let speaker = utterance
+                .speaker
+                .as_deref()
+                .filter(|name| !name.trim().is_empty());
+            builder
+                .push_utterance(speaker, start..end, [Inline::text(utterance.text.trim())])
+                .map_err(|failure| error("text", failure.to_string()))?;
+        }
+        Ok(builder.finish())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::time::Duration;
+
+        use super::*;
+
+        #[test]
+        fn reads_speakers_and_timing() {
+            let input = r#"{"utterances": [
+                {"speaker": "Eiffel", "start": 0.1, "end": 2.75, "text": " Day 1. "},
+                {"speaker": "", "start": 3, "end": 4, "text": "Hello."},
+                {"speaker": "Hera", "start": 5, "end": 6, "text": "  "}
+            ]}"#;
+
+            let document = parse(input, "Wolf 359").expect("export should import");
+
+            let timeline = document.text().timeline().expect("timeline recorded");
+            let summary: Vec<_> = document
+                .text()
+                .body()
+                .utterances()
+                .map(|utterance| {
+                    (
+                        utterance.speaker().map(|name| name.as_str().to_owned()),
+                        utterance.start().and_then(|point| timeline.offset(point)),
+                        utterance.content().to_vec(),
+                    )
+                })
+                .collect();
+            assert_eq!(
+                summary,
+                [
+                    (
+                        Some("Eiffel".to_owned()),
+                        Some(Duration::from_millis(100)),
+                        vec![Inline::text("Day 1.")]
+                    ),
+                    (
+                        None,
+                        Some(Duration::from_secs(3)),
+                        vec![Inline::text("Hello.")]
+                    ),
+                ]
+            );
+        }
+
+        #[test]
+        fn reports_backwards_utterances_with_their_path() {
+            let input = r#"{"utterances": [{"start": 2, "end": 1, "text": "Day 1."}]}"#;
+
+            let error = parse(input, "Wolf 359").expect_err("input must be rejected");
+
+            let path = error.location().and_then(SourceLocation::path);
+            assert_eq!(
+                path.map(ToString::to_string).as_deref(),
+                Some("/utterances[1]/end")
+            );
+        }
+    }
+}
@@ -0,0 +1,369 @@
+//! YAML front matter as a TEI header.
+//!
+//! Writers drafting scripts in Markdown often keep episode metadata in a
+//! front matter block fenced by `---` lines. [`parse`] reads the subset of
+//! YAML such blocks use (`key: value` pairs, quoted values, comments, and
+//! lists written as `- item` lines or as `[a, b]`) and builds a
+//! [`TeiHeader`] from these keys:
+//!
+//! - `title`, which is required;
+//! - `speakers`, added to the cast list in order;
+//! - `languages`, added to the profile description;
+//! - `series`, numbered by optional `season` and `episode` keys, as a
+//!   `<seriesStmt>`.
+//!
+//! Other keys are ignored, so front matter written for static site
+//! generators imports unchanged. Nested mappings, anchors, and block scalars
+//! are not supported.
+
+use super::source::{self, error_at};
+use crate::{FileDesc, ProfileDesc, SeriesStmt, TeiError, TeiHeader};
+
+const FORMAT: &str = "YAML front matter";
+
+enum Value {
+    Scalar(String),
+    List(Vec<String>),
+}
+
+struct Entry<'a> {
+    key: &'a str,
+    line: usize,
+    value: Value,
+}
+
+/// Builds a header from the front matter opening `input`.
+///
+/// When the first line is `---`, the block ends at the next `---` or `...`
+/// line and anything after it, such as the Markdown script, is ignored.
+/// Otherwise the whole input is read as front matter.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Import`] when a line is not a `key: value` pair or
+/// list item, a key repeats, a quoted value is unterminated, the fenced block
+/// is never closed, the title is missing or blank, a speaker or language is
+/// blank, or a season or episode is not a positive number or lacks a
+/// `series`.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::import::front_matter;
+///
+/// let script = "---\n\
+///               title: \"Wolf 359: Succulent\"\n\
+///               speakers: [Eiffel, Hera]\n\
+///               languages:\n  - en\n\
+///               series: Wolf 359\n\
+///               episode: 12\n\
+///               ---\n\
+///               EIFFEL: Day 1 of the mission.\n";
+/// let header = front_matter::parse(script)?;
+///
+/// assert_eq!(header.file_desc().title().as_str(), "Wolf 359: Succulent");
+/// assert_eq!(
+///     header.file_desc().series_stmt().and_then(|series| series.episode_number()),
+///     Some(12),
+/// );
+/// assert_eq!(header.profile_desc().map(|profile| profile.len_speakers()), Some(2));
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn parse(input: &str) -> Result<TeiHeader, TeiError> {
+    let normalised = source::normalise(input);
+    let entries = entries(&normalised)?;
+    build(&entries)
+}
+
+fn entries(input: &str) -> Result<Vec<Entry<'_>>, TeiError> {
+    let mut lines = input
+        .split('\n')
+        .enumerate()
+        .map(|(index, line)| (index + 1, line))
+        .peekable();
+    let fenced = lines
+        .next_if(|(_, line)| line.trim_end() == "---")
+        .is_some();
+    let mut entries: Vec<Entry<'_>> = Vec::new();
+    let mut last_line = 1;
+    for (number, line) in lines {
+        if fenced && matches!(line.trim_end(), "---" | "...") {
+            return Ok(entries);
+        }
+        let content = strip_comment(line);
+        if content.trim().is_empty() {
+            continue;
+        }
+        last_line = number;
+        if let Some(item) = list_item(content) {
+            let Some(Entry {
+                value: Value::List(items),
+                ..
+            }) = entries.last_mut()
+            else {
+                return Err(error_at(FORMAT, "list item outside a list", number));
+            };
+            items.push(scalar(item, number)?);
+            continue;
+        }
+        let entry = entry(content, number)?;
+        if entries.iter().any(|known| known.key == entry.key) {
+            let message = format!("duplicate key `{}`", entry.key);
+            return Err(error_at(FORMAT, &message, number));
+        }
+        entries.push(entry);
+    }
+    if fenced {
+        return Err(error_at(
+            FORMAT,
+            "front matter is not closed by `---`",
+            last_line,
+        ));
+    }
+    Ok(entries)
+}
+
+/// Returns the text of a `- item` line.
+fn list_item(content: &str) -> Option<&str> {
+    let item = content.trim_start().strip_prefix('-')?;
+    (item.is_empty() || item.starts_with(char::is_whitespace)).then_some(item)
+}
+
+fn entry(content: &str, line: usize) -> Result<Entry<'_>, TeiError> {
+    if content.starts_with(char::is_whitespace) {
+        return Err(error_at(FORMAT, "nested mappings are not supported", line));
+    }
+    let Some((key, rest)) = content.split_once(':') else {
+        return Err(error_at(FORMAT, "expected `key: value`", line));
+    };
+    let text = rest.trim();
+    let value = if text.is_empty() {
+        Value::List(Vec::new())
+    } else if let Some(flow) = text.strip_prefix('[') {
+        let inner = flow
+            .strip_suffix(']')
+            .ok_or_else(|| error_at(FORMAT, "unterminated list", line))?;
+        let items = split_unquoted(inner, ',')
+            .into_iter()
+            .filter(|item| !item.trim().is_empty())
+            .map(|item| scalar(item, line))
+            .collect::<Result<_, _>>()?;
+        Value::List(items)
+    } else {
+        Value::Scalar(scalar(text, line)?)
+    };
+    Ok(Entry {
+        key: key.trim(),
+        line,
+        value,
+    })
+}
+
+/// Drops a `#` comment opening the line or following whitespace outside
+/// quotes.
+fn strip_comment(line: &str) -> &str {
+    let mut end = 0;
+    for (index, part) in split_unquoted(line, '#').into_iter().enumerate() {
+        if index > 0 {
+            let before = line.get(..end).unwrap_or_default();
+            if before.is_empty() || before.ends_with(char::is_whitespace) {
+                return before;
+            }
+            end += 1;
+        }
+        end += part.len();
+    }
+    line
+}
+
+/// Splits `text` at each `separator` that is not inside quotes.
+///
+/// A quote opens a quoted value only where one may start, so apostrophes
+/// within plain values are left alone; a doubled single quote closes and
+/// reopens the value, as YAML escapes it.
+fn split_unquoted(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut quote = None;
+    let mut start = 0;
+    let mut previous = ' ';
+    for (index, character) in text.char_indices() {
+        match quote {
+            Some(open) if open == character && previous != '\\' => quote = None,
+            None if character == separator => {
+                parts.push(text.get(start..index).unwrap_or_default());
+                start = index + character.len_utf8();
+            }
+            None if matches!(character, '"' | '\'')
+                && (previous.is_whitespace()
+                    || matches!(previous, '[' | ',')
+                    || previous == character) =>
+            {
+                quote = Some(character);
+            }
+            Some(_) | None => {}
+        }
+        previous = character;
+    }
+    parts.push(text.get(start..).unwrap_or_default());
+    parts
+}
+
+/// Reads a plain, single-quoted, or double-quoted value.
+fn scalar(text: &str, line: usize) -> Result<String, TeiError> {
+    let value = text.trim();
+    for quote in ['"', '\''] {
+        if let Some(opened) = value.strip_prefix(quote) {
+            let inner = opened
+                .strip_suffix(quote)
+                .ok_or_else(|| error_at(FORMAT, "unterminated quoted value", line))?;
+            return Ok(if quote == '\'' {
+                inner.replace("''", "'")
+            } else {
+                inner.replace("\\\"", "\"").replace("\\\\", "\\")
+            });
+        }
+    }
+    Ok(value.to_owned())
+}
+
+fn build(entries: &[Entry<'_>]) -> Result<TeiHeader, TeiError> {
+    let find = |key: &str| entries.iter().find(|entry| entry.key == key);
+    let title = find("title").ok_or_else(|| error_at(FORMAT, "missing `title`", 1))?;
+    let mut file_desc = FileDesc::from_title_str(&single(title)?)
+        .map_err(|failure| error_at(FORMAT, &failure.to_string(), title.line))?;
+
+    let numbers = [("season", find("season")), ("episode", find("episode"))];
+    if let Some(series) = find("series") {
+        let mut series_stmt = SeriesStmt::new(single(series)?)
+            .map_err(|failure| error_at(FORMAT, &failure.to_string(), series.line))?;
+        for (key, found) in numbers {
+            let Some(entry) = found else { continue };
+            let number = single(entry)?
+                .parse()
+                .map_err(|_| error_at(FORMAT, &format!("`{key}` must be a number"), entry.line))?;
+            series_stmt = if key == "season" {
+                series_stmt.with_season_number(number)
+            } else {
+                series_stmt.with_episode_number(number)
+            }
+            .map_err(|failure| error_at(FORMAT, &failure.to_string(), entry.line))?;
+        }
+        file_desc = file_desc.with_series_stmt(series_stmt);
+    } else if let Some((key, Some(entry))) = numbers.into_iter().find(|(_, found)| found.is_some())
+    {
+        let message = format!("`{key}` needs a `series`");
+        return Err(error_at(FORMAT, &message, entry.line));
+    }
+
+    let mut profile = ProfileDesc::new();
+    if let Some(entry) = find("speakers") {
+        for speaker in values(entry) {
+            profile
+                .add_speaker(speaker)
+                .map_err(|failure| error_at(FORMAT, &failure.to_string(), entry.line))?;
+        }
+    }
+    if let Some(entry) = find("languages") {
+        for language in values(entry) {
+            profile
+                .add_language(language)
+                .map_err(|failure| error_at(FORMAT, &failure.to_string(), entry.line))?;
+        }
+    }
+    let header = TeiHeader::new(file_desc);
+    Ok(if profile.is_empty() {
+        header
+    } else {
+        header.with_profile_desc(profile)
+    })
+}
+
+fn single(entry: &Entry<'_>) -> Result<String, TeiError> {
+    match &entry.value {
+        Value::Scalar(value) => Ok(value.clone()),
+        Value::List(_) => Err(error_at(
+            FORMAT,
+            &format!("`{}` must be a single value", entry.key),
+            entry.line,
+        )),
+    }
+}
+
+/// Returns the items of a list, or a lone value as a list of one.
+fn values<'a>(entry: &'a Entry<'_>) -> &'a [String] {
+    match &entry.value {
+        Value::Scalar(value) => std::slice::from_ref(value),
+        Value::List(items) => items,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SourceLocation;
+    use rstest::rstest;
+
+    #[test]
+    fn reads_fenced_front_matter_into_a_header() {
+        let script = "\u{feff}---\r\n\
+                      # Episode metadata\r\n\
+                      title: 'Hera''s Day # 1'\r\n\
+                      layout: episode\r\n\
+                      speakers: [Eiffel, \"Hilbert, Alexander\"]\r\n\
+                      languages:\r\n\
+                      \x20 - en  # spoken\r\n\
+                      \x20 - 'ru'\r\n\
+                      series: Wolf 359\r\n\
+                      season: 1\r\n\
+                      episode: 12\r\n\
+                      ...\r\n\
+                      title: not read\r\n";
+
+        let header = parse(script).expect("front matter should import");
+
+        let file_desc = header.file_desc();
+        assert_eq!(file_desc.title().as_str(), "Hera's Day # 1");
+        let series = file_desc.series_stmt().expect("series recorded");
+        assert_eq!(
+            (
+                series.title(),
+                series.season_number(),
+                series.episode_number()
+            ),
+            ("Wolf 359", Some(1), Some(12))
+        );
+        let profile = header.profile_desc().expect("profile recorded");
+        let speakers: Vec<_> = profile
+            .speakers()
+            .iter()
+            .map(crate::SpeakerName::as_str)
+            .collect();
+        assert_eq!(speakers, ["Eiffel", "Hilbert, Alexander"]);
+        let languages: Vec<_> = profile
+            .languages()
+            .iter()
+            .map(crate::LanguageTag::as_str)
+            .collect();
+        assert_eq!(languages, ["en", "ru"]);
+    }
+
+    #[rstest]
+    #[case("title: Succulent\n  nested: yes\n", 2)]
+    #[case("- Eiffel\n", 1)]
+    #[case("title: Succulent\ntitle: Again\n", 2)]
+    #[case("title: \"Succulent\n", 1)]
+    #[case("---\ntitle: Succulent\n", 2)]
+    #[case("series: Wolf 359\nepisode: 12\n", 1)]
+    #[case("title: Succulent\nepisode: 12\n", 2)]
+    #[case("title: Succulent\nseries: Wolf 359\nseason: first\n", 3)]
+    #[case("title: Succulent\nspeakers: [Eiffel, '']\n", 2)]
+    fn reports_malformed_front_matter_with_its_line(#[case] input: &str, #[case] line: usize) {
+        let error = parse(input).expect_err("input must be rejected");
+
+        assert!(
+            matches!(error, TeiError::Import { format: FORMAT, .. }),
+            "{error:?}"
+        );
+        assert_eq!(error.location().and_then(SourceLocation::line), Some(line));
+    }
+}
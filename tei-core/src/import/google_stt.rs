@@ -0,0 +1,303 @@
+//! Google Cloud Speech-to-Text responses as timed utterances.
+//!
+//! Word timing comes from the `words` of each result's first alternative,
+//! so the request must enable word time offsets. Diarised responses repeat
+//! every word of the recording, tagged with a speaker, in the final result;
+//! when that result names speakers it is read on its own, and each change
+//! of speaker starts a new utterance. A numeric `speakerTag` of 1 becomes
+//! the speaker `speaker_1`, while a `speakerLabel` from the v2 API is kept
+//! as it is. Without speakers, each sentence becomes an utterance.
+//!
+//! Both the bare response and a finished long-running operation wrapping it
+//! in `response` are accepted, as are the v2 `startOffset` and `endOffset`
+//! names. An offset left out is zero, since the API omits default values.
+//! Word confidence is only reported when requested; words scored below
+//! [`AsrOptions::unclear_below`] are wrapped in `<unclear>`. The importer is
+//! available with the default `json` feature.
+
+use std::borrow::Cow;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use super::AsrOptions;
+use super::asr::{self, Token, Word};
+use crate::text::parse_seconds;
+use crate::{SourceLocation, TeiDocument, TeiError, TeiPath};
+
+const FORMAT: &str = "Google Speech-to-Text";
+
+/// Converts a Google Speech-to-Text response into a timed document titled
+/// `title`, using the default [`AsrOptions`].
+///
+/// # Errors
+///
+/// Returns [`TeiError::Import`] when the input is not a recognition
+/// response or a word has a malformed offset. Returns
+/// [`TeiError::DocumentTitle`] when `title` is blank.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::import::google_stt;
+///
+/// let response = r#"{"results": [{"alternatives": [{
+///     "transcript": "Day 1.",
+///     "words": [
+///         {"startTime": "0.500s", "endTime": "0.800s", "word": "Day", "speakerTag": 1},
+///         {"startTime": "0.800s", "endTime": "1.100s", "word": "1.", "speakerTag": 1}
+///     ]
+/// }]}]}"#;
+/// let document = google_stt::parse(response, "Wolf 359")?;
+///
+/// let utterance = document.text().body().utterances().next().expect("one utterance");
+/// assert_eq!(utterance.speaker().map(|s| s.as_str()), Some("speaker_1"));
+/// assert_eq!(utterance.content().len(), 1);
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn parse(input: &str, title: &str) -> Result<TeiDocument, TeiError> {
+    parse_with_options(input, title, AsrOptions::new())
+}
+
+/// Converts a Google Speech-to-Text response into a timed document titled
+/// `title`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`parse`].
+pub fn parse_with_options(
+    input: &str,
+    title: &str,
+    options: AsrOptions,
+) -> Result<TeiDocument, TeiError> {
+    let envelope: Envelope =
+        serde_json::from_str(input).map_err(|error| asr::json_error(FORMAT, &error))?;
+    let (base, results) = match envelope.response {
+        Some(response) => (TeiPath::new().child("response"), response.results),
+        None => (TeiPath::new(), envelope.results),
+    };
+
+    let diarised = results
+        .iter()
+        .enumerate()
+        .next_back()
+        .filter(|(_, last)| last.words().any(|word| word.speaker().is_some()));
+    let chosen: Vec<(usize, &RecognitionResult)> =
+        diarised.map_or_else(|| results.iter().enumerate().collect(), |last| vec![last]);
+
+    let mut tokens = Vec::new();
+    for (result_index, result) in chosen {
+        for (word_index, info) in result.words().enumerate() {
+            let path = base
+                .clone()
+                .nth_child("results", result_index + 1)
+                .nth_child("alternatives", 1)
+                .nth_child("words", word_index + 1);
+            tokens.push(Token::Word(info.read(&path)?));
+        }
+    }
+    asr::transcript(title, tokens, options)
+}
+
+/// A response, or a long-running operation carrying one.
+#[derive(Deserialize)]
+struct Envelope {
+    #[serde(default)]
+    results: Vec<RecognitionResult>,
+    #[serde(default)]
+    response: Option<Response>,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    #[serde(default)]
+    results: Vec<RecognitionResult>,
+}
+
+#[derive(Deserialize)]
+struct RecognitionResult {
+    #[serde(default)]
+    alternatives: Vec<Alternative>,
+}
+
+impl RecognitionResult {
+    fn words(&self) -> impl Iterator<Item = &WordInfo> {
+        self.alternatives
+            .first()
+            .into_iter()
+            .flat_map(|alternative| &alternative.words)
+    }
+}
+
+#[derive(Deserialize)]
+struct Alternative {
+    #[serde(default)]
+    words: Vec<WordInfo>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WordInfo {
+    #[serde(default, alias = "startOffset")]
+    start_time: Option<String>,
+    #[serde(default, alias = "endOffset")]
+    end_time: Option<String>,
+    word: String,
+    #[serde(default)]
+    confidence: Option<f64>,
+    #[serde(default)]
+    speaker_tag: Option<u32>,
+    #[serde(default)]
+    speaker_label: Option<String>,
+}
+
+impl WordInfo {
+    /// Names the speaker, treating the default tag 0 and a blank label as
+    /// no speaker.
+    fn speaker(&self) -> Option<Cow<'_, str>> {
+        let label = self
+            .speaker_label
+            .as_deref()
+            .filter(|label| !label.trim().is_empty());
+        match (label, self.speaker_tag) {
+            (Some(name), _) => Some(Cow::Borrowed(name)),
+            (None, Some(tag)) if tag > 0 => Some(Cow::Owned(format!("speaker_{tag}"))),
+            (None, _) => None,
+        }
+    }
+
+    /// Reads the word found at `path`.
+    fn read(&self, path: &TeiPath) -> Result<Word<'_>, TeiError> {
+        let error = |field: &str, message: String| {
+            TeiError::import_at(
+                FORMAT,
+                message,
+                SourceLocation::new().with_path(path.clone().child(field)),
+            )
+        };
+        let offset = |value: Option<&str>, field: &str| {
+            let Some(text) = value else {
+                return Ok(Duration::ZERO);
+            };
+            text.strip_suffix('s')
+                .and_then(parse_seconds)
+                .ok_or_else(|| error(field, format!("malformed {field} `{text}`")))
+        };
+        let start = offset(self.start_time.as_deref(), "startTime")?;
+        let end = offset(self.end_time.as_deref(), "endTime")?;
+        if end < start {
+            return Err(error("endTime", "word ends before it starts".to_owned()));
+        }
+        Ok(Word {
+            speaker: self.speaker(),
+            timing: start..end,
+            text: &self.word,
+            confidence: self.confidence,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Certainty, Inline, SpeakerName, Unclear};
+    use rstest::rstest;
+
+    const DIARISED: &str = r#"{
+        "name": "operation-359",
+        "done": true,
+        "response": {"results": [
+            {"alternatives": [{"transcript": "Day one. Hello, Eiffel.", "words": [
+                {"startTime": "1s", "endTime": "1.400s", "word": "Day"}
+            ]}]},
+            {"alternatives": [{"words": [
+                {"startTime": "1s", "endTime": "1.400s", "word": "Day",
+                 "confidence": 0.98, "speakerTag": 1},
+                {"startTime": "1.400s", "endTime": "1.800s", "word": "one.",
+                 "confidence": 0.97, "speakerTag": 1},
+                {"startTime": "2.500s", "endTime": "2.900s", "word": "Hello,",
+                 "confidence": 0.95, "speakerTag": 2},
+                {"startTime": "2.900s", "endTime": "3.250s", "word": "Eiffel.",
+                 "confidence": 0.42, "speakerTag": 2}
+            ]}]}
+        ]}
+    }"#;
+
+    #[test]
+    fn reads_speakers_from_the_final_diarised_result() {
+        let document = parse(DIARISED, "Wolf 359").expect("response should import");
+
+        let speakers: Vec<_> = document
+            .header()
+            .profile_desc()
+            .map(|profile| profile.speakers().iter().map(SpeakerName::as_str).collect())
+            .unwrap_or_default();
+        assert_eq!(speakers, ["speaker_1", "speaker_2"]);
+        let mut unclear = Unclear::new([Inline::text("Eiffel")]);
+        unclear.set_cert(Certainty::Low);
+        let contents: Vec<_> = document
+            .text()
+            .body()
+            .utterances()
+            .map(|utterance| utterance.content().to_vec())
+            .collect();
+        assert_eq!(
+            contents,
+            [
+                vec![Inline::text("Day one.")],
+                vec![
+                    Inline::text("Hello, "),
+                    Inline::Unclear(unclear),
+                    Inline::text(".")
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn joins_results_and_splits_sentences_without_speakers() {
+        let input = r#"{"results": [
+            {"alternatives": [{"words": [
+                {"endTime": "0.400s", "word": "Day"},
+                {"startTime": "0.400s", "endTime": "0.900s", "word": "one."}
+            ]}]},
+            {"alternatives": [{"words": [
+                {"startOffset": "1.200s", "endOffset": "1.500s", "word": "Still"},
+                {"startOffset": "1.500s", "endOffset": "2s", "word": "here."}
+            ]}]}
+        ]}"#;
+
+        let document = parse(input, "Wolf 359").expect("response should import");
+
+        let timeline = document.text().timeline().expect("timeline recorded");
+        let starts: Vec<_> = document
+            .text()
+            .body()
+            .utterances()
+            .map(|utterance| utterance.start().and_then(|point| timeline.offset(point)))
+            .collect();
+        assert_eq!(
+            starts,
+            [Some(Duration::ZERO), Some(Duration::from_millis(1_200))]
+        );
+        assert!(document.header().profile_desc().is_none());
+    }
+
+    #[rstest]
+    #[case(r#"{"results": [{"alternatives": [{"words": [{"startTime": "1", "word": "Day"}]}]}]}"#)]
+    #[case(r#"{"results": [{"alternatives": [{"words": [{"startTime": "2s", "endTime": "1s", "word": "Day"}]}]}]}"#)]
+    fn reports_malformed_offsets_with_their_path(#[case] input: &str) {
+        let error = parse(input, "Wolf 359").expect_err("input must be rejected");
+
+        assert!(
+            matches!(error, TeiError::Import { format: FORMAT, .. }),
+            "{error:?}"
+        );
+        let located = error.location().and_then(SourceLocation::path);
+        let rendered = located.map(ToString::to_string).unwrap_or_default();
+        assert!(
+            rendered.starts_with("/results[1]/alternatives[1]/words[1]/"),
+            "{rendered}"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! Plain-text transcripts written as speaker-labelled paragraphs.
+//!
+//! Transcript editors export text in a handful of closely related layouts:
+//!
+//! - `Eiffel: Day 1 of the mission.`, a label opening the line;
+//! - `[00:01:02] Eiffel: Day 1.`, the same with the start time in brackets;
+//! - `Eiffel  0:03` opening a paragraph, with the text on the lines below.
+//!
+//! Lines without a label continue the current turn. Each turn becomes an
+//! utterance lasting until the next timed turn starts; the last one has no
+//! length, and a turn without a time is left untimed.
+
+use std::mem;
+use std::time::Duration;
+
+use super::TranscriptBuilder;
+use super::source::{self, error_at};
+use super::srt::SpeakerLabels;
+use crate::{Inline, TeiDocument, TeiError};
+
+/// Longest heading, in characters, read as a speaker name.
+const MAX_NAME_CHARS: usize = 32;
+
+/// One speaker's paragraph.
+struct Turn {
+    line: usize,
+    speaker: Option<String>,
+    start: Option<Duration>,
+    text: String,
+}
+
+/// Converts the labelled paragraphs of `input` into a document titled
+/// `title`, naming `format` in errors.
+pub(super) fn parse(
+    format: &'static str,
+    input: &str,
+    title: &str,
+) -> Result<TeiDocument, TeiError> {
+    let turns = turns(format, &source::normalise(input))?;
+    let mut builder = TranscriptBuilder::new(title)?;
+    for (index, turn) in turns.iter().enumerate() {
+        let content = [Inline::text(turn.text.as_str())];
+        let speaker = turn.speaker.as_deref();
+        let pushed = match turn.start {
+            Some(start) => {
+                let end = turns
+                    .iter()
+                    .skip(index + 1)
+                    .find_map(|next| next.start)
+                    .map_or(start, |next| next.max(start));
+                builder.push_utterance(speaker, start..end, content)
+            }
+            None => builder.push_untimed_utterance(speaker, content),
+        };
+        pushed.map_err(|failure| error_at(format, &failure.to_string(), turn.line))?;
+    }
+    Ok(builder.finish())
+}
+
+fn turns(format: &'static str, input: &str) -> Result<Vec<Turn>, TeiError> {
+    let mut turns: Vec<Turn> = Vec::new();
+    let mut opens_paragraph = true;
+    for (index, raw) in input.split('\n').enumerate() {
+        let line = index + 1;
+        let trimmed = raw.trim();
+        let first = mem::replace(&mut opens_paragraph, trimmed.is_empty());
+        if trimmed.is_empty() {
+            continue;
+        }
+        if let Some((name, start)) = heading(trimmed).filter(|_| first) {
+            turns.push(Turn {
+                line,
+                speaker: Some(name.to_owned()),
+                start: Some(start),
+                text: String::new(),
+            });
+            continue;
+        }
+        let (start, rest) = bracketed_time(format, trimmed, line)?;
+        let label = SpeakerLabels::Capitalised.split(rest);
+        match turns.last_mut() {
+            Some(turn) if label.is_none() && start.is_none() => {
+                if !turn.text.is_empty() {
+                    turn.text.push(' ');
+                }
+                turn.text.push_str(rest);
+            }
+            _ => turns.push(Turn {
+                line,
+                speaker: label.map(|(name, _)| name.to_owned()),
+                start,
+                text: label.map_or(rest, |(_, said)| said).to_owned(),
+            }),
+        }
+    }
+    turns.retain(|turn| !turn.text.trim().is_empty());
+    Ok(turns)
+}
+
+/// Reads a heading such as `Eiffel  0:03`: a name followed by the time the
+/// speaker starts, alone on the first line of a paragraph.
+fn heading(line: &str) -> Option<(&str, Duration)> {
+    let (name, time) = line.rsplit_once(char::is_whitespace)?;
+    let start = source::elapsed(time)?;
+    let speaker = name.trim();
+    let plausible = !speaker.is_empty()
+        && speaker.chars().count() <= MAX_NAME_CHARS
+        && speaker.starts_with(char::is_alphabetic)
+        && !speaker.contains([':', '[', ']']);
+    plausible.then_some((speaker, start))
+}
+
+/// Splits a leading `[00:01:02]` start time from `line`.
+fn bracketed_time<'a>(
+    format: &'static str,
+    line: &'a str,
+    number: usize,
+) -> Result<(Option<Duration>, &'a str), TeiError> {
+    let Some((time, rest)) = line
+        .strip_prefix('[')
+        .and_then(|inner| inner.split_once(']'))
+    else {
+        return Ok((None, line));
+    };
+    let start = source::elapsed(time.trim())
+        .ok_or_else(|| error_at(format, &format!("malformed timestamp `{time}`"), number))?;
+    Ok((Some(start), rest.trim_start()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SourceLocation;
+    use rstest::rstest;
+
+    fn summary(document: &TeiDocument) -> Vec<(Option<String>, Option<Duration>, usize)> {
+        let timeline = document.text().timeline();
+        document
+            .text()
+            .body()
+            .utterances()
+            .map(|utterance| {
+                let start = utterance
+                    .start()
+                    .and_then(|point| timeline.and_then(|known| known.offset(point)));
+                let words = utterance
+                    .content()
+                    .iter()
+                    .filter_map(Inline::as_text)
+                    .map(|text| text.split_whitespace().count())
+                    .sum();
+                (
+                    utterance.speaker().map(|name| name.as_str().to_owned()),
+                    start,
+                    words,
+                )
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case("Eiffel  0:03\nDay 1 of the\nmission.\n\nHera  1:02:10\nHello.\n")]
+    #[case("[00:00:03] Eiffel: Day 1 of the\nmission.\n[01:02:10] Hera: Hello.\n")]
+    fn reads_timed_layouts(#[case] input: &str) {
+        let document = parse("Test", input, "Wolf 359").expect("transcript should import");
+
+        assert_eq!(
+            summary(&document),
+            [
+                (Some("Eiffel".to_owned()), Some(Duration::from_secs(3)), 5),
+                (Some("Hera".to_owned()), Some(Duration::from_secs(3_730)), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn leaves_turns_without_times_untimed() {
+        let input = "\u{feff}Eiffel: Day 1.\r\nStill here.\r\nSpeaker 2: Hello.\r\n";
+
+        let document = parse("Test", input, "Wolf 359").expect("transcript should import");
+
+        assert_eq!(
+            summary(&document),
+            [
+                (Some("Eiffel".to_owned()), None, 4),
+                (Some("Speaker 2".to_owned()), None, 1),
+            ]
+        );
+        assert!(document.text().timeline().is_none());
+        let extent = document.header().file_desc().extent();
+        assert_eq!(extent.and_then(crate::Extent::duration), None);
+    }
+
+    #[test]
+    fn reports_malformed_bracketed_times() {
+        let error = parse("Test", "Eiffel: Hi.\n[0:6:00] Hera: Hello.", "Wolf 359")
+            .expect_err("input must be rejected");
+
+        assert!(
+            matches!(error, TeiError::Import { format: "Test", .. }),
+            "{error:?}"
+        );
+        assert_eq!(error.location().and_then(SourceLocation::line), Some(2));
+    }
+}
@@ -0,0 +1,197 @@
+//! Conversion of transcripts from other formats into TEI documents.
+//!
+//! Each importer reads its format into utterances and hands them to a
+//! [`TranscriptBuilder`], which anchors every timed utterance to a shared
+//! [`Timeline`], lists each speaker once in the profile description, and
+//! records the running time and counts in the header extent. Plain-text
+//! exports without timestamps produce untimed utterances. [`front_matter`]
+//! reads header metadata alone, from the YAML block opening a script.
+
+#[cfg(feature = "json")]
+mod asr;
+pub mod audacity;
+#[cfg(feature = "json")]
+pub mod aws_transcribe;
+mod cue;
+pub mod descript;
+pub mod front_matter;
+#[cfg(feature = "json")]
+pub mod google_stt;
+mod labelled;
+pub mod otter;
+mod source;
+pub mod srt;
+pub mod textgrid;
+pub mod webvtt;
+
+#[cfg(feature = "json")]
+pub use asr::AsrOptions;
+
+use std::ops::Range;
+use std::time::Duration;
+
+use crate::{
+    BodyBlock, Extent, FileDesc, HeaderValidationError, Inline, ProfileDesc, RawMarkup, TeiBody,
+    TeiDocument, TeiError, TeiHeader, TeiText, Timeline, Utterance,
+};
+
+/// Assembles a timed transcript one utterance at a time.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use tei_core::import::TranscriptBuilder;
+/// use tei_core::Inline;
+///
+/// let mut builder = TranscriptBuilder::new("Wolf 359")?;
+/// builder.push_utterance(
+///     Some("Eiffel"),
+///     Duration::from_secs(1)..Duration::from_secs(3),
+///     [Inline::text("Day 1.")],
+/// )?;
+/// let document = builder.finish();
+///
+/// let speakers = document.header().profile_desc().map(|profile| profile.speakers().len());
+/// assert_eq!(speakers, Some(1));
+/// assert_eq!(document.text().body().utterances().count(), 1);
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct TranscriptBuilder {
+    file_desc: FileDesc,
+    profile: ProfileDesc,
+    timeline: Timeline,
+    body: TeiBody,
+    running_time: Duration,
+}
+
+impl TranscriptBuilder {
+    /// Starts a transcript with the given title.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TeiError::DocumentTitle`] when the title trims to an empty
+    /// string.
+    pub fn new(title: &str) -> Result<Self, TeiError> {
+        Ok(Self {
+            file_desc: FileDesc::from_title_str(title)?,
+            profile: ProfileDesc::new(),
+            timeline: Timeline::new(),
+            body: TeiBody::default(),
+            running_time: Duration::ZERO,
+        })
+    }
+
+    /// Appends an utterance spoken during `timing`, measured from the start
+    /// of the recording.
+    ///
+    /// The speaker is added to the profile description the first time it
+    /// appears.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TeiError::Body`] when the content has no visible text or the
+    /// speaker is blank.
+    pub fn push_utterance(
+        &mut self,
+        speaker: Option<&str>,
+        timing: Range<Duration>,
+        content: impl IntoIterator<Item = Inline>,
+    ) -> Result<(), TeiError> {
+        let mut utterance = Utterance::from_inline(speaker, content)?;
+        if let Some(name) = utterance.speaker() {
+            self.add_speaker(name.as_str())?;
+        }
+        let start = self.timeline.add_offset(timing.start);
+        let end = self.timeline.add_offset(timing.end);
+        utterance.set_timing(&start, &end);
+        self.body.push_utterance(utterance);
+        self.running_time = self.running_time.max(timing.end);
+        Ok(())
+    }
+
+    /// Appends an utterance whose timing the source does not record.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`push_utterance`](Self::push_utterance).
+    pub fn push_untimed_utterance(
+        &mut self,
+        speaker: Option<&str>,
+        content: impl IntoIterator<Item = Inline>,
+    ) -> Result<(), TeiError> {
+        let utterance = Utterance::from_inline(speaker, content)?;
+        if let Some(name) = utterance.speaker() {
+            self.add_speaker(name.as_str())?;
+        }
+        self.body.push_utterance(utterance);
+        Ok(())
+    }
+
+    /// Appends a `<milestone>` marking the instant `at`, for sources that
+    /// annotate moments rather than speech.
+    ///
+    /// The milestone carries `unit="label"`, a `@synch` pointer to the
+    /// timeline and, unless it is blank, `label` in `@n`. It is kept as raw
+    /// markup, since the data model has no milestone block.
+    pub fn push_milestone(&mut self, at: Duration, label: &str) {
+        let point = self.timeline.add_offset(at);
+        let trimmed = label.trim();
+        let name = if trimmed.is_empty() {
+            String::new()
+        } else {
+            format!(" n=\"{}\"", crate::escape::attribute(trimmed))
+        };
+        let markup = format!("<milestone unit=\"label\"{name} synch=\"#{point}\"/>");
+        self.body
+            .extend([BodyBlock::Raw(RawMarkup::generated(markup))]);
+        self.running_time = self.running_time.max(at);
+    }
+
+    /// Finishes the document, recording the speakers, the timeline, and an
+    /// extent measuring the utterances, the words and, when any utterance
+    /// was timed, the running time.
+    #[must_use]
+    pub fn finish(self) -> TeiDocument {
+        let mut file_desc = self.file_desc;
+        let timed = self.timeline.points().len() > 1;
+        let extent = Extent::new();
+        file_desc.set_extent(if timed {
+            extent.with_duration(self.running_time)
+        } else {
+            extent
+        });
+        let mut header = TeiHeader::new(file_desc);
+        if !self.profile.speakers().is_empty() {
+            header = header.with_profile_desc(self.profile);
+        }
+        let mut text = TeiText::new(self.body);
+        if timed {
+            text.set_timeline(self.timeline);
+        }
+        let mut document = TeiDocument::new(header, text);
+        document.refresh_extent();
+        document
+    }
+
+    /// Lists a speaker in the profile description, unless it is already
+    /// there, so that formats with a speaker table can record speakers who
+    /// never speak.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeaderValidationError::EmptyField`] when the name is blank.
+    pub fn add_speaker(&mut self, name: &str) -> Result<(), HeaderValidationError> {
+        let known = self
+            .profile
+            .speakers()
+            .iter()
+            .any(|speaker| speaker.as_str() == name);
+        if known {
+            return Ok(());
+        }
+        self.profile.add_speaker(name)
+    }
+}
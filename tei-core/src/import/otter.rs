@@ -0,0 +1,221 @@
+//! Otter.ai transcript exports as utterances.
+//!
+//! The plain-text export opens each paragraph with the speaker's name and
+//! start time, as in `Eiffel  0:03`, and puts the text on the lines below;
+//! exports without timestamps write `Eiffel: Day 1.` instead. Timed
+//! paragraphs last until the next one starts.
+//!
+//! The JSON form is the one Otter's API returns for a conversation:
+//! `speakers`, each an `id` with a `speaker_name`, and `transcripts`, each
+//! with a `speaker_id`, `start_offset` and `end_offset` in milliseconds, and
+//! its `transcript`, optionally wrapped in a `speech` object. A speaker id
+//! missing from `speakers` is named `Speaker <id>`. It is read with the
+//! default `json` feature.
+
+use super::labelled;
+use crate::{TeiDocument, TeiError};
+
+const FORMAT: &str = "Otter";
+
+/// Converts an Otter.ai plain-text export into a document titled `title`.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Import`] when a bracketed timestamp is malformed.
+/// Returns [`TeiError::DocumentTitle`] when `title` is blank.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::import::otter;
+///
+/// let transcript = "Eiffel  0:03\nDay 1 of the mission.\n\nHera  0:07\nHello.\n";
+/// let document = otter::parse_text(transcript, "Wolf 359")?;
+///
+/// assert_eq!(document.text().body().utterances().count(), 2);
+/// assert!(document.text().timeline().is_some());
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn parse_text(input: &str, title: &str) -> Result<TeiDocument, TeiError> {
+    labelled::parse(FORMAT, input, title)
+}
+
+/// Converts an Otter.ai JSON conversation into a timed document titled
+/// `title`.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Import`] when the input is not an Otter
+/// conversation or a transcript ends before it starts. Returns
+/// [`TeiError::DocumentTitle`] when `title` is blank.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::import::otter;
+///
+/// let conversation = r#"{
+///     "speakers": [{"id": 7, "speaker_name": "Eiffel"}],
+///     "transcripts": [
+///         {"speaker_id": 7, "start_offset": 3000, "end_offset": 5250, "transcript": "Day 1."}
+///     ]
+/// }"#;
+/// let document = otter::parse_json(conversation, "Wolf 359")?;
+///
+/// let utterance = document.text().body().utterances().next().expect("one utterance");
+/// assert_eq!(utterance.speaker().map(|s| s.as_str()), Some("Eiffel"));
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+#[cfg(feature = "json")]
+pub fn parse_json(input: &str, title: &str) -> Result<TeiDocument, TeiError> {
+    json::parse(input, title)
+}
+
+#[cfg(feature = "json")]
+mod json {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use serde::Deserialize;
+
+    use super::FORMAT;
+    use crate::import::{TranscriptBuilder, asr};
+    use crate::{Inline, SourceLocation, TeiDocument, TeiError, TeiPath};
+
+    #[derive(Deserialize)]
+    struct Envelope {
+        #[serde(default)]
+        speech: Option<Conversation>,
+        #[serde(flatten)]
+        conversation: Conversation,
+    }
+
+    #[derive(Deserialize)]
+    struct Conversation {
+        #[serde(default)]
+        speakers: Vec<Speaker>,
+        #[serde(default)]
+        transcripts: Vec<Transcript>,
+    }
+
+    #[derive(Deserialize)]
+    struct Speaker {
+        id: u64,
+        speaker_name: String,
+    }
+
+    #[derive(Deserialize)]
+    struct Transcript {
+        #[serde(default)]
+        speaker_id: Option<u64>,
+        start_offset: u64,
+        end_offset: u64,
+        #[serde(rename = "transcript")]
+        text: String,
+    }
+
+    pub(super) fn parse(input: &str, title: &str) -> Result<TeiDocument, TeiError> {
+        let envelope: Envelope =
+            serde_json::from_str(input).map_err(|error| asr::json_error(FORMAT, &error))?;
+        let (base, conversation) = match envelope.speech {
+            Some(speech) => (TeiPath::new().child("speech"), speech),
+            None => (TeiPath::new(), envelope.conversation),
+        };
+        let names: HashMap<u64, &str> = conversation
+            .speakers
+            .iter()
+            .map(|speaker| (speaker.id, speaker.speaker_name.as_str()))
+            .collect();
+
+        let mut builder = TranscriptBuilder::new(title)?;
+        for (index, transcript) in conversation.transcripts.iter().enumerate() {
+            if transcript.text.trim().is_empty() {
+                continue;
+            }
+            let path = base.clone().nth_child("transcripts", index + 1);
+            let error = |field: &str, message: String| {
+                TeiError::import_at(
+                    FORMAT,
+                    message,
+                    SourceLocation::new().with_path(path.clone().child(field)),
+                )
+            };
+            if transcript.end_offset < transcript.start_offset {
+                return Err(error(
+                    "end_offset",
+                    "transcript ends before it starts".to_owned(),
+                ));
+            }
+            let speaker = transcript.speaker_id.map(|id| {
+                names
+                    .get(&id)
+                    .map_or_else(|| format!("Speaker {id}"), |name| (*name).to_owned())
+            });
+            let timing = Duration::from_millis(transcript.start_offset)
+                ..Duration::from_millis(transcript.end_offset);
+            builder
+                .push_utterance(
+                    speaker.as_deref(),
+                    timing,
+                    [Inline::text(transcript.text.trim())],
+                )
+                .map_err(|failure| error("transcript", failure.to_string()))?;
+        }
+        Ok(builder.finish())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn names_speakers_from_the_speaker_list() {
+            let input = r#"{"speech": {
+                "speakers": [{"id": 7, "speaker_name": "Eiffel"}],
+                "transcripts": [
+                    {"speaker_id": 7, "start_offset": 0, "end_offset": 1500, "transcript": "Day 1."},
+                    {"speaker_id": 9, "start_offset": 2000, "end_offset": 2500, "transcript": "Hi."},
+                    {"start_offset": 3000, "end_offset": 4000, "transcript": "(static)"}
+                ]
+            }}"#;
+
+            let document = parse(input, "Wolf 359").expect("conversation should import");
+
+            let speakers: Vec<_> = document
+                .text()
+                .body()
+                .utterances()
+                .map(|utterance| utterance.speaker().map(|name| name.as_str().to_owned()))
+                .collect();
+            assert_eq!(
+                speakers,
+                [
+                    Some("Eiffel".to_owned()),
+                    Some("Speaker 9".to_owned()),
+                    None
+                ]
+            );
+            let duration = document
+                .header()
+                .file_desc()
+                .extent()
+                .and_then(crate::Extent::duration);
+            assert_eq!(duration, Some(Duration::from_secs(4)));
+        }
+
+        #[test]
+        fn reports_backwards_transcripts_with_their_path() {
+            let input = r#"{"transcripts": [
+                {"start_offset": 2000, "end_offset": 1000, "transcript": "Day 1."}
+            ]}"#;
+
+            let error = parse(input, "Wolf 359").expect_err("input must be rejected");
+
+            let path = error.location().and_then(SourceLocation::path);
+            assert_eq!(
+                path.map(ToString::to_string).as_deref(),
+                Some("/transcripts[1]/end_offset")
+            );
+        }
+    }
+}
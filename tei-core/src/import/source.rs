@@ -0,0 +1,159 @@
+//! Line handling and clock times shared by the text-based importers.
+
+use std::time::Duration;
+
+use crate::{SourceLocation, TeiError};
+
+/// Strips a byte order mark and converts every line ending to `\n`.
+pub(super) fn normalise(input: &str) -> String {
+    input
+        .strip_prefix('\u{feff}')
+        .unwrap_or(input)
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+}
+
+/// Splits `input` into runs of non-blank lines, numbered from one.
+pub(super) fn blocks(input: &str) -> impl Iterator<Item = Vec<(usize, &str)>> {
+    let mut lines = input
+        .split('\n')
+        .enumerate()
+        .map(|(index, line)| (index + 1, line));
+    std::iter::from_fn(move || {
+        let block: Vec<_> = lines
+            .by_ref()
+            .skip_while(|(_, line)| line.trim().is_empty())
+            .take_while(|(_, line)| !line.trim().is_empty())
+            .collect();
+        (!block.is_empty()).then_some(block)
+    })
+}
+
+/// Reads a `hh:mm:ss.ttt` or `mm:ss.ttt` clock time whose milliseconds follow
+/// `decimal`.
+pub(super) fn clock(text: &str, decimal: char) -> Option<Duration> {
+    let (time, fraction) = text.split_once(decimal)?;
+    let mut fields = time.rsplit(':');
+    let seconds = two_digits(fields.next()?)?;
+    let minutes = two_digits(fields.next()?)?;
+    let hours = match fields.next() {
+        Some(value) if !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit()) => {
+            value.parse::<u64>().ok()?
+        }
+        Some(_) => return None,
+        None => 0,
+    };
+    if fields.next().is_some() || seconds > 59 || minutes > 59 || fraction.len() != 3 {
+        return None;
+    }
+    let millis = fraction.parse::<u32>().ok()?;
+    let whole = hours
+        .checked_mul(3_600)?
+        .checked_add(minutes * 60 + seconds)?;
+    Some(Duration::new(whole, millis * 1_000_000))
+}
+
+fn two_digits(value: &str) -> Option<u64> {
+    (value.len() == 2 && value.bytes().all(|byte| byte.is_ascii_digit()))
+        .then(|| value.parse().ok())
+        .flatten()
+}
+
+/// Reads the clock time `text` on `line`, accepting any of `decimals` before
+/// the milliseconds and reporting malformed values.
+pub(super) fn timestamp(
+    format: &'static str,
+    text: &str,
+    decimals: &[char],
+    line: usize,
+) -> Result<Duration, TeiError> {
+    decimals
+        .iter()
+        .find_map(|decimal| clock(text, *decimal))
+        .ok_or_else(|| error_at(format, &format!("malformed timestamp `{text}`"), line))
+}
+
+/// Reads an elapsed time written `m:ss`, `mm:ss`, or `h:mm:ss`, as
+/// transcript editors print them, with optional decimal seconds.
+pub(super) fn elapsed(text: &str) -> Option<Duration> {
+    let (time, fraction) = text.split_once('.').unwrap_or((text, ""));
+    let fields: Vec<&str> = time.split(':').collect();
+    let (hour_field, minute_field, second_field) = match fields.as_slice() {
+        [minutes, seconds] => ("0", *minutes, *seconds),
+        [hours, minutes, seconds] if minutes.len() == 2 => (*hours, *minutes, *seconds),
+        _ => return None,
+    };
+    let number = |value: &str| {
+        (!value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit()))
+            .then(|| value.parse::<u64>().ok())
+            .flatten()
+    };
+    let (hours, minutes) = (number(hour_field)?, number(minute_field)?);
+    let whole_seconds = two_digits(second_field)?;
+    if whole_seconds > 59 || (fields.len() == 3 && minutes > 59) {
+        return None;
+    }
+    let mut total = Duration::from_secs(
+        hours
+            .checked_mul(3_600)?
+            .checked_add(minutes.checked_mul(60)?)?
+            .checked_add(whole_seconds)?,
+    );
+    if !fraction.is_empty() {
+        total += crate::text::parse_seconds(&format!("0.{fraction}"))?;
+    }
+    Some(total)
+}
+
+/// Builds an import error pointing at the start of `line`.
+pub(super) fn error_at(format: &'static str, message: &str, line: usize) -> TeiError {
+    TeiError::import_at(
+        format,
+        message,
+        SourceLocation::new().with_line_column(line, 1),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("00:01.000", '.', Some(Duration::from_secs(1)))]
+    #[case("12:34:56.789", '.', Some(Duration::new(45_296, 789_000_000)))]
+    #[case("1:00:00,250", ',', Some(Duration::from_millis(3_600_250)))]
+    #[case("00:60.000", '.', None)]
+    #[case("1:00.000", '.', None)]
+    #[case("00:01,000", '.', None)]
+    #[case("00:01.5", '.', None)]
+    fn reads_clock_times(
+        #[case] text: &str,
+        #[case] decimal: char,
+        #[case] expected: Option<Duration>,
+    ) {
+        assert_eq!(clock(text, decimal), expected);
+    }
+
+    #[rstest]
+    #[case("0:03", Some(Duration::from_secs(3)))]
+    #[case("12:05", Some(Duration::from_secs(725)))]
+    #[case("1:02:10", Some(Duration::from_secs(3_730)))]
+    #[case("00:01:02.5", Some(Duration::from_millis(62_500)))]
+    #[case("0:3", None)]
+    #[case("1:2:10", None)]
+    #[case("0:60", None)]
+    #[case("3", None)]
+    fn reads_elapsed_times(#[case] text: &str, #[case] expected: Option<Duration>) {
+        assert_eq!(elapsed(text), expected);
+    }
+
+    #[test]
+    fn numbers_blocks_by_line() {
+        let input = normalise("\u{feff}one\r\ntwo\r\n\r\n\r\nthree\r");
+
+        let blocks: Vec<_> = blocks(&input).collect();
+
+        assert_eq!(blocks, [vec![(1, "one"), (2, "two")], vec![(5, "three")]]);
+    }
+}
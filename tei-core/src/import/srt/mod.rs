@@ -0,0 +1,344 @@
+//! SRT subtitles as timed utterances.
+//!
+//! Each cue becomes an utterance anchored to its start and end time. SRT has
+//! no markup for speakers, so a label such as `EIFFEL:` at the start of a
+//! line names one; [`SpeakerLabels`] selects which labels count. A label
+//! part-way through a cue starts a new utterance, and a cue without a label
+//! keeps the speaker of the latest one.
+//!
+//! Subtitles are often split mid-sentence to fit the screen. An unlabelled
+//! cue that follows such a split within [`SrtOptions::merge_gap`] is merged
+//! into the preceding utterance, as is a cue opening with an ellipsis after
+//! one that closes with it. `<i>`, `<b>`, and `<u>` become `<hi>`, and
+//! positioning overrides such as `{\an8}` are dropped.
+
+mod options;
+
+pub use options::{SpeakerLabels, SrtOptions};
+
+use std::time::Duration;
+
+use super::TranscriptBuilder;
+use super::cue;
+use super::source::{self, blocks, error_at};
+use crate::{TeiDocument, TeiError};
+
+const FORMAT: &str = "SRT";
+
+const ELLIPSES: [&str; 2] = ["...", "\u{2026}"];
+
+/// Converts SRT subtitles into a timed document titled `title`, using the
+/// default [`SrtOptions`].
+///
+/// # Errors
+///
+/// Returns [`TeiError::Import`] when a cue has no timing line or a
+/// timestamp is malformed or ends before it starts. Returns
+/// [`TeiError::DocumentTitle`] when `title` is blank.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::import::srt;
+///
+/// let subtitles = "1\n00:00:01,000 --> 00:00:02,500\nEIFFEL: Day 1 of the\n\n\
+///                  2\n00:00:02,600 --> 00:00:04,000\nmission.\n";
+/// let document = srt::parse(subtitles, "Wolf 359")?;
+///
+/// let utterance = document.text().body().utterances().next();
+/// assert_eq!(utterance.and_then(|u| u.speaker()).map(|s| s.as_str()), Some("EIFFEL"));
+/// assert_eq!(document.text().body().utterances().count(), 1);
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn parse(input: &str, title: &str) -> Result<TeiDocument, TeiError> {
+    parse_with_options(input, title, SrtOptions::new())
+}
+
+/// Converts SRT subtitles into a timed document titled `title`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`parse`].
+pub fn parse_with_options(
+    input: &str,
+    title: &str,
+    options: SrtOptions,
+) -> Result<TeiDocument, TeiError> {
+    let mut builder = TranscriptBuilder::new(title)?;
+    let normalised = source::normalise(input);
+    let mut speaker = None;
+    let mut pending: Option<Segment> = None;
+
+    for block in blocks(&normalised) {
+        let segments = Segment::read(&block, options.speaker_labels(), &mut speaker)?;
+        for segment in segments {
+            pending = match pending {
+                Some(mut previous) if previous.continues_into(&segment, options.merge_gap()) => {
+                    previous.absorb(&segment);
+                    Some(previous)
+                }
+                Some(previous) => {
+                    previous.push_to(&mut builder)?;
+                    Some(segment)
+                }
+                None => Some(segment),
+            };
+        }
+    }
+    if let Some(last) = pending {
+        last.push_to(&mut builder)?;
+    }
+    Ok(builder.finish())
+}
+
+/// The text one speaker says in a cue, kept as cue markup until the cues it
+/// may merge with are known.
+struct Segment {
+    line: usize,
+    labelled: bool,
+    speaker: Option<String>,
+    start: Duration,
+    end: Duration,
+    text: String,
+}
+
+impl Segment {
+    /// Reads the speakers' segments of the cue in `block`, tracking the
+    /// latest label in `speaker`.
+    fn read(
+        block: &[(usize, &str)],
+        labels: SpeakerLabels,
+        speaker: &mut Option<String>,
+    ) -> Result<Vec<Self>, TeiError> {
+        let Some(&(first_line, first)) = block.first() else {
+            return Ok(Vec::new());
+        };
+        // The cue number is optional in practice, so it is not checked.
+        let timing_index = usize::from(!first.contains("-->"));
+        let Some(&(line, timing)) = block.get(timing_index) else {
+            return Err(error_at(FORMAT, "cue has no timing line", first_line));
+        };
+        let Some((start_text, rest)) = timing.split_once("-->") else {
+            return Err(error_at(FORMAT, "cue has no timing line", line));
+        };
+        let end_text = rest.split_whitespace().next().unwrap_or_default();
+        let start = source::timestamp(FORMAT, start_text.trim(), &[',', '.'], line)?;
+        let end = source::timestamp(FORMAT, end_text, &[',', '.'], line)?;
+        if end < start {
+            return Err(error_at(FORMAT, "cue ends before it starts", line));
+        }
+
+        let mut segments: Vec<Self> = Vec::new();
+        for &(_, text) in block.iter().skip(timing_index + 1) {
+            let cleaned = strip_overrides(text);
+            let label = labels.split(&cleaned);
+            if let Some((name, _)) = label {
+                *speaker = Some(name.to_owned());
+            }
+            match segments.last_mut() {
+                Some(segment) if label.is_none() => {
+                    segment.text.push(' ');
+                    segment.text.push_str(cleaned.trim());
+                }
+                _ => segments.push(Self {
+                    line,
+                    labelled: label.is_some(),
+                    speaker: speaker.clone(),
+                    start,
+                    end,
+                    text: label
+                        .map_or_else(|| cleaned.trim(), |(_, said)| said)
+                        .to_owned(),
+                }),
+            }
+        }
+        Ok(segments)
+    }
+
+    /// Reports whether `next` continues a sentence this segment leaves open.
+    fn continues_into(&self, next: &Self, merge_gap: Option<Duration>) -> bool {
+        let Some(gap) = merge_gap else {
+            return false;
+        };
+        if next.labelled || next.start.saturating_sub(self.end) > gap {
+            return false;
+        }
+        let ending = visible_ending(&self.text);
+        let trailing_ellipsis = ELLIPSES.iter().any(|mark| ending.ends_with(mark));
+        if trailing_ellipsis {
+            return ELLIPSES.iter().any(|mark| next.text.starts_with(mark));
+        }
+        !ending.ends_with(['.', '!', '?', '\u{266a}'])
+    }
+
+    /// Appends `next`, dropping the ellipses that mark the join.
+    fn absorb(&mut self, next: &Self) {
+        let trimmed = ELLIPSES
+            .iter()
+            .find_map(|mark| self.text.strip_suffix(mark))
+            .map(str::to_owned);
+        if let Some(text) = trimmed {
+            self.text = text;
+        }
+        let continuation = ELLIPSES
+            .iter()
+            .find_map(|mark| next.text.strip_prefix(mark))
+            .unwrap_or(&next.text);
+        self.text.push(' ');
+        self.text.push_str(continuation.trim_start());
+        self.end = self.end.max(next.end);
+    }
+
+    fn push_to(self, builder: &mut TranscriptBuilder) -> Result<(), TeiError> {
+        for (voice, content) in cue::segments(&self.text) {
+            let speaker = voice.as_deref().or(self.speaker.as_deref());
+            builder
+                .push_utterance(speaker, self.start..self.end, content)
+                .map_err(|failure| error_at(FORMAT, &failure.to_string(), self.line))?;
+        }
+        Ok(())
+    }
+}
+
+/// Removes positioning overrides written in braces, such as `{\an8}`.
+fn strip_overrides(line: &str) -> String {
+    let mut stripped = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find("{\\") {
+        let (before, override_start) = rest.split_at(start);
+        stripped.push_str(before);
+        let Some((_, after)) = override_start.split_once('}') else {
+            rest = override_start;
+            break;
+        };
+        rest = after;
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+/// Returns `text` without trailing closing tags, quotes, and brackets, so
+/// its final punctuation can be inspected.
+fn visible_ending(text: &str) -> &str {
+    let mut ending = text.trim_end();
+    loop {
+        let unwrapped = ending.trim_end_matches(['"', '\'', ')', ']', '\u{201d}', '\u{2019}']);
+        let untagged = unwrapped
+            .strip_suffix('>')
+            .and_then(|inner| inner.rfind('<').and_then(|start| inner.get(..start)));
+        match untagged {
+            Some(shorter) => ending = shorter.trim_end(),
+            None => return unwrapped,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SourceLocation;
+    use rstest::rstest;
+
+    const SAMPLE: &str = "1\r\n00:00:01,000 --> 00:00:02,000\r\n{\\an8}EIFFEL: Day 1 of the\r\n\r\n\
+                          2\r\n00:00:02,500 --> 00:00:04,000\r\n<i>mission.</i>\r\n\r\n\
+                          3\r\n00:00:04,000 --> 00:00:05,000\r\nStill here.\r\nHERA: Hello...\r\n\r\n\
+                          4\r\n00:00:05,200 --> 00:00:06,000\r\n...Eiffel.\r\n";
+
+    fn summary(document: &TeiDocument) -> Vec<(Option<String>, String, String)> {
+        let timeline = document.text().timeline().expect("timeline recorded");
+        document
+            .text()
+            .body()
+            .utterances()
+            .map(|utterance| {
+                let seconds = |pointer: Option<&str>| {
+                    let offset = pointer.and_then(|point| timeline.offset(point));
+                    format!("{:?}", offset.unwrap_or_default())
+                };
+                (
+                    utterance
+                        .speaker()
+                        .map(|speaker| speaker.as_str().to_owned()),
+                    seconds(utterance.start()),
+                    seconds(utterance.end()),
+                )
+            })
+            .collect()
+    }
+
+    fn owned(speaker: Option<&str>, start: &str, end: &str) -> (Option<String>, String, String) {
+        (speaker.map(str::to_owned), start.to_owned(), end.to_owned())
+    }
+
+    #[test]
+    fn merges_cues_split_mid_sentence() {
+        let document = parse(SAMPLE, "Wolf 359").expect("sample should import");
+
+        assert_eq!(
+            summary(&document),
+            [
+                owned(Some("EIFFEL"), "1s", "4s"),
+                owned(Some("EIFFEL"), "4s", "5s"),
+                owned(Some("HERA"), "4s", "6s"),
+            ]
+        );
+        let words: Vec<_> = document
+            .text()
+            .body()
+            .blocks()
+            .map(crate::BodyBlock::word_count)
+            .collect();
+        assert_eq!(words, [5, 2, 2]);
+    }
+
+    #[test]
+    fn keeps_cues_apart_without_merging() {
+        let options = SrtOptions::new()
+            .with_merge_gap(None)
+            .with_speaker_labels(SpeakerLabels::Off);
+
+        let document = parse_with_options(SAMPLE, "Wolf 359", options).expect("sample imports");
+
+        assert_eq!(
+            summary(&document),
+            [
+                owned(None, "1s", "2s"),
+                owned(None, "2.5s", "4s"),
+                owned(None, "4s", "5s"),
+                owned(None, "5.2s", "6s"),
+            ]
+        );
+        assert!(document.header().profile_desc().is_none());
+    }
+
+    #[test]
+    fn keeps_cues_apart_across_long_silences() {
+        let input = "00:00:01,000 --> 00:00:02,000\nDay 1 of the\n\n\
+                     00:00:05,000 --> 00:00:06,000\nmission\n";
+
+        let document = parse(input, "Wolf 359").expect("input should import");
+
+        assert_eq!(document.text().body().utterances().count(), 2);
+    }
+
+    #[rstest]
+    #[case("1\n00:00:05,000 --> 00:00:04,000\nBackwards.\n", 2)]
+    #[case("1\n00:00:01 --> 00:00:02,000\nLate.\n", 2)]
+    #[case("1\n\n2\nDay 1.\n", 1)]
+    fn reports_malformed_cues_with_their_line(#[case] input: &str, #[case] line: usize) {
+        let error = parse(input, "Wolf 359").expect_err("input must be rejected");
+
+        assert!(
+            matches!(error, TeiError::Import { format: "SRT", .. }),
+            "{error:?}"
+        );
+        assert_eq!(error.location().and_then(SourceLocation::line), Some(line));
+    }
+
+    #[rstest]
+    #[case("Day 1.</i>\"", "Day 1.")]
+    #[case("Day 1 <b>of</b>", "Day 1 <b>of")]
+    fn finds_the_visible_ending(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(visible_ending(text), expected);
+    }
+}
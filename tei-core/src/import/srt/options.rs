@@ -0,0 +1,140 @@
+//! Settings for reading SRT subtitles.
+
+use std::time::Duration;
+
+/// Longest label, in characters, taken to name a speaker.
+const MAX_LABEL_CHARS: usize = 32;
+
+/// Most words a label may hold, so prose such as `The plan is simple:` is
+/// left alone.
+const MAX_LABEL_WORDS: usize = 3;
+
+/// How a speaker label at the start of a subtitle line is recognised.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum SpeakerLabels {
+    /// Never reads a speaker from the text.
+    Off,
+    /// Recognises upper-case labels such as `EIFFEL:` or `DR. HERA:`.
+    #[default]
+    UpperCase,
+    /// Recognises labels starting with a capital letter, such as `Eiffel:`
+    /// or `Dr Hera:`, as well as upper-case ones.
+    Capitalised,
+}
+
+impl SpeakerLabels {
+    /// Splits `line` into a speaker label and the text after it.
+    pub(crate) fn split(self, line: &str) -> Option<(&str, &str)> {
+        let (head, rest) = line.split_once(':')?;
+        if !(rest.is_empty() || rest.starts_with(char::is_whitespace)) {
+            return None;
+        }
+        let label = head.trim_start_matches(['-', ' ', '\t']).trim_end();
+        let plausible = label.chars().count() <= MAX_LABEL_CHARS
+            && label.split_whitespace().count() <= MAX_LABEL_WORDS
+            && label.chars().any(char::is_alphabetic)
+            && label
+                .chars()
+                .all(|character| character.is_alphanumeric() || " .'-".contains(character));
+        let matches = match self {
+            Self::Off => false,
+            Self::UpperCase => !label.chars().any(char::is_lowercase),
+            Self::Capitalised => label.starts_with(char::is_uppercase),
+        };
+        (plausible && matches).then(|| (label, rest.trim_start()))
+    }
+}
+
+/// Settings applied by [`parse_with_options`](super::parse_with_options).
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use tei_core::import::srt::{SpeakerLabels, SrtOptions};
+///
+/// let options = SrtOptions::new()
+///     .with_speaker_labels(SpeakerLabels::Capitalised)
+///     .with_merge_gap(None);
+/// assert_eq!(options.speaker_labels(), SpeakerLabels::Capitalised);
+/// assert_eq!(SrtOptions::new().merge_gap(), Some(Duration::from_secs(1)));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SrtOptions {
+    speaker_labels: SpeakerLabels,
+    merge_gap: Option<Duration>,
+}
+
+impl SrtOptions {
+    /// Longest silence, by default, between two cues that are merged into
+    /// one utterance.
+    pub const DEFAULT_MERGE_GAP: Duration = Duration::from_secs(1);
+
+    /// Creates options matching [`parse`](super::parse).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            speaker_labels: SpeakerLabels::UpperCase,
+            merge_gap: Some(Self::DEFAULT_MERGE_GAP),
+        }
+    }
+
+    /// Selects how speaker labels are recognised.
+    #[must_use]
+    pub const fn with_speaker_labels(mut self, labels: SpeakerLabels) -> Self {
+        self.speaker_labels = labels;
+        self
+    }
+
+    /// Returns how speaker labels are recognised.
+    #[must_use]
+    pub const fn speaker_labels(&self) -> SpeakerLabels {
+        self.speaker_labels
+    }
+
+    /// Sets the longest silence between a cue that stops mid-sentence and
+    /// the unlabelled cue continuing it; `None` keeps every cue separate.
+    #[must_use]
+    pub const fn with_merge_gap(mut self, gap: Option<Duration>) -> Self {
+        self.merge_gap = gap;
+        self
+    }
+
+    /// Returns the longest silence bridged when merging cues, if merging is
+    /// enabled.
+    #[must_use]
+    pub const fn merge_gap(&self) -> Option<Duration> {
+        self.merge_gap
+    }
+}
+
+impl Default for SrtOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(SpeakerLabels::UpperCase, "EIFFEL: Day 1.", Some(("EIFFEL", "Day 1.")))]
+    #[case(SpeakerLabels::UpperCase, "- DR. HERA:Hi", None)]
+    #[case(SpeakerLabels::UpperCase, "- DR. HERA: Hi", Some(("DR. HERA", "Hi")))]
+    #[case(SpeakerLabels::UpperCase, "Eiffel: Day 1.", None)]
+    #[case(SpeakerLabels::Capitalised, "Eiffel: Day 1.", Some(("Eiffel", "Day 1.")))]
+    #[case(SpeakerLabels::Capitalised, "The plan is simple: wait.", None)]
+    #[case(SpeakerLabels::Capitalised, "At 10:30 we dock.", None)]
+    #[case(SpeakerLabels::UpperCase, "10: 30", None)]
+    #[case(SpeakerLabels::Off, "EIFFEL: Day 1.", None)]
+    fn detects_speaker_labels(
+        #[case] labels: SpeakerLabels,
+        #[case] line: &str,
+        #[case] expected: Option<(&str, &str)>,
+    ) {
+        assert_eq!(labels.split(line), expected);
+    }
+}
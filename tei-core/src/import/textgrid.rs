@@ -0,0 +1,398 @@
+//! Praat `TextGrid` files as timed utterances.
+//!
+//! Each interval tier is read as one speaker, named after the tier, and
+//! every interval with text becomes an utterance spanning the interval.
+//! Utterances from all tiers are ordered by start time; blank intervals,
+//! which Praat uses to fill the gaps between labels, are skipped, as are
+//! point tiers.
+//!
+//! Both the long text format Praat writes by default and the short format
+//! are accepted: values are read in order and the labels between them,
+//! such as `xmin =` and `item [1]:`, are ignored, as Praat itself does.
+//! Binary `TextGrid` files are not supported.
+
+use std::ops::Range;
+use std::time::Duration;
+
+use super::TranscriptBuilder;
+use super::source::{self, error_at};
+use crate::{Inline, TeiDocument, TeiError};
+
+const FORMAT: &str = "TextGrid";
+
+/// Converts a Praat `TextGrid` into a timed document titled `title`.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Import`] when the input is not a text `TextGrid`, ends
+/// early, or holds a malformed time or an interval that ends before it
+/// starts. Returns [`TeiError::DocumentTitle`] when `title` is blank.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::import::textgrid;
+///
+/// let grid = "File type = \"ooTextFile\"\nObject class = \"TextGrid\"\n\n\
+///     0\n2.5\n<exists>\n1\n\"IntervalTier\"\n\"Eiffel\"\n0\n2.5\n2\n\
+///     0\n0.5\n\"\"\n0.5\n2.5\n\"Day 1.\"\n";
+/// let document = textgrid::parse(grid, "Wolf 359")?;
+///
+/// let utterance = document.text().body().utterances().next().expect("one utterance");
+/// assert_eq!(utterance.speaker().map(|s| s.as_str()), Some("Eiffel"));
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn parse(input: &str, title: &str) -> Result<TeiDocument, TeiError> {
+    let normalised = source::normalise(input);
+    let mut values = Values::new(&normalised);
+    let file_type = values.text("file type")?;
+    let class = values.text("object class")?;
+    if file_type != "ooTextFile" || class != "TextGrid" {
+        return Err(error_at(FORMAT, "input is not a text TextGrid", 1));
+    }
+    values.number("xmin")?;
+    values.number("xmax")?;
+    let mut intervals = Vec::new();
+    if values.flag()? {
+        let tiers = values.count("tier count")?;
+        for _ in 0..tiers {
+            read_tier(&mut values, &mut intervals)?;
+        }
+    }
+
+    intervals.sort_by_key(|interval| interval.timing.start);
+    let mut builder = TranscriptBuilder::new(title)?;
+    for interval in intervals {
+        builder
+            .push_utterance(
+                Some(&interval.speaker),
+                interval.timing,
+                [Inline::text(interval.text)],
+            )
+            .map_err(|failure| error_at(FORMAT, &failure.to_string(), interval.line))?;
+    }
+    Ok(builder.finish())
+}
+
+/// A labelled interval from one tier.
+struct Interval {
+    line: usize,
+    speaker: String,
+    timing: Range<Duration>,
+    text: String,
+}
+
+fn read_tier(values: &mut Values<'_>, intervals: &mut Vec<Interval>) -> Result<(), TeiError> {
+    let class = values.text("tier class")?;
+    let name = values.text("tier name")?;
+    values.number("tier xmin")?;
+    values.number("tier xmax")?;
+    let count = values.count("interval count")?;
+    match class.as_str() {
+        "IntervalTier" => {
+            for _ in 0..count {
+                let start = values.time("interval xmin")?;
+                let line = values.line;
+                let end = values.time("interval xmax")?;
+                let text = values.text("interval text")?;
+                if end < start {
+                    return Err(error_at(FORMAT, "interval ends before it starts", line));
+                }
+                if !text.trim().is_empty() {
+                    intervals.push(Interval {
+                        line,
+                        speaker: name.clone(),
+                        timing: start..end,
+                        text,
+                    });
+                }
+            }
+        }
+        "TextTier" => {
+            for _ in 0..count {
+                values.number("point time")?;
+                values.text("point mark")?;
+            }
+        }
+        other => {
+            let message = format!("unsupported tier class `{other}`");
+            return Err(error_at(FORMAT, &message, values.line));
+        }
+    }
+    Ok(())
+}
+
+/// A value read from the `TextGrid`.
+enum Value {
+    Number(f64),
+    Text(String),
+    Flag(bool),
+}
+
+/// Reads the values of a `TextGrid` in order, skipping the labels between
+/// them.
+struct Values<'a> {
+    rest: &'a str,
+    line: usize,
+}
+
+impl<'a> Values<'a> {
+    const fn new(input: &'a str) -> Self {
+        Self {
+            rest: input,
+            line: 1,
+        }
+    }
+
+    fn text(&mut self, what: &str) -> Result<String, TeiError> {
+        match self.next(what)? {
+            Value::Text(text) => Ok(text),
+            _ => Err(self.expected(what, "a quoted string")),
+        }
+    }
+
+    fn number(&mut self, what: &str) -> Result<f64, TeiError> {
+        match self.next(what)? {
+            Value::Number(number) => Ok(number),
+            _ => Err(self.expected(what, "a number")),
+        }
+    }
+
+    fn time(&mut self, what: &str) -> Result<Duration, TeiError> {
+        let seconds = self.number(what)?;
+        Duration::try_from_secs_f64(seconds).map_err(|_| {
+            let message = format!("malformed {what} `{seconds}`");
+            error_at(FORMAT, &message, self.line)
+        })
+    }
+
+    fn count(&mut self, what: &str) -> Result<usize, TeiError> {
+        let number = self.number(what)?;
+        if number < 0.0 || number.fract() != 0.0 {
+            let message = format!("malformed {what} `{number}`");
+            return Err(error_at(FORMAT, &message, self.line));
+        }
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "the count was checked to be a non-negative whole number"
+        )]
+        let whole = number as usize;
+        Ok(whole)
+    }
+
+    fn flag(&mut self) -> Result<bool, TeiError> {
+        match self.next("tiers flag")? {
+            Value::Flag(exists) => Ok(exists),
+            _ => Err(self.expected("tiers flag", "<exists> or <absent>")),
+        }
+    }
+
+    fn expected(&self, what: &str, kind: &str) -> TeiError {
+        error_at(
+            FORMAT,
+            &format!("expected {kind} for the {what}"),
+            self.line,
+        )
+    }
+
+    fn next(&mut self, what: &str) -> Result<Value, TeiError> {
+        loop {
+            let Some(first) = self.rest.chars().next() else {
+                let message = format!("input ended before the {what}");
+                return Err(error_at(FORMAT, &message, self.line));
+            };
+            match first {
+                '"' => return self.quoted(),
+                '<' => return self.flag_value(),
+                '!' => self.skip_until(|character| character == '\n'),
+                '[' => {
+                    self.skip_until(|character| character == ']');
+                    self.advance(1);
+                }
+                '0'..='9' | '-' | '+' | '.' => return self.number_value(),
+                _ if first.is_whitespace() => self.advance(first.len_utf8()),
+                _ => {
+                    self.take_token();
+                }
+            }
+        }
+    }
+
+    /// Reads a string, where a doubled quote stands for one quote mark.
+    fn quoted(&mut self) -> Result<Value, TeiError> {
+        let start = self.line;
+        self.advance(1);
+        let mut text = String::new();
+        loop {
+            let Some((before, after)) = self.rest.split_once('"') else {
+                return Err(error_at(FORMAT, "unterminated string", start));
+            };
+            text.push_str(before);
+            self.advance(before.len() + 1);
+            if after.starts_with('"') {
+                text.push('"');
+                self.advance(1);
+            } else {
+                return Ok(Value::Text(text));
+            }
+        }
+    }
+
+    fn number_value(&mut self) -> Result<Value, TeiError> {
+        let token = self.take_token();
+        token
+            .parse()
+            .map(Value::Number)
+            .map_err(|_| error_at(FORMAT, &format!("malformed number `{token}`"), self.line))
+    }
+
+    fn flag_value(&mut self) -> Result<Value, TeiError> {
+        let token = self.take_token();
+        match token {
+            "<exists>" => Ok(Value::Flag(true)),
+            "<absent>" => Ok(Value::Flag(false)),
+            _ => Err(error_at(
+                FORMAT,
+                &format!("unknown flag `{token}`"),
+                self.line,
+            )),
+        }
+    }
+
+    /// Takes the characters up to the next whitespace, quote or bracket.
+    fn take_token(&mut self) -> &'a str {
+        let rest = self.rest;
+        let end = rest
+            .char_indices()
+            .skip(1)
+            .find(|&(_, character)| character.is_whitespace() || matches!(character, '"' | '['))
+            .map_or(rest.len(), |(index, _)| index);
+        let (token, _) = rest.split_at(end);
+        self.advance(end);
+        token
+    }
+
+    fn skip_until(&mut self, stop: impl Fn(char) -> bool) {
+        let end = self.rest.find(stop).unwrap_or(self.rest.len());
+        self.advance(end);
+    }
+
+    fn advance(&mut self, bytes: usize) {
+        let (passed, rest) = self.rest.split_at(bytes.min(self.rest.len()));
+        self.line += passed.matches('\n').count();
+        self.rest = rest;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SourceLocation;
+    use rstest::rstest;
+
+    const LONG: &str = "\u{feff}File type = \"ooTextFile\"\r
+Object class = \"TextGrid\"\r
+\r
+xmin = 0 \r
+xmax = 4.5 \r
+tiers? <exists> \r
+size = 3 \r
+item []: \r
+    item [1]:\r
+        class = \"IntervalTier\" \r
+        name = \"Hera\" \r
+        xmin = 0 \r
+        xmax = 4.5 \r
+        intervals: size = 3 \r
+        intervals [1]:\r
+            xmin = 0 \r
+            xmax = 1.25 \r
+            text = \"\" \r
+        intervals [2]:\r
+            xmin = 1.25 \r
+            xmax = 3 \r
+            text = \"Hello, \"\"Eiffel\"\".\" \r
+        intervals [3]:\r
+            xmin = 3 \r
+            xmax = 4.5 \r
+            text = \"\" \r
+    item [2]:\r
+        class = \"TextTier\" \r
+        name = \"events\" \r
+        xmin = 0 \r
+        xmax = 4.5 \r
+        points: size = 1 \r
+        points [1]:\r
+            number = 2 \r
+            mark = \"static\" \r
+    item [3]:\r
+        class = \"IntervalTier\" \r
+        name = \"Eiffel\" \r
+        xmin = 0 \r
+        xmax = 4.5 \r
+        intervals: size = 2 \r
+        intervals [1]:\r
+            xmin = 0 \r
+            xmax = 1 \r
+            text = \"Day 1.\" \r
+        intervals [2]:\r
+            xmin = 1 \r
+            xmax = 4.5 \r
+            text = \"   \" \r
+";
+
+    #[test]
+    fn reads_interval_tiers_as_speakers() {
+        let document = parse(LONG, "Wolf 359").expect("grid should import");
+
+        let timeline = document.text().timeline().expect("timeline recorded");
+        let summary: Vec<_> = document
+            .text()
+            .body()
+            .utterances()
+            .map(|utterance| {
+                (
+                    utterance.speaker().map(crate::Speaker::as_str),
+                    utterance.start().and_then(|point| timeline.offset(point)),
+                    utterance.content().to_vec(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (
+                    Some("Eiffel"),
+                    Some(Duration::ZERO),
+                    vec![Inline::text("Day 1.")]
+                ),
+                (
+                    Some("Hera"),
+                    Some(Duration::from_millis(1_250)),
+                    vec![Inline::text("Hello, \"Eiffel\".")]
+                ),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("File type = \"ooTextFile\"\nObject class = \"Sound\"\n", 1)]
+    #[case(
+        "File type = \"ooTextFile\"\nObject class = \"TextGrid\"\n0\n1\n<exists>\n1\n",
+        7
+    )]
+    #[case(
+        "\"ooTextFile\"\n\"TextGrid\"\n0\n1\n<exists>\n1\n\"IntervalTier\"\n\"A\"\n0\n1\n1\n0.5\n0.2\n\"x\"\n",
+        12
+    )]
+    fn reports_malformed_grids_with_their_line(#[case] input: &str, #[case] line: usize) {
+        let error = parse(input, "Wolf 359").expect_err("input must be rejected");
+
+        assert!(
+            matches!(error, TeiError::Import { format: FORMAT, .. }),
+            "{error:?}"
+        );
+        assert_eq!(error.location().and_then(SourceLocation::line), Some(line));
+    }
+}
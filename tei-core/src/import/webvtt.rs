@@ -0,0 +1,181 @@
+//! `WebVTT` subtitles as timed utterances.
+//!
+//! Each cue becomes an utterance anchored to its start and end time. A voice
+//! span such as `<v Roger Bingham>` names the speaker, and a cue holding
+//! several voices becomes one utterance per voice. `<i>`, `<b>`, and `<u>`
+//! become `<hi>` with `rend` set to `italic`, `bold`, or `underline`, and
+//! `<lang>` becomes `<hi>` with `xml:lang`. Other tags are dropped with their
+//! text kept, and cue identifiers, settings, `NOTE`, `STYLE`, and `REGION`
+//! blocks are skipped.
+
+use std::time::Duration;
+
+use super::TranscriptBuilder;
+use super::cue;
+use super::source::{self, blocks, error_at};
+use crate::{TeiDocument, TeiError};
+
+const FORMAT: &str = "WebVTT";
+
+/// Converts a `WebVTT` file into a timed document titled `title`.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Import`] when the input lacks the `WEBVTT` signature,
+/// a cue has no timing line, or a timestamp is malformed or ends before it
+/// starts. Returns [`TeiError::DocumentTitle`] when `title` is blank.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::import::webvtt;
+///
+/// let vtt = "WEBVTT\n\n00:00:01.000 --> 00:00:03.500\n<v Eiffel>Day 1 of the <i>mission</i>.\n";
+/// let document = webvtt::parse(vtt, "Wolf 359")?;
+///
+/// let utterance = document.text().body().utterances().next();
+/// assert_eq!(utterance.and_then(|u| u.speaker()).map(|s| s.as_str()), Some("Eiffel"));
+/// let timeline = document.text().timeline();
+/// let end = utterance.and_then(|u| u.end()).and_then(|end| timeline?.offset(end));
+/// assert_eq!(end, Some(std::time::Duration::from_millis(3_500)));
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn parse(input: &str, title: &str) -> Result<TeiDocument, TeiError> {
+    let mut builder = TranscriptBuilder::new(title)?;
+    let normalised = source::normalise(input);
+    let mut blocks = blocks(&normalised);
+
+    let signature = blocks
+        .next()
+        .and_then(|block| block.first().copied())
+        .filter(|(_, line)| is_signature(line));
+    if signature.is_none() {
+        return Err(error_at(FORMAT, "missing `WEBVTT` signature", 1));
+    }
+
+    for block in blocks {
+        if let Some(cue) = Cue::read(&block)? {
+            for (speaker, content) in cue::segments(&cue.payload) {
+                builder
+                    .push_utterance(speaker.as_deref(), cue.start..cue.end, content)
+                    .map_err(|failure| error_at(FORMAT, &failure.to_string(), cue.line))?;
+            }
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// A cue's timing and text.
+struct Cue {
+    line: usize,
+    start: Duration,
+    end: Duration,
+    payload: String,
+}
+
+impl Cue {
+    /// Reads the cue in `block`, or `None` for comment, style, and region
+    /// blocks.
+    fn read(block: &[(usize, &str)]) -> Result<Option<Self>, TeiError> {
+        let Some(&(first_line, first)) = block.first() else {
+            return Ok(None);
+        };
+        if ["NOTE", "STYLE", "REGION"]
+            .iter()
+            .any(|keyword| starts_with_keyword(first, keyword))
+        {
+            return Ok(None);
+        }
+
+        let timing_index = usize::from(!first.contains("-->"));
+        let Some(&(line, timing)) = block.get(timing_index) else {
+            return Err(error_at(FORMAT, "cue has no timing line", first_line));
+        };
+        let Some((start_text, rest)) = timing.split_once("-->") else {
+            return Err(error_at(FORMAT, "cue has no timing line", line));
+        };
+        let end_text = rest.split_whitespace().next().unwrap_or_default();
+        let start = source::timestamp(FORMAT, start_text.trim(), &['.'], line)?;
+        let end = source::timestamp(FORMAT, end_text, &['.'], line)?;
+        if end < start {
+            return Err(error_at(FORMAT, "cue ends before it starts", line));
+        }
+
+        let payload = block
+            .iter()
+            .skip(timing_index + 1)
+            .map(|(_, text)| *text)
+            .collect::<Vec<_>>()
+            .join(" ");
+        Ok(Some(Self {
+            line,
+            start,
+            end,
+            payload,
+        }))
+    }
+}
+
+fn is_signature(line: &str) -> bool {
+    starts_with_keyword(line, "WEBVTT")
+}
+
+/// Reports whether `line` is `keyword` alone or followed by a space or tab.
+fn starts_with_keyword(line: &str, keyword: &str) -> bool {
+    line.strip_prefix(keyword)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\t']))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SourceLocation;
+    use rstest::rstest;
+
+    const SAMPLE: &str = "\u{feff}WEBVTT - Wolf 359\r\n\r\nNOTE recorded on the Hephaestus\r\n\r\nintro\r\n00:01.000 --> 00:03.000 align:start\r\n<v Eiffel>Day 1.\r\n<v Hera>Hello.\r\n\r\n01:00:04.250 --> 01:00:05.000\r\nStatic.\r\n";
+
+    #[test]
+    fn converts_cues_into_timed_utterances() {
+        let document = parse(SAMPLE, "Wolf 359").expect("sample should import");
+
+        let utterances: Vec<_> = document.text().body().utterances().collect();
+        let speakers: Vec<_> = utterances
+            .iter()
+            .map(|utterance| utterance.speaker().map(crate::Speaker::as_str))
+            .collect();
+        assert_eq!(speakers, [Some("Eiffel"), Some("Hera"), None]);
+        assert_eq!(utterances.first().and_then(|u| u.start()), Some("#T1"));
+        assert_eq!(utterances.get(1).and_then(|u| u.end()), Some("#T2"));
+
+        let timeline = document.text().timeline().expect("timeline recorded");
+        let last_end = utterances.get(2).and_then(|u| u.end());
+        assert_eq!(
+            last_end.and_then(|end| timeline.offset(end)),
+            Some(Duration::from_secs(3_605))
+        );
+        let named = document.header().profile_desc().map(|p| p.speakers().len());
+        assert_eq!(named, Some(2));
+    }
+
+    #[rstest]
+    #[case("Wolf 359\n", 1)]
+    #[case("WEBVTTX\n", 1)]
+    #[case("WEBVTT\n\n00:05.000 --> 00:04.000\nBackwards.\n", 3)]
+    #[case("WEBVTT\n\n00:01.000 --> soon\nLate.\n", 3)]
+    #[case("WEBVTT\n\nintro\nDay 1.\n", 4)]
+    fn reports_malformed_input_with_its_line(#[case] input: &str, #[case] line: usize) {
+        let error = parse(input, "Wolf 359").expect_err("input must be rejected");
+
+        assert!(
+            matches!(
+                error,
+                TeiError::Import {
+                    format: "WebVTT",
+                    ..
+                }
+            ),
+            "{error:?}"
+        );
+        assert_eq!(error.location().and_then(SourceLocation::line), Some(line));
+    }
+}
@@ -0,0 +1,250 @@
+//! The JSON form of body blocks and their inline content.
+//!
+//! Blocks and inline elements are objects tagged by `type`, and plain text is
+//! a JSON string, so frontends can switch on one field instead of probing
+//! for XML-style keys.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    BodyBlock, Certainty, Hi, Inline, LanguageTag, Note, P, Pause, Quote, RawMarkup, TeiError,
+    Unclear, Utterance,
+};
+
+/// A body block: `{"type": "p" | "u" | "raw", ...}`.
+#[derive(Deserialize, Serialize)]
+#[serde(tag = "type", deny_unknown_fields)]
+pub(super) enum Block {
+    #[serde(rename = "p")]
+    Paragraph {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        lang: Option<String>,
+        content: Vec<Content>,
+    },
+    #[serde(rename = "u")]
+    Utterance {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        lang: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        who: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        start: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        end: Option<String>,
+        content: Vec<Content>,
+    },
+    #[serde(rename = "raw")]
+    Raw { markup: String },
+}
+
+/// Inline content: a string of text or a tagged element.
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+pub(super) enum Content {
+    Text(String),
+    Element(Element),
+}
+
+/// An inline element: `{"type": "hi" | "pause" | "unclear" | "quote" | "note"
+/// | "raw", ...}`.
+#[derive(Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase", deny_unknown_fields)]
+pub(super) enum Element {
+    Hi {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        lang: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rend: Option<String>,
+        content: Vec<Content>,
+    },
+    Pause {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        dur: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        kind: Option<String>,
+    },
+    Unclear {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cert: Option<Certainty>,
+        content: Vec<Content>,
+    },
+    Quote {
+        content: Vec<Content>,
+    },
+    Note {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        place: Option<String>,
+        content: Vec<Content>,
+    },
+    Raw {
+        markup: String,
+    },
+}
+
+impl From<&BodyBlock> for Block {
+    fn from(block: &BodyBlock) -> Self {
+        match block {
+            BodyBlock::Paragraph(paragraph) => Self::Paragraph {
+                id: paragraph.id().map(|id| id.as_str().to_owned()),
+                lang: paragraph.lang().map(|lang| lang.as_str().to_owned()),
+                content: contents(paragraph.content()),
+            },
+            BodyBlock::Utterance(utterance) => Self::Utterance {
+                id: utterance.id().map(|id| id.as_str().to_owned()),
+                lang: utterance.lang().map(|lang| lang.as_str().to_owned()),
+                who: utterance.speaker().map(|who| who.as_str().to_owned()),
+                start: utterance.start().map(ToOwned::to_owned),
+                end: utterance.end().map(ToOwned::to_owned),
+                content: contents(utterance.content()),
+            },
+            BodyBlock::Raw(raw) => Self::Raw {
+                markup: raw.as_str().to_owned(),
+            },
+        }
+    }
+}
+
+impl TryFrom<Block> for BodyBlock {
+    type Error = TeiError;
+
+    fn try_from(block: Block) -> Result<Self, Self::Error> {
+        match block {
+            Block::Paragraph { id, lang, content } => {
+                let mut paragraph = P::from_inline(inlines(content)?)?;
+                if let Some(value) = id {
+                    paragraph.set_id(value)?;
+                }
+                if let Some(value) = lang {
+                    paragraph.set_lang(LanguageTag::new(value)?);
+                }
+                Ok(Self::Paragraph(paragraph))
+            }
+            Block::Utterance {
+                id,
+                lang,
+                who,
+                start,
+                end,
+                content,
+            } => {
+                let mut utterance = Utterance::from_inline(who, inlines(content)?)?;
+                utterance.restore_timing(start, end);
+                if let Some(value) = id {
+                    utterance.set_id(value)?;
+                }
+                if let Some(value) = lang {
+                    utterance.set_lang(LanguageTag::new(value)?);
+                }
+                Ok(Self::Utterance(utterance))
+            }
+            Block::Raw { markup } => Ok(Self::Raw(RawMarkup::new(markup)?)),
+        }
+    }
+}
+
+fn contents(content: &[Inline]) -> Vec<Content> {
+    content.iter().map(Content::from).collect()
+}
+
+fn inlines(content: Vec<Content>) -> Result<Vec<Inline>, TeiError> {
+    content.into_iter().map(Inline::try_from).collect()
+}
+
+impl From<&Inline> for Content {
+    fn from(inline: &Inline) -> Self {
+        match inline {
+            Inline::Text(text) => Self::Text(text.clone()),
+            Inline::Hi(hi) => Self::Element(Element::Hi {
+                lang: hi.lang().map(|lang| lang.as_str().to_owned()),
+                rend: hi.rend().map(ToOwned::to_owned),
+                content: contents(hi.content()),
+            }),
+            Inline::Pause(pause) => Self::Element(Element::Pause {
+                dur: pause.duration().map(ToOwned::to_owned),
+                kind: pause.kind().map(ToOwned::to_owned),
+            }),
+            Inline::Unclear(unclear) => Self::Element(Element::Unclear {
+                reason: unclear.reason().map(ToOwned::to_owned),
+                cert: unclear.cert(),
+                content: contents(unclear.content()),
+            }),
+            Inline::Quote(quote) => Self::Element(Element::Quote {
+                content: contents(quote.content()),
+            }),
+            Inline::Note(note) => Self::Element(Element::Note {
+                place: note.place().map(ToOwned::to_owned),
+                content: contents(note.content()),
+            }),
+            Inline::Raw(raw) => Self::Element(Element::Raw {
+                markup: raw.as_str().to_owned(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<Content> for Inline {
+    type Error = TeiError;
+
+    fn try_from(item: Content) -> Result<Self, Self::Error> {
+        let element = match item {
+            Content::Text(text) => return Ok(Self::Text(text)),
+            Content::Element(element) => element,
+        };
+        match element {
+            Element::Hi {
+                lang,
+                rend,
+                content,
+            } => {
+                let children = inlines(content)?;
+                let mut hi = match rend {
+                    Some(value) => Hi::try_with_rend(value, children)?,
+                    None => Hi::try_new(children)?,
+                };
+                if let Some(value) = lang {
+                    hi.set_lang(LanguageTag::new(value)?);
+                }
+                Ok(Self::Hi(hi))
+            }
+            Element::Pause { dur, kind } => {
+                let mut pause = Pause::new();
+                if let Some(value) = dur {
+                    pause.set_duration(value);
+                }
+                if let Some(value) = kind {
+                    pause.set_kind(value);
+                }
+                Ok(Self::Pause(pause))
+            }
+            Element::Unclear {
+                reason,
+                cert,
+                content,
+            } => {
+                let mut unclear = Unclear::try_new(inlines(content)?)?;
+                if let Some(value) = reason {
+                    unclear.set_reason(value);
+                }
+                if let Some(value) = cert {
+                    unclear.set_cert(value);
+                }
+                Ok(Self::Unclear(unclear))
+            }
+            Element::Quote { content } => Ok(Self::Quote(Quote::try_new(inlines(content)?)?)),
+            Element::Note { place, content } => {
+                let mut note = Note::try_new(inlines(content)?)?;
+                if let Some(value) = place {
+                    note.set_place(value);
+                }
+                Ok(Self::Note(note))
+            }
+            Element::Raw { markup } => Ok(Self::Raw(RawMarkup::new(markup)?)),
+        }
+    }
+}
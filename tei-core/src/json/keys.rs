@@ -0,0 +1,243 @@
+//! Renaming between the XML-oriented serde names and JSON keys.
+//!
+//! The header types carry serde names such as `@type` and `$text` that only
+//! make sense to the XML layer. On the way out each key is renamed by
+//! [`json_key`]. On the way in, [`Restoring`] reads the JSON through serde
+//! and, whenever a struct asks for its fields, maps each JSON key back to the
+//! field it came from, so the header is still validated by its own
+//! `Deserialize` implementations.
+
+use serde::de::{
+    self, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, VariantAccess,
+    Visitor,
+};
+use serde::forward_to_deserialize_any;
+use serde_json::{Map, Value};
+
+/// Returns the JSON key for the serde name `field`.
+///
+/// Attributes lose their `@` and any `xml:` prefix, text content becomes
+/// `text`, and mixed content becomes `content`.
+pub(super) fn json_key(field: &str) -> &str {
+    match field {
+        "$text" => "text",
+        "$value" => "content",
+        _ => {
+            let name = field.strip_prefix('@').unwrap_or(field);
+            name.strip_prefix("xml:").unwrap_or(name)
+        }
+    }
+}
+
+/// Renames every key in `value` with [`json_key`].
+pub(super) fn rename(value: Value) -> Value {
+    match value {
+        Value::Object(entries) => Value::Object(
+            entries
+                .into_iter()
+                .map(|(key, entry)| (json_key(&key).to_owned(), rename(entry)))
+                .collect::<Map<String, Value>>(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(rename).collect()),
+        other => other,
+    }
+}
+
+/// JSON read with the data model's serde names restored.
+pub(super) struct Restoring(pub(super) Value);
+
+impl<'de> de::Deserializer<'de> for Restoring {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Array(items) => visitor.visit_seq(Items(items.into_iter())),
+            Value::Object(entries) => visitor.visit_map(Entries::new(entries, &[])),
+            other => de::Deserializer::deserialize_any(other, visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            other => visitor.visit_some(Self(other)),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Object(entries) => visitor.visit_map(Entries::new(entries, fields)),
+            other => de::Deserializer::deserialize_struct(other, name, fields, visitor),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Object(entries) if entries.len() == 1 => {
+                let mut single = entries.into_iter();
+                let (variant, value) = single
+                    .next()
+                    .ok_or_else(|| de::Error::custom("expected a single variant"))?;
+                visitor.visit_enum(Variant { variant, value })
+            }
+            other => de::Deserializer::deserialize_enum(other, name, variants, visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map identifier
+        ignored_any
+    }
+}
+
+struct Items(std::vec::IntoIter<Value>);
+
+impl<'de> SeqAccess<'de> for Items {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        self.0
+            .next()
+            .map(|item| seed.deserialize(Restoring(item)))
+            .transpose()
+    }
+}
+
+/// Object entries whose keys are mapped back onto `fields`.
+struct Entries {
+    remaining: serde_json::map::IntoIter,
+    fields: &'static [&'static str],
+    value: Option<Value>,
+}
+
+impl Entries {
+    fn new(entries: Map<String, Value>, fields: &'static [&'static str]) -> Self {
+        Self {
+            remaining: entries.into_iter(),
+            fields,
+            value: None,
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for Entries {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let Some((key, value)) = self.remaining.next() else {
+            return Ok(None);
+        };
+        self.value = Some(value);
+        let field = self
+            .fields
+            .iter()
+            .find(|field| json_key(field) == key)
+            .map_or(key, |field| (*field).to_owned());
+        seed.deserialize(IntoDeserializer::<Self::Error>::into_deserializer(field))
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let value = self
+            .value
+            .take()
+            .ok_or_else(|| de::Error::custom("value requested before its key"))?;
+        seed.deserialize(Restoring(value))
+    }
+}
+
+struct Variant {
+    variant: String,
+    value: Value,
+}
+
+impl<'de> EnumAccess<'de> for Variant {
+    type Error = serde_json::Error;
+    type Variant = Restoring;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let name = seed.deserialize(IntoDeserializer::<Self::Error>::into_deserializer(
+            self.variant,
+        ))?;
+        Ok((name, Restoring(self.value)))
+    }
+}
+
+impl<'de> VariantAccess<'de> for Restoring {
+    type Error = serde_json::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_struct(self, "", fields, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("@type", "type")]
+    #[case("@xml:id", "id")]
+    #[case("@xml:lang", "lang")]
+    #[case("$text", "text")]
+    #[case("$value", "content")]
+    #[case("respStmt", "respStmt")]
+    fn renames_serde_names(#[case] field: &str, #[case] expected: &str) {
+        assert_eq!(json_key(field), expected);
+    }
+}
@@ -0,0 +1,378 @@
+//! A stable JSON representation of [`TeiDocument`] for web frontends.
+//!
+//! The serde derives on the data model are shaped for `quick-xml`, so their
+//! JSON output leaks XML artefacts such as `@who` and `$value`. [`to_json`]
+//! and [`from_json`] use a mapping of their own instead:
+//!
+//! - The document is `{"header": ..., "body": [...]}`, with a `timeline`
+//!   object between them when the text has one.
+//! - Header and timeline elements keep their TEI names. Attributes drop the
+//!   `@` and any `xml:` prefix, so `@xml:lang` becomes `lang`; element text
+//!   is `text` and mixed content is `content`.
+//! - Each body block is an object tagged by `type`: `p` and `u` carry `id`,
+//!   `lang` and, for utterances, `who`, `start` and `end`, plus a `content`
+//!   array; `raw` carries the verbatim `markup`.
+//! - Inline content is a JSON string for text, or an object tagged by
+//!   `type`: `hi` with `lang`, `rend` and `content`, `pause` with `dur` and
+//!   `kind`, `unclear` with `reason`, `cert` and `content`, `quote` with
+//!   `content`, `note` with `place` and `content`, or `raw` with `markup`.
+//!
+//! Absent optional values are omitted rather than written as `null`. The
+//! mapping is part of the public contract: changing it is a breaking change.
+//! It is available with the default `json` feature.
+
+mod body;
+mod keys;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{BodyBlock, TeiBody, TeiDocument, TeiError, TeiHeader, TeiText, Timeline};
+
+use body::Block;
+use keys::Restoring;
+
+/// The top-level JSON object.
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Mapped {
+    header: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timeline: Option<Value>,
+    body: Vec<Block>,
+}
+
+/// Serialises a document into its JSON representation.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Json`] when the document cannot be represented as
+/// JSON.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{TeiDocument, Utterance, to_json};
+///
+/// let mut document = TeiDocument::from_title_str("Wolf 359")?;
+/// document
+///     .text_mut()
+///     .push_utterance(Utterance::from_text_segments(Some("eiffel"), ["Day 1."])?);
+///
+/// assert_eq!(
+///     to_json(&document)?,
+///     concat!(
+///         r#"{"header":{"fileDesc":{"title":[{"text":"Wolf 359"}]}},"#,
+///         r#""body":[{"type":"u","who":"eiffel","content":["Day 1."]}]}"#,
+///     ),
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn to_json(document: &TeiDocument) -> Result<String, TeiError> {
+    let mapped = map(document, TeiError::json)?;
+    serde_json::to_string(&mapped).map_err(|error| TeiError::json(error.to_string()))
+}
+
+/// Parses a document from its JSON representation.
+///
+/// The header and every block go through the same validation as the
+/// constructors, so invalid JSON content is rejected just as invalid XML is.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Json`] when `json` is malformed or does not follow
+/// the mapping, and the validation error of the failing element when its
+/// content is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{TeiDocument, from_json, to_json};
+///
+/// let document = TeiDocument::from_title_str("Wolf 359")?;
+/// assert_eq!(from_json(&to_json(&document)?)?, document);
+///
+/// assert!(from_json(r#"{"header":{},"body":[]}"#).is_err());
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn from_json(json: &str) -> Result<TeiDocument, TeiError> {
+    let mapped = serde_json::from_str(json).map_err(|error| TeiError::json(error.to_string()))?;
+    from_value(mapped, TeiError::json)
+}
+
+/// Converts a document into its JSON representation as a [`Value`] tree.
+///
+/// Bindings that build native objects, such as Python dictionaries, walk the
+/// tree instead of re-parsing the text produced by [`to_json`].
+///
+/// # Errors
+///
+/// Returns [`TeiError::Json`] when the document cannot be represented as
+/// JSON.
+pub fn to_json_value(document: &TeiDocument) -> Result<Value, TeiError> {
+    let mapped = map(document, TeiError::json)?;
+    serde_json::to_value(mapped).map_err(|error| TeiError::json(error.to_string()))
+}
+
+/// Reads a document from a [`Value`] tree following the JSON mapping.
+///
+/// # Errors
+///
+/// Returns the same errors as [`from_json`], bar those for malformed text.
+pub fn from_json_value(value: Value) -> Result<TeiDocument, TeiError> {
+    from_value(value, TeiError::json)
+}
+
+/// Converts a header into the `header` object of the JSON mapping.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Json`] when the header cannot be represented as JSON.
+pub fn header_to_json_value(header: &TeiHeader) -> Result<Value, TeiError> {
+    serde_json::to_value(header)
+        .map(keys::rename)
+        .map_err(|error| TeiError::json(error.to_string()))
+}
+
+/// Reads a header from the `header` object of the JSON mapping.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Json`] when `value` does not follow the mapping or
+/// holds invalid metadata.
+pub fn header_from_json_value(value: Value) -> Result<TeiHeader, TeiError> {
+    TeiHeader::deserialize(Restoring(value)).map_err(|error| TeiError::json(error.to_string()))
+}
+
+/// Converts a body block into its tagged object in the JSON mapping.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Json`] when the block cannot be represented as JSON.
+pub fn block_to_json_value(block: &BodyBlock) -> Result<Value, TeiError> {
+    serde_json::to_value(Block::from(block)).map_err(|error| TeiError::json(error.to_string()))
+}
+
+/// Reads a body block from its tagged object in the JSON mapping.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Json`] when `value` does not follow the mapping, and
+/// the validation error of the block when its content is invalid.
+pub fn block_from_json_value(value: Value) -> Result<BodyBlock, TeiError> {
+    Block::deserialize(value)
+        .map_err(|error| TeiError::json(error.to_string()))
+        .and_then(BodyBlock::try_from)
+}
+
+/// Maps `document` onto its JSON structure, reporting failures with `error`.
+///
+/// Other formats that share the mapping, such as `MessagePack`, encode the
+/// result.
+pub(crate) fn map(
+    document: &TeiDocument,
+    error: fn(String) -> TeiError,
+) -> Result<Mapped, TeiError> {
+    let header =
+        serde_json::to_value(document.header()).map_err(|cause| error(cause.to_string()))?;
+    let timeline = document
+        .text()
+        .timeline()
+        .map(serde_json::to_value)
+        .transpose()
+        .map_err(|cause| error(cause.to_string()))?;
+    Ok(Mapped {
+        header: keys::rename(header),
+        timeline: timeline.map(keys::rename),
+        body: document.text().body().blocks().map(Block::from).collect(),
+    })
+}
+
+/// Reads a document from its mapped value, reporting values that do not
+/// follow the mapping with `error`.
+pub(crate) fn from_value(
+    value: Value,
+    error: fn(String) -> TeiError,
+) -> Result<TeiDocument, TeiError> {
+    let mapped = Mapped::deserialize(value).map_err(|cause| error(cause.to_string()))?;
+    let header = TeiHeader::deserialize(Restoring(mapped.header))
+        .map_err(|cause| error(cause.to_string()))?;
+    let blocks = mapped
+        .body
+        .into_iter()
+        .map(BodyBlock::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut text = TeiText::new(TeiBody::new(blocks));
+    if let Some(timeline) = mapped.timeline {
+        text.set_timeline(
+            Timeline::deserialize(Restoring(timeline)).map_err(|cause| error(cause.to_string()))?,
+        );
+    }
+    Ok(TeiDocument::new(header, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BodyContentError, Certainty, Hi, Inline, LanguageTag, Note, P, Pause, Quote, RawMarkup,
+        Unclear, Utterance,
+    };
+
+    fn document() -> TeiDocument {
+        let mut pause = Pause::new();
+        pause.set_duration("PT1S");
+        pause.set_kind("breath");
+        let mut hi = Hi::with_rend("italic", [Inline::text("waters")]);
+        hi.set_lang(LanguageTag::new("en").expect("valid language"));
+        let mut unclear = Unclear::new([Inline::text("Hephaestus")]);
+        unclear.set_cert(Certainty::Low);
+        let mut note = Note::new([Inline::text("Static.")]);
+        note.set_place("foot");
+        let mut utterance = Utterance::from_inline(
+            Some("eiffel"),
+            [
+                Inline::text("Day 1."),
+                Inline::Pause(pause),
+                Inline::Hi(hi),
+                Inline::Unclear(unclear),
+                Inline::Quote(Quote::new([Inline::text("Hello?")])),
+                Inline::Note(note),
+                Inline::Raw(RawMarkup::new("<gap/>").expect("markup is not blank")),
+            ],
+        )
+        .expect("valid utterance");
+        utterance.set_id("u1").expect("valid identifier");
+
+        let mut document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        let body = document.text_mut().body_mut();
+        body.push_paragraph(P::from_text_segments(["Previously."]).expect("valid paragraph"));
+        body.push_utterance(utterance);
+        body.extend([BodyBlock::Raw(
+            RawMarkup::new("<div/>").expect("markup is not blank"),
+        )]);
+        document
+    }
+
+    #[test]
+    fn writes_tagged_blocks_without_xml_artefacts() {
+        let json = to_json(&document()).expect("document should serialise");
+
+        let value: Value = serde_json::from_str(&json).expect("output is JSON");
+        assert_eq!(
+            value.get("body"),
+            Some(&serde_json::json!([
+                {"type": "p", "content": ["Previously."]},
+                {"type": "u", "id": "u1", "who": "eiffel", "content": [
+                    "Day 1.",
+                    {"type": "pause", "dur": "PT1S", "kind": "breath"},
+                    {"type": "hi", "lang": "en", "rend": "italic", "content": ["waters"]},
+                    {"type": "unclear", "cert": "low", "content": ["Hephaestus"]},
+                    {"type": "quote", "content": ["Hello?"]},
+                    {"type": "note", "place": "foot", "content": ["Static."]},
+                    {"type": "raw", "markup": "<gap/>"},
+                ]},
+                {"type": "raw", "markup": "<div/>"},
+            ]))
+        );
+        assert!(!json.contains('@') && !json.contains('$'), "{json}");
+    }
+
+    #[test]
+    fn writes_the_timeline_and_utterance_timing() {
+        let mut timeline = Timeline::new();
+        let start = timeline.add_offset(std::time::Duration::from_millis(1_500));
+        let end = timeline.add_offset(std::time::Duration::from_secs(4));
+        let mut utterance =
+            Utterance::from_text_segments(Some("eiffel"), ["Day 1."]).expect("valid utterance");
+        utterance.set_timing(&start, &end);
+        let mut document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        document.text_mut().set_timeline(timeline);
+        document.text_mut().push_utterance(utterance);
+
+        let json = to_json(&document).expect("document should serialise");
+
+        let value: Value = serde_json::from_str(&json).expect("output is JSON");
+        assert_eq!(
+            value.get("timeline"),
+            Some(&serde_json::json!({
+                "unit": "s",
+                "origin": "#T0",
+                "when": [
+                    {"id": "T0"},
+                    {"id": "T1", "interval": "1.5", "since": "#T0"},
+                    {"id": "T2", "interval": "4", "since": "#T0"},
+                ],
+            }))
+        );
+        assert_eq!(
+            value.pointer("/body/0/start").and_then(Value::as_str),
+            Some("#T1")
+        );
+        assert_eq!(from_json(&json).expect("JSON should parse"), document);
+    }
+
+    #[test]
+    fn round_trips_documents() {
+        let json = to_json(&document()).expect("document should serialise");
+
+        assert_eq!(from_json(&json).expect("JSON should parse"), document());
+    }
+
+    #[test]
+    fn round_trips_value_trees() {
+        let value = to_json_value(&document()).expect("document should convert");
+
+        assert_eq!(
+            value.pointer("/body/1/who").and_then(Value::as_str),
+            Some("eiffel")
+        );
+        assert_eq!(
+            from_json_value(value).expect("value should convert"),
+            document()
+        );
+    }
+
+    #[test]
+    fn converts_headers_and_blocks_individually() {
+        let document = document();
+        let header = header_to_json_value(document.header()).expect("header should convert");
+        let block = document
+            .text()
+            .body()
+            .block(1)
+            .expect("fixture has an utterance");
+        let value = block_to_json_value(block).expect("block should convert");
+
+        assert_eq!(
+            header_from_json_value(header).expect("header should parse"),
+            *document.header()
+        );
+        assert_eq!(
+            block_from_json_value(value).expect("block should parse"),
+            *block
+        );
+        assert!(block_from_json_value(serde_json::json!({"type": "p"})).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_content() {
+        let json = r#"{"header":{"fileDesc":{"title":[{"text":"Wolf 359"}]}},"body":[{"type":"p","content":["  "]}]}"#;
+
+        let error = from_json(json).expect_err("blank paragraphs must fail");
+
+        assert_eq!(
+            error,
+            TeiError::Body(BodyContentError::EmptySegment {
+                container: crate::Container::Paragraph
+            })
+        );
+    }
+
+    #[test]
+    fn reports_malformed_json() {
+        let error = from_json("{\"header\":").expect_err("truncated JSON must fail");
+
+        assert!(matches!(error, TeiError::Json { .. }), "{error:?}");
+    }
+}
@@ -7,60 +7,58 @@
 //! text module models the TEI body using paragraphs and utterances so tests can
 //! exercise real script fragments.
 
+mod anonymise;
+mod error;
+mod error_code;
+pub mod escape;
+pub mod export;
 mod header;
+pub mod import;
+#[cfg(feature = "json")]
+mod json;
+mod limit;
+mod location;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+mod preview;
 mod text;
+mod timestamp;
 mod title;
+mod validate;
 
+pub use anonymise::Anonymiser;
+pub use error::{ErrorSource, TeiError};
+pub use error_code::{CodedDisplay, ErrorCode};
 pub use header::{
-    AnnotationSystem, AnnotationSystemId, EncodingDesc, FileDesc, HeaderValidationError,
-    LanguageTag, ProfileDesc, ResponsibleParty, RevisionChange, RevisionDesc, SpeakerName,
-    TeiHeader,
+    Abstract, AnnotationSystem, AnnotationSystemId, AppInfo, Application, Availability, CatRef,
+    Category, CiteStructure, ClassDecl, Creation, EditionStmt, EditorialDecl, EncodingDesc, Extent,
+    FileDesc, HeaderValidationError, Hyphenation, HyphenationEol, Idno, IdnoType, Interpretation,
+    Keywords, LanguageTag, Licence, Media, Normalization, NormalizationMethod, NotesStmt,
+    ProfileDesc, ProjectDesc, PublicationStmt, Quotation, QuotationMarks, RefsDecl, Rendition,
+    RenditionScheme, RespStmt, ResponsibleParty, RevisionChange, RevisionDesc, SamplingDecl,
+    SeriesStmt, SourceDesc, SpeakerName, SpeakerRole, TagsDecl, Taxonomy, TeiHeader, TextClass,
+    XenoData,
 };
+#[cfg(feature = "json")]
+pub use json::{
+    block_from_json_value, block_to_json_value, from_json, from_json_value, header_from_json_value,
+    header_to_json_value, to_json, to_json_value,
+};
+pub use limit::ResourceLimit;
+pub use location::{SourceLocation, TeiPath};
+#[cfg(feature = "msgpack")]
+pub use msgpack::{from_msgpack, to_msgpack};
+pub use preview::Preview;
 pub use text::{
-    BodyBlock, BodyContentError, Hi, IdentifierValidationError, Inline, P, Pause, Speaker,
-    SpeakerValidationError, TeiBody, TeiText, Utterance, XmlId,
+    BodyBlock, BodyContentError, Certainty, Container, Hi, IdentifierValidationError, Inline, Note,
+    P, Pause, Quote, RawMarkup, Speaker, SpeakerValidationError, TeiBody, TeiText, Timeline,
+    Unclear, Utterance, When, XmlId,
 };
-pub use title::{DocumentTitle, DocumentTitleError};
+pub use timestamp::{TimestampFormat, TimestampStyle};
+pub use title::{DocumentTitle, DocumentTitleError, TitleOptions, TitleType};
+pub use validate::{ValidationIssue, ValidationReport};
 
 use serde::{Deserialize, Serialize};
-use thiserror::Error;
-
-/// Errors raised by TEI core data model operations.
-#[derive(Clone, Debug, Error, Eq, PartialEq)]
-#[non_exhaustive]
-pub enum TeiError {
-    /// Wrapper around [`DocumentTitleError`] values.
-    #[error(transparent)]
-    DocumentTitle(#[from] DocumentTitleError),
-    /// Wrapper around [`HeaderValidationError`] values.
-    #[error(transparent)]
-    Header(#[from] HeaderValidationError),
-    /// Wrapper around [`BodyContentError`] values.
-    #[error(transparent)]
-    Body(#[from] BodyContentError),
-    /// Wrapper around [`IdentifierValidationError`] values.
-    #[error(transparent)]
-    Identifier(#[from] IdentifierValidationError),
-    /// Wrapper around [`SpeakerValidationError`] values.
-    #[error(transparent)]
-    Speaker(#[from] SpeakerValidationError),
-    /// XML parsing or serialisation failed.
-    #[error("XML processing error: {message}")]
-    Xml {
-        /// Message describing the failure emitted by the XML layer.
-        message: String,
-    },
-}
-
-impl TeiError {
-    /// Builds an XML processing error with the provided message.
-    #[must_use]
-    pub fn xml(message: impl Into<String>) -> Self {
-        Self::Xml {
-            message: message.into(),
-        }
-    }
-}
 
 /// Root TEI document combining metadata and textual content.
 ///
@@ -113,79 +111,144 @@ impl TeiDocument {
         &self.text
     }
 
+    /// Returns a mutable reference to the textual component.
+    pub const fn text_mut(&mut self) -> &mut TeiText {
+        &mut self.text
+    }
+
+    /// Recomputes the utterance and word counts recorded in the header extent.
+    ///
+    /// Call this before emission so the `<extent>` measures match the body.
+    /// Any recorded audio duration is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::{TeiDocument, TeiError, Utterance};
+    ///
+    /// let mut document = TeiDocument::from_title_str("Welcome to Night Vale")?;
+    /// document
+    ///     .text_mut()
+    ///     .push_utterance(Utterance::from_text_segments(Some("cecil"), ["Hello, listeners."])?);
+    /// document.refresh_extent();
+    ///
+    /// let extent = document.header().file_desc().extent();
+    /// assert_eq!(extent.and_then(|value| value.utterance_count()), Some(1));
+    /// assert_eq!(extent.and_then(|value| value.word_count()), Some(2));
+    /// # Ok::<(), TeiError>(())
+    /// ```
+    pub fn refresh_extent(&mut self) {
+        let body = self.text.body();
+        let extent = self
+            .header
+            .file_desc()
+            .extent()
+            .cloned()
+            .unwrap_or_default()
+            .with_utterance_count(body.utterances().count())
+            .with_word_count(body.word_count());
+        self.header.file_desc_mut().set_extent(extent);
+    }
+
     /// Returns the validated title.
     #[must_use]
     pub const fn title(&self) -> &DocumentTitle {
         self.header.file_desc().title()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn constructs_document_from_title() {
-        let document = TeiDocument::from_title_str("King Falls AM")
-            .unwrap_or_else(|error| panic!("valid document: {error}"));
-        assert_eq!(document.title().as_str(), "King Falls AM");
+    /// Replaces the main title in the header.
+    pub fn set_title(&mut self, title: DocumentTitle) {
+        self.header.file_desc_mut().set_title(title);
     }
 
-    #[test]
-    fn converts_document_title_error_into_tei_error() {
-        let error: TeiError = DocumentTitleError::Empty.into();
-        assert!(matches!(
-            error,
-            TeiError::DocumentTitle(DocumentTitleError::Empty)
-        ));
+    /// Returns the season number recorded in the series statement.
+    #[must_use]
+    pub fn season_number(&self) -> Option<u32> {
+        self.header
+            .file_desc()
+            .series_stmt()
+            .and_then(SeriesStmt::season_number)
     }
 
-    #[test]
-    fn converts_body_content_error_into_tei_error() {
-        let error: TeiError = BodyContentError::EmptySpeaker.into();
-        assert!(matches!(
-            error,
-            TeiError::Body(BodyContentError::EmptySpeaker)
-        ));
+    /// Returns the episode number recorded in the series statement.
+    #[must_use]
+    pub fn episode_number(&self) -> Option<u32> {
+        self.header
+            .file_desc()
+            .series_stmt()
+            .and_then(SeriesStmt::episode_number)
     }
 
-    #[test]
-    fn converts_header_validation_error_into_tei_error() {
-        let error: TeiError = HeaderValidationError::EmptyField { field: "header" }.into();
-
-        assert!(matches!(
-            error,
-            TeiError::Header(HeaderValidationError::EmptyField { field: "header" })
-        ));
+    /// Returns the document-wide default language.
+    ///
+    /// The first language recorded in the profile description acts as the
+    /// root of `xml:lang` inheritance for body content.
+    #[must_use]
+    pub fn default_lang(&self) -> Option<&LanguageTag> {
+        self.header
+            .profile_desc()
+            .and_then(|profile| profile.languages().first())
     }
 
-    #[test]
-    fn converts_identifier_validation_error_into_tei_error() {
-        let error: TeiError = IdentifierValidationError::Empty.into();
-
-        assert!(matches!(
-            error,
-            TeiError::Identifier(IdentifierValidationError::Empty)
-        ));
+    /// Resolves the effective language of a body block.
+    ///
+    /// Blocks declaring `xml:lang` keep their own language; all others
+    /// inherit [`TeiDocument::default_lang`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::{
+    ///     BodyBlock, FileDesc, LanguageTag, P, ProfileDesc, TeiDocument, TeiError, TeiHeader,
+    ///     TeiText,
+    /// };
+    ///
+    /// let mut profile = ProfileDesc::new();
+    /// profile.add_language("en")?;
+    /// let header = TeiHeader::new(FileDesc::from_title_str("Multilingual")?)
+    ///     .with_profile_desc(profile);
+    /// let mut aside = P::from_text_segments(["Bore da"])?;
+    /// aside.set_lang(LanguageTag::new("cy")?);
+    /// let mut text = TeiText::empty();
+    /// text.push_paragraph(P::from_text_segments(["Good morning"])?)
+    ///     .push_paragraph(aside);
+    /// let document = TeiDocument::new(header, text);
+    ///
+    /// let languages: Vec<_> = document
+    ///     .text()
+    ///     .body()
+    ///     .blocks()
+    ///     .map(|block| document.resolve_block_lang(block).map(LanguageTag::as_str))
+    ///     .collect();
+    /// assert_eq!(languages, [Some("en"), Some("cy")]);
+    /// # Ok::<(), TeiError>(())
+    /// ```
+    #[must_use]
+    pub fn resolve_block_lang<'a>(&'a self, block: &'a BodyBlock) -> Option<&'a LanguageTag> {
+        block.resolve_lang(self.default_lang())
     }
+}
 
-    #[test]
-    fn converts_speaker_validation_error_into_tei_error() {
-        let error: TeiError = SpeakerValidationError::Empty.into();
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        assert!(matches!(
-            error,
-            TeiError::Speaker(SpeakerValidationError::Empty)
-        ));
+    #[test]
+    fn constructs_document_from_title() {
+        let document = TeiDocument::from_title_str("King Falls AM")
+            .unwrap_or_else(|error| panic!("valid document: {error}"));
+        assert_eq!(document.title().as_str(), "King Falls AM");
     }
 
     #[test]
-    fn constructs_xml_error_from_message() {
-        let error = TeiError::xml("missing header");
-        let TeiError::Xml { message } = error else {
-            panic!("expected XML error variant");
-        };
+    fn replaces_the_title_in_place() {
+        let mut document = TeiDocument::from_title_str("King Falls AM")
+            .unwrap_or_else(|error| panic!("valid document: {error}"));
+        let title =
+            DocumentTitle::new("The Bright Sessions").unwrap_or_else(|error| panic!("{error}"));
+
+        document.set_title(title);
 
-        assert_eq!(message, "missing header");
+        assert_eq!(document.title().as_str(), "The Bright Sessions");
     }
 }
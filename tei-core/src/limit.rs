@@ -0,0 +1,52 @@
+//! Resource limits applied while parsing untrusted markup.
+//!
+//! Services that accept uploads bound how deep, how reference-heavy and how
+//! large a document may be before any of it is deserialised.
+//! [`TeiError::LimitExceeded`](crate::TeiError::LimitExceeded) names the
+//! [`ResourceLimit`] that tripped.
+
+use std::fmt;
+
+/// A parser limit that input can exceed.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::ResourceLimit;
+///
+/// assert_eq!(ResourceLimit::Depth.to_string(), "nesting depth");
+/// assert_eq!(ResourceLimit::DocumentSize.unit(), " bytes");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ResourceLimit {
+    /// Number of elements open at once.
+    Depth,
+    /// Number of entity and character references in text and attributes.
+    EntityExpansions,
+    /// Length of the input in bytes.
+    DocumentSize,
+}
+
+impl ResourceLimit {
+    /// Returns the unit the limit is counted in, with a leading space, or an
+    /// empty string for plain counts.
+    #[must_use]
+    pub const fn unit(self) -> &'static str {
+        match self {
+            Self::Depth => " elements",
+            Self::EntityExpansions => " references",
+            Self::DocumentSize => " bytes",
+        }
+    }
+}
+
+impl fmt::Display for ResourceLimit {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Depth => "nesting depth",
+            Self::EntityExpansions => "entity expansion",
+            Self::DocumentSize => "document size",
+        })
+    }
+}
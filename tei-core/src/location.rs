@@ -0,0 +1,261 @@
+//! Locations that point diagnostics at a spot in a TEI document.
+//!
+//! Parse failures carry a byte offset into the source text, along with the
+//! line and column it falls on, while findings
+//! from [`TeiDocument::validate`](crate::TeiDocument::validate) carry a
+//! [`TeiPath`] such as `/TEI/text/body/u[3]/@who`. Editors can use either to
+//! jump to the offending markup instead of searching large files by message.
+
+use std::fmt;
+
+/// Element path from the document root, rendered like `/TEI/text/body/u[2]`.
+///
+/// Positions are one-based and count only siblings with the same name, as in
+/// path expressions, so `u[2]` is the second utterance whatever paragraphs
+/// sit between the two.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::TeiPath;
+///
+/// let path = TeiPath::root()
+///     .child("text")
+///     .child("body")
+///     .nth_child("u", 2)
+///     .attribute("who");
+/// assert_eq!(path.to_string(), "/TEI/text/body/u[2]/@who");
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TeiPath {
+    steps: Vec<String>,
+}
+
+impl TeiPath {
+    /// Returns an empty path, rendered as `/`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    /// Returns the path of the `<TEI>` root element.
+    #[must_use]
+    pub fn root() -> Self {
+        Self::new().child("TEI")
+    }
+
+    /// Appends an element step without a position.
+    #[must_use]
+    pub fn child(mut self, name: &str) -> Self {
+        self.steps.push(name.to_owned());
+        self
+    }
+
+    /// Appends an element step selecting the `position`-th sibling of that
+    /// name, counting from one.
+    #[must_use]
+    pub fn nth_child(mut self, name: &str, position: usize) -> Self {
+        self.steps.push(format!("{name}[{position}]"));
+        self
+    }
+
+    /// Appends an attribute step such as `@who`.
+    #[must_use]
+    pub fn attribute(mut self, name: &str) -> Self {
+        self.steps.push(format!("@{name}"));
+        self
+    }
+
+    /// Returns the rendered steps in document order.
+    #[must_use]
+    pub const fn steps(&self) -> &[String] {
+        self.steps.as_slice()
+    }
+}
+
+impl fmt::Display for TeiPath {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.steps.is_empty() {
+            return formatter.write_str("/");
+        }
+
+        for step in &self.steps {
+            write!(formatter, "/{step}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Where in the source text a failure was detected.
+///
+/// Any part may be missing: structural errors raised after the XML was
+/// read have no meaningful offset, and syntax errors near the prolog may
+/// precede the first element. Lines and columns are one-based, with columns
+/// counted in characters.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{SourceLocation, TeiPath};
+///
+/// let location = SourceLocation::new()
+///     .with_offset(42)
+///     .with_path(TeiPath::root().child("teiHeader"));
+/// assert_eq!(location.to_string(), "byte 42 in /TEI/teiHeader");
+///
+/// let source = "<TEI>\n  <teiHeader>";
+/// let located = SourceLocation::new().with_offset(8).with_line_column_in(source);
+/// assert_eq!((located.line(), located.column()), (Some(2), Some(3)));
+/// assert_eq!(located.to_string(), "line 2, column 3, byte 8");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SourceLocation {
+    offset: Option<usize>,
+    line_column: Option<(usize, usize)>,
+    path: Option<TeiPath>,
+}
+
+impl SourceLocation {
+    /// Creates a location with neither an offset nor a path.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            offset: None,
+            line_column: None,
+            path: None,
+        }
+    }
+
+    /// Records the byte offset into the source text.
+    #[must_use]
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Records a one-based line and column.
+    #[must_use]
+    pub const fn with_line_column(mut self, line: usize, column: usize) -> Self {
+        self.line_column = Some((line, column));
+        self
+    }
+
+    /// Derives the line and column of the recorded offset within `source`.
+    ///
+    /// Locations without an offset are returned unchanged. Offsets past the
+    /// end of `source` resolve to its final position.
+    #[must_use]
+    pub fn with_line_column_in(self, source: &str) -> Self {
+        let Some(offset) = self.offset else {
+            return self;
+        };
+
+        let before = source.as_bytes().get(..offset).unwrap_or(source.as_bytes());
+        let line_start = before
+            .iter()
+            .rposition(|byte| *byte == b'\n')
+            .map_or(0, |newline| newline + 1);
+        let line = before.split(|byte| *byte == b'\n').count();
+        let column = String::from_utf8_lossy(before.get(line_start..).unwrap_or_default())
+            .chars()
+            .count()
+            + 1;
+        self.with_line_column(line, column)
+    }
+
+    /// Records the path of the enclosing element.
+    #[must_use]
+    pub fn with_path(mut self, path: TeiPath) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Returns the byte offset when known.
+    #[must_use]
+    pub const fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Returns the one-based line when known.
+    #[must_use]
+    pub const fn line(&self) -> Option<usize> {
+        match self.line_column {
+            Some((line, _)) => Some(line),
+            None => None,
+        }
+    }
+
+    /// Returns the one-based column, counted in characters, when known.
+    #[must_use]
+    pub const fn column(&self) -> Option<usize> {
+        match self.line_column {
+            Some((_, column)) => Some(column),
+            None => None,
+        }
+    }
+
+    /// Returns the element path when known.
+    #[must_use]
+    pub const fn path(&self) -> Option<&TeiPath> {
+        self.path.as_ref()
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some((line, column)) = self.line_column {
+            parts.push(format!("line {line}, column {column}"));
+        }
+        if let Some(offset) = self.offset {
+            parts.push(format!("byte {offset}"));
+        }
+
+        match (parts.is_empty(), &self.path) {
+            (false, Some(path)) => write!(formatter, "{} in {path}", parts.join(", ")),
+            (false, None) => formatter.write_str(&parts.join(", ")),
+            (true, Some(path)) => path.fmt(formatter),
+            (true, None) => formatter.write_str("unknown location"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_path_renders_as_slash() {
+        assert_eq!(TeiPath::new().to_string(), "/");
+    }
+
+    #[test]
+    fn location_renders_available_parts() {
+        let path = TeiPath::root().child("text");
+
+        assert_eq!(SourceLocation::new().with_offset(7).to_string(), "byte 7");
+        assert_eq!(
+            SourceLocation::new().with_path(path).to_string(),
+            "/TEI/text"
+        );
+    }
+
+    #[test]
+    fn counts_columns_in_characters() {
+        let location = SourceLocation::new()
+            .with_offset("<title>Café ".len())
+            .with_line_column_in("<title>Café </titel>");
+
+        assert_eq!(location.line(), Some(1));
+        assert_eq!(location.column(), Some(13));
+    }
+
+    #[test]
+    fn clamps_offsets_past_the_end() {
+        let location = SourceLocation::new()
+            .with_offset(99)
+            .with_line_column_in("<TEI>\n<text>");
+
+        assert_eq!((location.line(), location.column()), (Some(2), Some(7)));
+    }
+}
@@ -0,0 +1,103 @@
+//! `MessagePack` encoding of [`TeiDocument`].
+//!
+//! The encoding is the JSON mapping of [`to_json`](crate::to_json) written
+//! as `MessagePack`: every object becomes a map keyed by the same names, so a
+//! payload decodes into the same structures as the JSON form. This lets
+//! Python callers hand documents to `msgspec` without agreeing on serialiser
+//! settings out of band. It is available with the default `msgpack` feature.
+
+use serde_json::Value;
+
+use crate::json::{from_value, map};
+use crate::{TeiDocument, TeiError};
+
+/// Encodes a document as `MessagePack` using the canonical field names.
+///
+/// # Errors
+///
+/// Returns [`TeiError::MessagePack`] when the document cannot be encoded.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{TeiDocument, from_msgpack, to_msgpack};
+///
+/// let document = TeiDocument::from_title_str("Wolf 359")?;
+/// let encoded = to_msgpack(&document)?;
+///
+/// assert_eq!(from_msgpack(&encoded)?, document);
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn to_msgpack(document: &TeiDocument) -> Result<Vec<u8>, TeiError> {
+    let mapped = map(document, TeiError::message_pack)?;
+    rmp_serde::to_vec_named(&mapped).map_err(|error| TeiError::message_pack(error.to_string()))
+}
+
+/// Decodes a document from `MessagePack` produced by [`to_msgpack`] or by
+/// any encoder following the same field names.
+///
+/// # Errors
+///
+/// Returns [`TeiError::MessagePack`] when `bytes` are malformed or do not
+/// follow the mapping, and the validation error of the failing element when
+/// its content is invalid.
+pub fn from_msgpack(bytes: &[u8]) -> Result<TeiDocument, TeiError> {
+    let mapped: Value =
+        rmp_serde::from_slice(bytes).map_err(|error| TeiError::message_pack(error.to_string()))?;
+    from_value(mapped, TeiError::message_pack)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Inline, Pause, Utterance, from_json, to_json};
+
+    fn document() -> TeiDocument {
+        let mut document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        let utterance = Utterance::from_inline(
+            Some("eiffel"),
+            [Inline::text("Day 1."), Inline::Pause(Pause::new())],
+        )
+        .expect("valid utterance");
+        document.text_mut().push_utterance(utterance);
+        document.refresh_extent();
+        document
+    }
+
+    #[test]
+    fn round_trips_documents() {
+        let encoded = to_msgpack(&document()).expect("document should encode");
+
+        assert_eq!(
+            from_msgpack(&encoded).expect("payload should decode"),
+            document()
+        );
+    }
+
+    #[test]
+    fn encodes_the_json_mapping() {
+        let encoded = to_msgpack(&document()).expect("document should encode");
+
+        let decoded: Value = rmp_serde::from_slice(&encoded).expect("payload is MessagePack");
+        let json = to_json(&document()).expect("document should serialise");
+        assert_eq!(decoded, serde_json::from_str::<Value>(&json).expect("JSON"));
+        assert_eq!(from_json(&json).expect("JSON should parse"), document());
+    }
+
+    #[test]
+    fn reports_malformed_payloads() {
+        let error = from_msgpack(&[0xc1]).expect_err("reserved byte must fail");
+
+        assert!(matches!(error, TeiError::MessagePack { .. }), "{error:?}");
+    }
+
+    #[test]
+    fn reports_payloads_outside_the_mapping() {
+        let encoded =
+            rmp_serde::to_vec_named(&serde_json::json!({"body": []})).expect("value should encode");
+
+        let error = from_msgpack(&encoded).expect_err("missing header must fail");
+
+        assert!(matches!(error, TeiError::MessagePack { .. }), "{error:?}");
+    }
+}
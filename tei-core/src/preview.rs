@@ -0,0 +1,141 @@
+//! Word-limited previews of documents for feeds and search-result cards.
+//!
+//! Previews cut the body at block boundaries, so every paragraph and
+//! utterance that survives is complete and the emitted markup stays valid.
+//! The returned [`Preview`] records how much content was left out.
+
+use crate::{BodyBlock, TeiBody, TeiDocument, TeiText};
+
+/// A truncated copy of a document alongside what was omitted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Preview {
+    document: TeiDocument,
+    omitted_blocks: usize,
+    omitted_words: usize,
+}
+
+impl Preview {
+    /// Returns the truncated document.
+    #[must_use]
+    pub const fn document(&self) -> &TeiDocument {
+        &self.document
+    }
+
+    /// Consumes the preview and returns the truncated document.
+    #[must_use]
+    pub fn into_document(self) -> TeiDocument {
+        self.document
+    }
+
+    /// Reports whether any body blocks were left out.
+    #[must_use]
+    pub const fn is_truncated(&self) -> bool {
+        self.omitted_blocks > 0
+    }
+
+    /// Returns the number of body blocks left out.
+    #[must_use]
+    pub const fn omitted_blocks(&self) -> usize {
+        self.omitted_blocks
+    }
+
+    /// Returns the number of words in the omitted blocks.
+    #[must_use]
+    pub const fn omitted_words(&self) -> usize {
+        self.omitted_words
+    }
+}
+
+impl TeiDocument {
+    /// Builds a preview holding the leading blocks that fit in `max_words`.
+    ///
+    /// Blocks are never split: the preview stops before the first block that
+    /// would exceed the budget, even if later blocks are shorter. The header
+    /// is copied unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::{TeiDocument, TeiError, Utterance};
+    ///
+    /// let mut document = TeiDocument::from_title_str("Wolf 359")?;
+    /// document
+    ///     .text_mut()
+    ///     .push_utterance(Utterance::from_text_segments(Some("eiffel"), ["Day 1 of the mission."])?)
+    ///     .push_utterance(Utterance::from_text_segments(Some("hera"), ["Good morning, Eiffel."])?);
+    ///
+    /// let preview = document.preview(6);
+    /// assert_eq!(preview.document().text().body().blocks().len(), 1);
+    /// assert_eq!(preview.omitted_blocks(), 1);
+    /// assert_eq!(preview.omitted_words(), 3);
+    /// # Ok::<(), TeiError>(())
+    /// ```
+    #[must_use]
+    pub fn preview(&self, max_words: usize) -> Preview {
+        let blocks = || self.text.body().blocks();
+        let mut used_words = 0_usize;
+        let kept = blocks()
+            .take_while(|block| {
+                let total = used_words.saturating_add(block.word_count());
+                let fits = total <= max_words;
+                if fits {
+                    used_words = total;
+                }
+                fits
+            })
+            .count();
+        let text = TeiText::new(TeiBody::new(blocks().take(kept).cloned()));
+
+        Preview {
+            document: Self::new(self.header.clone(), text),
+            omitted_blocks: blocks().len() - kept,
+            omitted_words: blocks().skip(kept).map(BodyBlock::word_count).sum(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::P;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn document() -> TeiDocument {
+        let mut document = TeiDocument::from_title_str("The Bright Sessions")
+            .unwrap_or_else(|error| panic!("valid title: {error}"));
+        for text in ["Session one begins.", "Sam describes the incident.", "End."] {
+            document.text_mut().push_paragraph(
+                P::from_text_segments([text])
+                    .unwrap_or_else(|error| panic!("valid paragraph: {error}")),
+            );
+        }
+        document
+    }
+
+    #[rstest]
+    #[case(0, 0, 3)]
+    #[case(3, 1, 2)]
+    #[case(7, 2, 1)]
+    #[case(100, 3, 0)]
+    fn stops_at_block_boundaries(
+        document: TeiDocument,
+        #[case] max_words: usize,
+        #[case] kept: usize,
+        #[case] omitted: usize,
+    ) {
+        let preview = document.preview(max_words);
+
+        assert_eq!(preview.document().text().body().blocks().len(), kept);
+        assert_eq!(preview.omitted_blocks(), omitted);
+        assert_eq!(preview.is_truncated(), omitted > 0);
+    }
+
+    #[rstest]
+    fn keeps_header_and_counts_omitted_words(document: TeiDocument) {
+        let preview = document.preview(3);
+
+        assert_eq!(preview.document().header(), document.header());
+        assert_eq!(preview.omitted_words(), 5);
+    }
+}
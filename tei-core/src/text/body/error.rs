@@ -1,5 +1,65 @@
+//! Validation errors raised while assembling TEI body content.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::ErrorCode;
+
+/// Body element that rejected invalid content.
+///
+/// The [`fmt::Display`] form matches the lower-case names used in error
+/// messages so diagnostics read naturally.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{BodyContentError, Container};
+///
+/// let error = BodyContentError::EmptySegment {
+///     container: Container::Utterance,
+/// };
+/// assert_eq!(error.to_string(), "utterance segments may not be empty");
+/// ```
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Container {
+    /// A `<p>` paragraph block.
+    Paragraph,
+    /// A `<u>` utterance block.
+    Utterance,
+    /// An emphasised `<hi>` inline run.
+    Hi,
+    /// An `<unclear>` inline passage.
+    Unclear,
+    /// A `<quote>` inline quotation.
+    Quote,
+    /// A `<note>` inline note.
+    Note,
+}
+
+impl Container {
+    /// Returns the human-readable container name used in messages.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Paragraph => "paragraph",
+            Self::Utterance => "utterance",
+            Self::Hi => "hi",
+            Self::Unclear => "unclear",
+            Self::Quote => "quote",
+            Self::Note => "note",
+        }
+    }
+}
+
+impl fmt::Display for Container {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
 /// Error raised when TEI body content fails validation.
 #[derive(Clone, Debug, Error, Eq, PartialEq)]
 pub enum BodyContentError {
@@ -7,14 +67,14 @@ pub enum BodyContentError {
     #[error("{container} content must include at least one non-empty segment")]
     EmptyContent {
         /// Name of the container that failed validation.
-        container: &'static str,
+        container: Container,
     },
 
     /// A text segment lacked visible characters.
     #[error("{container} segments may not be empty")]
     EmptySegment {
         /// Name of the container that received the invalid segment.
-        container: &'static str,
+        container: Container,
     },
 
     /// A speaker reference was provided but contained no visible characters.
@@ -25,13 +85,59 @@ pub enum BodyContentError {
     #[error("{container} identifiers must not be empty")]
     EmptyIdentifier {
         /// Name of the container that received the invalid identifier.
-        container: &'static str,
+        container: Container,
     },
 
     /// An `xml:id` attribute contained internal whitespace, which is disallowed.
     #[error("{container} identifiers must not contain whitespace")]
     InvalidIdentifier {
         /// Name of the container that received the invalid identifier.
-        container: &'static str,
+        container: Container,
     },
+
+    /// Two elements in the document declared the same `xml:id`.
+    #[error("identifier `{id}` is declared more than once")]
+    DuplicateIdentifier {
+        /// Identifier that was repeated.
+        id: String,
+    },
+
+    /// An utterance named a speaker missing from the profile cast list.
+    #[error("speaker `{speaker}` is not declared in the cast list")]
+    UnknownSpeaker {
+        /// Speaker reference that failed to resolve.
+        speaker: String,
+    },
+
+    /// A pause duration was not a day-time `xsd:duration`.
+    #[error("pause duration `{value}` is not a valid duration")]
+    InvalidPauseDuration {
+        /// Rejected `@dur` value.
+        value: String,
+    },
+
+    /// Pauses in the body add up to more than the recorded audio duration.
+    #[error("pauses total longer than the recorded duration")]
+    PausesExceedDuration,
+
+    /// Raw markup kept for an unmodelled element held no visible characters.
+    #[error("raw markup must not be empty")]
+    EmptyRawMarkup,
+}
+
+impl ErrorCode for BodyContentError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::EmptyContent { .. } => "TEI-BODY-EMPTY-CONTENT",
+            Self::EmptySegment { .. } => "TEI-BODY-EMPTY-SEGMENT",
+            Self::EmptySpeaker => "TEI-BODY-EMPTY-SPEAKER",
+            Self::EmptyIdentifier { .. } => "TEI-BODY-EMPTY-IDENTIFIER",
+            Self::InvalidIdentifier { .. } => "TEI-BODY-INVALID-IDENTIFIER",
+            Self::DuplicateIdentifier { .. } => "TEI-BODY-DUPLICATE-IDENTIFIER",
+            Self::UnknownSpeaker { .. } => "TEI-BODY-UNKNOWN-SPEAKER",
+            Self::InvalidPauseDuration { .. } => "TEI-BODY-INVALID-PAUSE-DURATION",
+            Self::PausesExceedDuration => "TEI-BODY-PAUSES-EXCEED-DURATION",
+            Self::EmptyRawMarkup => "TEI-BODY-EMPTY-RAW-MARKUP",
+        }
+    }
 }
@@ -2,13 +2,17 @@
 //!
 //! Serialises as `<body>` containing `<p>` and `<u>` elements via serde with
 //! blocks stored in the `$value` field.
+//!
+//! Each block sits behind its own [`Arc`], so cloning a body, and therefore a
+//! whole document, copies no inline content. Editing a block copies that
+//! block alone, and only when another clone still shares it.
 
 mod error;
 mod paragraph;
 mod utterance;
 mod validation;
 
-pub use error::BodyContentError;
+pub use error::{BodyContentError, Container};
 pub use paragraph::P;
 pub use utterance::Utterance;
 
@@ -17,14 +21,23 @@ pub(crate) use validation::{
     push_validated_text_segment, set_optional_identifier, trim_preserving_original,
 };
 
-use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::header::LanguageTag;
+use crate::text::{Inline, RawMarkup};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Ordered collection of block-level TEI elements.
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename = "body")]
 pub struct TeiBody {
-    #[serde(rename = "$value", default)]
-    blocks: Vec<BodyBlock>,
+    #[serde(
+        rename = "$value",
+        default,
+        serialize_with = "serialize_blocks",
+        deserialize_with = "deserialize_blocks"
+    )]
+    blocks: Vec<Arc<BodyBlock>>,
 }
 
 impl TeiBody {
@@ -45,35 +58,80 @@ impl TeiBody {
     #[must_use]
     pub fn new(blocks: impl IntoIterator<Item = BodyBlock>) -> Self {
         Self {
-            blocks: blocks.into_iter().collect(),
+            blocks: blocks.into_iter().map(Arc::new).collect(),
         }
     }
 
     /// Appends a paragraph block to the body.
     pub fn push_paragraph(&mut self, paragraph: P) {
-        self.blocks.push(BodyBlock::Paragraph(paragraph));
+        self.blocks.push(Arc::new(BodyBlock::Paragraph(paragraph)));
     }
 
     /// Appends an utterance block to the body.
     pub fn push_utterance(&mut self, utterance: Utterance) {
-        self.blocks.push(BodyBlock::Utterance(utterance));
+        self.blocks.push(Arc::new(BodyBlock::Utterance(utterance)));
+    }
+
+    /// Returns the block at `index` for in-place editing, or `None` when the
+    /// body is shorter.
+    ///
+    /// The block is copied first if a clone still shares it; the other blocks
+    /// stay shared.
+    pub fn block_mut(&mut self, index: usize) -> Option<&mut BodyBlock> {
+        self.blocks.get_mut(index).map(Arc::make_mut)
     }
 
     /// Extends the body with additional blocks.
     pub fn extend(&mut self, blocks: impl IntoIterator<Item = BodyBlock>) {
-        self.blocks.extend(blocks);
+        self.blocks.extend(blocks.into_iter().map(Arc::new));
+    }
+
+    /// Returns an iterator over the recorded blocks in document order.
+    #[must_use = "Iterators are lazy; iterate or collect to inspect blocks."]
+    pub fn blocks(&self) -> impl DoubleEndedIterator<Item = &BodyBlock> + ExactSizeIterator {
+        self.blocks.iter().map(AsRef::as_ref)
+    }
+
+    /// Returns the block at `index`, or `None` when the body is shorter.
+    #[must_use]
+    pub fn block(&self, index: usize) -> Option<&BodyBlock> {
+        self.blocks.get(index).map(AsRef::as_ref)
     }
 
-    /// Returns the recorded blocks.
+    /// Reports whether two bodies still share every block.
+    ///
+    /// Clones share their blocks until one side is edited, so diffing a
+    /// document against an untouched clone can skip the block comparison.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::{P, TeiBody};
+    ///
+    /// let mut body = TeiBody::default();
+    /// body.push_paragraph(P::from_text_segments(["Setup"])?);
+    /// let mut copy = body.clone();
+    /// assert!(body.shares_blocks_with(&copy));
+    ///
+    /// copy.push_paragraph(P::from_text_segments(["Payoff"])?);
+    /// assert!(!body.shares_blocks_with(&copy));
+    /// assert_eq!(body.blocks().len(), 1);
+    /// # Ok::<(), tei_core::BodyContentError>(())
+    /// ```
     #[must_use]
-    pub const fn blocks(&self) -> &[BodyBlock] {
-        self.blocks.as_slice()
+    pub fn shares_blocks_with(&self, other: &Self) -> bool {
+        self.blocks.len() == other.blocks.len()
+            && self
+                .blocks
+                .iter()
+                .zip(&other.blocks)
+                .all(|(ours, theirs)| Arc::ptr_eq(ours, theirs))
     }
 
     /// Returns an iterator over recorded paragraphs.
     #[must_use = "Iterators are lazy; iterate or collect to inspect paragraphs."]
     pub fn paragraphs(&self) -> impl Iterator<Item = &P> {
-        self.blocks.iter().filter_map(|block| {
+        self.blocks().filter_map(|block| {
             if let BodyBlock::Paragraph(paragraph) = block {
                 Some(paragraph)
             } else {
@@ -85,7 +143,7 @@ impl TeiBody {
     /// Returns an iterator over recorded utterances.
     #[must_use = "Iterators are lazy; iterate or collect to inspect utterances."]
     pub fn utterances(&self) -> impl Iterator<Item = &Utterance> {
-        self.blocks.iter().filter_map(|block| {
+        self.blocks().filter_map(|block| {
             if let BodyBlock::Utterance(utterance) = block {
                 Some(utterance)
             } else {
@@ -94,6 +152,25 @@ impl TeiBody {
         })
     }
 
+    pub(crate) fn utterances_mut(&mut self) -> impl Iterator<Item = &mut Utterance> {
+        self.blocks
+            .iter_mut()
+            .filter(|block| matches!(***block, BodyBlock::Utterance(_)))
+            .filter_map(|block| {
+                if let BodyBlock::Utterance(utterance) = Arc::make_mut(block) {
+                    Some(utterance)
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Counts whitespace-separated words across every block.
+    #[must_use]
+    pub fn word_count(&self) -> usize {
+        self.blocks().map(BodyBlock::word_count).sum()
+    }
+
     /// Reports whether the body contains any blocks.
     #[must_use]
     pub const fn is_empty(&self) -> bool {
@@ -101,6 +178,21 @@ impl TeiBody {
     }
 }
 
+fn serialize_blocks<S>(blocks: &[Arc<BodyBlock>], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(blocks.iter().map(AsRef::as_ref))
+}
+
+fn deserialize_blocks<'de, D>(deserializer: D) -> Result<Vec<Arc<BodyBlock>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<BodyBlock>::deserialize(deserializer)
+        .map(|blocks| blocks.into_iter().map(Arc::new).collect())
+}
+
 /// Block-level body content.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum BodyBlock {
@@ -110,6 +202,54 @@ pub enum BodyBlock {
     /// A spoken utterance.
     #[serde(rename = "u")]
     Utterance(Utterance),
+    /// An element the data model does not cover, emitted verbatim.
+    #[serde(rename = "raw")]
+    Raw(RawMarkup),
+}
+
+impl BodyBlock {
+    /// Returns the `xml:lang` declared directly on the block, if any.
+    #[must_use]
+    pub fn lang(&self) -> Option<&LanguageTag> {
+        match self {
+            Self::Paragraph(paragraph) => paragraph.lang(),
+            Self::Utterance(utterance) => utterance.lang(),
+            Self::Raw(_) => None,
+        }
+    }
+
+    /// Counts whitespace-separated words in the block, including text nested
+    /// in `<hi>` runs but not the commentary in `<note>`.
+    #[must_use]
+    pub fn word_count(&self) -> usize {
+        match self {
+            Self::Paragraph(paragraph) => count_words(paragraph.content()),
+            Self::Utterance(utterance) => count_words(utterance.content()),
+            Self::Raw(_) => 0,
+        }
+    }
+
+    /// Resolves the block language, falling back to the inherited value.
+    #[must_use]
+    pub fn resolve_lang<'a>(
+        &'a self,
+        inherited: Option<&'a LanguageTag>,
+    ) -> Option<&'a LanguageTag> {
+        self.lang().or(inherited)
+    }
+}
+
+fn count_words(content: &[Inline]) -> usize {
+    content
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => text.split_whitespace().count(),
+            Inline::Hi(hi) => count_words(hi.content()),
+            Inline::Unclear(unclear) => count_words(unclear.content()),
+            Inline::Quote(quote) => count_words(quote.content()),
+            Inline::Pause(_) | Inline::Note(_) | Inline::Raw(_) => 0,
+        })
+        .sum()
 }
 
 #[cfg(test)]
@@ -130,4 +270,110 @@ mod tests {
         assert_eq!(body.paragraphs().collect::<Vec<_>>(), vec![&paragraph]);
         assert_eq!(body.utterances().collect::<Vec<_>>(), vec![&utterance]);
     }
+
+    #[test]
+    fn mutating_a_clone_leaves_the_original_untouched() {
+        let utterance = Utterance::from_text_segments(Some("host"), ["Hello"])
+            .unwrap_or_else(|error| panic!("valid utterance: {error}"));
+        let original = TeiBody::new([BodyBlock::Utterance(utterance)]);
+        let mut copy = original.clone();
+
+        for speaker_utterance in copy.utterances_mut() {
+            speaker_utterance
+                .set_speaker("guest")
+                .unwrap_or_else(|error| panic!("valid speaker: {error}"));
+        }
+
+        assert!(!original.shares_blocks_with(&copy));
+        assert_eq!(
+            original
+                .utterances()
+                .filter_map(Utterance::speaker)
+                .map(crate::Speaker::as_str)
+                .collect::<Vec<_>>(),
+            ["host"]
+        );
+    }
+
+    #[test]
+    fn editing_a_clone_copies_only_the_edited_block() {
+        let paragraph = P::from_text_segments(["Setup"])
+            .unwrap_or_else(|error| panic!("valid paragraph: {error}"));
+        let utterance = Utterance::from_text_segments(Some("host"), ["Hello"])
+            .unwrap_or_else(|error| panic!("valid utterance: {error}"));
+        let original = TeiBody::new([
+            BodyBlock::Paragraph(paragraph.clone()),
+            BodyBlock::Utterance(utterance),
+            BodyBlock::Paragraph(paragraph),
+        ]);
+        let mut copy = original.clone();
+
+        if let Some(BodyBlock::Utterance(edited)) = copy.block_mut(1) {
+            edited
+                .set_speaker("guest")
+                .unwrap_or_else(|error| panic!("valid speaker: {error}"));
+        }
+
+        let shared: Vec<_> = original
+            .blocks
+            .iter()
+            .zip(&copy.blocks)
+            .map(|(ours, theirs)| Arc::ptr_eq(ours, theirs))
+            .collect();
+        assert_eq!(shared, [true, false, true]);
+        assert_ne!(original, copy);
+    }
+
+    #[test]
+    fn block_mut_edits_one_block() {
+        let paragraph = P::from_text_segments(["Setup"])
+            .unwrap_or_else(|error| panic!("valid paragraph: {error}"));
+        let mut body = TeiBody::new([BodyBlock::Paragraph(paragraph)]);
+
+        if let Some(BodyBlock::Paragraph(edited)) = body.block_mut(0) {
+            edited
+                .set_id("p1")
+                .unwrap_or_else(|error| panic!("valid identifier: {error}"));
+        }
+
+        assert_eq!(
+            body.paragraphs()
+                .filter_map(P::id)
+                .map(crate::XmlId::as_str)
+                .collect::<Vec<_>>(),
+            ["p1"]
+        );
+        assert!(body.block_mut(1).is_none());
+    }
+
+    #[test]
+    fn word_count_includes_emphasised_text() {
+        let utterance = Utterance::from_inline(
+            Some("host"),
+            [
+                Inline::text("Welcome back to "),
+                Inline::pause(),
+                Inline::hi([Inline::text("Night Vale")]),
+            ],
+        )
+        .unwrap_or_else(|error| panic!("valid utterance: {error}"));
+        let body = TeiBody::new([BodyBlock::Utterance(utterance)]);
+
+        assert_eq!(body.word_count(), 5);
+    }
+
+    #[test]
+    fn block_language_overrides_inherited_language() {
+        let english = LanguageTag::new("en").unwrap_or_else(|error| panic!("valid tag: {error}"));
+        let welsh = LanguageTag::new("cy").unwrap_or_else(|error| panic!("valid tag: {error}"));
+        let mut paragraph = P::from_text_segments(["Bore da"])
+            .unwrap_or_else(|error| panic!("valid paragraph: {error}"));
+        let inheriting = BodyBlock::Paragraph(paragraph.clone());
+        paragraph.set_lang(welsh.clone());
+        let declaring = BodyBlock::Paragraph(paragraph);
+
+        assert_eq!(inheriting.resolve_lang(Some(&english)), Some(&english));
+        assert_eq!(declaring.resolve_lang(Some(&english)), Some(&welsh));
+        assert_eq!(declaring.lang(), Some(&welsh));
+    }
 }
@@ -3,11 +3,12 @@
 //! Defines the TEI `<p>` block with helper constructors that validate inline
 //! segments and optional `xml:id` attributes.
 
+use crate::header::LanguageTag;
 use crate::text::{Inline, types::XmlId};
 
 use super::{
-    BodyContentError, ensure_container_content, push_validated_inline, push_validated_text_segment,
-    set_optional_identifier,
+    BodyContentError, Container, ensure_container_content, push_validated_inline,
+    push_validated_text_segment, set_optional_identifier,
 };
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +23,13 @@ pub struct P {
         default
     )]
     id: Option<XmlId>,
+    #[serde(
+        rename = "@xml:lang",
+        alias = "@lang",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    lang: Option<LanguageTag>,
     #[serde(rename = "$value", default)]
     content: Vec<Inline>,
 }
@@ -63,11 +71,15 @@ impl P {
     {
         let mut content = Vec::new();
         for segment in segments {
-            push_validated_text_segment(&mut content, segment, "paragraph")?;
+            push_validated_text_segment(&mut content, segment, Container::Paragraph)?;
         }
-        ensure_container_content(&content, "paragraph")?;
+        ensure_container_content(&content, Container::Paragraph)?;
 
-        Ok(Self { id: None, content })
+        Ok(Self {
+            id: None,
+            lang: None,
+            content,
+        })
     }
 
     /// Builds a paragraph from pre-constructed inline content.
@@ -80,10 +92,11 @@ impl P {
         content: impl IntoIterator<Item = Inline>,
     ) -> Result<Self, BodyContentError> {
         let collected: Vec<Inline> = content.into_iter().collect();
-        ensure_container_content(&collected, "paragraph")?;
+        ensure_container_content(&collected, Container::Paragraph)?;
 
         Ok(Self {
             id: None,
+            lang: None,
             content: collected,
         })
     }
@@ -97,7 +110,7 @@ impl P {
     /// [`BodyContentError::InvalidIdentifier`] when the identifier contains
     /// internal whitespace.
     pub fn set_id(&mut self, id: impl Into<String>) -> Result<(), BodyContentError> {
-        set_optional_identifier(&mut self.id, id, "paragraph")
+        set_optional_identifier(&mut self.id, id, Container::Paragraph)
     }
 
     /// Clears any associated `xml:id`.
@@ -115,6 +128,53 @@ impl P {
         self.id.as_ref()
     }
 
+    /// Returns the `xml:lang` declared on the paragraph, if any.
+    #[must_use]
+    #[expect(
+        clippy::missing_const_for_fn,
+        reason = "Option::as_ref is not const-stable on current MSRV."
+    )]
+    pub fn lang(&self) -> Option<&LanguageTag> {
+        self.lang.as_ref()
+    }
+
+    /// Declares the language of the paragraph via `xml:lang`.
+    pub fn set_lang(&mut self, lang: LanguageTag) {
+        self.lang = Some(lang);
+    }
+
+    /// Removes the `xml:lang` declaration so the paragraph inherits its language.
+    pub fn clear_lang(&mut self) {
+        self.lang = None;
+    }
+
+    /// Resolves the effective language, falling back to the inherited value.
+    ///
+    /// TEI scopes `xml:lang` to the element and its descendants, so a
+    /// paragraph without its own declaration speaks the language of its parent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::{LanguageTag, P};
+    ///
+    /// let english = LanguageTag::new("en")?;
+    /// let mut paragraph = P::from_text_segments(["Croeso"])?;
+    /// assert_eq!(paragraph.resolve_lang(Some(&english)), Some(&english));
+    ///
+    /// let welsh = LanguageTag::new("cy")?;
+    /// paragraph.set_lang(welsh.clone());
+    /// assert_eq!(paragraph.resolve_lang(Some(&english)), Some(&welsh));
+    /// # Ok::<(), tei_core::TeiError>(())
+    /// ```
+    #[must_use]
+    pub fn resolve_lang<'a>(
+        &'a self,
+        inherited: Option<&'a LanguageTag>,
+    ) -> Option<&'a LanguageTag> {
+        self.lang.as_ref().or(inherited)
+    }
+
     /// Returns the stored segments.
     #[must_use]
     pub const fn content(&self) -> &[Inline] {
@@ -131,7 +191,7 @@ impl P {
     where
         S: Into<String>,
     {
-        push_validated_text_segment(&mut self.content, segment, "paragraph")
+        push_validated_text_segment(&mut self.content, segment, Container::Paragraph)
     }
 
     /// Appends a new inline node.
@@ -142,7 +202,7 @@ impl P {
     /// visible characters. Returns [`BodyContentError::EmptyContent`] when the
     /// inline element has no meaningful children.
     pub fn push_inline(&mut self, inline: Inline) -> Result<(), BodyContentError> {
-        push_validated_inline(&mut self.content, inline, "paragraph")
+        push_validated_inline(&mut self.content, inline, Container::Paragraph)
     }
 }
 
@@ -169,21 +229,21 @@ mod tests {
         let result = P::from_text_segments(Vec::<String>::new());
         assert!(matches!(
             result,
-            Err(BodyContentError::EmptyContent { container }) if container == "paragraph"
+            Err(BodyContentError::EmptyContent { container }) if container == Container::Paragraph
         ));
     }
 
     #[rstest]
     #[case::paragraph(
-        "paragraph",
+        Container::Paragraph,
         set_paragraph_identifier as fn(&str) -> Result<(), BodyContentError>,
     )]
     #[case::utterance(
-        "utterance",
+        Container::Utterance,
         set_utterance_identifier as fn(&str) -> Result<(), BodyContentError>,
     )]
     fn rejects_identifier_with_whitespace(
-        #[case] container: &'static str,
+        #[case] container: Container,
         #[case] constructor: fn(&str) -> Result<(), BodyContentError>,
     ) {
         let Err(error) = constructor("identifier with space") else {
@@ -1,16 +1,19 @@
 //! Spoken utterances with optional speaker metadata and inline content.
 //!
 //! Serialises as `<u who="…">…</u>` with mixed [`Inline`] nodes inside the
-//! `$value` field so emphasis and pause cues are preserved.
+//! `$value` field so emphasis and pause cues are preserved. `@start` and
+//! `@end` point at [`Timeline`](crate::Timeline) entries when the utterance is
+//! timed.
 
+use crate::header::LanguageTag;
 use crate::text::{
     Inline,
     types::{Speaker, SpeakerValidationError, XmlId},
 };
 
 use super::{
-    BodyContentError, ensure_container_content, normalise_optional_speaker, push_validated_inline,
-    push_validated_text_segment, set_optional_identifier,
+    BodyContentError, Container, ensure_container_content, normalise_optional_speaker,
+    push_validated_inline, push_validated_text_segment, set_optional_identifier,
 };
 use serde::{Deserialize, Serialize};
 
@@ -25,8 +28,19 @@ pub struct Utterance {
         default
     )]
     id: Option<XmlId>,
+    #[serde(
+        rename = "@xml:lang",
+        alias = "@lang",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    lang: Option<LanguageTag>,
     #[serde(rename = "@who", skip_serializing_if = "Option::is_none", default)]
     speaker: Option<Speaker>,
+    #[serde(rename = "@start", skip_serializing_if = "Option::is_none", default)]
+    start: Option<String>,
+    #[serde(rename = "@end", skip_serializing_if = "Option::is_none", default)]
+    end: Option<String>,
     #[serde(rename = "$value", default)]
     content: Vec<Inline>,
 }
@@ -78,13 +92,16 @@ impl Utterance {
         let normalised_speaker = normalise_optional_speaker(speaker)?;
         let mut content = Vec::new();
         for segment in segments {
-            push_validated_text_segment(&mut content, segment, "utterance")?;
+            push_validated_text_segment(&mut content, segment, Container::Utterance)?;
         }
-        ensure_container_content(&content, "utterance")?;
+        ensure_container_content(&content, Container::Utterance)?;
 
         Ok(Self {
             id: None,
+            lang: None,
             speaker: normalised_speaker,
+            start: None,
+            end: None,
             content,
         })
     }
@@ -104,11 +121,14 @@ impl Utterance {
     {
         let normalised_speaker = normalise_optional_speaker(speaker)?;
         let collected: Vec<Inline> = content.into_iter().collect();
-        ensure_container_content(&collected, "utterance")?;
+        ensure_container_content(&collected, Container::Utterance)?;
 
         Ok(Self {
             id: None,
+            lang: None,
             speaker: normalised_speaker,
+            start: None,
+            end: None,
             content: collected,
         })
     }
@@ -122,7 +142,7 @@ impl Utterance {
     /// [`BodyContentError::InvalidIdentifier`] when the identifier contains
     /// internal whitespace.
     pub fn set_id(&mut self, id: impl Into<String>) -> Result<(), BodyContentError> {
-        set_optional_identifier(&mut self.id, id, "utterance")
+        set_optional_identifier(&mut self.id, id, Container::Utterance)
     }
 
     /// Clears any associated `xml:id`.
@@ -140,6 +160,53 @@ impl Utterance {
         self.id.as_ref()
     }
 
+    /// Returns the `xml:lang` declared on the utterance, if any.
+    #[must_use]
+    #[expect(
+        clippy::missing_const_for_fn,
+        reason = "Option::as_ref is not const-stable on current MSRV."
+    )]
+    pub fn lang(&self) -> Option<&LanguageTag> {
+        self.lang.as_ref()
+    }
+
+    /// Declares the language of the utterance via `xml:lang`.
+    pub fn set_lang(&mut self, lang: LanguageTag) {
+        self.lang = Some(lang);
+    }
+
+    /// Removes the `xml:lang` declaration so the utterance inherits its language.
+    pub fn clear_lang(&mut self) {
+        self.lang = None;
+    }
+
+    /// Resolves the effective language, falling back to the inherited value.
+    ///
+    /// TEI scopes `xml:lang` to the element and its descendants, so a
+    /// utterance without its own declaration speaks the language of its parent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::{LanguageTag, Utterance};
+    ///
+    /// let english = LanguageTag::new("en")?;
+    /// let mut utterance = Utterance::from_text_segments(Some("host"), ["Hola"])?;
+    /// assert_eq!(utterance.resolve_lang(Some(&english)), Some(&english));
+    ///
+    /// let spanish = LanguageTag::new("es")?;
+    /// utterance.set_lang(spanish.clone());
+    /// assert_eq!(utterance.resolve_lang(None), Some(&spanish));
+    /// # Ok::<(), tei_core::TeiError>(())
+    /// ```
+    #[must_use]
+    pub fn resolve_lang<'a>(
+        &'a self,
+        inherited: Option<&'a LanguageTag>,
+    ) -> Option<&'a LanguageTag> {
+        self.lang.as_ref().or(inherited)
+    }
+
     /// Assigns the speaker responsible for the utterance.
     ///
     /// # Errors
@@ -156,6 +223,10 @@ impl Utterance {
         }
     }
 
+    pub(crate) fn replace_speaker(&mut self, speaker: Speaker) {
+        self.speaker = Some(speaker);
+    }
+
     /// Clears the recorded speaker.
     pub fn clear_speaker(&mut self) {
         self.speaker = None;
@@ -171,6 +242,54 @@ impl Utterance {
         self.speaker.as_ref()
     }
 
+    /// Anchors the utterance to the timeline points it starts and ends at.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use tei_core::{Timeline, Utterance};
+    ///
+    /// let mut timeline = Timeline::new();
+    /// let start = timeline.add_offset(Duration::from_secs(1));
+    /// let end = timeline.add_offset(Duration::from_secs(3));
+    /// let mut utterance = Utterance::from_text_segments(Some("eiffel"), ["Day 1."])?;
+    /// utterance.set_timing(&start, &end);
+    ///
+    /// assert_eq!(utterance.start(), Some("#T1"));
+    /// assert_eq!(utterance.end().and_then(|end| timeline.offset(end)), Some(Duration::from_secs(3)));
+    /// # Ok::<(), tei_core::BodyContentError>(())
+    /// ```
+    pub fn set_timing(&mut self, start: &XmlId, end: &XmlId) {
+        self.start = Some(format!("#{start}"));
+        self.end = Some(format!("#{end}"));
+    }
+
+    /// Stores `@start` and `@end` pointers read from another representation.
+    pub(crate) fn restore_timing(&mut self, start: Option<String>, end: Option<String>) {
+        self.start = start;
+        self.end = end;
+    }
+
+    /// Removes the `@start` and `@end` pointers.
+    pub fn clear_timing(&mut self) {
+        self.start = None;
+        self.end = None;
+    }
+
+    /// Returns the `@start` pointer into the timeline, when timed.
+    #[must_use]
+    pub fn start(&self) -> Option<&str> {
+        self.start.as_deref()
+    }
+
+    /// Returns the `@end` pointer into the timeline, when timed.
+    #[must_use]
+    pub fn end(&self) -> Option<&str> {
+        self.end.as_deref()
+    }
+
     /// Returns the stored segments.
     #[must_use]
     #[expect(
@@ -191,7 +310,7 @@ impl Utterance {
     where
         S: Into<String>,
     {
-        push_validated_text_segment(&mut self.content, segment, "utterance")
+        push_validated_text_segment(&mut self.content, segment, Container::Utterance)
     }
 
     /// Appends a new inline node.
@@ -202,7 +321,7 @@ impl Utterance {
     /// visible characters. Returns [`BodyContentError::EmptyContent`] when the
     /// inline element has no meaningful children.
     pub fn push_inline(&mut self, inline: Inline) -> Result<(), BodyContentError> {
-        push_validated_inline(&mut self.content, inline, "utterance")
+        push_validated_inline(&mut self.content, inline, Container::Utterance)
     }
 }
 
@@ -215,7 +334,7 @@ mod tests {
         let result = Utterance::from_text_segments::<String, String>(None, Vec::<String>::new());
         assert!(matches!(
             result,
-            Err(BodyContentError::EmptyContent { container }) if container == "utterance"
+            Err(BodyContentError::EmptyContent { container }) if container == Container::Utterance
         ));
     }
 
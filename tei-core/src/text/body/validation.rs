@@ -8,11 +8,11 @@ use crate::text::{
     types::{IdentifierValidationError, Speaker, SpeakerValidationError, XmlId},
 };
 
-use super::BodyContentError;
+use super::{BodyContentError, Container};
 
 pub(crate) fn ensure_container_content(
     content: &[Inline],
-    container: &'static str,
+    container: Container,
 ) -> Result<(), BodyContentError> {
     if content.is_empty() {
         return Err(BodyContentError::EmptyContent { container });
@@ -52,7 +52,7 @@ pub(crate) fn trim_preserving_original(value: String) -> String {
 pub(crate) fn set_optional_identifier(
     field: &mut Option<XmlId>,
     value: impl Into<String>,
-    container: &'static str,
+    container: Container,
 ) -> Result<(), BodyContentError> {
     match XmlId::try_from(value.into()) {
         Ok(identifier) => {
@@ -71,7 +71,7 @@ pub(crate) fn set_optional_identifier(
 pub(crate) fn push_validated_text_segment(
     content: &mut Vec<Inline>,
     segment: impl Into<String>,
-    container: &'static str,
+    container: Container,
 ) -> Result<(), BodyContentError> {
     let inline = Inline::text(segment.into());
     validate_inline(&inline, container)?;
@@ -83,7 +83,7 @@ pub(crate) fn push_validated_text_segment(
 pub(crate) fn push_validated_inline(
     content: &mut Vec<Inline>,
     inline: Inline,
-    container: &'static str,
+    container: Container,
 ) -> Result<(), BodyContentError> {
     validate_inline(&inline, container)?;
     content.push(inline);
@@ -91,7 +91,7 @@ pub(crate) fn push_validated_inline(
     Ok(())
 }
 
-fn validate_inline(inline: &Inline, container: &'static str) -> Result<(), BodyContentError> {
+fn validate_inline(inline: &Inline, container: Container) -> Result<(), BodyContentError> {
     match inline {
         Inline::Text(text) => {
             if text.trim().is_empty() {
@@ -101,6 +101,9 @@ fn validate_inline(inline: &Inline, container: &'static str) -> Result<(), BodyC
             Ok(())
         }
         Inline::Hi(hi) => ensure_container_content(hi.content(), container),
-        Inline::Pause(_) => Ok(()),
+        Inline::Unclear(unclear) => ensure_container_content(unclear.content(), container),
+        Inline::Quote(quote) => ensure_container_content(quote.content(), container),
+        Inline::Note(note) => ensure_container_content(note.content(), container),
+        Inline::Pause(_) | Inline::Raw(_) => Ok(()),
     }
 }
@@ -1,75 +1,28 @@
-//! Inline TEI content such as emphasised runs and pauses.
+//! Emphasised runs.
 //!
-//! Mixed content is modelled as an [`Inline`] enum so paragraphs and utterances
-//! can hold either plain text or nested inline elements.
+//! Serialises as `<hi>` around the emphasised words, with an optional `rend`
+//! hint describing how the emphasis was rendered or spoken.
 
-use super::body::{BodyContentError, ensure_container_content, push_validated_inline};
 use serde::de::{self, Deserializer};
 use serde::{Deserialize, Serialize};
 
-/// Inline content occurring inside paragraphs and utterances.
-///
-/// # Examples
-///
-/// ```
-/// use tei_core::{Hi, Inline, P};
-///
-/// let emphasis = Inline::Hi(Hi::new([Inline::text("important")]));
-/// let paragraph = match P::from_inline([Inline::text("An "), emphasis]) {
-///     Ok(paragraph) => paragraph,
-///     Err(error) => panic!("paragraph should be valid: {error}"),
-/// };
-///
-/// assert_eq!(paragraph.content().len(), 2);
-/// ```
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
-#[serde(untagged)]
-pub enum Inline {
-    /// Plain text content.
-    Text(String),
-    /// Emphasised content wrapped in `<hi>`.
-    Hi(Hi),
-    /// A pause marker rendered as `<pause/>`.
-    Pause(Pause),
-}
-
-impl Inline {
-    /// Builds a plain text inline node.
-    #[must_use]
-    pub fn text(content: impl Into<String>) -> Self {
-        Self::Text(content.into())
-    }
-
-    /// Builds an emphasised inline node.
-    #[must_use]
-    pub fn hi(content: impl IntoIterator<Item = Self>) -> Self {
-        Self::Hi(Hi::new(content))
-    }
-
-    /// Builds a pause marker.
-    #[must_use]
-    pub const fn pause() -> Self {
-        Self::Pause(Pause::new())
-    }
-
-    /// Returns the contained text when this variant is [`Inline::Text`].
-    #[must_use]
-    #[expect(
-        clippy::missing_const_for_fn,
-        reason = "String::as_str is not const-stable on the current MSRV."
-    )]
-    pub fn as_text(&self) -> Option<&str> {
-        match self {
-            Self::Text(value) => Some(value.as_str()),
-            _ => None,
-        }
-    }
-}
+use super::Inline;
+use crate::header::LanguageTag;
+use crate::text::body::{
+    BodyContentError, Container, ensure_container_content, push_validated_inline,
+};
 
 /// Emphasised inline element corresponding to `<hi>`.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
 #[serde(rename = "hi")]
 pub struct Hi {
+    #[serde(
+        rename = "@xml:lang",
+        alias = "@lang",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    lang: Option<LanguageTag>,
     #[serde(rename = "rend", skip_serializing_if = "Option::is_none", default)]
     rend: Option<String>,
     #[serde(rename = "$value", default)]
@@ -84,16 +37,22 @@ impl<'de> Deserialize<'de> for Hi {
         #[derive(Deserialize)]
         #[serde(deny_unknown_fields)]
         struct RawHi {
+            #[serde(rename = "@xml:lang", alias = "@lang", default)]
+            lang: Option<LanguageTag>,
             #[serde(rename = "rend", default)]
             rend: Option<String>,
+            // Whitespace handling is resolved by the XML layer before parsing.
+            #[serde(rename = "@xml:space", alias = "@space", default)]
+            _space: Option<String>,
             #[serde(rename = "$value", default)]
             content: Vec<Inline>,
         }
 
         let raw = RawHi::deserialize(deserializer)?;
-        ensure_container_content(&raw.content, "hi").map_err(de::Error::custom)?;
+        ensure_container_content(&raw.content, Container::Hi).map_err(de::Error::custom)?;
 
         Ok(Self {
+            lang: raw.lang,
             rend: raw.rend,
             content: raw.content,
         })
@@ -124,7 +83,7 @@ impl Hi {
     /// meaningful content.
     pub fn try_new(content: impl IntoIterator<Item = Inline>) -> Result<Self, BodyContentError> {
         let collected: Vec<Inline> = content.into_iter().collect();
-        ensure_container_content(&collected, "hi")?;
+        ensure_container_content(&collected, Container::Hi)?;
 
         Ok(Self::from_parts(None, collected))
     }
@@ -142,7 +101,7 @@ impl Hi {
         content: impl IntoIterator<Item = Inline>,
     ) -> Result<Self, BodyContentError> {
         let collected: Vec<Inline> = content.into_iter().collect();
-        ensure_container_content(&collected, "hi")?;
+        ensure_container_content(&collected, Container::Hi)?;
 
         Ok(Self::from_parts(Some(rend.into()), collected))
     }
@@ -163,6 +122,50 @@ impl Hi {
         self.rend = None;
     }
 
+    /// Returns the `xml:lang` declared on the emphasised run, if any.
+    #[must_use]
+    #[expect(
+        clippy::missing_const_for_fn,
+        reason = "Option::as_ref is not const-stable on current MSRV."
+    )]
+    pub fn lang(&self) -> Option<&LanguageTag> {
+        self.lang.as_ref()
+    }
+
+    /// Declares the language of the emphasised run via `xml:lang`.
+    pub fn set_lang(&mut self, lang: LanguageTag) {
+        self.lang = Some(lang);
+    }
+
+    /// Removes the `xml:lang` declaration so the run inherits its language.
+    pub fn clear_lang(&mut self) {
+        self.lang = None;
+    }
+
+    /// Resolves the effective language, falling back to the inherited value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::{Hi, Inline, LanguageTag};
+    ///
+    /// let english = LanguageTag::new("en")?;
+    /// let mut hi = Hi::new([Inline::text("bonjour")]);
+    /// assert_eq!(hi.resolve_lang(Some(&english)), Some(&english));
+    ///
+    /// let french = LanguageTag::new("fr")?;
+    /// hi.set_lang(french.clone());
+    /// assert_eq!(hi.resolve_lang(Some(&english)), Some(&french));
+    /// # Ok::<(), tei_core::HeaderValidationError>(())
+    /// ```
+    #[must_use]
+    pub fn resolve_lang<'a>(
+        &'a self,
+        inherited: Option<&'a LanguageTag>,
+    ) -> Option<&'a LanguageTag> {
+        self.lang.as_ref().or(inherited)
+    }
+
     /// Returns the inline children.
     #[must_use]
     #[expect(
@@ -181,7 +184,7 @@ impl Hi {
     /// visible characters. Returns [`BodyContentError::EmptyContent`] when a
     /// nested inline element has no meaningful children.
     pub fn push_inline(&mut self, inline: Inline) -> Result<(), BodyContentError> {
-        push_validated_inline(&mut self.content, inline, "hi")
+        push_validated_inline(&mut self.content, inline, Container::Hi)
     }
 
     #[expect(
@@ -189,97 +192,26 @@ impl Hi {
         reason = "Vec values are not const-constructible on the current MSRV."
     )]
     fn from_parts(rend: Option<String>, content: Vec<Inline>) -> Self {
-        Self { rend, content }
-    }
-}
-
-/// Pause marker rendered as `<pause/>`.
-#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
-#[serde(rename = "pause", deny_unknown_fields)]
-pub struct Pause {
-    #[serde(rename = "@dur", skip_serializing_if = "Option::is_none", default)]
-    duration: Option<String>,
-    #[serde(rename = "@type", skip_serializing_if = "Option::is_none", default)]
-    pause_type: Option<String>,
-}
-
-impl Pause {
-    /// Creates an empty pause marker.
-    #[must_use]
-    pub const fn new() -> Self {
         Self {
-            duration: None,
-            pause_type: None,
+            lang: None,
+            rend,
+            content,
         }
     }
-
-    /// Returns the recorded duration.
-    #[must_use]
-    pub fn duration(&self) -> Option<&str> {
-        self.duration.as_deref()
-    }
-
-    /// Assigns a duration value.
-    pub fn set_duration(&mut self, duration: impl Into<String>) {
-        self.duration = Some(duration.into());
-    }
-
-    /// Clears the recorded duration.
-    pub fn clear_duration(&mut self) {
-        self.duration = None;
-    }
-
-    /// Returns the pause classification.
-    #[must_use]
-    pub fn kind(&self) -> Option<&str> {
-        self.pause_type.as_deref()
-    }
-
-    /// Assigns a pause classification.
-    pub fn set_kind(&mut self, kind: impl Into<String>) {
-        self.pause_type = Some(kind.into());
-    }
-
-    /// Clears the pause classification.
-    pub fn clear_kind(&mut self) {
-        self.pause_type = None;
-    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-    use crate::text::BodyContentError;
     use rstest::{fixture, rstest};
     use serde_json as json;
 
+    use super::*;
+
     #[fixture]
     fn emphasised_inline() -> Inline {
         Inline::text("emphasis")
     }
 
-    #[fixture]
-    fn empty_pause() -> Pause {
-        Pause::new()
-    }
-
-    // Asserts that deserialising an [`Inline`] value fails with a matching error.
-    fn assert_inline_deserialisation_error(
-        payload: &str,
-        expected_error_substring: &str,
-        description: &str,
-    ) {
-        let Err(error) = json::from_str::<Inline>(payload) else {
-            panic!("{description}");
-        };
-        let message = error.to_string();
-
-        assert!(
-            message.contains(expected_error_substring),
-            "{description}: {message}"
-        );
-    }
-
     #[rstest]
     fn hi_records_children(emphasised_inline: Inline) {
         let hi = Hi::try_new([emphasised_inline.clone()])
@@ -290,15 +222,6 @@ mod tests {
         assert_eq!(content.first().and_then(Inline::as_text), Some("emphasis"));
     }
 
-    #[rstest]
-    fn pause_records_duration_and_kind(mut empty_pause: Pause) {
-        empty_pause.set_duration("PT1S");
-        empty_pause.set_kind("breath");
-
-        assert_eq!(empty_pause.duration(), Some("PT1S"));
-        assert_eq!(empty_pause.kind(), Some("breath"));
-    }
-
     #[rstest]
     fn hi_try_with_rend_records_hint(emphasised_inline: Inline) {
         let hi = Hi::try_with_rend("stress", [emphasised_inline.clone()])
@@ -315,7 +238,7 @@ mod tests {
 
         assert!(matches!(
             result,
-            Err(BodyContentError::EmptyContent { container }) if container == "hi"
+            Err(BodyContentError::EmptyContent { container }) if container == Container::Hi
         ));
     }
 
@@ -328,26 +251,24 @@ mod tests {
 
         assert!(matches!(
             result,
-            Err(BodyContentError::EmptySegment { container }) if container == "hi"
+            Err(BodyContentError::EmptySegment { container }) if container == Container::Hi
         ));
     }
 
     #[rstest]
-    fn inline_deserialisation_reports_type_mismatch() {
-        assert_inline_deserialisation_error(
-            "42",
-            "did not match any variant of untagged enum Inline",
-            "error message should describe variant mismatch",
-        );
+    fn hi_deserialises_xml_lang(emphasised_inline: Inline) {
+        let hi = json::from_str::<Hi>(r#"{"@xml:lang":"cy","$value":["emphasis"]}"#)
+            .unwrap_or_else(|error| panic!("hi with language should deserialise: {error}"));
+
+        assert_eq!(hi.lang().map(LanguageTag::as_str), Some("cy"));
+        assert_eq!(hi.content(), [emphasised_inline]);
     }
 
-    #[rstest]
-    fn inline_deserialisation_reports_missing_hi_content() {
-        assert_inline_deserialisation_error(
-            r#"{"$value":[]}"#,
-            "did not match any variant of untagged enum Inline",
-            "error message should describe inline variant mismatch",
-        );
+    #[test]
+    fn hi_deserialisation_rejects_blank_language() {
+        let result = json::from_str::<Hi>(r#"{"@xml:lang":"  ","$value":["emphasis"]}"#);
+
+        assert!(result.is_err(), "blank xml:lang should not deserialise");
     }
 
     #[test]
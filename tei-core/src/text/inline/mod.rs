@@ -0,0 +1,148 @@
+//! Inline TEI content such as emphasised runs, pauses, unclear passages,
+//! quotations and notes.
+//!
+//! Mixed content is modelled as an [`Inline`] enum so paragraphs and utterances
+//! can hold either plain text or nested inline elements.
+
+mod hi;
+mod note;
+mod pause;
+mod quote;
+mod raw;
+mod unclear;
+
+pub use hi::Hi;
+pub use note::Note;
+pub use pause::Pause;
+pub use quote::Quote;
+pub use raw::RawMarkup;
+pub use unclear::{Certainty, Unclear};
+
+use serde::{Deserialize, Serialize};
+
+/// Inline content occurring inside paragraphs and utterances.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{Hi, Inline, P};
+///
+/// let emphasis = Inline::Hi(Hi::new([Inline::text("important")]));
+/// let paragraph = match P::from_inline([Inline::text("An "), emphasis]) {
+///     Ok(paragraph) => paragraph,
+///     Err(error) => panic!("paragraph should be valid: {error}"),
+/// };
+///
+/// assert_eq!(paragraph.content().len(), 2);
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Inline {
+    /// Plain text content.
+    Text(String),
+    /// Emphasised content wrapped in `<hi>`.
+    Hi(Hi),
+    /// A pause marker rendered as `<pause/>`.
+    Pause(Pause),
+    /// A passage the transcriber could not make out, wrapped in `<unclear>`.
+    #[serde(serialize_with = "unclear::serialize_inline")]
+    Unclear(Unclear),
+    /// A quotation wrapped in `<quote>`.
+    #[serde(serialize_with = "quote::serialize_inline")]
+    Quote(Quote),
+    /// An editorial note wrapped in `<note>`.
+    #[serde(serialize_with = "note::serialize_inline")]
+    Note(Note),
+    /// An element the data model does not cover, emitted verbatim.
+    #[serde(serialize_with = "raw::serialize_inline")]
+    Raw(RawMarkup),
+}
+
+impl Inline {
+    /// Builds a plain text inline node.
+    #[must_use]
+    pub fn text(content: impl Into<String>) -> Self {
+        Self::Text(content.into())
+    }
+
+    /// Builds an emphasised inline node.
+    #[must_use]
+    pub fn hi(content: impl IntoIterator<Item = Self>) -> Self {
+        Self::Hi(Hi::new(content))
+    }
+
+    /// Builds a pause marker.
+    #[must_use]
+    pub const fn pause() -> Self {
+        Self::Pause(Pause::new())
+    }
+
+    /// Returns the contained text when this variant is [`Inline::Text`].
+    #[must_use]
+    #[expect(
+        clippy::missing_const_for_fn,
+        reason = "String::as_str is not const-stable on the current MSRV."
+    )]
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(value) => Some(value.as_str()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::{fixture, rstest};
+    use serde_json as json;
+
+    #[fixture]
+    fn empty_pause() -> Pause {
+        Pause::new()
+    }
+
+    // Asserts that deserialising an [`Inline`] value fails with a matching error.
+    fn assert_inline_deserialisation_error(
+        payload: &str,
+        expected_error_substring: &str,
+        description: &str,
+    ) {
+        let Err(error) = json::from_str::<Inline>(payload) else {
+            panic!("{description}");
+        };
+        let message = error.to_string();
+
+        assert!(
+            message.contains(expected_error_substring),
+            "{description}: {message}"
+        );
+    }
+
+    #[rstest]
+    fn pause_records_duration_and_kind(mut empty_pause: Pause) {
+        empty_pause.set_duration("PT1S");
+        empty_pause.set_kind("breath");
+
+        assert_eq!(empty_pause.duration(), Some("PT1S"));
+        assert_eq!(empty_pause.kind(), Some("breath"));
+    }
+
+    #[rstest]
+    fn inline_deserialisation_reports_type_mismatch() {
+        assert_inline_deserialisation_error(
+            "42",
+            "did not match any variant of untagged enum Inline",
+            "error message should describe variant mismatch",
+        );
+    }
+
+    #[rstest]
+    fn inline_deserialisation_reports_missing_hi_content() {
+        assert_inline_deserialisation_error(
+            r#"{"$value":[]}"#,
+            "did not match any variant of untagged enum Inline",
+            "error message should describe inline variant mismatch",
+        );
+    }
+}
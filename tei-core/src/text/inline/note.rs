@@ -0,0 +1,130 @@
+//! Editorial notes attached to the text.
+//!
+//! Serialises as `<note>` with an optional `@place` attribute. Notes are
+//! commentary rather than speech, so word counts and spoken-text exports
+//! leave them out.
+
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+
+use super::Inline;
+use crate::text::body::{
+    BodyContentError, Container, ensure_container_content, push_validated_inline,
+};
+
+/// A note corresponding to `<note>`, anchored where it occurs in the text.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{Inline, Note};
+///
+/// let mut note = Note::try_new([Inline::text("Recorded aboard the Hephaestus.")])?;
+/// note.set_place("foot");
+///
+/// assert_eq!(note.place(), Some("foot"));
+/// # Ok::<(), tei_core::BodyContentError>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename = "note")]
+pub struct Note {
+    #[serde(rename = "@place", skip_serializing_if = "Option::is_none")]
+    place: Option<String>,
+    #[serde(rename = "$value")]
+    content: Vec<Inline>,
+}
+
+impl<'de> Deserialize<'de> for Note {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct RawNote {
+            #[serde(rename = "@place", default)]
+            place: Option<String>,
+            #[serde(rename = "$value", default)]
+            content: Vec<Inline>,
+        }
+
+        let raw = RawNote::deserialize(deserializer)?;
+        ensure_container_content(&raw.content, Container::Note).map_err(de::Error::custom)?;
+
+        Ok(Self {
+            place: raw.place,
+            content: raw.content,
+        })
+    }
+}
+
+impl Note {
+    /// Builds a note without validating the content.
+    #[must_use]
+    pub fn new(content: impl IntoIterator<Item = Inline>) -> Self {
+        Self {
+            place: None,
+            content: content.into_iter().collect(),
+        }
+    }
+
+    /// Builds a note, validating that content contains visible segments.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BodyContentError::EmptyContent`] when all inline children are
+    /// empty after trimming or when nested elements contain no meaningful
+    /// content.
+    pub fn try_new(content: impl IntoIterator<Item = Inline>) -> Result<Self, BodyContentError> {
+        let collected: Vec<Inline> = content.into_iter().collect();
+        ensure_container_content(&collected, Container::Note)?;
+
+        Ok(Self::new(collected))
+    }
+
+    /// Returns where the note belongs on the page, such as `foot`.
+    #[must_use]
+    pub fn place(&self) -> Option<&str> {
+        self.place.as_deref()
+    }
+
+    /// Records where the note belongs on the page.
+    pub fn set_place(&mut self, place: impl Into<String>) {
+        self.place = Some(place.into());
+    }
+
+    /// Clears the recorded placement.
+    pub fn clear_place(&mut self) {
+        self.place = None;
+    }
+
+    /// Returns the inline children.
+    #[must_use]
+    #[expect(
+        clippy::missing_const_for_fn,
+        reason = "Vec::as_slice is not const-stable on the current MSRV."
+    )]
+    pub fn content(&self) -> &[Inline] {
+        self.content.as_slice()
+    }
+
+    /// Appends an inline child.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BodyContentError::EmptySegment`] when the inline text lacks
+    /// visible characters. Returns [`BodyContentError::EmptyContent`] when a
+    /// nested inline element has no meaningful children.
+    pub fn push_inline(&mut self, inline: Inline) -> Result<(), BodyContentError> {
+        push_validated_inline(&mut self.content, inline, Container::Note)
+    }
+}
+
+/// Serialises an inline note as a tagged `<note>` element, since untagged
+/// structs cannot be written inside mixed content.
+pub(super) fn serialize_inline<S>(note: &Note, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_newtype_variant("Inline", 5, "note", note)
+}
@@ -0,0 +1,58 @@
+//! Pause cues recorded inside utterances and paragraphs.
+//!
+//! Serialises as `<pause/>` with optional `@dur` and `@type` attributes.
+
+use serde::{Deserialize, Serialize};
+
+/// Pause marker rendered as `<pause/>`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "pause", deny_unknown_fields)]
+pub struct Pause {
+    #[serde(rename = "@dur", skip_serializing_if = "Option::is_none", default)]
+    duration: Option<String>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none", default)]
+    pause_type: Option<String>,
+}
+
+impl Pause {
+    /// Creates an empty pause marker.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            duration: None,
+            pause_type: None,
+        }
+    }
+
+    /// Returns the recorded duration.
+    #[must_use]
+    pub fn duration(&self) -> Option<&str> {
+        self.duration.as_deref()
+    }
+
+    /// Assigns a duration value.
+    pub fn set_duration(&mut self, duration: impl Into<String>) {
+        self.duration = Some(duration.into());
+    }
+
+    /// Clears the recorded duration.
+    pub fn clear_duration(&mut self) {
+        self.duration = None;
+    }
+
+    /// Returns the pause classification.
+    #[must_use]
+    pub fn kind(&self) -> Option<&str> {
+        self.pause_type.as_deref()
+    }
+
+    /// Assigns a pause classification.
+    pub fn set_kind(&mut self, kind: impl Into<String>) {
+        self.pause_type = Some(kind.into());
+    }
+
+    /// Clears the pause classification.
+    pub fn clear_kind(&mut self) {
+        self.pause_type = None;
+    }
+}
@@ -0,0 +1,107 @@
+//! Quoted passages.
+//!
+//! Serialises as `<quote>` around the quoted words, such as a line read out
+//! from a letter or a clip played back on air.
+
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+
+use super::Inline;
+use crate::text::body::{
+    BodyContentError, Container, ensure_container_content, push_validated_inline,
+};
+
+/// A quotation corresponding to `<quote>`.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{Inline, Quote};
+///
+/// let quote = Quote::try_new([Inline::text("Is anyone out there?")])?;
+///
+/// assert_eq!(quote.content(), [Inline::text("Is anyone out there?")]);
+/// # Ok::<(), tei_core::BodyContentError>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename = "quote")]
+pub struct Quote {
+    #[serde(rename = "$value")]
+    content: Vec<Inline>,
+}
+
+impl<'de> Deserialize<'de> for Quote {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct RawQuote {
+            #[serde(rename = "$value", default)]
+            content: Vec<Inline>,
+        }
+
+        let raw = RawQuote::deserialize(deserializer)?;
+        ensure_container_content(&raw.content, Container::Quote).map_err(de::Error::custom)?;
+
+        Ok(Self {
+            content: raw.content,
+        })
+    }
+}
+
+impl Quote {
+    /// Builds a quotation without validating the content.
+    #[must_use]
+    pub fn new(content: impl IntoIterator<Item = Inline>) -> Self {
+        Self {
+            content: content.into_iter().collect(),
+        }
+    }
+
+    /// Builds a quotation, validating that content contains visible
+    /// segments.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BodyContentError::EmptyContent`] when all inline children are
+    /// empty after trimming or when nested elements contain no meaningful
+    /// content.
+    pub fn try_new(content: impl IntoIterator<Item = Inline>) -> Result<Self, BodyContentError> {
+        let collected: Vec<Inline> = content.into_iter().collect();
+        ensure_container_content(&collected, Container::Quote)?;
+
+        Ok(Self::new(collected))
+    }
+
+    /// Returns the inline children.
+    #[must_use]
+    #[expect(
+        clippy::missing_const_for_fn,
+        reason = "Vec::as_slice is not const-stable on the current MSRV."
+    )]
+    pub fn content(&self) -> &[Inline] {
+        self.content.as_slice()
+    }
+
+    /// Appends an inline child.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BodyContentError::EmptySegment`] when the inline text lacks
+    /// visible characters. Returns [`BodyContentError::EmptyContent`] when a
+    /// nested inline element has no meaningful children.
+    pub fn push_inline(&mut self, inline: Inline) -> Result<(), BodyContentError> {
+        push_validated_inline(&mut self.content, inline, Container::Quote)
+    }
+}
+
+/// Serialises an inline quotation as a tagged `<quote>` element, since
+/// untagged structs cannot be written inside mixed content.
+pub(super) fn serialize_inline<S>(quote: &Quote, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_newtype_variant("Inline", 4, "quote", quote)
+}
@@ -0,0 +1,93 @@
+//! Markup for elements the data model does not cover, kept verbatim.
+//!
+//! Serialises as a `<raw markup="..."/>` placeholder. The XML layer swaps the
+//! placeholder for the stored markup when it emits a document, so elements
+//! the profile does not model survive a round trip unchanged.
+
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::text::BodyContentError;
+
+/// An element the data model does not cover, stored as its original markup.
+///
+/// The markup is emitted exactly as stored, so it must be a well-formed
+/// element using namespace prefixes the document declares. The XML layer
+/// checks it is well-formed before writing it.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{Inline, P, RawMarkup};
+///
+/// let gap = RawMarkup::new("<gap reason=\"inaudible\"/>")?;
+/// let paragraph = P::from_inline([Inline::text("Then "), Inline::Raw(gap)])?;
+///
+/// assert_eq!(paragraph.content().len(), 2);
+/// # Ok::<(), tei_core::BodyContentError>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename = "raw")]
+pub struct RawMarkup {
+    #[serde(rename = "@markup")]
+    markup: String,
+}
+
+impl RawMarkup {
+    /// Stores `markup` to be emitted verbatim.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BodyContentError::EmptyRawMarkup`] when `markup` holds no
+    /// visible characters.
+    pub fn new(markup: impl Into<String>) -> Result<Self, BodyContentError> {
+        let stored = markup.into();
+        if stored.trim().is_empty() {
+            return Err(BodyContentError::EmptyRawMarkup);
+        }
+        Ok(Self { markup: stored })
+    }
+
+    /// Stores markup the crate wrote itself, which is never blank.
+    pub(crate) const fn generated(markup: String) -> Self {
+        Self { markup }
+    }
+
+    /// Returns the stored markup.
+    #[must_use]
+    #[expect(
+        clippy::missing_const_for_fn,
+        reason = "String::as_str is not const-stable on the current MSRV."
+    )]
+    pub fn as_str(&self) -> &str {
+        self.markup.as_str()
+    }
+}
+
+impl<'de> Deserialize<'de> for RawMarkup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Inline content is untagged, so the placeholder is told apart from
+        // other elements by its attribute alone.
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Placeholder {
+            #[serde(rename = "@markup")]
+            markup: String,
+        }
+
+        let placeholder = Placeholder::deserialize(deserializer)?;
+        Self::new(placeholder.markup).map_err(de::Error::custom)
+    }
+}
+
+/// Serialises inline raw markup as a tagged `<raw>` element, since untagged
+/// structs cannot be written inside mixed content.
+pub(super) fn serialize_inline<S>(raw: &RawMarkup, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_newtype_variant("Inline", 6, "raw", raw)
+}
@@ -0,0 +1,205 @@
+//! Passages the transcriber could not make out with confidence.
+//!
+//! Serialises as `<unclear>` with optional `@reason` and `@cert` attributes
+//! around the best reading of the passage.
+
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+
+use super::Inline;
+use crate::text::body::{
+    BodyContentError, Container, ensure_container_content, push_validated_inline,
+};
+
+/// How sure the transcriber is of a reading, as recorded in `@cert`.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Certainty {
+    /// The reading is very probably right.
+    High,
+    /// The reading is as likely right as not.
+    Medium,
+    /// The reading is probably wrong.
+    Low,
+    /// Nothing is known about the reading's reliability.
+    Unknown,
+}
+
+impl Certainty {
+    /// Maps a recogniser's confidence score between 0 and 1 onto a
+    /// certainty: 0.8 and above is high, 0.5 and above is medium, and
+    /// anything lower, including a score that is not a number, is low.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::Certainty;
+    ///
+    /// assert_eq!(Certainty::from_confidence(0.93), Certainty::High);
+    /// assert_eq!(Certainty::from_confidence(0.61), Certainty::Medium);
+    /// assert_eq!(Certainty::from_confidence(0.2), Certainty::Low);
+    /// ```
+    #[must_use]
+    pub fn from_confidence(score: f64) -> Self {
+        if score >= 0.8 {
+            Self::High
+        } else if score >= 0.5 {
+            Self::Medium
+        } else {
+            Self::Low
+        }
+    }
+
+    /// Returns the value written to `@cert`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::High => "high",
+            Self::Medium => "medium",
+            Self::Low => "low",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+/// A passage corresponding to `<unclear>`, holding its most likely reading.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{Certainty, Inline, Unclear};
+///
+/// let mut unclear = Unclear::try_new([Inline::text("Hephaestus")])?;
+/// unclear.set_cert(Certainty::Low);
+/// unclear.set_reason("background noise");
+///
+/// assert_eq!(unclear.cert(), Some(Certainty::Low));
+/// assert_eq!(unclear.reason(), Some("background noise"));
+/// # Ok::<(), tei_core::BodyContentError>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename = "unclear")]
+pub struct Unclear {
+    #[serde(rename = "@reason", skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+    #[serde(rename = "@cert", skip_serializing_if = "Option::is_none")]
+    cert: Option<Certainty>,
+    #[serde(rename = "$value")]
+    content: Vec<Inline>,
+}
+
+impl<'de> Deserialize<'de> for Unclear {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct RawUnclear {
+            #[serde(rename = "@reason", default)]
+            reason: Option<String>,
+            #[serde(rename = "@cert", default)]
+            cert: Option<Certainty>,
+            #[serde(rename = "$value", default)]
+            content: Vec<Inline>,
+        }
+
+        let raw = RawUnclear::deserialize(deserializer)?;
+        ensure_container_content(&raw.content, Container::Unclear).map_err(de::Error::custom)?;
+
+        Ok(Self {
+            reason: raw.reason,
+            cert: raw.cert,
+            content: raw.content,
+        })
+    }
+}
+
+impl Unclear {
+    /// Builds an unclear passage without validating the content.
+    #[must_use]
+    pub fn new(content: impl IntoIterator<Item = Inline>) -> Self {
+        Self {
+            reason: None,
+            cert: None,
+            content: content.into_iter().collect(),
+        }
+    }
+
+    /// Builds an unclear passage, validating that content contains visible
+    /// segments.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BodyContentError::EmptyContent`] when all inline children are
+    /// empty after trimming or when nested elements contain no meaningful
+    /// content.
+    pub fn try_new(content: impl IntoIterator<Item = Inline>) -> Result<Self, BodyContentError> {
+        let collected: Vec<Inline> = content.into_iter().collect();
+        ensure_container_content(&collected, Container::Unclear)?;
+
+        Ok(Self::new(collected))
+    }
+
+    /// Returns how sure the transcriber is of the reading.
+    #[must_use]
+    pub const fn cert(&self) -> Option<Certainty> {
+        self.cert
+    }
+
+    /// Records how sure the transcriber is of the reading.
+    pub const fn set_cert(&mut self, cert: Certainty) {
+        self.cert = Some(cert);
+    }
+
+    /// Clears the recorded certainty.
+    pub const fn clear_cert(&mut self) {
+        self.cert = None;
+    }
+
+    /// Returns why the passage is hard to make out.
+    #[must_use]
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    /// Records why the passage is hard to make out.
+    pub fn set_reason(&mut self, reason: impl Into<String>) {
+        self.reason = Some(reason.into());
+    }
+
+    /// Clears the recorded reason.
+    pub fn clear_reason(&mut self) {
+        self.reason = None;
+    }
+
+    /// Returns the inline children.
+    #[must_use]
+    #[expect(
+        clippy::missing_const_for_fn,
+        reason = "Vec::as_slice is not const-stable on the current MSRV."
+    )]
+    pub fn content(&self) -> &[Inline] {
+        self.content.as_slice()
+    }
+
+    /// Appends an inline child.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BodyContentError::EmptySegment`] when the inline text lacks
+    /// visible characters. Returns [`BodyContentError::EmptyContent`] when a
+    /// nested inline element has no meaningful children.
+    pub fn push_inline(&mut self, inline: Inline) -> Result<(), BodyContentError> {
+        push_validated_inline(&mut self.content, inline, Container::Unclear)
+    }
+}
+
+/// Serialises an inline unclear passage as a tagged `<unclear>` element,
+/// since untagged structs cannot be written inside mixed content.
+pub(super) fn serialize_inline<S>(unclear: &Unclear, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_newtype_variant("Inline", 3, "unclear", unclear)
+}
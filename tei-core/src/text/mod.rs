@@ -3,20 +3,27 @@
 //! The text model now records structured body content. A `TeiText` owns a
 //! `TeiBody`, which in turn stores ordered blocks of paragraphs and utterances.
 //! Each element validates that visible text is present so downstream tooling can
-//! rely on non-empty content.
+//! rely on non-empty content. An optional `Timeline` ahead of the body holds the
+//! points in time that utterances are anchored to.
 
 mod body;
 mod inline;
+mod timeline;
 mod types;
 
-pub use body::{BodyBlock, BodyContentError, P, TeiBody, Utterance};
-pub use inline::{Hi, Inline, Pause};
+pub(crate) use body::ensure_container_content;
+pub use body::{BodyBlock, BodyContentError, Container, P, TeiBody, Utterance};
+pub use inline::{Certainty, Hi, Inline, Note, Pause, Quote, RawMarkup, Unclear};
+pub use timeline::{Timeline, When};
+pub(crate) use timeline::{format_seconds, parse_seconds};
 pub use types::{IdentifierValidationError, Speaker, SpeakerValidationError, XmlId};
 
 /// Body of a TEI document, including paragraphs and utterances.
 #[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 #[serde(rename = "text")]
 pub struct TeiText {
+    #[serde(rename = "timeline", skip_serializing_if = "Option::is_none", default)]
+    timeline: Option<Timeline>,
     #[serde(rename = "body")]
     body: TeiBody,
 }
@@ -45,7 +52,10 @@ impl TeiText {
     /// ```
     #[must_use]
     pub const fn new(body: TeiBody) -> Self {
-        Self { body }
+        Self {
+            timeline: None,
+            body,
+        }
     }
 
     /// Returns an empty text node.
@@ -71,6 +81,27 @@ impl TeiText {
         &mut self.body
     }
 
+    /// Returns the timeline utterances are anchored to, when present.
+    #[must_use]
+    pub const fn timeline(&self) -> Option<&Timeline> {
+        self.timeline.as_ref()
+    }
+
+    /// Returns a mutable reference to the timeline, when present.
+    pub const fn timeline_mut(&mut self) -> Option<&mut Timeline> {
+        self.timeline.as_mut()
+    }
+
+    /// Records the timeline written ahead of the body.
+    pub fn set_timeline(&mut self, timeline: Timeline) {
+        self.timeline = Some(timeline);
+    }
+
+    /// Removes the timeline.
+    pub fn clear_timeline(&mut self) {
+        self.timeline = None;
+    }
+
     /// Appends a paragraph block to the underlying body.
     ///
     /// # Examples
@@ -142,10 +173,10 @@ impl TeiText {
     ///     .push_utterance(utterance.clone());
     ///
     /// assert_eq!(
-    ///     text.body().blocks(),
+    ///     text.body().blocks().collect::<Vec<_>>(),
     ///     [
-    ///         BodyBlock::Paragraph(paragraph),
-    ///         BodyBlock::Utterance(utterance)
+    ///         &BodyBlock::Paragraph(paragraph),
+    ///         &BodyBlock::Utterance(utterance)
     ///     ]
     /// );
     /// ```
@@ -195,10 +226,10 @@ mod tests {
         body.push_utterance(utterance.clone());
 
         assert_eq!(
-            body.blocks(),
+            body.blocks().collect::<Vec<_>>(),
             [
-                BodyBlock::Paragraph(paragraph),
-                BodyBlock::Utterance(utterance)
+                &BodyBlock::Paragraph(paragraph),
+                &BodyBlock::Utterance(utterance)
             ]
         );
     }
@@ -210,10 +241,10 @@ mod tests {
             .push_utterance(sample_utterance.clone());
 
         assert_eq!(
-            text.body().blocks(),
+            text.body().blocks().collect::<Vec<_>>(),
             [
-                BodyBlock::Paragraph(sample_paragraph),
-                BodyBlock::Utterance(sample_utterance)
+                &BodyBlock::Paragraph(sample_paragraph),
+                &BodyBlock::Utterance(sample_utterance)
             ]
         );
     }
@@ -225,10 +256,10 @@ mod tests {
             .push_utterance(sample_utterance.clone());
 
         assert_eq!(
-            text.body().blocks(),
+            text.body().blocks().collect::<Vec<_>>(),
             [
-                BodyBlock::Paragraph(sample_paragraph),
-                BodyBlock::Utterance(sample_utterance)
+                &BodyBlock::Paragraph(sample_paragraph),
+                &BodyBlock::Utterance(sample_utterance)
             ]
         );
     }
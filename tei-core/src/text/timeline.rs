@@ -0,0 +1,225 @@
+//! Points in time that utterances can be anchored to.
+//!
+//! Serialises as `<timeline unit="s" origin="#T0">` holding one `<when/>` per
+//! point. The origin carries no interval; every other point records its
+//! offset from the origin in seconds through `@interval` and `@since`, so
+//! `<u start="#T3" end="#T4">` pins an utterance to the recording.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::XmlId;
+
+const ORIGIN: &str = "T0";
+
+/// An ordered set of points in time, measured in seconds from an origin.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use tei_core::Timeline;
+///
+/// let mut timeline = Timeline::new();
+/// let start = timeline.add_offset(Duration::from_millis(1_500));
+///
+/// assert_eq!(start.as_str(), "T1");
+/// assert_eq!(timeline.offset("#T1"), Some(Duration::from_millis(1_500)));
+/// assert_eq!(timeline.add_offset(Duration::from_millis(1_500)), start);
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "timeline")]
+pub struct Timeline {
+    #[serde(rename = "@unit")]
+    unit: String,
+    #[serde(rename = "@origin")]
+    origin: String,
+    #[serde(rename = "when", default)]
+    points: Vec<When>,
+}
+
+/// A point on a [`Timeline`], rendered as `<when/>`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "when")]
+pub struct When {
+    #[serde(rename = "@xml:id", alias = "@id")]
+    id: XmlId,
+    #[serde(rename = "@interval", skip_serializing_if = "Option::is_none", default)]
+    interval: Option<String>,
+    #[serde(rename = "@since", skip_serializing_if = "Option::is_none", default)]
+    since: Option<String>,
+}
+
+impl Timeline {
+    /// Creates a timeline holding only its origin, `T0`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            unit: "s".to_owned(),
+            origin: format!("#{ORIGIN}"),
+            points: vec![When {
+                id: XmlId::generated(ORIGIN.to_owned()),
+                interval: None,
+                since: None,
+            }],
+        }
+    }
+
+    /// Returns the point at `offset` from the origin, adding it when the
+    /// timeline has none.
+    pub fn add_offset(&mut self, offset: Duration) -> XmlId {
+        let interval = format_seconds(offset);
+        let existing = self.points.iter().find(|point| {
+            point.since.as_deref() == Some(self.origin.as_str())
+                && point.interval.as_deref() == Some(interval.as_str())
+        });
+        if let Some(point) = existing {
+            return point.id.clone();
+        }
+        let id = self.unused_id();
+        self.points.push(When {
+            id: id.clone(),
+            interval: Some(interval),
+            since: Some(self.origin.clone()),
+        });
+        id
+    }
+
+    /// Resolves a pointer such as `#T3` to its offset from the origin.
+    ///
+    /// Returns `None` when no point has that identifier, or when the point is
+    /// not measured in seconds from the origin.
+    #[must_use]
+    pub fn offset(&self, pointer: &str) -> Option<Duration> {
+        let id = pointer.strip_prefix('#').unwrap_or(pointer);
+        let point = self.points.iter().find(|point| point.id.as_str() == id)?;
+        if self.origin.strip_prefix('#') == Some(id) {
+            return Some(Duration::ZERO);
+        }
+        if self.unit != "s" || point.since.as_deref() != Some(self.origin.as_str()) {
+            return None;
+        }
+        parse_seconds(point.interval.as_deref()?)
+    }
+
+    /// Returns the points in document order, origin first.
+    #[must_use]
+    #[expect(
+        clippy::missing_const_for_fn,
+        reason = "Vec::as_slice is not const-stable on the current MSRV."
+    )]
+    pub fn points(&self) -> &[When] {
+        self.points.as_slice()
+    }
+
+    fn unused_id(&self) -> XmlId {
+        let taken = |candidate: &str| {
+            self.points
+                .iter()
+                .any(|point| point.id.as_str() == candidate)
+        };
+        // Of the `len + 1` candidates, at least one is free.
+        let id = (self.points.len()..=self.points.len().saturating_mul(2))
+            .map(|number| format!("T{number}"))
+            .find(|candidate| !taken(candidate))
+            .unwrap_or_default();
+        XmlId::generated(id)
+    }
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl When {
+    /// Returns the identifier utterances point at.
+    #[must_use]
+    pub const fn id(&self) -> &XmlId {
+        &self.id
+    }
+
+    /// Returns the recorded `@interval`, in the timeline's unit.
+    #[must_use]
+    pub fn interval(&self) -> Option<&str> {
+        self.interval.as_deref()
+    }
+
+    /// Returns the `@since` pointer the interval is measured from.
+    #[must_use]
+    pub fn since(&self) -> Option<&str> {
+        self.since.as_deref()
+    }
+}
+
+/// Writes `duration` as decimal seconds without trailing zeros.
+pub(crate) fn format_seconds(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let nanos = duration.subsec_nanos();
+    if nanos == 0 {
+        return seconds.to_string();
+    }
+    let fraction = format!("{nanos:09}");
+    format!("{seconds}.{}", fraction.trim_end_matches('0'))
+}
+
+/// Reads decimal seconds, ignoring digits beyond nanosecond precision.
+pub(crate) fn parse_seconds(value: &str) -> Option<Duration> {
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if whole.is_empty() || !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    let seconds = whole.parse::<u64>().ok()?;
+    let digits: String = fraction
+        .chars()
+        .chain("000000000".chars())
+        .take(9)
+        .collect();
+    let nanos = digits.parse::<u32>().ok()?;
+    Some(Duration::new(seconds, nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Duration::ZERO, "0")]
+    #[case(Duration::from_secs(90), "90")]
+    #[case(Duration::from_millis(1_500), "1.5")]
+    #[case(Duration::new(3_661, 7_000_000), "3661.007")]
+    fn writes_offsets_as_decimal_seconds(#[case] offset: Duration, #[case] expected: &str) {
+        assert_eq!(format_seconds(offset), expected);
+        assert_eq!(parse_seconds(expected), Some(offset));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case(".5")]
+    #[case("1.5s")]
+    #[case("-1")]
+    fn rejects_malformed_intervals(#[case] value: &str) {
+        assert_eq!(parse_seconds(value), None);
+    }
+
+    #[test]
+    fn skips_identifiers_already_in_use() {
+        let mut timeline = Timeline::new();
+        timeline.points.push(When {
+            id: XmlId::generated("T2".to_owned()),
+            interval: Some("9".to_owned()),
+            since: Some("#T0".to_owned()),
+        });
+
+        let id = timeline.add_offset(Duration::from_secs(4));
+
+        assert_eq!(id.as_str(), "T3");
+        assert_eq!(timeline.offset("T2"), Some(Duration::from_secs(9)));
+        assert_eq!(timeline.offset("#T0"), Some(Duration::ZERO));
+        assert_eq!(timeline.offset("#T9"), None);
+    }
+}
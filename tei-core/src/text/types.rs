@@ -10,6 +10,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 use thiserror::Error;
 
 use super::body::trim_preserving_original;
+use crate::ErrorCode;
 
 /// Validated wrapper for TEI `xml:id` attributes.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
@@ -27,6 +28,15 @@ pub enum IdentifierValidationError {
     ContainsWhitespace,
 }
 
+impl ErrorCode for IdentifierValidationError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Empty => "TEI-ID-EMPTY",
+            Self::ContainsWhitespace => "TEI-ID-WHITESPACE",
+        }
+    }
+}
+
 impl XmlId {
     /// Builds an identifier from user input.
     ///
@@ -49,6 +59,12 @@ impl XmlId {
         Ok(Self(trimmed))
     }
 
+    /// Wraps an identifier the crate generated itself, which never contains
+    /// whitespace.
+    pub(crate) const fn generated(value: String) -> Self {
+        Self(value)
+    }
+
     /// Returns the identifier as a string slice.
     #[must_use]
     #[expect(
@@ -118,6 +134,14 @@ pub enum SpeakerValidationError {
     Empty,
 }
 
+impl ErrorCode for SpeakerValidationError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Empty => "TEI-SPEAKER-EMPTY",
+        }
+    }
+}
+
 impl Speaker {
     /// Builds a speaker reference from user input.
     ///
@@ -0,0 +1,186 @@
+//! Shared duration and timestamp formatting for transcript exports.
+//!
+//! Exporters render times as `hh:mm:ss` or `mm:ss` with optional fractional
+//! seconds. Keeping the rules in one [`TimestampFormat`] stops each output
+//! format from drifting, while the separators remain configurable for locales
+//! and formats (such as SRT) that use a comma before the fraction.
+
+use std::time::Duration;
+
+const MAX_FRACTION_DIGITS: u8 = 3;
+
+/// Layout used when rendering a timestamp.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum TimestampStyle {
+    /// Uses `hh:mm:ss` from one hour onwards and `mm:ss` below it.
+    #[default]
+    Auto,
+    /// Always renders hours, as in `00:04:05`.
+    HoursMinutesSeconds,
+    /// Never renders hours; minutes keep counting past 59, as in `75:00`.
+    MinutesSeconds,
+}
+
+/// Formatting options for durations and timestamps.
+///
+/// Every component is zero-padded to two digits. Fractional seconds are
+/// truncated, never rounded, so a timestamp never points past the audio it
+/// describes.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use tei_core::{TimestampFormat, TimestampStyle};
+///
+/// let offset = Duration::from_millis(3_725_450);
+/// assert_eq!(TimestampFormat::default().format(offset), "01:02:05");
+///
+/// let srt = TimestampFormat::default()
+///     .with_style(TimestampStyle::HoursMinutesSeconds)
+///     .with_decimal_separator(',')
+///     .with_fraction_digits(3);
+/// assert_eq!(srt.format(Duration::from_millis(65_020)), "00:01:05,020");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TimestampFormat {
+    style: TimestampStyle,
+    separator: char,
+    decimal_separator: char,
+    fraction_digits: u8,
+}
+
+impl Default for TimestampFormat {
+    fn default() -> Self {
+        Self {
+            style: TimestampStyle::Auto,
+            separator: ':',
+            decimal_separator: '.',
+            fraction_digits: 0,
+        }
+    }
+}
+
+impl TimestampFormat {
+    /// Selects the layout.
+    #[must_use]
+    pub const fn with_style(mut self, style: TimestampStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the separator placed between hours, minutes, and seconds.
+    #[must_use]
+    pub const fn with_separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets the separator placed before fractional seconds.
+    #[must_use]
+    pub const fn with_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Sets how many fractional digits to render, up to millisecond
+    /// precision; larger values are clamped to three.
+    #[must_use]
+    pub const fn with_fraction_digits(mut self, digits: u8) -> Self {
+        self.fraction_digits = if digits > MAX_FRACTION_DIGITS {
+            MAX_FRACTION_DIGITS
+        } else {
+            digits
+        };
+        self
+    }
+
+    /// Returns the selected layout.
+    #[must_use]
+    pub const fn style(&self) -> TimestampStyle {
+        self.style
+    }
+
+    /// Returns the number of fractional digits rendered.
+    #[must_use]
+    pub const fn fraction_digits(&self) -> u8 {
+        self.fraction_digits
+    }
+
+    /// Renders a duration or offset from the start of the recording.
+    #[must_use]
+    pub fn format(&self, duration: Duration) -> String {
+        let total_seconds = duration.as_secs();
+        let seconds = total_seconds.rem_euclid(60);
+        let total_minutes = total_seconds.div_euclid(60);
+        let show_hours = match self.style {
+            TimestampStyle::Auto => total_minutes >= 60,
+            TimestampStyle::HoursMinutesSeconds => true,
+            TimestampStyle::MinutesSeconds => false,
+        };
+        let separator = self.separator;
+        let fraction = self.format_fraction(duration);
+
+        if show_hours {
+            let hours = total_minutes.div_euclid(60);
+            let minutes = total_minutes.rem_euclid(60);
+            format!("{hours:02}{separator}{minutes:02}{separator}{seconds:02}{fraction}")
+        } else {
+            format!("{total_minutes:02}{separator}{seconds:02}{fraction}")
+        }
+    }
+
+    fn format_fraction(&self, duration: Duration) -> String {
+        if self.fraction_digits == 0 {
+            return String::new();
+        }
+
+        let divisor = 10_u32.pow(u32::from(MAX_FRACTION_DIGITS - self.fraction_digits));
+        let fraction = duration.subsec_millis().div_euclid(divisor);
+        let width = usize::from(self.fraction_digits);
+        format!("{}{fraction:0width$}", self.decimal_separator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(TimestampStyle::Auto, 65, "01:05")]
+    #[case(TimestampStyle::Auto, 3_600, "01:00:00")]
+    #[case(TimestampStyle::HoursMinutesSeconds, 65, "00:01:05")]
+    #[case(TimestampStyle::MinutesSeconds, 4_500, "75:00")]
+    fn formats_each_style(
+        #[case] style: TimestampStyle,
+        #[case] seconds: u64,
+        #[case] expected: &str,
+    ) {
+        let format = TimestampFormat::default().with_style(style);
+
+        assert_eq!(format.format(Duration::from_secs(seconds)), expected);
+    }
+
+    #[rstest]
+    #[case(1, "00:01.9")]
+    #[case(2, "00:01.98")]
+    #[case(3, "00:01.987")]
+    #[case(9, "00:01.987")]
+    fn truncates_fractional_seconds(#[case] digits: u8, #[case] expected: &str) {
+        let format = TimestampFormat::default().with_fraction_digits(digits);
+
+        assert_eq!(format.format(Duration::from_millis(1_987)), expected);
+    }
+
+    #[test]
+    fn applies_custom_separators() {
+        let format = TimestampFormat::default()
+            .with_separator('.')
+            .with_decimal_separator(',')
+            .with_fraction_digits(1);
+
+        assert_eq!(format.format(Duration::from_millis(61_500)), "01.01,5");
+    }
+}
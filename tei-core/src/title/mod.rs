@@ -7,12 +7,93 @@ use serde::de::{self, Deserializer};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::ErrorCode;
+
+mod options;
+
+pub use options::TitleOptions;
+
 /// Error raised when a [`DocumentTitle`] fails validation.
 #[derive(Clone, Debug, Deserialize, Error, Eq, PartialEq, Serialize)]
 pub enum DocumentTitleError {
     /// The provided title was empty after trimming whitespace.
     #[error("document title may not be empty")]
     Empty,
+    /// A file description listed titles but none of them was the main title.
+    #[error("file description must include a main title")]
+    MissingMain,
+    /// A title type that may only occur once was repeated.
+    #[error("file description may include only one {title_type} title")]
+    Duplicate {
+        /// Type of the repeated title.
+        title_type: TitleType,
+    },
+    /// The title contained a character XML 1.0 forbids, such as a control
+    /// character other than a tab or line break, so it cannot be emitted.
+    #[error("document title contains character U+{code_point:04X}, which XML 1.0 forbids")]
+    ControlCharacter {
+        /// Unicode scalar value of the offending character.
+        code_point: u32,
+    },
+    /// The title exceeded the configured maximum length.
+    #[error("document title is {length} characters long; the maximum is {max_length}")]
+    TooLong {
+        /// Configured maximum length in characters.
+        max_length: usize,
+        /// Length of the normalised title in characters.
+        length: usize,
+    },
+}
+
+impl ErrorCode for DocumentTitleError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Empty => "TEI-TITLE-EMPTY",
+            Self::MissingMain => "TEI-TITLE-MISSING-MAIN",
+            Self::Duplicate { .. } => "TEI-TITLE-DUPLICATE",
+            Self::ControlCharacter { .. } => "TEI-TITLE-CONTROL-CHARACTER",
+            Self::TooLong { .. } => "TEI-TITLE-TOO-LONG",
+        }
+    }
+}
+
+/// Role of a title within a file description, serialised as `@type`.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::TitleType;
+///
+/// assert_eq!(TitleType::Sub.as_str(), "sub");
+/// assert_eq!(TitleType::Alt.to_string(), "alt");
+/// ```
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TitleType {
+    /// The principal title of the episode.
+    Main,
+    /// A subtitle qualifying the main title.
+    Sub,
+    /// An alternative title, such as a working or syndicated name.
+    Alt,
+}
+
+impl TitleType {
+    /// Returns the TEI `@type` value for the title role.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Main => "main",
+            Self::Sub => "sub",
+            Self::Alt => "alt",
+        }
+    }
+}
+
+impl fmt::Display for TitleType {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
 }
 
 /// Title metadata carried by a [`crate::TeiDocument`].
@@ -0,0 +1,181 @@
+//! Construction options for stricter [`DocumentTitle`] validation.
+//!
+//! The default options collapse internal whitespace runs to single spaces and
+//! reject characters XML 1.0 forbids, so titles that would later fail XML
+//! emission are caught when they are built. Callers can preserve internal spacing and
+//! cap the title length.
+
+use super::{DocumentTitle, DocumentTitleError};
+use crate::escape;
+
+/// Rules applied by [`DocumentTitle::new_with_options`].
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{DocumentTitle, DocumentTitleError, TitleOptions};
+///
+/// let collapsed = DocumentTitle::new_with_options("Wolf  359", TitleOptions::new())?;
+/// assert_eq!(collapsed.as_str(), "Wolf 359");
+///
+/// let preserved = DocumentTitle::new_with_options(
+///     "Wolf  359",
+///     TitleOptions::new().preserve_internal_whitespace(true),
+/// )?;
+/// assert_eq!(preserved.as_str(), "Wolf  359");
+///
+/// let error = DocumentTitle::new_with_options("Wolf 359", TitleOptions::new().with_max_length(4))
+///     .unwrap_err();
+/// assert_eq!(error, DocumentTitleError::TooLong { max_length: 4, length: 8 });
+/// # Ok::<(), DocumentTitleError>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TitleOptions {
+    preserve_internal_whitespace: bool,
+    max_length: Option<usize>,
+    allow_control_characters: bool,
+}
+
+impl TitleOptions {
+    /// Creates the default options: collapse whitespace, no length limit, and
+    /// reject characters XML 1.0 forbids.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            preserve_internal_whitespace: false,
+            max_length: None,
+            allow_control_characters: false,
+        }
+    }
+
+    /// Keeps internal whitespace runs, such as double spaces, unchanged.
+    #[must_use]
+    pub const fn preserve_internal_whitespace(mut self, preserve: bool) -> Self {
+        self.preserve_internal_whitespace = preserve;
+        self
+    }
+
+    /// Rejects titles longer than `max_length` characters once normalised.
+    #[must_use]
+    pub const fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Accepts characters XML 1.0 forbids, such as most control characters,
+    /// instead of rejecting them. Tabs and line breaks are always accepted.
+    #[must_use]
+    pub const fn allow_control_characters(mut self, allow: bool) -> Self {
+        self.allow_control_characters = allow;
+        self
+    }
+
+    pub(super) fn apply(self, value: &str) -> Result<String, DocumentTitleError> {
+        let trimmed = value.trim();
+        let normalised = if self.preserve_internal_whitespace {
+            trimmed.to_owned()
+        } else {
+            trimmed.split_whitespace().collect::<Vec<_>>().join(" ")
+        };
+
+        if normalised.is_empty() {
+            return Err(DocumentTitleError::Empty);
+        }
+
+        if let Some(forbidden) =
+            escape::first_forbidden(&normalised).filter(|_| !self.allow_control_characters)
+        {
+            return Err(DocumentTitleError::ControlCharacter {
+                code_point: u32::from(forbidden),
+            });
+        }
+
+        let length = normalised.chars().count();
+        match self.max_length {
+            Some(max_length) if length > max_length => {
+                Err(DocumentTitleError::TooLong { max_length, length })
+            }
+            _ => Ok(normalised),
+        }
+    }
+}
+
+impl Default for TitleOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DocumentTitle {
+    /// Creates a document title using explicit validation options.
+    ///
+    /// Unlike [`DocumentTitle::new`], which only trims, the default
+    /// [`TitleOptions`] also collapse internal whitespace and reject characters
+    /// XML 1.0 forbids up front rather than leaving them to fail at emission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DocumentTitleError::Empty`] for blank titles,
+    /// [`DocumentTitleError::ControlCharacter`] when a character XML 1.0
+    /// forbids remains, and [`DocumentTitleError::TooLong`] when the title
+    /// exceeds the configured maximum length.
+    pub fn new_with_options(
+        value: impl Into<String>,
+        options: TitleOptions,
+    ) -> Result<Self, DocumentTitleError> {
+        options.apply(&value.into()).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("Wolf\u{0007}359", 0x07)]
+    #[case("Wolf\u{001B}[1m359", 0x1B)]
+    #[case("Wolf 359\u{FFFE}", 0xFFFE)]
+    #[case("Wolf\u{FFFF}359", 0xFFFF)]
+    fn rejects_forbidden_characters(#[case] input: &str, #[case] code_point: u32) {
+        assert_eq!(
+            DocumentTitle::new_with_options(input, TitleOptions::new()),
+            Err(DocumentTitleError::ControlCharacter { code_point })
+        );
+    }
+
+    #[rstest]
+    #[case("Wolf\t359")]
+    #[case("Wolf\n359")]
+    #[case("Wolf\r\n359")]
+    fn preserved_whitespace_keeps_tabs_and_line_breaks(#[case] input: &str) {
+        let options = TitleOptions::new().preserve_internal_whitespace(true);
+
+        let title = DocumentTitle::new_with_options(input, options)
+            .unwrap_or_else(|error| panic!("XML permits {input:?}: {error}"));
+
+        assert_eq!(title.as_str(), input);
+    }
+
+    #[test]
+    fn allows_forbidden_characters_on_request() {
+        let options = TitleOptions::new().allow_control_characters(true);
+
+        assert!(DocumentTitle::new_with_options("Wolf\u{7}359", options).is_ok());
+    }
+
+    #[test]
+    fn collapses_newlines_by_default() {
+        let title = DocumentTitle::new_with_options("Wolf\n\n359", TitleOptions::new())
+            .unwrap_or_else(|error| panic!("title should normalise: {error}"));
+
+        assert_eq!(title.as_str(), "Wolf 359");
+    }
+
+    #[test]
+    fn counts_characters_rather_than_bytes() {
+        let options = TitleOptions::new().with_max_length(5);
+
+        assert!(DocumentTitle::new_with_options("Café!", options).is_ok());
+    }
+}
@@ -0,0 +1,328 @@
+//! Whole-document validation that gathers every problem in a single pass.
+//!
+//! Constructors reject malformed values as they arrive, yet documents edited
+//! in place can still break rules spanning several elements: repeated
+//! identifiers, utterances naming speakers missing from the cast list, stale
+//! extent counts, or pauses outlasting the recording. [`TeiDocument::validate`]
+//! runs all of these checks and reports each failure, with the [`TeiPath`] of
+//! the offending markup, instead of stopping at the first.
+
+mod report;
+mod timing;
+
+pub use report::{ValidationIssue, ValidationReport};
+
+use std::collections::HashSet;
+
+use crate::text::ensure_container_content;
+use crate::{
+    BodyBlock, BodyContentError, Container, HeaderValidationError, SourceDesc, Speaker,
+    SpeakerName, TeiDocument, TeiPath,
+};
+
+impl TeiDocument {
+    /// Runs every document-level check and collects the failures.
+    ///
+    /// The report covers category pointers and extent counts in the header,
+    /// non-empty body content, `xml:id` uniqueness across media and body
+    /// blocks, utterance speakers against the cast list (when one is
+    /// declared), and pause durations against the recorded audio length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::{FileDesc, ProfileDesc, TeiDocument, TeiError, TeiHeader, TeiText, Utterance};
+    ///
+    /// let mut profile = ProfileDesc::new();
+    /// profile.add_speaker("eiffel")?;
+    /// let header = TeiHeader::new(FileDesc::from_title_str("Wolf 359")?)
+    ///     .with_profile_desc(profile);
+    /// let mut text = TeiText::empty();
+    /// text.push_utterance(Utterance::from_text_segments(Some("hera"), ["Hello, Eiffel."])?);
+    ///
+    /// let report = TeiDocument::new(header, text).validate();
+    /// assert!(!report.is_valid());
+    /// assert_eq!(report.codes(), ["TEI-BODY-UNKNOWN-SPEAKER"]);
+    /// assert_eq!(report.issues()[0].path().to_string(), "/TEI/text/body/u[1]/@who");
+    /// # Ok::<(), TeiError>(())
+    /// ```
+    #[must_use]
+    pub fn validate(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        let blocks = block_paths(self.text.body().blocks());
+        self.check_header(&mut report);
+        check_body_content(&blocks, &mut report);
+        self.check_identifiers(&blocks, &mut report);
+        self.check_speakers(&blocks, &mut report);
+        timing::check_timing(self, &blocks, &mut report);
+        report
+    }
+
+    fn check_header(&self, report: &mut ValidationReport) {
+        if let Err(error) = self.header.check_cat_refs() {
+            let path = header_path().child("profileDesc").child("textClass");
+            report.push(error, path);
+        }
+
+        let Some(extent) = self.header.file_desc().extent() else {
+            return;
+        };
+        let body = self.text.body();
+        let measures = [
+            (
+                "utterances",
+                extent.utterance_count(),
+                body.utterances().count(),
+            ),
+            ("words", extent.word_count(), body.word_count()),
+        ];
+        for (measure, recorded, actual) in measures {
+            if let Some(count) = recorded.filter(|count| *count != actual) {
+                let error = HeaderValidationError::ExtentMismatch {
+                    measure,
+                    recorded: count,
+                    actual,
+                };
+                report.push(error, extent_path());
+            }
+        }
+    }
+
+    fn check_identifiers(&self, blocks: &[(TeiPath, &BodyBlock)], report: &mut ValidationReport) {
+        let media_ids = self
+            .header
+            .file_desc()
+            .source_desc()
+            .map(SourceDesc::media)
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, media)| media.id().map(|id| (media_path(index + 1), id)));
+        let block_ids = blocks.iter().filter_map(|(path, block)| {
+            let id = match block {
+                BodyBlock::Paragraph(paragraph) => paragraph.id(),
+                BodyBlock::Utterance(utterance) => utterance.id(),
+                BodyBlock::Raw(_) => None,
+            };
+            id.map(|value| (path.clone(), value))
+        });
+
+        let mut seen = HashSet::new();
+        let mut reported = HashSet::new();
+        for (path, id) in media_ids.chain(block_ids) {
+            let value = id.as_str();
+            if !seen.insert(value) && reported.insert(value) {
+                let error = BodyContentError::DuplicateIdentifier {
+                    id: value.to_owned(),
+                };
+                report.push(error, path.attribute("xml:id"));
+            }
+        }
+    }
+
+    fn check_speakers(&self, blocks: &[(TeiPath, &BodyBlock)], report: &mut ValidationReport) {
+        let Some(profile) = self.header.profile_desc() else {
+            return;
+        };
+        if profile.speakers().is_empty() {
+            return;
+        }
+
+        let cast: HashSet<&str> = profile.speakers().iter().map(SpeakerName::as_str).collect();
+        let mut reported = HashSet::new();
+        for (path, block) in blocks {
+            let BodyBlock::Utterance(utterance) = block else {
+                continue;
+            };
+            let Some(reference) = utterance.speaker().map(Speaker::as_str) else {
+                continue;
+            };
+            let name = reference.strip_prefix('#').unwrap_or(reference);
+            if !cast.contains(name) && reported.insert(reference) {
+                let error = BodyContentError::UnknownSpeaker {
+                    speaker: reference.to_owned(),
+                };
+                report.push(error, path.clone().attribute("who"));
+            }
+        }
+    }
+}
+
+fn check_body_content(blocks: &[(TeiPath, &BodyBlock)], report: &mut ValidationReport) {
+    for (path, block) in blocks {
+        let outcome = match block {
+            BodyBlock::Paragraph(paragraph) => {
+                ensure_container_content(paragraph.content(), Container::Paragraph)
+            }
+            BodyBlock::Utterance(utterance) => {
+                ensure_container_content(utterance.content(), Container::Utterance)
+            }
+            BodyBlock::Raw(_) => Ok(()),
+        };
+        if let Err(error) = outcome {
+            report.push(error, path.clone());
+        }
+    }
+}
+
+/// Pairs each body block with its path, numbering `<p>` and `<u>`
+/// separately. Raw markup is left out, since no check looks inside it.
+fn block_paths<'a>(blocks: impl Iterator<Item = &'a BodyBlock>) -> Vec<(TeiPath, &'a BodyBlock)> {
+    let body = TeiPath::root().child("text").child("body");
+    let mut paragraphs = 0;
+    let mut utterances = 0;
+
+    blocks
+        .filter_map(|block| {
+            let step = match block {
+                BodyBlock::Paragraph(_) => {
+                    paragraphs += 1;
+                    ("p", paragraphs)
+                }
+                BodyBlock::Utterance(_) => {
+                    utterances += 1;
+                    ("u", utterances)
+                }
+                BodyBlock::Raw(_) => return None,
+            };
+            Some((body.clone().nth_child(step.0, step.1), block))
+        })
+        .collect()
+}
+
+fn header_path() -> TeiPath {
+    TeiPath::root().child("teiHeader")
+}
+
+fn extent_path() -> TeiPath {
+    header_path().child("fileDesc").child("extent")
+}
+
+fn media_path(position: usize) -> TeiPath {
+    header_path()
+        .child("fileDesc")
+        .child("sourceDesc")
+        .child("recordingStmt")
+        .nth_child("recording", position)
+        .child("media")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FileDesc, Inline, P, ProfileDesc, TeiError, TeiHeader, TeiText, Utterance};
+
+    pub(super) fn document(header: TeiHeader, blocks: Vec<BodyBlock>) -> TeiDocument {
+        let mut text = TeiText::empty();
+        text.body_mut().extend(blocks);
+        TeiDocument::new(header, text)
+    }
+
+    pub(super) fn file_desc() -> FileDesc {
+        FileDesc::from_title_str("Wolf 359").unwrap_or_else(|error| panic!("valid title: {error}"))
+    }
+
+    pub(super) fn utterance(speaker: &str, id: Option<&str>, content: Vec<Inline>) -> BodyBlock {
+        let mut utterance = Utterance::from_inline(Some(speaker), content)
+            .unwrap_or_else(|error| panic!("valid utterance: {error}"));
+        if let Some(value) = id {
+            utterance
+                .set_id(value)
+                .unwrap_or_else(|error| panic!("valid identifier: {error}"));
+        }
+        BodyBlock::Utterance(utterance)
+    }
+
+    fn paths(report: &ValidationReport) -> Vec<String> {
+        report
+            .issues()
+            .iter()
+            .map(|issue| issue.path().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn minimal_document_is_valid() {
+        let report = document(TeiHeader::new(file_desc()), Vec::new()).validate();
+
+        assert!(report.is_valid());
+        assert!(report.issues().is_empty());
+    }
+
+    #[test]
+    fn reports_each_duplicate_identifier_once() {
+        let mut paragraph = P::from_text_segments(["Day 1."])
+            .unwrap_or_else(|error| panic!("valid paragraph: {error}"));
+        paragraph
+            .set_id("u1")
+            .unwrap_or_else(|error| panic!("valid identifier: {error}"));
+        let blocks = vec![
+            BodyBlock::Paragraph(paragraph),
+            utterance("eiffel", Some("u1"), vec![Inline::text("Hello.")]),
+            utterance("eiffel", Some("u1"), vec![Inline::text("Again.")]),
+        ];
+
+        let report = document(TeiHeader::new(file_desc()), blocks).validate();
+
+        assert_eq!(paths(&report), ["/TEI/text/body/u[1]/@xml:id"]);
+        assert_eq!(
+            report.issues().first().map(ValidationIssue::error),
+            Some(&TeiError::from(BodyContentError::DuplicateIdentifier {
+                id: "u1".to_owned(),
+            }))
+        );
+    }
+
+    #[test]
+    fn resolves_speaker_pointers_against_the_cast() {
+        let mut profile = ProfileDesc::new();
+        profile
+            .add_speaker("eiffel")
+            .unwrap_or_else(|error| panic!("valid speaker: {error}"));
+        let blocks = vec![
+            utterance("#eiffel", None, vec![Inline::text("Hello.")]),
+            utterance("hera", None, vec![Inline::text("Hi.")]),
+            utterance("hera", None, vec![Inline::text("Hi again.")]),
+        ];
+        let header = TeiHeader::new(file_desc()).with_profile_desc(profile);
+
+        let report = document(header, blocks).validate();
+
+        assert_eq!(report.codes(), ["TEI-BODY-UNKNOWN-SPEAKER"]);
+        assert_eq!(paths(&report), ["/TEI/text/body/u[2]/@who"]);
+    }
+
+    #[test]
+    fn skips_speaker_check_without_cast_list() {
+        let blocks = vec![utterance("hera", None, vec![Inline::text("Hi.")])];
+
+        assert!(
+            document(TeiHeader::new(file_desc()), blocks)
+                .validate()
+                .is_valid()
+        );
+    }
+
+    #[test]
+    fn flags_stale_extent_counts() {
+        let mut stale = file_desc();
+        stale.set_extent(
+            crate::Extent::new()
+                .with_utterance_count(3)
+                .with_word_count(1),
+        );
+        let blocks = vec![utterance("eiffel", None, vec![Inline::text("Hello.")])];
+
+        let report = document(TeiHeader::new(stale), blocks).validate();
+
+        assert_eq!(
+            report.issues().first().map(ValidationIssue::error),
+            Some(&TeiError::from(HeaderValidationError::ExtentMismatch {
+                measure: "utterances",
+                recorded: 3,
+                actual: 1,
+            }))
+        );
+        assert_eq!(paths(&report), ["/TEI/teiHeader/fileDesc/extent"]);
+    }
+}
@@ -0,0 +1,85 @@
+//! Findings collected by [`TeiDocument::validate`](crate::TeiDocument::validate).
+
+use std::fmt;
+
+use crate::{ErrorCode, TeiError, TeiPath};
+
+/// A single validation failure and the element it concerns.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidationIssue {
+    error: TeiError,
+    path: TeiPath,
+}
+
+impl ValidationIssue {
+    pub(super) fn new(error: impl Into<TeiError>, path: TeiPath) -> Self {
+        Self {
+            error: error.into(),
+            path,
+        }
+    }
+
+    /// Returns the underlying error.
+    #[must_use]
+    pub const fn error(&self) -> &TeiError {
+        &self.error
+    }
+
+    /// Returns the path of the offending element or attribute.
+    #[must_use]
+    pub const fn path(&self) -> &TeiPath {
+        &self.path
+    }
+
+    /// Returns the stable code of the underlying error.
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        self.error.code()
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}: {}", self.path, self.error)
+    }
+}
+
+/// Problems found by [`TeiDocument::validate`](crate::TeiDocument::validate),
+/// in discovery order.
+///
+/// Header checks run first, followed by body content, identifier
+/// uniqueness, speaker resolution, and timing.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ValidationReport {
+    issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Reports whether the document passed every check.
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns the recorded issues.
+    #[must_use]
+    pub const fn issues(&self) -> &[ValidationIssue] {
+        self.issues.as_slice()
+    }
+
+    /// Returns the stable codes of the recorded issues.
+    #[must_use]
+    pub fn codes(&self) -> Vec<&'static str> {
+        self.issues.iter().map(ValidationIssue::code).collect()
+    }
+
+    /// Consumes the report and returns the recorded issues.
+    #[must_use]
+    pub fn into_issues(self) -> Vec<ValidationIssue> {
+        self.issues
+    }
+
+    pub(super) fn push(&mut self, error: impl Into<TeiError>, path: TeiPath) {
+        self.issues.push(ValidationIssue::new(error, path));
+    }
+}
@@ -0,0 +1,125 @@
+//! Timing checks: pause durations must parse and fit within the recording.
+
+use std::time::Duration;
+
+use super::{ValidationReport, extent_path};
+use crate::header::parse_xsd_duration;
+use crate::{BodyBlock, BodyContentError, Extent, Inline, TeiDocument, TeiPath};
+
+pub(super) fn check_timing(
+    document: &TeiDocument,
+    blocks: &[(TeiPath, &BodyBlock)],
+    report: &mut ValidationReport,
+) {
+    let mut pauses = Vec::new();
+    for (path, block) in blocks {
+        let content = match block {
+            BodyBlock::Paragraph(paragraph) => paragraph.content(),
+            BodyBlock::Utterance(utterance) => utterance.content(),
+            BodyBlock::Raw(_) => continue,
+        };
+        collect_pauses(content, path, &mut pauses);
+    }
+
+    let mut total = Duration::ZERO;
+    for (path, value) in pauses {
+        if let Some(duration) = parse_xsd_duration(value) {
+            total = total.saturating_add(duration);
+        } else {
+            let error = BodyContentError::InvalidPauseDuration {
+                value: value.to_owned(),
+            };
+            report.push(error, path.attribute("dur"));
+        }
+    }
+
+    let recorded = document
+        .header()
+        .file_desc()
+        .extent()
+        .and_then(Extent::duration);
+    if recorded.is_some_and(|duration| total > duration) {
+        report.push(BodyContentError::PausesExceedDuration, extent_path());
+    }
+}
+
+/// Collects the paths and `@dur` values of spoken pauses, descending into
+/// `<hi>`, `<unclear>` and `<quote>` but not into notes.
+fn collect_pauses<'a>(
+    content: &'a [Inline],
+    parent: &TeiPath,
+    pauses: &mut Vec<(TeiPath, &'a str)>,
+) {
+    let mut pause_count = 0;
+    let mut hi_count = 0;
+    let mut unclear_count = 0;
+    let mut quote_count = 0;
+    for inline in content {
+        match inline {
+            Inline::Pause(pause) => {
+                pause_count += 1;
+                if let Some(value) = pause.duration() {
+                    pauses.push((parent.clone().nth_child("pause", pause_count), value));
+                }
+            }
+            Inline::Hi(hi) => {
+                hi_count += 1;
+                let path = parent.clone().nth_child("hi", hi_count);
+                collect_pauses(hi.content(), &path, pauses);
+            }
+            Inline::Unclear(unclear) => {
+                unclear_count += 1;
+                let path = parent.clone().nth_child("unclear", unclear_count);
+                collect_pauses(unclear.content(), &path, pauses);
+            }
+            Inline::Quote(quote) => {
+                quote_count += 1;
+                let path = parent.clone().nth_child("quote", quote_count);
+                collect_pauses(quote.content(), &path, pauses);
+            }
+            Inline::Text(_) | Inline::Note(_) | Inline::Raw(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{document, file_desc, utterance};
+    use crate::{Hi, Pause, TeiHeader};
+
+    use super::*;
+
+    fn paused(duration: &str) -> Vec<Inline> {
+        let mut pause = Pause::new();
+        pause.set_duration(duration);
+        vec![Inline::text("Well"), Inline::Pause(pause)]
+    }
+
+    #[test]
+    fn checks_pause_timing() {
+        let mut timed = file_desc();
+        timed.set_extent(Extent::new().with_duration(Duration::from_secs(5)));
+        let blocks = vec![
+            utterance("eiffel", None, paused("PT4S")),
+            utterance("eiffel", None, paused("PT2S")),
+            utterance("eiffel", None, vec![Inline::Hi(Hi::new(paused("a while")))]),
+        ];
+
+        let report = document(TeiHeader::new(timed), blocks).validate();
+
+        assert_eq!(
+            report.codes(),
+            [
+                "TEI-BODY-INVALID-PAUSE-DURATION",
+                "TEI-BODY-PAUSES-EXCEED-DURATION",
+            ]
+        );
+        assert_eq!(
+            report
+                .issues()
+                .first()
+                .map(|issue| issue.path().to_string()),
+            Some("/TEI/text/body/u[3]/hi[1]/pause[1]/@dur".to_owned())
+        );
+    }
+}
@@ -78,8 +78,7 @@ where
     let zero_based = index.checked_sub(1).context("block indices start at 1")?;
     let body = state.body();
     let block = body
-        .blocks()
-        .get(zero_based)
+        .block(zero_based)
         .context("scenario should configure the block")?;
     f(block)
 }
@@ -99,7 +98,7 @@ fn emphasised_text(hi: &Hi) -> Result<&str> {
 fn build_state() -> Result<BodyState> {
     let state = BodyState::default();
     ensure!(
-        state.body.borrow().blocks().is_empty(),
+        state.body.borrow().is_empty(),
         "fresh body must start without blocks"
     );
     ensure!(
@@ -134,10 +133,7 @@ fn validated_state_result() -> Result<BodyState> {
 fn an_empty_body(#[from(validated_state)] state: &BodyState) -> Result<()> {
     state.reset_body();
     let body = state.body();
-    ensure!(
-        body.blocks().is_empty(),
-        "body reset should remove all blocks"
-    );
+    ensure!(body.is_empty(), "body reset should remove all blocks");
     ensure!(
         state.last_error.borrow().is_none(),
         "reset body should clear recorded errors"
@@ -0,0 +1,149 @@
+//! Inline content classes: emphasis and pauses, plus the conversions
+//! between Python content lists and core inline nodes.
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use tei_core::{Inline, LanguageTag};
+
+use super::super::wrap_tei_result;
+
+/// Wrapper around [`tei_core::Pause`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "Pause")]
+#[derive(Clone, Debug)]
+pub struct Pause {
+    pub(super) inner: tei_core::Pause,
+}
+
+#[pymethods]
+impl Pause {
+    /// Constructs a pause with an optional `@dur` and `@type`.
+    #[new]
+    #[pyo3(signature = (duration=None, kind=None))]
+    #[must_use]
+    pub fn new(duration: Option<String>, kind: Option<String>) -> Self {
+        let mut inner = tei_core::Pause::new();
+        if let Some(value) = duration {
+            inner.set_duration(value);
+        }
+        if let Some(value) = kind {
+            inner.set_kind(value);
+        }
+        Self { inner }
+    }
+
+    /// Returns the recorded duration.
+    #[getter]
+    #[must_use]
+    pub fn duration(&self) -> Option<String> {
+        self.inner.duration().map(str::to_owned)
+    }
+
+    /// Returns the pause classification.
+    #[getter]
+    #[must_use]
+    pub fn kind(&self) -> Option<String> {
+        self.inner.kind().map(str::to_owned)
+    }
+}
+
+/// Wrapper around [`tei_core::Hi`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "Hi")]
+#[derive(Clone, Debug)]
+pub struct Hi {
+    pub(super) inner: tei_core::Hi,
+}
+
+#[pymethods]
+impl Hi {
+    /// Constructs emphasised content with an optional rendering hint and
+    /// language.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PyTypeError`] when an item is not a `str`, `Hi`, or `Pause`,
+    /// and `ValueError` when the content is blank or the language is empty.
+    #[new]
+    #[pyo3(signature = (content, rend=None, lang=None))]
+    pub fn new(
+        content: Vec<Bound<'_, PyAny>>,
+        rend: Option<String>,
+        lang: Option<String>,
+    ) -> PyResult<Self> {
+        let mut inner = wrap_tei_result(tei_core::Hi::try_new(inlines(content)?))?;
+        if let Some(value) = rend {
+            inner.set_rend(value);
+        }
+        if let Some(tag) = language(lang)? {
+            inner.set_lang(tag);
+        }
+        Ok(Self { inner })
+    }
+
+    /// Returns the inline content.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PyErr` when Python cannot allocate a nested object.
+    #[getter]
+    pub fn content(&self, py: Python<'_>) -> PyResult<Vec<PyObject>> {
+        content_objects(py, self.inner.content())
+    }
+
+    /// Returns the rendering hint.
+    #[getter]
+    #[must_use]
+    pub fn rend(&self) -> Option<String> {
+        self.inner.rend().map(str::to_owned)
+    }
+
+    /// Returns the declared `xml:lang`.
+    #[getter]
+    #[must_use]
+    pub fn lang(&self) -> Option<String> {
+        self.inner.lang().map(ToString::to_string)
+    }
+}
+
+pub(super) fn inlines(items: Vec<Bound<'_, PyAny>>) -> PyResult<Vec<Inline>> {
+    items
+        .into_iter()
+        .map(|item| {
+            if let Ok(text) = item.extract::<String>() {
+                return Ok(Inline::Text(text));
+            }
+            if let Ok(hi) = item.extract::<Hi>() {
+                return Ok(Inline::Hi(hi.inner));
+            }
+            if let Ok(pause) = item.extract::<Pause>() {
+                return Ok(Inline::Pause(pause.inner));
+            }
+            Err(PyTypeError::new_err(
+                "inline content must be str, Hi, or Pause objects",
+            ))
+        })
+        .collect()
+}
+
+pub(super) fn content_objects(py: Python<'_>, content: &[Inline]) -> PyResult<Vec<PyObject>> {
+    content
+        .iter()
+        .filter_map(|inline| match inline {
+            Inline::Text(text) => Some(Ok(text.into_py(py))),
+            Inline::Hi(hi) => Some(Py::new(py, Hi { inner: hi.clone() }).map(Py::into_any)),
+            Inline::Pause(pause) => Some(
+                Py::new(
+                    py,
+                    Pause {
+                        inner: pause.clone(),
+                    },
+                )
+                .map(Py::into_any),
+            ),
+            Inline::Unclear(_) | Inline::Quote(_) | Inline::Note(_) | Inline::Raw(_) => None,
+        })
+        .collect()
+}
+
+pub(super) fn language(lang: Option<String>) -> PyResult<Option<LanguageTag>> {
+    wrap_tei_result(lang.map(LanguageTag::new).transpose())
+}
@@ -0,0 +1,399 @@
+//! Body content classes: utterances, paragraphs, emphasis, and pauses.
+//!
+//! Each class wraps the matching `tei-core` type and validates on
+//! construction, so invalid content raises `ValueError` before it reaches a
+//! document. Inline content is passed as a list mixing `str`, [`Hi`], and
+//! [`Pause`] objects. Reading it back returns the same kinds, leaving out
+//! inline nodes without a Python class, such as `<note>`.
+
+mod inline;
+
+pub use inline::{Hi, Pause};
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+
+use super::{dict, wrap_tei_result};
+use inline::{content_objects, inlines, language};
+
+/// Wrapper around [`tei_core::P`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "P")]
+#[derive(Clone, Debug)]
+pub struct P {
+    pub(super) inner: tei_core::P,
+}
+
+#[pymethods]
+impl P {
+    /// Constructs a paragraph with an optional `xml:id` and language.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PyTypeError`] when an item is not a `str`, `Hi`, or `Pause`,
+    /// and `ValueError` when the content is blank or the identifier or
+    /// language is invalid.
+    #[new]
+    #[pyo3(signature = (content, id=None, lang=None))]
+    pub fn new(
+        content: Vec<Bound<'_, PyAny>>,
+        id: Option<String>,
+        lang: Option<String>,
+    ) -> PyResult<Self> {
+        let mut inner = wrap_tei_result(tei_core::P::from_inline(inlines(content)?))?;
+        if let Some(value) = id {
+            wrap_tei_result(inner.set_id(value))?;
+        }
+        if let Some(tag) = language(lang)? {
+            inner.set_lang(tag);
+        }
+        Ok(Self { inner })
+    }
+
+    /// Returns the inline content.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PyErr` when Python cannot allocate a nested object.
+    #[getter]
+    pub fn content(&self, py: Python<'_>) -> PyResult<Vec<PyObject>> {
+        content_objects(py, self.inner.content())
+    }
+
+    /// Returns the `xml:id`.
+    #[getter]
+    #[must_use]
+    pub fn id(&self) -> Option<String> {
+        self.inner.id().map(ToString::to_string)
+    }
+
+    /// Replaces the `xml:id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BodyContentError` when the identifier is blank or contains
+    /// whitespace.
+    pub fn set_id(&mut self, id: String) -> PyResult<()> {
+        wrap_tei_result(self.inner.set_id(id))
+    }
+
+    /// Returns the declared `xml:lang`.
+    #[getter]
+    #[must_use]
+    pub fn lang(&self) -> Option<String> {
+        self.inner.lang().map(ToString::to_string)
+    }
+}
+
+/// Wrapper around [`tei_core::Utterance`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "Utterance")]
+#[derive(Clone, Debug)]
+pub struct Utterance {
+    pub(super) inner: tei_core::Utterance,
+}
+
+#[pymethods]
+impl Utterance {
+    /// Constructs an utterance with an optional speaker, `xml:id`, and
+    /// language.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PyTypeError`] when an item is not a `str`, `Hi`, or `Pause`,
+    /// and `ValueError` when the content or speaker is blank or the
+    /// identifier or language is invalid.
+    #[new]
+    #[pyo3(signature = (content, speaker=None, id=None, lang=None))]
+    pub fn new(
+        content: Vec<Bound<'_, PyAny>>,
+        speaker: Option<String>,
+        id: Option<String>,
+        lang: Option<String>,
+    ) -> PyResult<Self> {
+        let mut inner =
+            wrap_tei_result(tei_core::Utterance::from_inline(speaker, inlines(content)?))?;
+        if let Some(value) = id {
+            wrap_tei_result(inner.set_id(value))?;
+        }
+        if let Some(tag) = language(lang)? {
+            inner.set_lang(tag);
+        }
+        Ok(Self { inner })
+    }
+
+    /// Returns the inline content.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PyErr` when Python cannot allocate a nested object.
+    #[getter]
+    pub fn content(&self, py: Python<'_>) -> PyResult<Vec<PyObject>> {
+        content_objects(py, self.inner.content())
+    }
+
+    /// Returns the speaker reference from `@who`.
+    #[getter]
+    #[must_use]
+    pub fn speaker(&self) -> Option<String> {
+        self.inner.speaker().map(ToString::to_string)
+    }
+
+    /// Returns the `xml:id`.
+    #[getter]
+    #[must_use]
+    pub fn id(&self) -> Option<String> {
+        self.inner.id().map(ToString::to_string)
+    }
+
+    /// Replaces the `xml:id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BodyContentError` when the identifier is blank or contains
+    /// whitespace.
+    pub fn set_id(&mut self, id: String) -> PyResult<()> {
+        wrap_tei_result(self.inner.set_id(id))
+    }
+
+    /// Returns the declared `xml:lang`.
+    #[getter]
+    #[must_use]
+    pub fn lang(&self) -> Option<String> {
+        self.inner.lang().map(ToString::to_string)
+    }
+
+    /// Returns the timeline reference in `@start`.
+    #[getter]
+    #[must_use]
+    pub fn start(&self) -> Option<String> {
+        self.inner.start().map(str::to_owned)
+    }
+
+    /// Returns the timeline reference in `@end`.
+    #[getter]
+    #[must_use]
+    pub fn end(&self) -> Option<String> {
+        self.inner.end().map(str::to_owned)
+    }
+}
+
+/// Wrapper around [`tei_core::BodyBlock`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "BodyBlock")]
+#[derive(Clone, Debug)]
+pub struct BodyBlock {
+    pub(super) inner: tei_core::BodyBlock,
+}
+
+impl From<tei_core::BodyBlock> for BodyBlock {
+    fn from(inner: tei_core::BodyBlock) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<BodyBlock> for tei_core::BodyBlock {
+    fn from(value: BodyBlock) -> Self {
+        value.inner
+    }
+}
+
+#[pymethods]
+impl BodyBlock {
+    /// Wraps a `P` or `Utterance` as a block.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PyTypeError`] when `content` is neither.
+    #[new]
+    pub fn new(content: &Bound<'_, PyAny>) -> PyResult<Self> {
+        block(content).map(Self::from)
+    }
+
+    /// Returns the element name of the block: `p`, `u`, or `raw` for markup
+    /// the data model does not cover.
+    #[getter]
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self.inner {
+            tei_core::BodyBlock::Paragraph(_) => "p",
+            tei_core::BodyBlock::Utterance(_) => "u",
+            tei_core::BodyBlock::Raw(_) => "raw",
+        }
+    }
+
+    /// Replaces the `xml:id` of the wrapped `P` or `Utterance`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BodyContentError` when the identifier is blank or contains
+    /// whitespace, and [`PyTypeError`] for raw blocks, which carry no
+    /// editable identifier.
+    pub fn set_id(&mut self, id: String) -> PyResult<()> {
+        match &mut self.inner {
+            tei_core::BodyBlock::Paragraph(paragraph) => wrap_tei_result(paragraph.set_id(id)),
+            tei_core::BodyBlock::Utterance(utterance) => wrap_tei_result(utterance.set_id(id)),
+            tei_core::BodyBlock::Raw(_) => Err(PyTypeError::new_err(
+                "raw blocks carry no editable identifier",
+            )),
+        }
+    }
+
+    /// Returns the wrapped `P` or `Utterance`, or the markup of a raw block
+    /// as a `str`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PyErr` when Python cannot allocate the object.
+    #[getter]
+    pub fn value(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(match &self.inner {
+            tei_core::BodyBlock::Paragraph(paragraph) => Py::new(
+                py,
+                P {
+                    inner: paragraph.clone(),
+                },
+            )?
+            .into_any(),
+            tei_core::BodyBlock::Utterance(utterance) => Py::new(
+                py,
+                Utterance {
+                    inner: utterance.clone(),
+                },
+            )?
+            .into_any(),
+            tei_core::BodyBlock::Raw(raw) => raw.as_str().into_py(py),
+        })
+    }
+
+    /// Returns the block as a `dict` tagged by `type`, following the JSON
+    /// mapping.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` when the block cannot be represented.
+    pub fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        dict::to_python(
+            py,
+            &wrap_tei_result(tei_core::block_to_json_value(&self.inner))?,
+        )
+    }
+
+    /// Reads a block from a `dict` produced by [`BodyBlock::to_dict`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` when `data` does not follow the mapping or holds
+    /// invalid content, and [`PyTypeError`] for values of other types.
+    #[staticmethod]
+    pub fn from_dict(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        wrap_tei_result(tei_core::block_from_json_value(dict::from_python(data)?)).map(Self::from)
+    }
+}
+
+/// Adds the body content classes to `module`.
+pub(super) fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<BodyBlock>()?;
+    module.add_class::<Utterance>()?;
+    module.add_class::<P>()?;
+    module.add_class::<Hi>()?;
+    module.add_class::<Pause>()?;
+    Ok(())
+}
+
+/// Reads a `BodyBlock`, `P`, or `Utterance` into a core block.
+pub(super) fn block(item: &Bound<'_, PyAny>) -> PyResult<tei_core::BodyBlock> {
+    if let Ok(wrapped) = item.extract::<BodyBlock>() {
+        return Ok(wrapped.inner);
+    }
+    if let Ok(paragraph) = item.extract::<P>() {
+        return Ok(tei_core::BodyBlock::Paragraph(paragraph.inner));
+    }
+    if let Ok(utterance) = item.extract::<Utterance>() {
+        return Ok(tei_core::BodyBlock::Utterance(utterance.inner));
+    }
+    Err(PyTypeError::new_err(
+        "body blocks must be BodyBlock, P, or Utterance objects",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::run_python;
+    use rstest::rstest;
+
+    #[test]
+    fn builds_documents_from_body_classes() {
+        let script = r#"
+line = tei.Utterance(
+    ["Day 1 of the ", tei.Hi(["mission"], rend="italic"), tei.Pause(duration="PT2S"), "."],
+    speaker="Eiffel",
+    id="u1",
+)
+note = tei.P(["Recorded aboard the Hephaestus."], lang="en")
+document = tei.Document("Wolf 359", [note, tei.BodyBlock(line)])
+
+blocks = document.blocks
+assert [block.kind for block in blocks] == ["p", "u"]
+first, second = blocks
+assert first.value.lang == "en"
+utterance = second.value
+assert (utterance.speaker, utterance.id) == ("Eiffel", "u1")
+text, hi, pause, stop = utterance.content
+assert (text, hi.content, hi.rend, pause.duration, stop) == ("Day 1 of the ", ["mission"], "italic", "PT2S", ".")
+"#;
+
+        run_python(script).unwrap_or_else(|error| panic!("script should succeed: {error}"));
+    }
+
+    #[test]
+    fn round_trips_blocks_through_dicts() {
+        let script = r#"
+block = tei.BodyBlock(tei.Utterance(["Hello", tei.Pause(kind="breath")], speaker="Eiffel"))
+data = block.to_dict()
+assert data == {"type": "u", "who": "Eiffel", "content": ["Hello", {"type": "pause", "kind": "breath"}]}
+assert tei.BodyBlock.from_dict(data).to_dict() == data
+"#;
+
+        run_python(script).unwrap_or_else(|error| panic!("script should succeed: {error}"));
+    }
+
+    #[test]
+    fn sets_identifiers_in_place() {
+        let script = r#"
+paragraph = tei.P(["Recorded aboard the Hephaestus."])
+paragraph.set_id("p1")
+block = tei.BodyBlock(tei.Utterance(["Hello"], speaker="Eiffel"))
+block.set_id("u1")
+assert (paragraph.id, block.value.id) == ("p1", "u1")
+
+try:
+    block.set_id("u 1")
+except tei.BodyContentError:
+    pass
+else:
+    raise AssertionError("accepted")
+raw = tei.BodyBlock.from_dict({"type": "raw", "markup": "<div/>"})
+try:
+    raw.set_id("d1")
+except TypeError:
+    pass
+else:
+    raise AssertionError("accepted")
+"#;
+
+        run_python(script).unwrap_or_else(|error| panic!("script should succeed: {error}"));
+    }
+
+    #[rstest]
+    #[case("tei.P(['   '])", "ValueError")]
+    #[case("tei.Utterance(['Hello'], speaker=' ')", "ValueError")]
+    #[case("tei.Utterance(['Hello'], id='u 1')", "ValueError")]
+    #[case("tei.Hi(['Hello'], lang='')", "ValueError")]
+    #[case("tei.P([42])", "TypeError")]
+    #[case("tei.Document('Wolf 359', ['Hello'])", "TypeError")]
+    fn rejects_invalid_content(#[case] expression: &str, #[case] exception: &str) {
+        let script = format!(
+            "try:\n    {expression}\nexcept {exception}:\n    pass\nelse:\n    raise AssertionError('accepted')\n"
+        );
+
+        run_python(&script).unwrap_or_else(|error| panic!("{expression} should raise: {error}"));
+    }
+}
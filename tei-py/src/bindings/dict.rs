@@ -0,0 +1,94 @@
+//! Conversion between the `tei-core` JSON mapping and plain Python objects.
+//!
+//! `to_dict` methods walk the [`Value`] tree of the mapping and build
+//! `dict`, `list`, `str`, `int`, `float`, `bool`, and `None` objects.
+//! `from_dict` methods read the same shapes back, accepting tuples as lists,
+//! so a dictionary survives `json.dumps` and `json.loads` unchanged.
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple};
+use serde_json::{Map, Number, Value};
+
+/// Builds the Python object mirroring `value`.
+pub(super) fn to_python(py: Python<'_>, value: &Value) -> PyResult<PyObject> {
+    Ok(match value {
+        Value::Null => py.None(),
+        Value::Bool(flag) => flag.into_py(py),
+        Value::Number(number) => number_to_python(py, number),
+        Value::String(text) => text.into_py(py),
+        Value::Array(items) => {
+            let list = PyList::empty_bound(py);
+            for item in items {
+                list.append(to_python(py, item)?)?;
+            }
+            list.into_py(py)
+        }
+        Value::Object(entries) => {
+            let dict = PyDict::new_bound(py);
+            for (key, entry) in entries {
+                dict.set_item(key, to_python(py, entry)?)?;
+            }
+            dict.into_py(py)
+        }
+    })
+}
+
+/// Reads a [`Value`] from plain Python objects.
+///
+/// # Errors
+///
+/// Returns `TypeError` when an object, or a dictionary key, has a type the
+/// mapping cannot hold, and `ValueError` for non-finite floats.
+pub(super) fn from_python(object: &Bound<'_, PyAny>) -> PyResult<Value> {
+    if object.is_none() {
+        return Ok(Value::Null);
+    }
+    if let Ok(flag) = object.downcast::<PyBool>() {
+        return Ok(Value::Bool(flag.is_true()));
+    }
+    if object.is_instance_of::<PyInt>() {
+        return object
+            .extract::<i64>()
+            .map(Value::from)
+            .or_else(|_| object.extract::<u64>().map(Value::from));
+    }
+    if let Ok(float) = object.downcast::<PyFloat>() {
+        return Number::from_f64(float.value())
+            .map(Value::Number)
+            .ok_or_else(|| PyValueError::new_err("non-finite floats cannot be represented"));
+    }
+    if let Ok(text) = object.downcast::<PyString>() {
+        return Ok(Value::String(text.to_str()?.to_owned()));
+    }
+    if let Ok(dict) = object.downcast::<PyDict>() {
+        let mut entries = Map::new();
+        for (key, entry) in dict {
+            let name = key
+                .downcast::<PyString>()
+                .map_err(|_| PyTypeError::new_err("dictionary keys must be str"))?;
+            entries.insert(name.to_str()?.to_owned(), from_python(&entry)?);
+        }
+        return Ok(Value::Object(entries));
+    }
+    if object.is_instance_of::<PyList>() || object.is_instance_of::<PyTuple>() {
+        return object
+            .iter()?
+            .map(|item| item.and_then(|value| from_python(&value)))
+            .collect::<PyResult<Vec<_>>>()
+            .map(Value::Array);
+    }
+    Err(PyTypeError::new_err(format!(
+        "cannot convert {} to TEI data",
+        object.get_type()
+    )))
+}
+
+fn number_to_python(py: Python<'_>, number: &Number) -> PyObject {
+    number
+        .as_i64()
+        .map(|value| value.into_py(py))
+        .or_else(|| number.as_u64().map(|value| value.into_py(py)))
+        .or_else(|| number.as_f64().map(|value| value.into_py(py)))
+        .unwrap_or_else(|| py.None())
+}
@@ -0,0 +1,319 @@
+//! The `Document` class wrapping a whole TEI document.
+
+use super::super::{TeiDocument, TeiError, emit_title_markup};
+use super::validation::{self, ValidationFinding};
+use super::{BodyBlock, TeiHeader, body, dict, frame, to_msgpack_py, wrap_tei_result};
+use pyo3::exceptions::PyIndexError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyString};
+use std::ops::Deref;
+use std::path::PathBuf;
+use tei_core::DocumentTitle;
+
+/// Wrapper around [`TeiDocument`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "Document")]
+#[derive(Clone, Debug)]
+pub struct Document {
+    inner: TeiDocument,
+}
+
+impl Document {
+    /// Attempts to build a [`Document`] from a raw title string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TeiError::DocumentTitle`] when the supplied title trims to
+    /// an empty string.
+    pub fn try_from_title(title: &str) -> Result<Self, TeiError> {
+        TeiDocument::from_title_str(title).map(Self::from)
+    }
+}
+
+impl From<TeiDocument> for Document {
+    fn from(inner: TeiDocument) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<Document> for TeiDocument {
+    fn from(value: Document) -> Self {
+        value.inner
+    }
+}
+
+impl Deref for Document {
+    type Target = TeiDocument;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+#[pymethods]
+impl Document {
+    /// Constructs a document with the provided title and optional body
+    /// blocks, each a `BodyBlock`, `P`, or `Utterance`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DocumentTitleError` when the trimmed title is empty, and
+    /// `TypeError` when a block is of another type.
+    #[new]
+    #[pyo3(signature = (title, blocks=None))]
+    pub fn new(title: &str, blocks: Option<Vec<Bound<'_, PyAny>>>) -> PyResult<Self> {
+        let mut document = wrap_tei_result(Self::try_from_title(title))?;
+        let body_blocks = blocks
+            .unwrap_or_default()
+            .iter()
+            .map(body::block)
+            .collect::<PyResult<Vec<_>>>()?;
+        document.inner.text_mut().body_mut().extend(body_blocks);
+        Ok(document)
+    }
+
+    /// Returns the validated document title.
+    #[getter]
+    #[must_use]
+    pub fn title(&self) -> String {
+        self.inner.title().to_string()
+    }
+
+    /// Returns the body blocks in document order.
+    #[getter]
+    #[must_use]
+    pub fn blocks(&self) -> Vec<BodyBlock> {
+        self.inner
+            .text()
+            .body()
+            .blocks()
+            .cloned()
+            .map(BodyBlock::from)
+            .collect()
+    }
+
+    /// Returns the TEI header.
+    #[getter]
+    #[must_use]
+    pub fn header(&self) -> TeiHeader {
+        TeiHeader::from(self.inner.header().clone())
+    }
+
+    /// Returns a copy whose header, and so whose title, is `header`.
+    #[must_use]
+    pub fn with_header(&self, header: TeiHeader) -> Self {
+        Self::from(TeiDocument::new(header.into(), self.inner.text().clone()))
+    }
+
+    /// Replaces the main title in place, keeping any subtitle.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DocumentTitleError` when the trimmed title is empty.
+    pub fn set_title(&mut self, title: &str) -> PyResult<()> {
+        let validated = wrap_tei_result(DocumentTitle::new(title))?;
+        self.inner.set_title(validated);
+        Ok(())
+    }
+
+    /// Appends an utterance of plain `text`, attributed to `speaker` when
+    /// one is given.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BodyContentError` when the speaker or text is blank.
+    #[pyo3(signature = (speaker, text))]
+    pub fn append_utterance(&mut self, speaker: Option<String>, text: String) -> PyResult<()> {
+        let utterance = wrap_tei_result(tei_core::Utterance::from_text_segments(speaker, [text]))?;
+        self.inner.text_mut().push_utterance(utterance);
+        Ok(())
+    }
+
+    /// Appends a `BodyBlock`, `P`, or `Utterance` to the body.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TypeError` when `block` is of another type.
+    pub fn append_block(&mut self, block: &Bound<'_, PyAny>) -> PyResult<()> {
+        let parsed = body::block(block)?;
+        self.inner.text_mut().body_mut().extend([parsed]);
+        Ok(())
+    }
+
+    /// Replaces the block at `index`, counting from the end when it is
+    /// negative, as with a list. Blocks read from `blocks` are copies, so
+    /// edits to them only reach the document through this method.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IndexError` when `index` is out of range and `TypeError`
+    /// when `block` is of another type.
+    pub fn set_block(&mut self, index: isize, block: &Bound<'_, PyAny>) -> PyResult<()> {
+        let parsed = body::block(block)?;
+        let body = self.inner.text_mut().body_mut();
+        let position = usize::try_from(index)
+            .ok()
+            .or_else(|| body.blocks().len().checked_sub(index.unsigned_abs()));
+        let slot = position
+            .and_then(|known| body.block_mut(known))
+            .ok_or_else(|| PyIndexError::new_err(format!("no block at index {index}")))?;
+        *slot = parsed;
+        Ok(())
+    }
+
+    /// Returns the document as a `dict` of plain Python values following the
+    /// `tei-core` JSON mapping.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TeiError` when the document cannot be represented.
+    pub fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        dict::to_python(py, &wrap_tei_result(tei_core::to_json_value(&self.inner))?)
+    }
+
+    /// Reads a document from a `dict` produced by [`Document::to_dict`] or
+    /// decoded from the JSON mapping.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonError` when `data` does not follow the mapping or
+    /// describes an invalid document, and `TypeError` for values of other
+    /// types.
+    #[staticmethod]
+    pub fn from_dict(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        wrap_tei_result(tei_core::from_json_value(dict::from_python(data)?)).map(Self::from)
+    }
+
+    /// Runs every document-level check and returns one `ValidationFinding`
+    /// per problem, in discovery order; an empty list means the document
+    /// passed. `episodic` is the only profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` when `profile` is unknown.
+    #[pyo3(signature = (profile="episodic"))]
+    pub fn validate(&self, profile: &str) -> PyResult<Vec<ValidationFinding>> {
+        validation::findings(&self.inner, profile)
+    }
+
+    /// Returns a pandas `DataFrame` with one row per utterance and the
+    /// columns `speaker`, `start`, `end`, `text`, and `id`. Times are in
+    /// seconds, or `None` when the utterance is untimed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ImportError` when pandas is not installed.
+    pub fn to_dataframe(&self, py: Python<'_>) -> PyResult<PyObject> {
+        frame::utterances(py, &self.inner)
+    }
+
+    /// Encodes the document as `MessagePack` bytes, like the module-level
+    /// `to_msgpack`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagePackError` when the document cannot be encoded.
+    pub fn to_msgpack<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        to_msgpack_py(py, self)
+    }
+
+    /// Compares documents by content.
+    #[must_use]
+    pub fn __eq__(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+
+    /// Leaves documents unhashable, as with `list`, because they are edited
+    /// in place and a content hash would change under a `set` or `dict`.
+    #[classattr]
+    const __hash__: Option<PyObject> = None;
+
+    /// Returns the number of body blocks.
+    #[must_use]
+    pub fn __len__(&self) -> usize {
+        self.inner.text().body().blocks().len()
+    }
+
+    /// Returns a summary naming the title and block count.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PyErr` when Python cannot render the title.
+    pub fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let title = PyString::new_bound(py, self.inner.title().as_str()).repr()?;
+        Ok(format!("<Document {title} blocks={}>", self.__len__()))
+    }
+
+    /// Returns the constructor arguments `pickle` uses to allocate a
+    /// document before restoring its state.
+    #[must_use]
+    pub fn __getnewargs__(&self) -> (String,) {
+        (self.title(),)
+    }
+
+    /// Returns the pickled state as `MessagePack` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagePackError` when the document cannot be encoded.
+    pub fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        to_msgpack_py(py, self)
+    }
+
+    /// Restores the document from state produced by `__getstate__`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MessagePackError` when the state is malformed or describes an
+    /// invalid document.
+    pub fn __setstate__(&mut self, state: &[u8]) -> PyResult<()> {
+        self.inner = wrap_tei_result(tei_core::from_msgpack(state))?;
+        Ok(())
+    }
+
+    /// Reads and parses the TEI file at `path`, a `str` or `os.PathLike`,
+    /// detecting UTF-16 and declared single-byte encodings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FileError` when the file cannot be read, and the parse errors
+    /// of `parse_xml` otherwise.
+    #[staticmethod]
+    pub fn from_file(py: Python<'_>, path: PathBuf) -> PyResult<Self> {
+        wrap_tei_result(py.allow_threads(move || tei_xml::parse_file(path))).map(Self::from)
+    }
+
+    /// Writes the document to `path`, a `str` or `os.PathLike`, as TEI XML.
+    ///
+    /// The file is replaced atomically, so readers never see a partial
+    /// document. `declaration` controls the leading `<?xml ...?>` line.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XmlError` when the document cannot be emitted and `FileError`
+    /// when the file cannot be written.
+    #[pyo3(signature = (path, declaration=true))]
+    pub fn save(&self, py: Python<'_>, path: PathBuf, declaration: bool) -> PyResult<()> {
+        let document = &self.inner;
+        let options = tei_xml::EmitOptions::new().with_declaration(declaration);
+        wrap_tei_result(py.allow_threads(move || tei_xml::emit_to_path(document, path, options)))
+    }
+
+    /// Emits the document title as TEI markup.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DocumentTitleError` when the stored document title is invalid.
+    pub fn emit_title_markup(&self) -> PyResult<String> {
+        wrap_tei_result(emit_title_markup(self.inner.title().as_str()))
+    }
+
+    /// Serializes the whole document as TEI XML, releasing the GIL while
+    /// the markup is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XmlError` when the document cannot be emitted.
+    pub fn to_xml(&self, py: Python<'_>) -> PyResult<String> {
+        wrap_tei_result(py.allow_threads(|| tei_xml::emit_xml(&self.inner)))
+    }
+}
@@ -0,0 +1,208 @@
+//! The exception hierarchy raised for `tei-core` errors.
+//!
+//! Every failure reported by the Rust core raises a subclass of
+//! `tei_rapporteur.TeiError`, chosen by the [`tei_core::TeiError`] variant,
+//! so callers can catch one layer without matching on messages. The base
+//! class derives from `ValueError`, keeping existing `except ValueError`
+//! handlers working. Each exception carries the stable error code in its
+//! `code` attribute, and its message keeps the `[CODE]` prefix.
+
+#![expect(
+    unexpected_cfgs,
+    reason = "create_exception! tests PyO3's `gil-refs` feature against this crate"
+)]
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyModule;
+use tei_core::ErrorCode;
+
+create_exception!(
+    tei_rapporteur,
+    TeiError,
+    PyValueError,
+    "Base class for errors reported by the TEI core."
+);
+create_exception!(
+    tei_rapporteur,
+    DocumentTitleError,
+    TeiError,
+    "A document title failed validation."
+);
+create_exception!(
+    tei_rapporteur,
+    HeaderValidationError,
+    TeiError,
+    "Header metadata failed validation."
+);
+create_exception!(
+    tei_rapporteur,
+    BodyContentError,
+    TeiError,
+    "Body content failed validation."
+);
+create_exception!(
+    tei_rapporteur,
+    IdentifierValidationError,
+    TeiError,
+    "An `xml:id` failed validation."
+);
+create_exception!(
+    tei_rapporteur,
+    SpeakerValidationError,
+    TeiError,
+    "A speaker reference failed validation."
+);
+create_exception!(
+    tei_rapporteur,
+    XmlError,
+    TeiError,
+    "XML parsing or serialisation failed."
+);
+create_exception!(
+    tei_rapporteur,
+    JsonError,
+    TeiError,
+    "Data did not follow the JSON mapping."
+);
+create_exception!(
+    tei_rapporteur,
+    MessagePackError,
+    TeiError,
+    "MessagePack encoding or decoding failed."
+);
+create_exception!(
+    tei_rapporteur,
+    FormatImportError,
+    TeiError,
+    "A transcript in another format could not be imported."
+);
+create_exception!(
+    tei_rapporteur,
+    FormatExportError,
+    TeiError,
+    "A document could not be written in another format."
+);
+create_exception!(
+    tei_rapporteur,
+    FileError,
+    TeiError,
+    "Reading or writing a file failed."
+);
+create_exception!(
+    tei_rapporteur,
+    UnsupportedEncodingError,
+    TeiError,
+    "The input is in a character encoding the parser cannot read."
+);
+create_exception!(
+    tei_rapporteur,
+    LimitExceededError,
+    TeiError,
+    "The input exceeded a parser resource limit."
+);
+
+/// Builds the Python exception for `error`, tagged with its code.
+pub(super) fn to_py_err(error: &tei_core::TeiError) -> PyErr {
+    let message = error.display_with_code().to_string();
+    let exception = match error {
+        tei_core::TeiError::DocumentTitle(_) => DocumentTitleError::new_err(message),
+        tei_core::TeiError::Header(_) => HeaderValidationError::new_err(message),
+        tei_core::TeiError::Body(_) => BodyContentError::new_err(message),
+        tei_core::TeiError::Identifier(_) => IdentifierValidationError::new_err(message),
+        tei_core::TeiError::Speaker(_) => SpeakerValidationError::new_err(message),
+        tei_core::TeiError::Xml { .. } => XmlError::new_err(message),
+        tei_core::TeiError::Json { .. } => JsonError::new_err(message),
+        tei_core::TeiError::MessagePack { .. } => MessagePackError::new_err(message),
+        tei_core::TeiError::Import { .. } => FormatImportError::new_err(message),
+        tei_core::TeiError::Export { .. } => FormatExportError::new_err(message),
+        tei_core::TeiError::Io { .. } => FileError::new_err(message),
+        tei_core::TeiError::UnsupportedEncoding { .. } => {
+            UnsupportedEncodingError::new_err(message)
+        }
+        tei_core::TeiError::LimitExceeded { .. } => LimitExceededError::new_err(message),
+        _ => TeiError::new_err(message),
+    };
+    Python::with_gil(|py| {
+        let tagged = exception.value_bound(py).setattr("code", error.code());
+        tagged.map_or_else(|failure| failure, |()| exception)
+    })
+}
+
+/// Adds the exception classes to `module`.
+pub(super) fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = module.py();
+    module.add("TeiError", py.get_type_bound::<TeiError>())?;
+    module.add(
+        "DocumentTitleError",
+        py.get_type_bound::<DocumentTitleError>(),
+    )?;
+    module.add(
+        "HeaderValidationError",
+        py.get_type_bound::<HeaderValidationError>(),
+    )?;
+    module.add("BodyContentError", py.get_type_bound::<BodyContentError>())?;
+    module.add(
+        "IdentifierValidationError",
+        py.get_type_bound::<IdentifierValidationError>(),
+    )?;
+    module.add(
+        "SpeakerValidationError",
+        py.get_type_bound::<SpeakerValidationError>(),
+    )?;
+    module.add("XmlError", py.get_type_bound::<XmlError>())?;
+    module.add("JsonError", py.get_type_bound::<JsonError>())?;
+    module.add("MessagePackError", py.get_type_bound::<MessagePackError>())?;
+    module.add(
+        "FormatImportError",
+        py.get_type_bound::<FormatImportError>(),
+    )?;
+    module.add(
+        "FormatExportError",
+        py.get_type_bound::<FormatExportError>(),
+    )?;
+    module.add("FileError", py.get_type_bound::<FileError>())?;
+    module.add(
+        "UnsupportedEncodingError",
+        py.get_type_bound::<UnsupportedEncodingError>(),
+    )?;
+    module.add(
+        "LimitExceededError",
+        py.get_type_bound::<LimitExceededError>(),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::run_python;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("tei.Document('  ')", "DocumentTitleError", "TEI-TITLE-EMPTY")]
+    #[case("tei.Creation(date='soon')", "HeaderValidationError", "TEI-HEADER")]
+    #[case("tei.P(['  '])", "BodyContentError", "TEI-BODY-EMPTY-SEGMENT")]
+    #[case("tei.parse_xml('<TEI>')", "XmlError", "TEI-XML")]
+    #[case(
+        "tei.Document.from_dict({'header': {}, 'body': []})",
+        "JsonError",
+        "TEI-JSON"
+    )]
+    #[case("tei.from_msgpack(b'\\xc1')", "MessagePackError", "TEI-MSGPACK")]
+    fn raises_the_matching_exception(
+        #[case] expression: &str,
+        #[case] exception: &str,
+        #[case] code: &str,
+    ) {
+        let script = format!(
+            "try:\n    {expression}\nexcept tei.{exception} as error:\n    \
+             assert isinstance(error, tei.TeiError) and isinstance(error, ValueError)\n    \
+             assert error.code.startswith('{code}'), error.code\n    \
+             assert str(error).startswith('[' + error.code + ']'), str(error)\n\
+             else:\n    raise AssertionError('accepted')\n"
+        );
+
+        run_python(&script).unwrap_or_else(|error| panic!("{expression} should raise: {error}"));
+    }
+}
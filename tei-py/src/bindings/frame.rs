@@ -0,0 +1,54 @@
+//! pandas data frames built from the utterances of a document.
+//!
+//! pandas is an optional dependency, installed with the `pandas` extra, so
+//! it is imported only when a frame is requested. Columns are built as
+//! Python lists and handed to the `DataFrame` constructor, which keeps the
+//! bindings free of any Arrow dependency.
+
+use std::time::Duration;
+
+use pyo3::exceptions::PyImportError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use tei_core::TeiDocument;
+use tei_core::export::{UtteranceRow, utterance_rows};
+
+/// Builds a `DataFrame` with one row per utterance of `document`.
+///
+/// # Errors
+///
+/// Returns `ImportError` when pandas is not installed.
+pub(super) fn utterances(py: Python<'_>, document: &TeiDocument) -> PyResult<PyObject> {
+    let pandas = py.import_bound("pandas").map_err(|error| {
+        let missing = PyImportError::new_err(
+            "Document.to_dataframe needs pandas; install tei-rapporteur[pandas]",
+        );
+        missing.set_cause(py, Some(error));
+        missing
+    })?;
+    let rows = utterance_rows(document);
+    let seconds = |time: Option<Duration>| time.map(|value| value.as_secs_f64());
+    let columns = PyDict::new_bound(py);
+    columns.set_item(
+        "speaker",
+        rows.iter().map(UtteranceRow::speaker).collect::<Vec<_>>(),
+    )?;
+    columns.set_item(
+        "start",
+        rows.iter()
+            .map(|row| seconds(row.start()))
+            .collect::<Vec<_>>(),
+    )?;
+    columns.set_item(
+        "end",
+        rows.iter()
+            .map(|row| seconds(row.end()))
+            .collect::<Vec<_>>(),
+    )?;
+    columns.set_item(
+        "text",
+        rows.iter().map(UtteranceRow::text).collect::<Vec<_>>(),
+    )?;
+    columns.set_item("id", rows.iter().map(UtteranceRow::id).collect::<Vec<_>>())?;
+    Ok(pandas.getattr("DataFrame")?.call1((columns,))?.unbind())
+}
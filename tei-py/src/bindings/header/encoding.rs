@@ -0,0 +1,230 @@
+//! The encoding description: annotation systems, software, and project
+//! notes.
+//!
+//! The editorial, tagging, classification, and referencing declarations are
+//! not yet exposed to Python.
+
+use pyo3::prelude::*;
+use tei_core::{ProjectDesc, SamplingDecl};
+
+use super::super::body::P;
+use super::super::wrap_tei_result;
+
+/// Wrapper around [`tei_core::AnnotationSystem`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "AnnotationSystem")]
+#[derive(Clone, Debug)]
+pub struct AnnotationSystem {
+    inner: tei_core::AnnotationSystem,
+}
+
+#[pymethods]
+impl AnnotationSystem {
+    /// Constructs an annotation system descriptor.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` when the identifier is blank.
+    #[new]
+    #[pyo3(signature = (identifier, description=String::new()))]
+    pub fn new(identifier: String, description: String) -> PyResult<Self> {
+        wrap_tei_result(tei_core::AnnotationSystem::new(identifier, description))
+            .map(|inner| Self { inner })
+    }
+
+    /// Returns the identifier.
+    #[getter]
+    #[must_use]
+    pub fn identifier(&self) -> String {
+        self.inner.identifier().to_string()
+    }
+
+    /// Returns the description.
+    #[getter]
+    #[must_use]
+    pub fn description(&self) -> Option<String> {
+        self.inner.description().map(str::to_owned)
+    }
+}
+
+/// Wrapper around [`tei_core::Application`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "Application")]
+#[derive(Clone, Debug)]
+pub struct Application {
+    inner: tei_core::Application,
+}
+
+#[pymethods]
+impl Application {
+    /// Constructs an application record from its identifier and version.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` when either value is blank.
+    #[new]
+    pub fn new(ident: String, version: String) -> PyResult<Self> {
+        wrap_tei_result(tei_core::Application::new(ident, version)).map(|inner| Self { inner })
+    }
+
+    /// Returns a copy recording when the application ran.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` when the date is not an ISO 8601 calendar date.
+    pub fn with_date(&self, date: String) -> PyResult<Self> {
+        wrap_tei_result(self.inner.clone().with_date(date)).map(|inner| Self { inner })
+    }
+
+    /// Returns a copy carrying a descriptive note.
+    #[must_use]
+    pub fn with_note(&self, note: String) -> Self {
+        Self {
+            inner: self.inner.clone().with_note(note),
+        }
+    }
+
+    /// Returns the application identifier.
+    #[getter]
+    #[must_use]
+    pub fn ident(&self) -> String {
+        self.inner.ident().to_owned()
+    }
+
+    /// Returns the application version.
+    #[getter]
+    #[must_use]
+    pub fn version(&self) -> String {
+        self.inner.version().to_owned()
+    }
+
+    /// Returns the date the application ran.
+    #[getter]
+    #[must_use]
+    pub fn date(&self) -> Option<String> {
+        self.inner.date().map(str::to_owned)
+    }
+
+    /// Returns the descriptive notes.
+    #[getter]
+    #[must_use]
+    pub fn notes(&self) -> Vec<String> {
+        self.inner.notes().to_vec()
+    }
+}
+
+/// Wrapper around [`tei_core::EncodingDesc`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "EncodingDesc")]
+#[derive(Clone, Debug, Default)]
+pub struct EncodingDesc {
+    pub(super) inner: tei_core::EncodingDesc,
+}
+
+#[pymethods]
+impl EncodingDesc {
+    /// Constructs an empty encoding description.
+    #[new]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records an annotation system.
+    pub fn add_annotation_system(&mut self, system: AnnotationSystem) {
+        self.inner.add_annotation_system(system.inner);
+    }
+
+    /// Returns a copy listing `applications` in its `<appInfo>`.
+    #[must_use]
+    pub fn with_applications(&self, applications: Vec<Application>) -> Self {
+        let mut app_info = tei_core::AppInfo::new();
+        for application in applications {
+            app_info.add_application(application.inner);
+        }
+        Self {
+            inner: self.inner.clone().with_app_info(app_info),
+        }
+    }
+
+    /// Returns a copy describing the project in `paragraphs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` when `paragraphs` is empty.
+    pub fn with_project_desc(&self, paragraphs: Vec<P>) -> PyResult<Self> {
+        let project = wrap_tei_result(ProjectDesc::new(core_paragraphs(paragraphs)))?;
+        Ok(Self {
+            inner: self.inner.clone().with_project_desc(project),
+        })
+    }
+
+    /// Returns a copy describing how the material was sampled in
+    /// `paragraphs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` when `paragraphs` is empty.
+    pub fn with_sampling_decl(&self, paragraphs: Vec<P>) -> PyResult<Self> {
+        let sampling = wrap_tei_result(SamplingDecl::new(core_paragraphs(paragraphs)))?;
+        Ok(Self {
+            inner: self.inner.clone().with_sampling_decl(sampling),
+        })
+    }
+
+    /// Returns the annotation systems.
+    #[getter]
+    #[must_use]
+    pub fn annotation_systems(&self) -> Vec<AnnotationSystem> {
+        self.inner
+            .annotation_systems()
+            .iter()
+            .map(|inner| AnnotationSystem {
+                inner: inner.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns the applications listed in `<appInfo>`.
+    #[getter]
+    #[must_use]
+    pub fn applications(&self) -> Vec<Application> {
+        self.inner
+            .app_info()
+            .map(tei_core::AppInfo::applications)
+            .unwrap_or_default()
+            .iter()
+            .map(|inner| Application {
+                inner: inner.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns the paragraphs of the project description.
+    #[getter]
+    #[must_use]
+    pub fn project_desc(&self) -> Vec<P> {
+        python_paragraphs(self.inner.project_desc().map(ProjectDesc::paragraphs))
+    }
+
+    /// Returns the paragraphs of the sampling declaration.
+    #[getter]
+    #[must_use]
+    pub fn sampling_decl(&self) -> Vec<P> {
+        python_paragraphs(self.inner.sampling_decl().map(SamplingDecl::paragraphs))
+    }
+}
+
+fn core_paragraphs(paragraphs: Vec<P>) -> Vec<tei_core::P> {
+    paragraphs
+        .into_iter()
+        .map(|paragraph| paragraph.inner)
+        .collect()
+}
+
+fn python_paragraphs(paragraphs: Option<&[tei_core::P]>) -> Vec<P> {
+    paragraphs
+        .unwrap_or_default()
+        .iter()
+        .map(|inner| P {
+            inner: inner.clone(),
+        })
+        .collect()
+}
@@ -0,0 +1,357 @@
+//! The file description and the recording it transcribes.
+
+use std::time::Duration;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use tei_core::{DocumentTitle, NotesStmt};
+
+use super::super::wrap_tei_result;
+use super::statements::{Idno, PublicationStmt, RespStmt, SeriesStmt};
+
+/// Wrapper around [`tei_core::Extent`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "Extent")]
+#[derive(Clone, Debug)]
+pub struct Extent {
+    inner: tei_core::Extent,
+}
+
+#[pymethods]
+impl Extent {
+    /// Constructs extent measurements, with the running time in seconds.
+    /// Fractional seconds are truncated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PyValueError`] when `duration` is negative or not finite.
+    #[new]
+    #[pyo3(signature = (duration=None, utterance_count=None, word_count=None))]
+    pub fn new(
+        duration: Option<f64>,
+        utterance_count: Option<usize>,
+        word_count: Option<usize>,
+    ) -> PyResult<Self> {
+        let mut inner = tei_core::Extent::new();
+        if let Some(seconds) = duration {
+            inner = inner.with_duration(seconds_to_duration(seconds)?);
+        }
+        if let Some(count) = utterance_count {
+            inner = inner.with_utterance_count(count);
+        }
+        if let Some(count) = word_count {
+            inner = inner.with_word_count(count);
+        }
+        Ok(Self { inner })
+    }
+
+    /// Returns the running time in seconds.
+    #[getter]
+    #[must_use]
+    pub fn duration(&self) -> Option<f64> {
+        self.inner.duration().map(|value| value.as_secs_f64())
+    }
+
+    /// Returns the number of utterances.
+    #[getter]
+    #[must_use]
+    pub const fn utterance_count(&self) -> Option<usize> {
+        self.inner.utterance_count()
+    }
+
+    /// Returns the number of words.
+    #[getter]
+    #[must_use]
+    pub const fn word_count(&self) -> Option<usize> {
+        self.inner.word_count()
+    }
+}
+
+/// Wrapper around [`tei_core::Media`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "Media")]
+#[derive(Clone, Debug)]
+pub struct Media {
+    inner: tei_core::Media,
+}
+
+#[pymethods]
+impl Media {
+    /// Constructs a recording reference, with the running time in seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PyValueError`] when the URL is blank, the MIME type is not
+    /// `type/subtype`, or `duration` is negative or not finite.
+    #[new]
+    #[pyo3(signature = (url, mime_type, duration=None))]
+    pub fn new(url: String, mime_type: String, duration: Option<f64>) -> PyResult<Self> {
+        let mut inner = wrap_tei_result(tei_core::Media::new(url, mime_type))?;
+        if let Some(seconds) = duration {
+            inner = inner.with_duration(seconds_to_duration(seconds)?);
+        }
+        Ok(Self { inner })
+    }
+
+    /// Returns the recording URL.
+    #[getter]
+    #[must_use]
+    pub fn url(&self) -> String {
+        self.inner.url().to_owned()
+    }
+
+    /// Returns the MIME type.
+    #[getter]
+    #[must_use]
+    pub fn mime_type(&self) -> String {
+        self.inner.mime_type().to_owned()
+    }
+
+    /// Returns the running time in seconds.
+    #[getter]
+    #[must_use]
+    pub fn duration(&self) -> Option<f64> {
+        self.inner.duration().map(|value| value.as_secs_f64())
+    }
+}
+
+/// Wrapper around [`tei_core::SourceDesc`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "SourceDesc")]
+#[derive(Clone, Debug, Default)]
+pub struct SourceDesc {
+    inner: tei_core::SourceDesc,
+}
+
+#[pymethods]
+impl SourceDesc {
+    /// Constructs an empty source description.
+    #[new]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a recording of the transcribed audio.
+    pub fn add_media(&mut self, media: Media) {
+        self.inner.add_media(media.inner);
+    }
+
+    /// Returns the recordings in insertion order.
+    #[getter]
+    #[must_use]
+    pub fn media(&self) -> Vec<Media> {
+        self.inner
+            .media()
+            .iter()
+            .map(|media| Media {
+                inner: media.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Wrapper around [`tei_core::FileDesc`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "FileDesc")]
+#[derive(Clone, Debug)]
+pub struct FileDesc {
+    pub(super) inner: tei_core::FileDesc,
+}
+
+#[pymethods]
+impl FileDesc {
+    /// Constructs a file description from the document title.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PyValueError`] when the title is blank.
+    #[new]
+    pub fn new(title: &str) -> PyResult<Self> {
+        wrap_tei_result(tei_core::FileDesc::from_title_str(title)).map(|inner| Self { inner })
+    }
+
+    /// Returns a copy carrying `publication`.
+    #[must_use]
+    pub fn with_publication_stmt(&self, publication: PublicationStmt) -> Self {
+        self.map(|inner| inner.with_publication_stmt(publication.inner))
+    }
+
+    /// Returns a copy carrying `series`.
+    #[must_use]
+    pub fn with_series_stmt(&self, series: SeriesStmt) -> Self {
+        self.map(|inner| inner.with_series_stmt(series.inner))
+    }
+
+    /// Returns a copy carrying `extent`.
+    #[must_use]
+    pub fn with_extent(&self, extent: Extent) -> Self {
+        self.map(|inner| inner.with_extent(extent.inner))
+    }
+
+    /// Returns a copy carrying `source`.
+    #[must_use]
+    pub fn with_source_desc(&self, source: SourceDesc) -> Self {
+        self.map(|inner| inner.with_source_desc(source.inner))
+    }
+
+    /// Returns a copy carrying `synopsis`; blank values clear it.
+    #[must_use]
+    pub fn with_synopsis(&self, synopsis: String) -> Self {
+        self.map(|inner| inner.with_synopsis(synopsis))
+    }
+
+    /// Returns a copy carrying `subtitle`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PyValueError`] when the subtitle is blank.
+    pub fn with_subtitle(&self, subtitle: &str) -> PyResult<Self> {
+        let title = wrap_tei_result(DocumentTitle::new(subtitle))?;
+        Ok(self.map(|inner| inner.with_subtitle(title)))
+    }
+
+    /// Returns a copy carrying free-form header notes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PyValueError`] when `notes` is empty or a note is blank.
+    pub fn with_notes(&self, notes: Vec<String>) -> PyResult<Self> {
+        let statement = wrap_tei_result(NotesStmt::new(notes))?;
+        Ok(self.map(|inner| inner.with_notes_stmt(statement)))
+    }
+
+    /// Records an alternative title.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PyValueError`] when the title is blank.
+    pub fn add_alternative_title(&mut self, title: &str) -> PyResult<()> {
+        let alternative = wrap_tei_result(DocumentTitle::new(title))?;
+        self.inner.add_alternative_title(alternative);
+        Ok(())
+    }
+
+    /// Records an identifier, such as the episode GUID.
+    pub fn add_idno(&mut self, idno: Idno) {
+        self.inner.add_idno(idno.inner);
+    }
+
+    /// Credits a contributor, such as a transcriber or editor.
+    pub fn add_resp_stmt(&mut self, resp_stmt: RespStmt) {
+        self.inner.add_resp_stmt(resp_stmt.inner);
+    }
+
+    /// Returns the main title.
+    #[getter]
+    #[must_use]
+    pub fn title(&self) -> String {
+        self.inner.title().to_string()
+    }
+
+    /// Returns the subtitle.
+    #[getter]
+    #[must_use]
+    pub fn subtitle(&self) -> Option<String> {
+        self.inner.subtitle().map(ToString::to_string)
+    }
+
+    /// Returns the alternative titles.
+    #[getter]
+    #[must_use]
+    pub fn alternative_titles(&self) -> Vec<String> {
+        self.inner
+            .alternative_titles()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    /// Returns the publication statement.
+    #[getter]
+    #[must_use]
+    pub fn publication_stmt(&self) -> Option<PublicationStmt> {
+        self.inner.publication_stmt().map(|inner| PublicationStmt {
+            inner: inner.clone(),
+        })
+    }
+
+    /// Returns the series statement.
+    #[getter]
+    #[must_use]
+    pub fn series_stmt(&self) -> Option<SeriesStmt> {
+        self.inner.series_stmt().map(|inner| SeriesStmt {
+            inner: inner.clone(),
+        })
+    }
+
+    /// Returns the extent measurements.
+    #[getter]
+    #[must_use]
+    pub fn extent(&self) -> Option<Extent> {
+        self.inner.extent().map(|inner| Extent {
+            inner: inner.clone(),
+        })
+    }
+
+    /// Returns the source description.
+    #[getter]
+    #[must_use]
+    pub fn source_desc(&self) -> Option<SourceDesc> {
+        self.inner.source_desc().map(|inner| SourceDesc {
+            inner: inner.clone(),
+        })
+    }
+
+    /// Returns the recorded identifiers.
+    #[getter]
+    #[must_use]
+    pub fn idnos(&self) -> Vec<Idno> {
+        self.inner
+            .idnos()
+            .iter()
+            .map(|inner| Idno {
+                inner: inner.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns the credited contributors.
+    #[getter]
+    #[must_use]
+    pub fn resp_stmts(&self) -> Vec<RespStmt> {
+        self.inner
+            .resp_stmts()
+            .iter()
+            .map(|inner| RespStmt {
+                inner: inner.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns the header notes.
+    #[getter]
+    #[must_use]
+    pub fn notes(&self) -> Vec<String> {
+        self.inner
+            .notes_stmt()
+            .map(|notes| notes.notes().to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Returns the synopsis.
+    #[getter]
+    #[must_use]
+    pub fn synopsis(&self) -> Option<String> {
+        self.inner.synopsis().map(str::to_owned)
+    }
+}
+
+impl FileDesc {
+    fn map(&self, update: impl FnOnce(tei_core::FileDesc) -> tei_core::FileDesc) -> Self {
+        Self {
+            inner: update(self.inner.clone()),
+        }
+    }
+}
+
+fn seconds_to_duration(seconds: f64) -> PyResult<Duration> {
+    Duration::try_from_secs_f64(seconds)
+        .map_err(|_| PyValueError::new_err(format!("invalid duration {seconds} seconds")))
+}
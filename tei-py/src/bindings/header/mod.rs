@@ -0,0 +1,319 @@
+//! Header metadata classes.
+//!
+//! Each class wraps the matching `tei-core` type. Builder methods named
+//! `with_*` return a modified copy, as the Rust builders return a modified
+//! value, whereas `add_*` methods append to the object in place. Invalid
+//! values raise `ValueError` carrying the coded message.
+
+mod encoding;
+mod file;
+mod profile;
+mod statements;
+
+pub use encoding::{AnnotationSystem, Application, EncodingDesc};
+pub use file::{Extent, FileDesc, Media, SourceDesc};
+pub use profile::{CatRef, Creation, Keywords, ProfileDesc, TextClass};
+pub use statements::{Idno, PublicationStmt, RespStmt, SeriesStmt};
+
+use pyo3::prelude::*;
+use pyo3::types::PyModule;
+
+use super::{dict, wrap_tei_result};
+
+/// Wrapper around [`tei_core::RevisionChange`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "RevisionChange")]
+#[derive(Clone, Debug)]
+pub struct RevisionChange {
+    inner: tei_core::RevisionChange,
+}
+
+#[pymethods]
+impl RevisionChange {
+    /// Constructs a revision note with an optional responsibility marker.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` when the description is blank.
+    #[new]
+    #[pyo3(signature = (description, resp=String::new()))]
+    pub fn new(description: String, resp: String) -> PyResult<Self> {
+        wrap_tei_result(tei_core::RevisionChange::new(description, resp))
+            .map(|inner| Self { inner })
+    }
+
+    /// Returns the note text.
+    #[getter]
+    #[must_use]
+    pub fn description(&self) -> String {
+        self.inner.description().to_owned()
+    }
+
+    /// Returns the responsibility marker.
+    #[getter]
+    #[must_use]
+    pub fn resp(&self) -> Option<String> {
+        self.inner.resp().map(ToString::to_string)
+    }
+}
+
+/// Wrapper around [`tei_core::RevisionDesc`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "RevisionDesc")]
+#[derive(Clone, Debug, Default)]
+pub struct RevisionDesc {
+    inner: tei_core::RevisionDesc,
+}
+
+#[pymethods]
+impl RevisionDesc {
+    /// Constructs an empty revision history.
+    #[new]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a revision note.
+    pub fn add_change(&mut self, change: RevisionChange) {
+        self.inner.add_change(change.inner);
+    }
+
+    /// Returns the revision notes in order.
+    #[getter]
+    #[must_use]
+    pub fn changes(&self) -> Vec<RevisionChange> {
+        self.inner
+            .changes()
+            .iter()
+            .map(|inner| RevisionChange {
+                inner: inner.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Wrapper around [`tei_core::TeiHeader`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "TeiHeader")]
+#[derive(Clone, Debug)]
+pub struct TeiHeader {
+    inner: tei_core::TeiHeader,
+}
+
+impl From<tei_core::TeiHeader> for TeiHeader {
+    fn from(inner: tei_core::TeiHeader) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<TeiHeader> for tei_core::TeiHeader {
+    fn from(value: TeiHeader) -> Self {
+        value.inner
+    }
+}
+
+#[pymethods]
+impl TeiHeader {
+    /// Constructs a header around `file_desc`.
+    #[new]
+    #[must_use]
+    pub fn new(file_desc: FileDesc) -> Self {
+        Self {
+            inner: tei_core::TeiHeader::new(file_desc.inner),
+        }
+    }
+
+    /// Returns a copy carrying `profile_desc`.
+    #[must_use]
+    pub fn with_profile_desc(&self, profile_desc: ProfileDesc) -> Self {
+        Self {
+            inner: self.inner.clone().with_profile_desc(profile_desc.inner),
+        }
+    }
+
+    /// Returns a copy carrying `encoding_desc`.
+    #[must_use]
+    pub fn with_encoding_desc(&self, encoding_desc: EncodingDesc) -> Self {
+        Self {
+            inner: self.inner.clone().with_encoding_desc(encoding_desc.inner),
+        }
+    }
+
+    /// Returns a copy carrying `revision_desc`.
+    #[must_use]
+    pub fn with_revision_desc(&self, revision_desc: RevisionDesc) -> Self {
+        Self {
+            inner: self.inner.clone().with_revision_desc(revision_desc.inner),
+        }
+    }
+
+    /// Returns the file description.
+    #[getter]
+    #[must_use]
+    pub fn file_desc(&self) -> FileDesc {
+        FileDesc {
+            inner: self.inner.file_desc().clone(),
+        }
+    }
+
+    /// Returns the profile description.
+    #[getter]
+    #[must_use]
+    pub fn profile_desc(&self) -> Option<ProfileDesc> {
+        self.inner.profile_desc().map(|inner| ProfileDesc {
+            inner: inner.clone(),
+        })
+    }
+
+    /// Returns the encoding description.
+    #[getter]
+    #[must_use]
+    pub fn encoding_desc(&self) -> Option<EncodingDesc> {
+        self.inner.encoding_desc().map(|inner| EncodingDesc {
+            inner: inner.clone(),
+        })
+    }
+
+    /// Returns the revision history.
+    #[getter]
+    #[must_use]
+    pub fn revision_desc(&self) -> Option<RevisionDesc> {
+        self.inner.revision_desc().map(|inner| RevisionDesc {
+            inner: inner.clone(),
+        })
+    }
+
+    /// Returns the header as a `dict` following the JSON mapping.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` when the header cannot be represented.
+    pub fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        dict::to_python(
+            py,
+            &wrap_tei_result(tei_core::header_to_json_value(&self.inner))?,
+        )
+    }
+
+    /// Reads a header from a `dict` produced by [`TeiHeader::to_dict`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` when `data` does not follow the mapping or holds
+    /// invalid metadata, and `TypeError` for values of other types.
+    #[staticmethod]
+    pub fn from_dict(data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        wrap_tei_result(tei_core::header_from_json_value(dict::from_python(data)?)).map(Self::from)
+    }
+}
+
+/// Adds the header classes to `module`.
+pub(super) fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<TeiHeader>()?;
+    module.add_class::<FileDesc>()?;
+    module.add_class::<PublicationStmt>()?;
+    module.add_class::<SeriesStmt>()?;
+    module.add_class::<RespStmt>()?;
+    module.add_class::<Idno>()?;
+    module.add_class::<Extent>()?;
+    module.add_class::<SourceDesc>()?;
+    module.add_class::<Media>()?;
+    module.add_class::<ProfileDesc>()?;
+    module.add_class::<Creation>()?;
+    module.add_class::<TextClass>()?;
+    module.add_class::<Keywords>()?;
+    module.add_class::<CatRef>()?;
+    module.add_class::<EncodingDesc>()?;
+    module.add_class::<AnnotationSystem>()?;
+    module.add_class::<Application>()?;
+    module.add_class::<RevisionDesc>()?;
+    module.add_class::<RevisionChange>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::run_python;
+    use rstest::rstest;
+
+    #[test]
+    fn assembles_a_full_header() {
+        let script = r#"
+series = tei.SeriesStmt("Wolf 359").with_season_number(1).with_episode_number(12)
+series.add_idno(tei.Idno("https://example.org/feed", "url"))
+file_desc = (
+    tei.FileDesc("Succulent")
+    .with_publication_stmt(tei.PublicationStmt("Kinda Evil Genius").with_date("2014-12-01"))
+    .with_series_stmt(series)
+    .with_extent(tei.Extent(duration=1230.5))
+)
+source = tei.SourceDesc()
+source.add_media(tei.Media("https://example.org/ep12.mp3", "audio/mpeg"))
+file_desc = file_desc.with_source_desc(source)
+file_desc.add_resp_stmt(tei.RespStmt("transcriber", "Minkowski"))
+
+profile = tei.ProfileDesc().with_creation(tei.Creation(date="2014-11-20"))
+profile.add_speaker("Eiffel", role="host")
+profile.add_language("en")
+encoding = tei.EncodingDesc().with_applications([tei.Application("whisper", "3")])
+encoding = encoding.with_project_desc([tei.P(["Fan transcripts."])])
+revisions = tei.RevisionDesc()
+revisions.add_change(tei.RevisionChange("Initial import", "Hera"))
+
+header = (
+    tei.TeiHeader(file_desc)
+    .with_profile_desc(profile)
+    .with_encoding_desc(encoding)
+    .with_revision_desc(revisions)
+)
+document = tei.Document("Placeholder").with_header(header)
+
+read = document.header
+assert document.title == "Succulent"
+assert read.file_desc.series_stmt.episode_number == 12
+assert read.file_desc.series_stmt.idnos[0].idno_type == "url"
+assert read.file_desc.extent.duration == 1230.0
+assert read.file_desc.source_desc.media[0].mime_type == "audio/mpeg"
+assert read.file_desc.resp_stmts[0].name == "Minkowski"
+assert read.profile_desc.speaker_role("Eiffel") == "host"
+assert read.profile_desc.creation.date == "2014-11-20"
+assert read.encoding_desc.applications[0].ident == "whisper"
+assert read.encoding_desc.project_desc[0].content == ["Fan transcripts."]
+assert read.revision_desc.changes[0].resp == "Hera"
+"#;
+
+        run_python(script).unwrap_or_else(|error| panic!("script should succeed: {error}"));
+    }
+
+    #[test]
+    fn round_trips_headers_through_dicts() {
+        let script = r#"
+profile = tei.ProfileDesc()
+profile.add_language("en")
+header = tei.TeiHeader(tei.FileDesc("Succulent")).with_profile_desc(profile)
+data = header.to_dict()
+assert data["fileDesc"]["title"] == [{"text": "Succulent"}]
+restored = tei.TeiHeader.from_dict(data)
+assert restored.profile_desc.languages == ["en"]
+assert restored.to_dict() == data
+"#;
+
+        run_python(script).unwrap_or_else(|error| panic!("script should succeed: {error}"));
+    }
+
+    #[rstest]
+    #[case("tei.FileDesc('  ')")]
+    #[case("tei.SeriesStmt('Wolf 359').with_episode_number(0)")]
+    #[case("tei.Idno('wolf-359', 'isbn')")]
+    #[case("tei.Idno('example.org/feed', 'url')")]
+    #[case("tei.Media('https://example.org/ep12.mp3', 'mpeg')")]
+    #[case("tei.Extent(duration=-1.0)")]
+    #[case("tei.Creation(date='soon')")]
+    #[case("tei.ProfileDesc().add_speaker('Eiffel', role=' ')")]
+    #[case("tei.RevisionChange('  ')")]
+    fn rejects_invalid_metadata(#[case] expression: &str) {
+        let script = format!(
+            "try:\n    {expression}\nexcept ValueError:\n    pass\nelse:\n    raise AssertionError('accepted')\n"
+        );
+
+        run_python(&script).unwrap_or_else(|error| panic!("{expression} should raise: {error}"));
+    }
+}
@@ -0,0 +1,301 @@
+//! The profile description: cast, languages, creation, and classification.
+
+use pyo3::prelude::*;
+use tei_core::SpeakerRole;
+
+use super::super::wrap_tei_result;
+
+/// Wrapper around [`tei_core::Creation`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "Creation")]
+#[derive(Clone, Debug)]
+pub struct Creation {
+    inner: tei_core::Creation,
+}
+
+#[pymethods]
+impl Creation {
+    /// Constructs a creation statement with an optional ISO date and place.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` when the date is not an ISO 8601 calendar date.
+    #[new]
+    #[pyo3(signature = (date=None, place=None))]
+    pub fn new(date: Option<String>, place: Option<String>) -> PyResult<Self> {
+        let mut inner = tei_core::Creation::new();
+        if let Some(value) = date {
+            inner = wrap_tei_result(inner.with_date(value))?;
+        }
+        if let Some(value) = place {
+            inner = inner.with_place(value);
+        }
+        Ok(Self { inner })
+    }
+
+    /// Returns the creation date.
+    #[getter]
+    #[must_use]
+    pub fn date(&self) -> Option<String> {
+        self.inner.date().map(str::to_owned)
+    }
+
+    /// Returns the place of creation.
+    #[getter]
+    #[must_use]
+    pub fn place(&self) -> Option<String> {
+        self.inner.place().map(str::to_owned)
+    }
+}
+
+/// Wrapper around [`tei_core::Keywords`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "Keywords")]
+#[derive(Clone, Debug)]
+pub struct Keywords {
+    inner: tei_core::Keywords,
+}
+
+#[pymethods]
+impl Keywords {
+    /// Constructs a keyword list from an optional vocabulary.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` when `terms` is empty or a term is blank.
+    #[new]
+    #[pyo3(signature = (terms, scheme=None))]
+    pub fn new(terms: Vec<String>, scheme: Option<String>) -> PyResult<Self> {
+        let mut inner = wrap_tei_result(tei_core::Keywords::new(terms))?;
+        if let Some(value) = scheme {
+            inner = inner.with_scheme(value);
+        }
+        Ok(Self { inner })
+    }
+
+    /// Returns the terms.
+    #[getter]
+    #[must_use]
+    pub fn terms(&self) -> Vec<String> {
+        self.inner.terms().to_vec()
+    }
+
+    /// Returns the vocabulary the terms come from.
+    #[getter]
+    #[must_use]
+    pub fn scheme(&self) -> Option<String> {
+        self.inner.scheme().map(str::to_owned)
+    }
+}
+
+/// Wrapper around [`tei_core::CatRef`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "CatRef")]
+#[derive(Clone, Debug)]
+pub struct CatRef {
+    inner: tei_core::CatRef,
+}
+
+#[pymethods]
+impl CatRef {
+    /// Constructs a reference to taxonomy categories, such as `#comedy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` when no category pointers are given.
+    #[new]
+    #[pyo3(signature = (targets, scheme=None))]
+    pub fn new(targets: Vec<String>, scheme: Option<String>) -> PyResult<Self> {
+        let mut inner = wrap_tei_result(tei_core::CatRef::new(targets))?;
+        if let Some(value) = scheme {
+            inner = inner.with_scheme(value);
+        }
+        Ok(Self { inner })
+    }
+
+    /// Returns the category pointers.
+    #[getter]
+    #[must_use]
+    pub fn targets(&self) -> Vec<String> {
+        self.inner.targets().to_vec()
+    }
+
+    /// Returns the taxonomy the categories belong to.
+    #[getter]
+    #[must_use]
+    pub fn scheme(&self) -> Option<String> {
+        self.inner.scheme().map(str::to_owned)
+    }
+}
+
+/// Wrapper around [`tei_core::TextClass`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "TextClass")]
+#[derive(Clone, Debug, Default)]
+pub struct TextClass {
+    inner: tei_core::TextClass,
+}
+
+#[pymethods]
+impl TextClass {
+    /// Constructs an empty classification.
+    #[new]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a keyword list.
+    pub fn add_keywords(&mut self, keywords: Keywords) {
+        self.inner.add_keywords(keywords.inner);
+    }
+
+    /// Records a category reference.
+    pub fn add_cat_ref(&mut self, cat_ref: CatRef) {
+        self.inner.add_cat_ref(cat_ref.inner);
+    }
+
+    /// Returns the keyword lists.
+    #[getter]
+    #[must_use]
+    pub fn keywords(&self) -> Vec<Keywords> {
+        self.inner
+            .keywords()
+            .iter()
+            .map(|inner| Keywords {
+                inner: inner.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns the category references.
+    #[getter]
+    #[must_use]
+    pub fn cat_refs(&self) -> Vec<CatRef> {
+        self.inner
+            .cat_refs()
+            .iter()
+            .map(|inner| CatRef {
+                inner: inner.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Wrapper around [`tei_core::ProfileDesc`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "ProfileDesc")]
+#[derive(Clone, Debug, Default)]
+pub struct ProfileDesc {
+    pub(super) inner: tei_core::ProfileDesc,
+}
+
+#[pymethods]
+impl ProfileDesc {
+    /// Constructs an empty profile description.
+    #[new]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a speaker to the cast list, with an optional role such as
+    /// `host` or `guest`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` when the name or role is blank.
+    #[pyo3(signature = (speaker, role=None))]
+    pub fn add_speaker(&mut self, speaker: String, role: Option<String>) -> PyResult<()> {
+        match role {
+            Some(label) => {
+                let parsed = wrap_tei_result(SpeakerRole::new(label))?;
+                wrap_tei_result(self.inner.add_speaker_with_role(speaker, parsed))
+            }
+            None => wrap_tei_result(self.inner.add_speaker(speaker)),
+        }
+    }
+
+    /// Adds a language identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValueError` when the language tag is blank.
+    pub fn add_language(&mut self, language: String) -> PyResult<()> {
+        wrap_tei_result(self.inner.add_language(language))
+    }
+
+    /// Returns a copy carrying `synopsis`.
+    #[must_use]
+    pub fn with_synopsis(&self, synopsis: String) -> Self {
+        Self {
+            inner: self.inner.clone().with_synopsis(synopsis),
+        }
+    }
+
+    /// Returns a copy carrying `creation`.
+    #[must_use]
+    pub fn with_creation(&self, creation: Creation) -> Self {
+        Self {
+            inner: self.inner.clone().with_creation(creation.inner),
+        }
+    }
+
+    /// Returns a copy carrying `text_class`.
+    #[must_use]
+    pub fn with_text_class(&self, text_class: TextClass) -> Self {
+        Self {
+            inner: self.inner.clone().with_text_class(text_class.inner),
+        }
+    }
+
+    /// Returns the speakers in cast order.
+    #[getter]
+    #[must_use]
+    pub fn speakers(&self) -> Vec<String> {
+        self.inner
+            .speakers()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    /// Returns the role recorded for `speaker`.
+    #[must_use]
+    pub fn speaker_role(&self, speaker: &str) -> Option<String> {
+        self.inner
+            .speaker_role(speaker)
+            .map(|role| role.as_str().to_owned())
+    }
+
+    /// Returns the language identifiers.
+    #[getter]
+    #[must_use]
+    pub fn languages(&self) -> Vec<String> {
+        self.inner
+            .languages()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    /// Returns the synopsis.
+    #[getter]
+    #[must_use]
+    pub fn synopsis(&self) -> Option<String> {
+        self.inner.synopsis().map(str::to_owned)
+    }
+
+    /// Returns the creation statement.
+    #[getter]
+    #[must_use]
+    pub fn creation(&self) -> Option<Creation> {
+        self.inner.creation().map(|inner| Creation {
+            inner: inner.clone(),
+        })
+    }
+
+    /// Returns the classification.
+    #[getter]
+    #[must_use]
+    pub fn text_class(&self) -> Option<TextClass> {
+        self.inner.text_class().map(|inner| TextClass {
+            inner: inner.clone(),
+        })
+    }
+}
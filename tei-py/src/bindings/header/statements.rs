@@ -0,0 +1,208 @@
+//! Publication, series, and responsibility statements and identifiers.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use tei_core::IdnoType;
+
+use super::super::wrap_tei_result;
+
+/// Wrapper around [`tei_core::Idno`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "Idno")]
+#[derive(Clone, Debug)]
+pub struct Idno {
+    pub(super) inner: tei_core::Idno,
+}
+
+#[pymethods]
+impl Idno {
+    /// Constructs an identifier, typed as `guid`, `doi`, or `url` when
+    /// `idno_type` is given.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PyValueError`] when the value is blank, the type is unknown,
+    /// or a DOI or URL is malformed.
+    #[new]
+    #[pyo3(signature = (value, idno_type=None))]
+    pub fn new(value: String, idno_type: Option<&str>) -> PyResult<Self> {
+        let inner = match idno_type {
+            None => tei_core::Idno::untyped(value),
+            Some("guid") => tei_core::Idno::new(IdnoType::Guid, value),
+            Some("doi") => tei_core::Idno::new(IdnoType::Doi, value),
+            Some("url") => tei_core::Idno::new(IdnoType::Url, value),
+            Some(other) => {
+                return Err(PyValueError::new_err(format!(
+                    "unknown identifier type `{other}`; expected guid, doi, or url"
+                )));
+            }
+        };
+        wrap_tei_result(inner).map(|idno| Self { inner: idno })
+    }
+
+    /// Returns the identifier type when recorded.
+    #[getter]
+    #[must_use]
+    pub fn idno_type(&self) -> Option<&'static str> {
+        self.inner.idno_type().map(IdnoType::as_str)
+    }
+
+    /// Returns the identifier value.
+    #[getter]
+    #[must_use]
+    pub fn value(&self) -> String {
+        self.inner.value().to_owned()
+    }
+}
+
+/// Wrapper around [`tei_core::PublicationStmt`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "PublicationStmt")]
+#[derive(Clone, Debug)]
+pub struct PublicationStmt {
+    pub(super) inner: tei_core::PublicationStmt,
+}
+
+#[pymethods]
+impl PublicationStmt {
+    /// Constructs a publication statement naming its publisher.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PyValueError`] when the publisher is blank.
+    #[new]
+    pub fn new(publisher: String) -> PyResult<Self> {
+        wrap_tei_result(tei_core::PublicationStmt::new(publisher)).map(|inner| Self { inner })
+    }
+
+    /// Returns a copy carrying the publication date.
+    #[must_use]
+    pub fn with_date(&self, date: String) -> Self {
+        Self {
+            inner: self.inner.clone().with_date(date),
+        }
+    }
+
+    /// Returns the publisher.
+    #[getter]
+    #[must_use]
+    pub fn publisher(&self) -> String {
+        self.inner.publisher().to_owned()
+    }
+
+    /// Returns the publication date.
+    #[getter]
+    #[must_use]
+    pub fn date(&self) -> Option<String> {
+        self.inner.date().map(str::to_owned)
+    }
+}
+
+/// Wrapper around [`tei_core::SeriesStmt`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "SeriesStmt")]
+#[derive(Clone, Debug)]
+pub struct SeriesStmt {
+    pub(super) inner: tei_core::SeriesStmt,
+}
+
+#[pymethods]
+impl SeriesStmt {
+    /// Constructs a series statement from the series title.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PyValueError`] when the title is blank.
+    #[new]
+    pub fn new(title: String) -> PyResult<Self> {
+        wrap_tei_result(tei_core::SeriesStmt::new(title)).map(|inner| Self { inner })
+    }
+
+    /// Returns a copy numbered with `season`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PyValueError`] when the number is zero.
+    pub fn with_season_number(&self, season: u32) -> PyResult<Self> {
+        wrap_tei_result(self.inner.clone().with_season_number(season)).map(|inner| Self { inner })
+    }
+
+    /// Returns a copy numbered with `episode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PyValueError`] when the number is zero.
+    pub fn with_episode_number(&self, episode: u32) -> PyResult<Self> {
+        wrap_tei_result(self.inner.clone().with_episode_number(episode)).map(|inner| Self { inner })
+    }
+
+    /// Records an identifier for the series, such as its feed URL.
+    pub fn add_idno(&mut self, idno: Idno) {
+        self.inner.add_idno(idno.inner);
+    }
+
+    /// Returns the series title.
+    #[getter]
+    #[must_use]
+    pub fn title(&self) -> String {
+        self.inner.title().to_owned()
+    }
+
+    /// Returns the season number.
+    #[getter]
+    #[must_use]
+    pub const fn season_number(&self) -> Option<u32> {
+        self.inner.season_number()
+    }
+
+    /// Returns the episode number.
+    #[getter]
+    #[must_use]
+    pub const fn episode_number(&self) -> Option<u32> {
+        self.inner.episode_number()
+    }
+
+    /// Returns the identifiers recorded for the series.
+    #[getter]
+    #[must_use]
+    pub fn idnos(&self) -> Vec<Idno> {
+        self.inner
+            .idnos()
+            .iter()
+            .map(|idno| Idno {
+                inner: idno.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Wrapper around [`tei_core::RespStmt`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "RespStmt")]
+#[derive(Clone, Debug)]
+pub struct RespStmt {
+    pub(super) inner: tei_core::RespStmt,
+}
+
+#[pymethods]
+impl RespStmt {
+    /// Credits `name` with `role`, such as a transcriber.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PyValueError`] when either value is blank.
+    #[new]
+    pub fn new(role: String, name: String) -> PyResult<Self> {
+        wrap_tei_result(tei_core::RespStmt::new(role, name)).map(|inner| Self { inner })
+    }
+
+    /// Returns the contributor's role.
+    #[getter]
+    #[must_use]
+    pub fn role(&self) -> String {
+        self.inner.role().to_owned()
+    }
+
+    /// Returns the contributor's name.
+    #[getter]
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+}
@@ -0,0 +1,138 @@
+//! Python classes and functions registered in the `tei_rapporteur` module.
+
+#![expect(
+    unsafe_op_in_unsafe_fn,
+    reason = "PyO3 generates unavoidable unsafe glue for the Python bindings"
+)]
+#![expect(
+    clippy::shadow_reuse,
+    reason = "PyO3 reuses module parameters when generating the PyInit stub"
+)]
+#![expect(
+    clippy::too_many_arguments,
+    reason = "PyO3 synthesises adapter parameters for exported pyfunctions"
+)]
+#![expect(
+    clippy::useless_conversion,
+    reason = "Result<T, TeiError> must be mapped into PyResult<T> for Python error translation"
+)]
+
+mod body;
+mod dict;
+mod document;
+mod errors;
+mod frame;
+mod header;
+mod options;
+mod validation;
+
+pub use body::{BodyBlock, Hi, P, Pause, Utterance};
+pub use document::Document;
+pub use header::{
+    AnnotationSystem, Application, CatRef, Creation, EncodingDesc, Extent, FileDesc, Idno,
+    Keywords, Media, ProfileDesc, PublicationStmt, RespStmt, RevisionChange, RevisionDesc,
+    SeriesStmt, SourceDesc, TeiHeader, TextClass,
+};
+pub use options::ParseOptions;
+pub use validation::ValidationFinding;
+
+use super::{TeiError, emit_title_markup};
+use pyo3::Bound;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyModule};
+use pyo3::wrap_pyfunction;
+
+#[pyfunction(name = "emit_title_markup")]
+fn emit_title_markup_py(raw_title: &str) -> PyResult<String> {
+    wrap_tei_result(emit_title_markup(raw_title))
+}
+
+/// Encodes a document as `MessagePack` bytes.
+///
+/// # Errors
+///
+/// Returns `MessagePackError` when the document cannot be encoded.
+#[pyfunction(name = "to_msgpack")]
+fn to_msgpack_py<'py>(py: Python<'py>, document: &Document) -> PyResult<Bound<'py, PyBytes>> {
+    let encoded = wrap_tei_result(tei_core::to_msgpack(document))?;
+    Ok(PyBytes::new_bound(py, &encoded))
+}
+
+/// Decodes a document from `MessagePack` bytes.
+///
+/// # Errors
+///
+/// Returns `MessagePackError` when the bytes are malformed or describe an
+/// invalid document.
+#[pyfunction(name = "from_msgpack")]
+fn from_msgpack_py(payload: &[u8]) -> PyResult<Document> {
+    wrap_tei_result(tei_core::from_msgpack(payload).map(Document::from))
+}
+
+/// Parses a TEI XML string into a document, releasing the GIL while the
+/// markup is read so other Python threads keep running. `options` bounds the
+/// resources the markup may use.
+///
+/// # Errors
+///
+/// Returns `XmlError` when the markup is malformed or describes an
+/// invalid document, and `LimitExceededError` when it breaches a limit.
+#[pyfunction(name = "parse_xml")]
+#[pyo3(signature = (xml, options=None))]
+fn parse_xml_py(py: Python<'_>, xml: &str, options: Option<ParseOptions>) -> PyResult<Document> {
+    let options = options.map_or_else(tei_xml::ParseOptions::new, Into::into);
+    wrap_tei_result(
+        py.allow_threads(|| tei_xml::parse_xml_with_options(xml, options))
+            .map(Document::from),
+    )
+}
+
+/// Registers the `tei_rapporteur` Python module.
+///
+/// # Errors
+///
+/// Returns [`PyErr`] when registering the module exports fails because the
+/// interpreter rejects one of the additions.
+#[pymodule]
+pub fn tei_rapporteur(py_context: Python<'_>, py_module: &Bound<'_, PyModule>) -> PyResult<()> {
+    py_module.add_class::<Document>()?;
+    py_module.add_class::<ValidationFinding>()?;
+    py_module.add_class::<ParseOptions>()?;
+    errors::register(py_module)?;
+    body::register(py_module)?;
+    header::register(py_module)?;
+    py_module.add_function(wrap_pyfunction!(emit_title_markup_py, py_module)?)?;
+    py_module.add_function(wrap_pyfunction!(to_msgpack_py, py_module)?)?;
+    py_module.add_function(wrap_pyfunction!(from_msgpack_py, py_module)?)?;
+    py_module.add_function(wrap_pyfunction!(parse_xml_py, py_module)?)?;
+    py_module.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    py_module.add("__py_runtime__", py_context.version())?;
+    Ok(())
+}
+
+/// Converts a Rust `Result<T, TeiError>` into a Python-friendly [`PyResult`].
+///
+/// Errors convertible into [`TeiError`], such as the header and body
+/// validation errors, are accepted as well. Successful values are forwarded
+/// unchanged, while [`TeiError`] values raise the matching subclass of
+/// `tei_rapporteur.TeiError`, whose message is rendered via
+/// [`tei_core::ErrorCode::display_with_code`] and whose `code` attribute holds the
+/// stable error code. Mapping errors in one place keeps the FFI boundary
+/// consistent.
+pub(crate) fn wrap_tei_result<T>(result: Result<T, impl Into<TeiError>>) -> PyResult<T> {
+    result.map_err(|error| errors::to_py_err(&error.into()))
+}
+
+/// Runs `script` with the registered module bound to `tei`.
+#[cfg(test)]
+pub(crate) fn run_python(script: &str) -> PyResult<()> {
+    use pyo3::types::PyDict;
+
+    Python::with_gil(|py| {
+        let module = PyModule::new_bound(py, "tei_rapporteur")?;
+        tei_rapporteur(py, &module)?;
+        let globals = PyDict::new_bound(py);
+        globals.set_item("tei", module)?;
+        py.run_bound(script, Some(&globals), None)
+    })
+}
@@ -0,0 +1,69 @@
+//! Parser resource limits for services reading untrusted uploads.
+//!
+//! `ParseOptions` mirrors the limits carried by [`tei_xml::ParseOptions`]:
+//! nesting depth, entity and character references, and document size. Each
+//! limit is an `int`, or `None` to lift it, and the defaults match
+//! `parse_xml` without options.
+
+use pyo3::prelude::*;
+
+/// Wrapper around [`tei_xml::ParseOptions`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "ParseOptions", frozen)]
+#[derive(Clone, Copy, Debug)]
+pub struct ParseOptions {
+    inner: tei_xml::ParseOptions,
+}
+
+impl From<ParseOptions> for tei_xml::ParseOptions {
+    fn from(value: ParseOptions) -> Self {
+        value.inner
+    }
+}
+
+#[pymethods]
+impl ParseOptions {
+    /// Builds options bounding nesting depth, entity references, and
+    /// document size in bytes.
+    #[new]
+    #[pyo3(signature = (
+        *,
+        max_depth=Some(tei_xml::ParseOptions::DEFAULT_MAX_DEPTH),
+        max_entity_expansions=Some(tei_xml::ParseOptions::DEFAULT_MAX_ENTITY_EXPANSIONS),
+        max_document_size=None,
+    ))]
+    #[must_use]
+    pub const fn new(
+        max_depth: Option<usize>,
+        max_entity_expansions: Option<usize>,
+        max_document_size: Option<usize>,
+    ) -> Self {
+        let inner = tei_xml::ParseOptions::new()
+            .with_max_depth(max_depth)
+            .with_max_entity_expansions(max_entity_expansions)
+            .with_max_document_size(max_document_size);
+        Self { inner }
+    }
+
+    /// Returns the deepest nesting allowed, or `None` when unbounded.
+    #[getter]
+    #[must_use]
+    pub const fn max_depth(&self) -> Option<usize> {
+        self.inner.max_depth()
+    }
+
+    /// Returns the entity and character references allowed, or `None` when
+    /// unbounded.
+    #[getter]
+    #[must_use]
+    pub const fn max_entity_expansions(&self) -> Option<usize> {
+        self.inner.max_entity_expansions()
+    }
+
+    /// Returns the largest document accepted in bytes, or `None` when
+    /// unbounded.
+    #[getter]
+    #[must_use]
+    pub const fn max_document_size(&self) -> Option<usize> {
+        self.inner.max_document_size()
+    }
+}
@@ -0,0 +1,112 @@
+//! Findings from whole-document validation.
+//!
+//! `Document.validate` mirrors [`tei_core::ValidationReport`], returning one
+//! `ValidationFinding` per issue in discovery order rather than raising, so
+//! review tools can list every problem with the path of its markup.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use tei_core::{TeiDocument, ValidationIssue};
+
+/// The only profile the core validates against.
+const EPISODIC_PROFILE: &str = "episodic";
+
+/// Wrapper around [`tei_core::ValidationIssue`] surfaced to Python.
+#[pyclass(module = "tei_rapporteur", name = "ValidationFinding", frozen)]
+#[derive(Clone, Debug)]
+pub struct ValidationFinding {
+    inner: ValidationIssue,
+}
+
+#[pymethods]
+impl ValidationFinding {
+    /// Returns how serious the finding is. Every check in the core reports
+    /// an `error`.
+    #[getter]
+    #[must_use]
+    pub const fn severity(&self) -> &'static str {
+        "error"
+    }
+
+    /// Returns the stable error code, such as `TEI-BODY-UNKNOWN-SPEAKER`.
+    #[getter]
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        self.inner.code()
+    }
+
+    /// Returns the path of the offending element or attribute.
+    #[getter]
+    #[must_use]
+    pub fn path(&self) -> String {
+        self.inner.path().to_string()
+    }
+
+    /// Returns the message without its code prefix.
+    #[getter]
+    #[must_use]
+    pub fn message(&self) -> String {
+        self.inner.error().to_string()
+    }
+
+    /// Returns the code and path, as in
+    /// `<ValidationFinding TEI-BODY-UNKNOWN-SPEAKER at /TEI/text/body/u[1]/@who>`.
+    #[must_use]
+    pub fn __repr__(&self) -> String {
+        format!("<ValidationFinding {} at {}>", self.code(), self.path())
+    }
+}
+
+/// Validates `document` against `profile` and wraps each issue.
+///
+/// # Errors
+///
+/// Returns `ValueError` when `profile` is not `episodic`.
+pub(super) fn findings(document: &TeiDocument, profile: &str) -> PyResult<Vec<ValidationFinding>> {
+    if profile != EPISODIC_PROFILE {
+        return Err(PyValueError::new_err(format!(
+            "unknown validation profile `{profile}`; expected {EPISODIC_PROFILE}"
+        )));
+    }
+    Ok(document
+        .validate()
+        .into_issues()
+        .into_iter()
+        .map(|inner| ValidationFinding { inner })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::run_python;
+
+    #[test]
+    fn lists_findings_instead_of_raising() {
+        let script = r#"
+profile = tei.ProfileDesc()
+profile.add_speaker("eiffel")
+header = tei.TeiHeader(tei.FileDesc("Wolf 359")).with_profile_desc(profile)
+document = tei.Document("Wolf 359").with_header(header)
+assert document.validate() == []
+
+document.append_utterance("hera", "Hello, Eiffel.")
+(finding,) = document.validate(profile="episodic")
+assert (finding.severity, finding.code, finding.path) == (
+    "error",
+    "TEI-BODY-UNKNOWN-SPEAKER",
+    "/TEI/text/body/u[1]/@who",
+)
+assert "hera" in finding.message and not finding.message.startswith("[")
+assert repr(finding) == "<ValidationFinding TEI-BODY-UNKNOWN-SPEAKER at /TEI/text/body/u[1]/@who>"
+
+try:
+    document.validate(profile="drama")
+except ValueError:
+    pass
+else:
+    raise AssertionError("accepted")
+"#;
+
+        run_python(script).unwrap_or_else(|error| panic!("script should succeed: {error}"));
+    }
+}
@@ -1,16 +1,31 @@
 //! `PyO3` bindings and helper functions exposed to Python callers.
 //!
 //! The crate surfaces the `tei_rapporteur` module, offering a lightweight
-//! `Document` wrapper that delegates validation to the Rust core. The module
-//! currently exposes title-centric helpers so downstream phases can evolve the
-//! API without rewriting the glue code. Rust callers continue to use the
-//! `emit_title_markup` helper directly whilst Python receives mirrored
-//! bindings.
+//! `Document` wrapper that delegates validation to the Rust core. Body
+//! content is built from `Utterance`, `P`, `Hi`, and `Pause` objects, wrapped
+//! in `BodyBlock`s, whose constructors raise `ValueError` on invalid input.
+//! Rust callers continue to use the `emit_title_markup` helper directly
+//! whilst Python receives mirrored bindings. `to_msgpack` and `from_msgpack`
+//! exchange whole documents as `MessagePack` bytes using the canonical
+//! encoding from `tei-core`, while `parse_xml` and `Document.to_xml` read and
+//! write TEI XML via `tei-xml`; `ParseOptions` bounds the resources
+//! `parse_xml` spends on untrusted input. `to_dict` and `from_dict` convert
+//! documents, headers, and blocks to and from plain Python values following
+//! the JSON mapping. Core errors raise subclasses of `tei_rapporteur.TeiError`
+//! carrying the error code. Documents are edited in place through methods
+//! such as `set_title`, `append_utterance`, and `set_block`, and
+//! `Document.validate` lists every failed document-level check as a
+//! `ValidationFinding`.
 
 use tei_core::{TeiDocument, TeiError};
 use tei_xml::serialize_document_title;
 
-pub use bindings::{Document, tei_rapporteur};
+pub use bindings::{
+    AnnotationSystem, Application, BodyBlock, CatRef, Creation, Document, EncodingDesc, Extent,
+    FileDesc, Hi, Idno, Keywords, Media, P, ParseOptions, Pause, ProfileDesc, PublicationStmt,
+    RespStmt, RevisionChange, RevisionDesc, SeriesStmt, SourceDesc, TeiHeader, TextClass,
+    Utterance, ValidationFinding, tei_rapporteur,
+};
 
 /// Validates and emits TEI markup suitable for exposure through `PyO3`.
 ///
@@ -33,130 +48,7 @@ pub fn emit_title_markup(raw_title: &str) -> Result<String, TeiError> {
     serialize_document_title(raw_title)
 }
 
-mod bindings {
-    #![expect(
-        unsafe_op_in_unsafe_fn,
-        reason = "PyO3 generates unavoidable unsafe glue for the Python bindings"
-    )]
-    #![expect(
-        clippy::shadow_reuse,
-        reason = "PyO3 reuses module parameters when generating the PyInit stub"
-    )]
-    #![expect(
-        clippy::too_many_arguments,
-        reason = "PyO3 synthesises adapter parameters for exported pyfunctions"
-    )]
-    #![expect(
-        clippy::useless_conversion,
-        reason = "Result<T, TeiError> must be mapped into PyResult<T> for Python error translation"
-    )]
-
-    use super::{TeiDocument, TeiError, emit_title_markup};
-    use pyo3::Bound;
-    use pyo3::exceptions::PyValueError;
-    use pyo3::prelude::*;
-    use pyo3::types::PyModule;
-    use pyo3::wrap_pyfunction;
-    use std::ops::Deref;
-
-    /// Wrapper around [`TeiDocument`] surfaced to Python.
-    #[pyclass(module = "tei_rapporteur", name = "Document")]
-    #[derive(Clone, Debug)]
-    pub struct Document {
-        inner: TeiDocument,
-    }
-
-    impl Document {
-        /// Attempts to build a [`Document`] from a raw title string.
-        ///
-        /// # Errors
-        ///
-        /// Returns [`TeiError::DocumentTitle`] when the supplied title trims to
-        /// an empty string.
-        pub fn try_from_title(title: &str) -> Result<Self, TeiError> {
-            TeiDocument::from_title_str(title).map(Self::from)
-        }
-    }
-
-    impl From<TeiDocument> for Document {
-        fn from(inner: TeiDocument) -> Self {
-            Self { inner }
-        }
-    }
-
-    impl From<Document> for TeiDocument {
-        fn from(value: Document) -> Self {
-            value.inner
-        }
-    }
-
-    impl Deref for Document {
-        type Target = TeiDocument;
-
-        fn deref(&self) -> &Self::Target {
-            &self.inner
-        }
-    }
-
-    #[pymethods]
-    impl Document {
-        /// Constructs a document with the provided title.
-        ///
-        /// # Errors
-        ///
-        /// Returns [`PyValueError`] when the trimmed title is empty.
-        #[new]
-        pub fn new(title: &str) -> PyResult<Self> {
-            wrap_tei_result(Self::try_from_title(title))
-        }
-
-        /// Returns the validated document title.
-        #[getter]
-        #[must_use]
-        pub fn title(&self) -> String {
-            self.inner.title().to_string()
-        }
-
-        /// Emits the document title as TEI markup.
-        ///
-        /// # Errors
-        ///
-        /// Returns [`PyValueError`] when the stored document title is invalid.
-        pub fn emit_title_markup(&self) -> PyResult<String> {
-            wrap_tei_result(emit_title_markup(self.inner.title().as_str()))
-        }
-    }
-
-    #[pyfunction(name = "emit_title_markup")]
-    fn emit_title_markup_py(raw_title: &str) -> PyResult<String> {
-        wrap_tei_result(emit_title_markup(raw_title))
-    }
-
-    /// Registers the `tei_rapporteur` Python module.
-    ///
-    /// # Errors
-    ///
-    /// Returns [`PyErr`] when registering the module exports fails because the
-    /// interpreter rejects one of the additions.
-    #[pymodule]
-    pub fn tei_rapporteur(py_context: Python<'_>, py_module: &Bound<'_, PyModule>) -> PyResult<()> {
-        py_module.add_class::<Document>()?;
-        py_module.add_function(wrap_pyfunction!(emit_title_markup_py, py_module)?)?;
-        py_module.add("__version__", env!("CARGO_PKG_VERSION"))?;
-        py_module.add("__py_runtime__", py_context.version())?;
-        Ok(())
-    }
-
-    /// Converts a Rust `Result<T, TeiError>` into a Python-friendly [`PyResult`].
-    ///
-    /// Successful values are forwarded unchanged, while [`TeiError`] values are
-    /// rendered via [`to_string`](TeiError::to_string) and wrapped in
-    /// [`PyValueError`]. This keeps the FFI boundary consistent by mapping Rust
-    /// domain errors to Python exceptions in one place.
-    fn wrap_tei_result<T>(result: Result<T, TeiError>) -> PyResult<T> {
-        result.map_err(|error| PyValueError::new_err(error.to_string()))
-    }
-}
+mod bindings;
 
 #[cfg(test)]
 mod tests {
@@ -179,6 +71,21 @@ mod tests {
         assert!(matches!(error, TeiError::DocumentTitle(_)));
     }
 
+    #[test]
+    fn python_errors_carry_error_codes() {
+        let error = Document::try_from_title("   ").expect_err("blank titles should fail");
+
+        Python::with_gil(|py| {
+            let py_error = bindings::wrap_tei_result::<()>(Err(error))
+                .expect_err("errors should map to Python exceptions");
+
+            assert_eq!(
+                py_error.value_bound(py).to_string(),
+                "[TEI-TITLE-EMPTY] document title may not be empty"
+            );
+        });
+    }
+
     #[test]
     fn module_registers_python_bindings() {
         Python::with_gil(|py| {
@@ -215,6 +122,67 @@ mod tests {
         });
     }
 
+    #[test]
+    fn python_functions_round_trip_msgpack() {
+        Python::with_gil(|py| {
+            let module = PyModule::new_bound(py, "tei_rapporteur").expect("module allocation");
+            tei_rapporteur(py, &module).expect("module registration");
+            let document =
+                Document::try_from_title("The Bright Sessions").expect("valid document title");
+
+            let encoded = module
+                .getattr("to_msgpack")
+                .expect("to_msgpack attribute")
+                .call1((document.clone(),))
+                .expect("Python call");
+            let decoded: Document = module
+                .getattr("from_msgpack")
+                .expect("from_msgpack attribute")
+                .call1((encoded,))
+                .expect("Python call")
+                .extract()
+                .expect("document extraction");
+
+            assert_eq!(TeiDocument::from(decoded), TeiDocument::from(document));
+        });
+    }
+
+    #[test]
+    fn python_functions_round_trip_xml() {
+        Python::with_gil(|py| {
+            let module = PyModule::new_bound(py, "tei_rapporteur").expect("module allocation");
+            tei_rapporteur(py, &module).expect("module registration");
+            let document = Document::try_from_title("Alice Isn't Dead").expect("valid title");
+            let xml = document.to_xml(py).expect("document should emit");
+
+            let parsed: Document = module
+                .getattr("parse_xml")
+                .expect("parse_xml attribute")
+                .call1((xml,))
+                .expect("Python call")
+                .extract()
+                .expect("document extraction");
+
+            assert_eq!(TeiDocument::from(parsed), TeiDocument::from(document));
+        });
+    }
+
+    #[test]
+    fn python_parse_xml_rejects_malformed_markup() {
+        Python::with_gil(|py| {
+            let module = PyModule::new_bound(py, "tei_rapporteur").expect("module allocation");
+            tei_rapporteur(py, &module).expect("module registration");
+
+            let error = module
+                .getattr("parse_xml")
+                .expect("parse_xml attribute")
+                .call1(("<TEI><teiHeader>",))
+                .expect_err("malformed markup should fail");
+
+            assert!(error.value_bound(py).to_string().starts_with("[TEI-"));
+        });
+    }
+
     #[test]
     fn document_method_emits_markup() {
         let document = Document::try_from_title("King Falls AM").expect("valid doc");
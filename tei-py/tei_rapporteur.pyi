@@ -0,0 +1,196 @@
+"""Type stubs for the `tei_rapporteur` extension module.
+
+The `test_stub_covers_the_compiled_module` test in `python/tests` checks that
+every public class, function, and method of the compiled module is declared
+here or in `tei_rapporteur/_header.pyi`, whose header classes this stub
+re-exports.
+The file sits beside the crate manifest, where maturin packages it as
+`tei_rapporteur/__init__.pyi` together with a `py.typed` marker; the header
+stub is added beside it through `tool.maturin.include`.
+"""
+
+import os
+from collections.abc import Mapping, Sequence
+from typing import TYPE_CHECKING, Any, ClassVar, Literal, final
+
+from tei_rapporteur._header import (
+    AnnotationSystem as AnnotationSystem,
+    Application as Application,
+    CatRef as CatRef,
+    Creation as Creation,
+    EncodingDesc as EncodingDesc,
+    Extent as Extent,
+    FileDesc as FileDesc,
+    Idno as Idno,
+    Keywords as Keywords,
+    Media as Media,
+    ProfileDesc as ProfileDesc,
+    PublicationStmt as PublicationStmt,
+    RespStmt as RespStmt,
+    RevisionChange as RevisionChange,
+    RevisionDesc as RevisionDesc,
+    SeriesStmt as SeriesStmt,
+    SourceDesc as SourceDesc,
+    TeiHeader as TeiHeader,
+    TextClass as TextClass,
+)
+
+if TYPE_CHECKING:
+    import pandas
+
+__version__: str
+__py_runtime__: str
+
+Inline = str | Hi | Pause
+Block = BodyBlock | P | Utterance
+
+class TeiError(ValueError):
+    code: str
+
+class DocumentTitleError(TeiError): ...
+class HeaderValidationError(TeiError): ...
+class BodyContentError(TeiError): ...
+class IdentifierValidationError(TeiError): ...
+class SpeakerValidationError(TeiError): ...
+class XmlError(TeiError): ...
+class JsonError(TeiError): ...
+class MessagePackError(TeiError): ...
+class FormatImportError(TeiError): ...
+class FormatExportError(TeiError): ...
+class FileError(TeiError): ...
+class UnsupportedEncodingError(TeiError): ...
+class LimitExceededError(TeiError): ...
+
+@final
+class Document:
+    def __init__(self, title: str, blocks: Sequence[Block] | None = None) -> None: ...
+    @property
+    def title(self) -> str: ...
+    @property
+    def blocks(self) -> list[BodyBlock]: ...
+    @property
+    def header(self) -> TeiHeader: ...
+    def with_header(self, header: TeiHeader) -> Document: ...
+    def set_title(self, title: str) -> None: ...
+    def append_utterance(self, speaker: str | None, text: str) -> None: ...
+    def append_block(self, block: Block) -> None: ...
+    def set_block(self, index: int, block: Block) -> None: ...
+    def to_dict(self) -> dict[str, Any]: ...
+    @staticmethod
+    def from_dict(data: Mapping[str, Any]) -> Document: ...
+    def validate(self, profile: Literal["episodic"] = "episodic") -> list[ValidationFinding]: ...
+    def to_dataframe(self) -> pandas.DataFrame: ...
+    def to_msgpack(self) -> bytes: ...
+    def to_xml(self) -> str: ...
+    @staticmethod
+    def from_file(path: str | os.PathLike[str]) -> Document: ...
+    def save(self, path: str | os.PathLike[str], declaration: bool = True) -> None: ...
+    def emit_title_markup(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __repr__(self) -> str: ...
+    __hash__: ClassVar[None]  # type: ignore[assignment]
+    def __len__(self) -> int: ...
+    def __getnewargs__(self) -> tuple[str]: ...
+    def __getstate__(self) -> bytes: ...
+    def __setstate__(self, state: bytes) -> None: ...
+
+@final
+class ValidationFinding:
+    @property
+    def severity(self) -> Literal["error"]: ...
+    @property
+    def code(self) -> str: ...
+    @property
+    def path(self) -> str: ...
+    @property
+    def message(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+def emit_title_markup(raw_title: str) -> str: ...
+def to_msgpack(document: Document) -> bytes: ...
+def from_msgpack(payload: bytes) -> Document: ...
+def parse_xml(xml: str, options: ParseOptions | None = None) -> Document: ...
+
+@final
+class ParseOptions:
+    def __init__(
+        self,
+        *,
+        max_depth: int | None = 256,
+        max_entity_expansions: int | None = 1_000_000,
+        max_document_size: int | None = None,
+    ) -> None: ...
+    @property
+    def max_depth(self) -> int | None: ...
+    @property
+    def max_entity_expansions(self) -> int | None: ...
+    @property
+    def max_document_size(self) -> int | None: ...
+
+@final
+class Pause:
+    def __init__(self, duration: str | None = None, kind: str | None = None) -> None: ...
+    @property
+    def duration(self) -> str | None: ...
+    @property
+    def kind(self) -> str | None: ...
+
+@final
+class Hi:
+    def __init__(
+        self, content: Sequence[Inline], rend: str | None = None, lang: str | None = None
+    ) -> None: ...
+    @property
+    def content(self) -> list[Inline]: ...
+    @property
+    def rend(self) -> str | None: ...
+    @property
+    def lang(self) -> str | None: ...
+
+@final
+class P:
+    def __init__(
+        self, content: Sequence[Inline], id: str | None = None, lang: str | None = None
+    ) -> None: ...
+    @property
+    def content(self) -> list[Inline]: ...
+    @property
+    def id(self) -> str | None: ...
+    def set_id(self, id: str) -> None: ...
+    @property
+    def lang(self) -> str | None: ...
+
+@final
+class Utterance:
+    def __init__(
+        self,
+        content: Sequence[Inline],
+        speaker: str | None = None,
+        id: str | None = None,
+        lang: str | None = None,
+    ) -> None: ...
+    @property
+    def content(self) -> list[Inline]: ...
+    @property
+    def speaker(self) -> str | None: ...
+    @property
+    def id(self) -> str | None: ...
+    def set_id(self, id: str) -> None: ...
+    @property
+    def lang(self) -> str | None: ...
+    @property
+    def start(self) -> str | None: ...
+    @property
+    def end(self) -> str | None: ...
+
+@final
+class BodyBlock:
+    def __init__(self, content: P | Utterance) -> None: ...
+    @property
+    def kind(self) -> Literal["p", "u", "raw"]: ...
+    @property
+    def value(self) -> P | Utterance | str: ...
+    def set_id(self, id: str) -> None: ...
+    def to_dict(self) -> dict[str, Any]: ...
+    @staticmethod
+    def from_dict(data: Mapping[str, Any]) -> BodyBlock: ...
@@ -0,0 +1,66 @@
+//! Runs the Python test suite in `python/tests` against the compiled module.
+//!
+//! `cargo test` does not build a wheel, so each test file is executed with the
+//! freshly registered `tei_rapporteur` module installed in `sys.modules`, and
+//! every `test_*` function it defines is called in turn. The functions take no
+//! pytest fixtures, so pytest collects the same files once the wheel is
+//! installed.
+
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyModule};
+use rstest::rstest;
+use tei_py::tei_rapporteur;
+
+/// Calls every test function defined by the executed file, collecting the
+/// failures so one broken test does not hide the rest.
+const RUNNER: &str = r#"
+import traceback
+
+failures = []
+for name, test in list(globals().items()):
+    if name.startswith("test_") and callable(test):
+        try:
+            test()
+        except Exception:
+            failures.append(f"{name}:\n{traceback.format_exc()}")
+assert not failures, "\n".join(failures)
+"#;
+
+/// Returns the module registered in `sys.modules`, registering it on first
+/// use so pickled classes resolve to the same objects across tests.
+fn registered_module(py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
+    let modules = py.import_bound("sys")?.getattr("modules")?;
+    if let Ok(module) = modules.get_item("tei_rapporteur") {
+        return Ok(module);
+    }
+    let module = PyModule::new_bound(py, "tei_rapporteur")?;
+    tei_rapporteur(py, &module)?;
+    modules.set_item("tei_rapporteur", &module)?;
+    Ok(module.into_any())
+}
+
+#[rstest]
+#[case::document(
+    "test_teipy_document.py",
+    include_str!("../../python/tests/test_teipy_document.py")
+)]
+#[case::bindings(
+    "test_teipy_bindings.py",
+    include_str!("../../python/tests/test_teipy_bindings.py")
+)]
+fn python_suite_passes(#[case] file: &str, #[case] source: &str) {
+    let path = format!("{}/../python/tests/{file}", env!("CARGO_MANIFEST_DIR"));
+
+    Python::with_gil(|py| {
+        registered_module(py).expect("module registration");
+        let globals = PyDict::new_bound(py);
+        globals
+            .set_item("__name__", file.trim_end_matches(".py"))
+            .expect("name binding");
+        globals.set_item("__file__", path).expect("file binding");
+
+        py.run_bound(source, Some(&globals), None)
+            .and_then(|()| py.run_bound(RUNNER, Some(&globals), None))
+            .unwrap_or_else(|error| panic!("{file} should pass: {error}"));
+    });
+}
@@ -0,0 +1,180 @@
+//! Canonical output suitable for content hashing.
+//!
+//! The rules follow Canonical XML 1.1 (without comments) closely enough that
+//! equal documents always produce identical bytes, and they are part of the
+//! crate's stability guarantee: changing them is a breaking change.
+//!
+//! - Attributes are written in double quotes, namespace declarations first,
+//!   then the remaining attributes ordered by namespace URI and local name, so
+//!   unprefixed attributes precede `xml:` ones.
+//! - Empty elements are written as start and end tag pairs.
+//! - CDATA sections become escaped text.
+//! - `\r\n` and lone `\r` in text and attribute values become `\n`.
+//! - Text escapes `&`, `<` and `>`; attribute values escape `&`, `<` and `"`,
+//!   plus tabs and newlines as `&#x9;` and `&#xA;`.
+//! - The XML declaration, comments and whitespace outside the root are
+//!   dropped.
+
+use std::borrow::Cow;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+use tei_core::TeiError;
+
+use crate::options::XSI_NAMESPACE;
+
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// Rewrites serialized markup into its canonical form.
+pub(crate) fn canonicalize(markup: &str) -> Result<String, TeiError> {
+    let mut reader = Reader::from_str(markup);
+    let mut output = String::with_capacity(markup.len());
+    let mut depth = 0_usize;
+
+    loop {
+        match reader
+            .read_event()
+            .map_err(|error| TeiError::xml(error.to_string()))?
+        {
+            Event::Eof => return Ok(output),
+            Event::Start(start) => {
+                write_start(&mut output, &start)?;
+                depth += 1;
+            }
+            Event::Empty(start) => {
+                write_start(&mut output, &start)?;
+                write_end(&mut output, start.name().as_ref());
+            }
+            Event::End(end) => {
+                write_end(&mut output, end.name().as_ref());
+                depth = depth.saturating_sub(1);
+            }
+            Event::Text(text) if depth > 0 => {
+                let content = text
+                    .unescape()
+                    .map_err(|error| TeiError::xml(error.to_string()))?;
+                escape_text(&mut output, &content);
+            }
+            Event::CData(cdata) => {
+                let content = String::from_utf8_lossy(cdata.as_ref());
+                escape_text(&mut output, &content);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn write_start(output: &mut String, start: &BytesStart<'_>) -> Result<(), TeiError> {
+    let mut attributes = Vec::new();
+    for attribute in start.attributes() {
+        let parsed = attribute.map_err(|error| TeiError::xml(error.to_string()))?;
+        let name = String::from_utf8_lossy(parsed.key.as_ref()).into_owned();
+        let value = parsed
+            .unescape_value()
+            .map_err(|error| TeiError::xml(error.to_string()))?
+            .into_owned();
+        attributes.push((sort_key(&name), name, value));
+    }
+    attributes.sort();
+
+    output.push('<');
+    output.push_str(&String::from_utf8_lossy(start.name().as_ref()));
+    for (_, name, value) in attributes {
+        output.push(' ');
+        output.push_str(&name);
+        output.push_str("=\"");
+        escape_attribute(output, &value);
+        output.push('"');
+    }
+    output.push('>');
+    Ok(())
+}
+
+fn write_end(output: &mut String, name: &[u8]) {
+    output.push_str("</");
+    output.push_str(&String::from_utf8_lossy(name));
+    output.push('>');
+}
+
+/// Orders namespace declarations first, then attributes by namespace URI and
+/// local name. Only the `xml` and `xsi` prefixes can appear on emitted
+/// attributes.
+pub(crate) fn sort_key(name: &str) -> (u8, String, String) {
+    if name == "xmlns" {
+        return (0, String::new(), String::new());
+    }
+    if let Some(prefix) = name.strip_prefix("xmlns:") {
+        return (0, prefix.to_owned(), String::new());
+    }
+
+    match name.split_once(':') {
+        Some(("xml", local)) => (1, XML_NAMESPACE.to_owned(), local.to_owned()),
+        Some(("xsi", local)) => (1, XSI_NAMESPACE.to_owned(), local.to_owned()),
+        Some((prefix, local)) => (1, prefix.to_owned(), local.to_owned()),
+        None => (1, String::new(), name.to_owned()),
+    }
+}
+
+fn escape_text(output: &mut String, content: &str) {
+    for character in normalise_newlines(content).chars() {
+        match character {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            other => output.push(other),
+        }
+    }
+}
+
+fn escape_attribute(output: &mut String, value: &str) {
+    for character in normalise_newlines(value).chars() {
+        match character {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '"' => output.push_str("&quot;"),
+            '\t' => output.push_str("&#x9;"),
+            '\n' => output.push_str("&#xA;"),
+            other => output.push(other),
+        }
+    }
+}
+
+fn normalise_newlines(value: &str) -> Cow<'_, str> {
+    if value.contains('\r') {
+        Cow::Owned(value.replace("\r\n", "\n").replace('\r', "\n"))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        "<u xml:lang=\"en\" who=\"eiffel\" xml:id=\"u1\">Hi</u>",
+        "<u who=\"eiffel\" xml:id=\"u1\" xml:lang=\"en\">Hi</u>"
+    )]
+    #[case(
+        "<TEI xml:lang=\"en\" xmlns=\"http://www.tei-c.org/ns/1.0\"><body/></TEI>",
+        "<TEI xmlns=\"http://www.tei-c.org/ns/1.0\" xml:lang=\"en\"><body></body></TEI>"
+    )]
+    #[case(
+        "<?xml version=\"1.0\"?>\n<p><![CDATA[a < b]]> &amp; c &gt; d</p>\n",
+        "<p>a &lt; b &amp; c &gt; d</p>"
+    )]
+    #[case(
+        "<TEI xmlns:xsi=\"x\" xml:lang=\"en\" xsi:schemaLocation=\"s\" n=\"1\"/>",
+        "<TEI xmlns:xsi=\"x\" n=\"1\" xsi:schemaLocation=\"s\" xml:lang=\"en\"></TEI>"
+    )]
+    #[case("<p>one\r\ntwo\rthree</p>", "<p>one\ntwo\nthree</p>")]
+    #[case("<u who='a\"b&#9;c'>x</u>", "<u who=\"a&quot;b&#x9;c\">x</u>")]
+    fn applies_canonical_rules(#[case] markup: &str, #[case] expected: &str) {
+        assert_eq!(
+            canonicalize(markup).expect("markup should canonicalize"),
+            expected
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! Optional CDATA wrapping for text dense with markup characters.
+//!
+//! Transcripts of code, equations or chat logs can escape every other
+//! character, which makes the emitted TEI hard to read. Wrapping such text
+//! nodes in CDATA sections keeps them legible without changing what they
+//! parse to.
+
+use quick_xml::Writer;
+use quick_xml::events::{BytesCData, Event};
+use quick_xml::reader::Reader;
+use tei_core::TeiError;
+
+/// Rewrites text nodes holding at least `threshold` of `&`, `<` and `>` as
+/// CDATA sections.
+///
+/// Text containing `]]>` cannot be wrapped in a single section and is left
+/// escaped. Attributes are never affected.
+pub(crate) fn wrap_escaped_text(markup: &str, threshold: usize) -> Result<String, TeiError> {
+    let mut reader = Reader::from_str(markup);
+    let mut writer = Writer::new(Vec::with_capacity(markup.len()));
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|error| TeiError::xml(error.to_string()))?;
+        let rewritten = match event {
+            Event::Eof => break,
+            Event::Text(text) => {
+                let content = text
+                    .unescape()
+                    .map_err(|error| TeiError::xml(error.to_string()))?;
+                let escapable = content
+                    .chars()
+                    .filter(|character| matches!(character, '&' | '<' | '>'))
+                    .count();
+                if escapable >= threshold.max(1) && !content.contains("]]>") {
+                    Event::CData(BytesCData::new(content.into_owned()))
+                } else {
+                    Event::Text(text)
+                }
+            }
+            other => other,
+        };
+        writer
+            .write_event(rewritten)
+            .map_err(|error| TeiError::xml(error.to_string()))?;
+    }
+
+    String::from_utf8(writer.into_inner()).map_err(|error| TeiError::xml(error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        "<p>if a &lt; b &amp;&amp; c &gt; d</p>",
+        3,
+        "<p><![CDATA[if a < b && c > d]]></p>"
+    )]
+    #[case("<p>Fish &amp; chips</p>", 3, "<p>Fish &amp; chips</p>")]
+    #[case("<p>a &lt;]]&gt; b &lt;</p>", 1, "<p>a &lt;]]&gt; b &lt;</p>")]
+    #[case(
+        "<u who=\"a&amp;b\">&lt;&lt;</u>",
+        2,
+        "<u who=\"a&amp;b\"><![CDATA[<<]]></u>"
+    )]
+    fn wraps_dense_text_only(
+        #[case] markup: &str,
+        #[case] threshold: usize,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            wrap_escaped_text(markup, threshold).expect("markup should rewrite"),
+            expected
+        );
+    }
+}
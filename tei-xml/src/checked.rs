@@ -0,0 +1,264 @@
+//! Serialisation that rejects characters XML 1.0 forbids as it writes.
+//!
+//! `quick-xml` writes text as given, so a stray control character in a
+//! transcript would produce markup no parser accepts. Rather than scanning
+//! the finished output, the serialiser writes through a [`Checked`] sink
+//! that follows the element structure of the markup it receives and scans
+//! each text node and attribute value once, as it is written. The first
+//! forbidden character aborts serialisation and is reported with the path of
+//! the element or attribute holding it.
+//!
+//! `quick-xml` also leaves whitespace in attribute values as it is, which
+//! parsers normalise to spaces. The sink rewrites tabs and line breaks there
+//! as the references [`escape_xml_attr`](crate::escape_xml_attr) uses, so
+//! every attribute the serialiser writes, such as `who`, `rend` or `dur`,
+//! reads back unchanged.
+
+use std::fmt::{self, Write};
+
+use quick_xml::se::Serializer;
+use serde::Serialize;
+use tei_core::escape::{attribute_reference, first_forbidden};
+use tei_core::{SourceLocation, TeiError, TeiPath};
+
+use crate::EmptyElements;
+use crate::locate::OpenElements;
+
+/// Serialises `value`, rejecting characters XML 1.0 forbids.
+///
+/// Paths in errors start at `base` when given, and at the root of the
+/// serialised markup otherwise.
+pub(crate) fn to_string<T: Serialize + ?Sized>(
+    value: &T,
+    base: Option<TeiPath>,
+    empty: EmptyElements,
+) -> Result<String, TeiError> {
+    let mut sink = Checked::new(base);
+    let mut serializer = Serializer::new(&mut sink);
+    serializer.expand_empty_elements(empty == EmptyElements::Expanded);
+    let serialised = value.serialize(serializer);
+
+    match (sink.forbidden, serialised) {
+        (Some(forbidden), _) => Err(forbidden.into_error()),
+        (None, Err(error)) => Err(TeiError::xml(error.to_string())),
+        (None, Ok(())) => Ok(sink.output),
+    }
+}
+
+/// Where the sink is within the markup written so far.
+#[derive(Clone, Copy)]
+enum State {
+    Text,
+    TagOpen,
+    Name,
+    Attributes,
+    Value(char),
+    Closing,
+    Other,
+}
+
+/// The first forbidden character and where it was written.
+struct Forbidden {
+    character: char,
+    path: Option<TeiPath>,
+}
+
+impl Forbidden {
+    fn into_error(self) -> TeiError {
+        let message = format!(
+            "document contains XML 1.0 forbidden character U+{:04X}",
+            u32::from(self.character)
+        );
+        match self.path {
+            Some(path) => TeiError::xml_at(message, SourceLocation::new().with_path(path)),
+            None => TeiError::xml(message),
+        }
+    }
+}
+
+/// Output buffer that tracks open elements and checks content as it arrives.
+struct Checked {
+    output: String,
+    open: OpenElements,
+    state: State,
+    /// Name of the element or attribute being written.
+    name: String,
+    closes_itself: bool,
+    forbidden: Option<Forbidden>,
+}
+
+impl Write for Checked {
+    fn write_str(&mut self, chunk: &str) -> fmt::Result {
+        let mut rest = chunk;
+        while let Some(character) = rest.chars().next() {
+            let consumed = match self.state {
+                State::Text => self.content(rest, '<')?,
+                State::Value(quote) => self.content(rest, quote)?,
+                _ => {
+                    self.step(character);
+                    self.output.push(character);
+                    character.len_utf8()
+                }
+            };
+            rest = rest.get(consumed..).unwrap_or_default();
+        }
+        Ok(())
+    }
+}
+
+impl Checked {
+    fn new(base: Option<TeiPath>) -> Self {
+        Self {
+            output: String::new(),
+            open: base.map_or_else(OpenElements::new, OpenElements::within),
+            state: State::Text,
+            name: String::new(),
+            closes_itself: false,
+            forbidden: None,
+        }
+    }
+
+    /// Checks and writes the content up to `delimiter`, then steps past the
+    /// delimiter if the chunk contains it. Returns the number of bytes
+    /// consumed.
+    fn content(&mut self, rest: &str, delimiter: char) -> Result<usize, fmt::Error> {
+        let end = rest.find(delimiter).unwrap_or(rest.len());
+        let content = rest.get(..end).unwrap_or_default();
+        if let Some(character) = first_forbidden(content) {
+            let path = match self.state {
+                State::Value(_) => self.open.path().map(|path| path.attribute(&self.name)),
+                _ => self.open.path(),
+            };
+            self.forbidden = Some(Forbidden { character, path });
+            return Err(fmt::Error);
+        }
+
+        if matches!(self.state, State::Value(_)) {
+            for character in content.chars() {
+                match attribute_reference(character).filter(|_| character.is_whitespace()) {
+                    Some(reference) => self.output.push_str(reference),
+                    None => self.output.push(character),
+                }
+            }
+        } else {
+            self.output.push_str(content);
+        }
+        if end == rest.len() {
+            return Ok(end);
+        }
+        self.step(delimiter);
+        self.output.push(delimiter);
+        Ok(end + delimiter.len_utf8())
+    }
+
+    fn step(&mut self, character: char) {
+        self.state = match (self.state, character) {
+            (State::Text, '<') => {
+                self.name.clear();
+                State::TagOpen
+            }
+            (State::TagOpen, '/') => State::Closing,
+            (State::TagOpen, '?' | '!') => State::Other,
+            (State::TagOpen | State::Name, '>') => {
+                self.start_element(false);
+                State::Text
+            }
+            (State::Name, '/') => {
+                self.start_element(true);
+                State::Attributes
+            }
+            (State::TagOpen | State::Name, other) if !other.is_whitespace() => {
+                self.name.push(other);
+                State::Name
+            }
+            (State::TagOpen | State::Name, _) => {
+                self.start_element(false);
+                State::Attributes
+            }
+            (State::Attributes, '"' | '\'') => State::Value(character),
+            (State::Attributes, '/') => {
+                self.closes_itself = true;
+                State::Attributes
+            }
+            (State::Attributes, '>') => {
+                if self.closes_itself {
+                    self.open.leave();
+                }
+                State::Text
+            }
+            (State::Attributes, '=') => State::Attributes,
+            (State::Attributes, other) if !other.is_whitespace() => {
+                self.name.push(other);
+                State::Attributes
+            }
+            (State::Value(_), _) => {
+                self.name.clear();
+                State::Attributes
+            }
+            (State::Closing, '>') => {
+                self.open.leave();
+                State::Text
+            }
+            (State::Other, '>') => State::Text,
+            (state, _) => state,
+        };
+    }
+
+    fn start_element(&mut self, closes_itself: bool) {
+        self.open.enter(&self.name);
+        self.name.clear();
+        self.closes_itself = closes_itself;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn path_of(markup: &[&str], base: Option<TeiPath>) -> Option<String> {
+        let mut sink = Checked::new(base);
+        let written = markup.iter().try_for_each(|chunk| sink.write_str(chunk));
+        assert_eq!(written.is_err(), sink.forbidden.is_some());
+        sink.forbidden
+            .and_then(|forbidden| forbidden.path)
+            .map(|path| path.to_string())
+    }
+
+    #[rstest]
+    #[case(&["<TEI><text><body><p>a</p><p>b\u{7}</p></body></text></TEI>"], "/TEI/text[1]/body[1]/p[2]")]
+    #[case(&["<TEI><text><body><u who=\"a\u{1}\">b</u>"], "/TEI/text[1]/body[1]/u[1]/@who")]
+    #[case(&["<TEI><a/><b x='1'/><a>", "ok", "</a><a>\u{2}"], "/TEI/a[3]")]
+    #[case(&["<T", "EI ", "n=\"1\"", "><p", ">x</p", "><p>\u{0}"], "/TEI/p[2]")]
+    fn reports_the_path_of_the_offending_node(#[case] markup: &[&str], #[case] expected: &str) {
+        assert_eq!(path_of(markup, None).as_deref(), Some(expected));
+    }
+
+    #[test]
+    fn locates_forbidden_characters_while_serialising() {
+        let document =
+            tei_core::TeiDocument::from_title_str("bell\u{7}").expect("control characters build");
+
+        let error = to_string(&document, None, EmptyElements::SelfClosing)
+            .expect_err("control characters must fail");
+
+        assert!(error.to_string().contains("U+0007"), "found {error}");
+        assert_eq!(
+            error
+                .location()
+                .and_then(SourceLocation::path)
+                .map(ToString::to_string)
+                .as_deref(),
+            Some("/TEI/teiHeader[1]/fileDesc[1]/title[1]")
+        );
+    }
+
+    #[test]
+    fn accepts_clean_markup_and_prefixes_paths() {
+        assert_eq!(path_of(&["<p rend=\"b\">R&amp;D</p>"], None), None);
+        assert_eq!(
+            path_of(&["<teiHeader><fileDesc>\u{B}"], Some(TeiPath::root())).as_deref(),
+            Some("/TEI/teiHeader[1]/fileDesc[1]")
+        );
+    }
+}
@@ -0,0 +1,304 @@
+//! Structural comparison of two TEI documents.
+//!
+//! Line diffs of emitted markup report reflowed whitespace and reordered
+//! attributes as changes, and a single inserted utterance shifts every line
+//! after it. [`diff`] instead parses both inputs, reads them into element
+//! trees normalised the way the parser reads markup, and compares the trees.
+//! It reports each
+//! added or removed element, each attribute change, and each changed run of
+//! text, with the [`TeiPath`] of the element concerned. Children are paired
+//! by name and `xml:id`, so an inserted block does not mark its later
+//! siblings as changed.
+
+mod tree;
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+use tei_core::{TeiError, TeiPath};
+
+use crate::parse_xml;
+use tree::{Element, Node, Step};
+
+/// A single structural difference between two documents.
+///
+/// Paths of removed and changed markup count positions in the first
+/// document; paths of added markup count positions in the second.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Difference {
+    /// An element present only in the second document.
+    ElementAdded {
+        /// Path of the added element.
+        path: TeiPath,
+    },
+    /// An element present only in the first document.
+    ElementRemoved {
+        /// Path of the removed element.
+        path: TeiPath,
+    },
+    /// An attribute present only in the second document.
+    AttributeAdded {
+        /// Path of the attribute.
+        path: TeiPath,
+        /// Value in the second document.
+        value: String,
+    },
+    /// An attribute present only in the first document.
+    AttributeRemoved {
+        /// Path of the attribute.
+        path: TeiPath,
+        /// Value in the first document.
+        value: String,
+    },
+    /// An attribute whose value differs.
+    AttributeChanged {
+        /// Path of the attribute.
+        path: TeiPath,
+        /// Value in the first document.
+        before: String,
+        /// Value in the second document.
+        after: String,
+    },
+    /// A run of text that differs, was added, or was removed. Missing text
+    /// is reported as an empty string.
+    TextChanged {
+        /// Path of the element holding the text.
+        path: TeiPath,
+        /// Text in the first document.
+        before: String,
+        /// Text in the second document.
+        after: String,
+    },
+}
+
+impl Difference {
+    /// Returns the path of the element or attribute concerned.
+    #[must_use]
+    pub const fn path(&self) -> &TeiPath {
+        match self {
+            Self::ElementAdded { path }
+            | Self::ElementRemoved { path }
+            | Self::AttributeAdded { path, .. }
+            | Self::AttributeRemoved { path, .. }
+            | Self::AttributeChanged { path, .. }
+            | Self::TextChanged { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ElementAdded { path } => write!(formatter, "added element {path}"),
+            Self::ElementRemoved { path } => write!(formatter, "removed element {path}"),
+            Self::AttributeAdded { path, value } => {
+                write!(formatter, "added attribute {path} = {value:?}")
+            }
+            Self::AttributeRemoved { path, value } => {
+                write!(formatter, "removed attribute {path} = {value:?}")
+            }
+            Self::AttributeChanged {
+                path,
+                before,
+                after,
+            } => write!(
+                formatter,
+                "changed attribute {path}: {before:?} -> {after:?}"
+            ),
+            Self::TextChanged {
+                path,
+                before,
+                after,
+            } => write!(formatter, "changed text in {path}: {before:?} -> {after:?}"),
+        }
+    }
+}
+
+/// Parses two TEI documents and lists their structural differences.
+///
+/// Leading and trailing whitespace in text, whitespace between elements,
+/// attribute order and quoting, namespace prefixes, and CDATA versus escaped
+/// text never count as differences.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Xml`] when either input fails to parse, in the same
+/// cases as [`parse_xml`].
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::TeiError;
+/// use tei_xml::diff;
+///
+/// let before = concat!(
+///     "<TEI><teiHeader><fileDesc><title>Wolf 359</title></fileDesc></teiHeader>",
+///     "<text><body><u who=\"eiffel\">Day 1.</u></body></text></TEI>",
+/// );
+/// let after = concat!(
+///     "<TEI>\n  <teiHeader><fileDesc><title>Wolf 359</title></fileDesc></teiHeader>\n",
+///     "  <text><body><u who=\"hera\">Day 1.</u></body></text>\n</TEI>",
+/// );
+///
+/// let differences = diff(before, after)?;
+/// assert_eq!(differences.len(), 1);
+/// assert_eq!(
+///     differences[0].to_string(),
+///     "changed attribute /TEI/text[1]/body[1]/u[1]/@who: \"eiffel\" -> \"hera\"",
+/// );
+/// # Ok::<(), TeiError>(())
+/// ```
+pub fn diff(before: &str, after: &str) -> Result<Vec<Difference>, TeiError> {
+    parse_xml(before)?;
+    parse_xml(after)?;
+    let left = tree::read(before)?;
+    let right = tree::read(after)?;
+
+    let mut differences = Vec::new();
+    compare(&left, &right, &TeiPath::root(), &mut differences);
+    Ok(differences)
+}
+
+fn compare(before: &Element, after: &Element, path: &TeiPath, out: &mut Vec<Difference>) {
+    compare_attributes(before, after, path, out);
+
+    let mut before_positions = HashMap::new();
+    let mut after_positions = HashMap::new();
+    for step in tree::align(&before.children, &after.children) {
+        match step {
+            Step::Both(Node::Element(left), Node::Element(right)) => {
+                let child = child_path(path, left, &mut before_positions);
+                count(right, &mut after_positions);
+                compare(left, right, &child, out);
+            }
+            Step::Both(Node::Text(left), Node::Text(right)) if left != right => {
+                out.push(text_change(path, left, right));
+            }
+            Step::Both(..) => {}
+            Step::Removed(Node::Element(element)) => out.push(Difference::ElementRemoved {
+                path: child_path(path, element, &mut before_positions),
+            }),
+            Step::Removed(Node::Text(text)) => out.push(text_change(path, text, "")),
+            Step::Added(Node::Element(element)) => out.push(Difference::ElementAdded {
+                path: child_path(path, element, &mut after_positions),
+            }),
+            Step::Added(Node::Text(text)) => out.push(text_change(path, "", text)),
+        }
+    }
+}
+
+fn compare_attributes(
+    before: &Element,
+    after: &Element,
+    path: &TeiPath,
+    out: &mut Vec<Difference>,
+) {
+    let names: BTreeSet<&String> = before
+        .attributes
+        .keys()
+        .chain(after.attributes.keys())
+        .collect();
+    for name in names {
+        let attribute = path.clone().attribute(name);
+        match (before.attributes.get(name), after.attributes.get(name)) {
+            (Some(left), Some(right)) if left != right => out.push(Difference::AttributeChanged {
+                path: attribute,
+                before: left.clone(),
+                after: right.clone(),
+            }),
+            (Some(value), None) => out.push(Difference::AttributeRemoved {
+                path: attribute,
+                value: value.clone(),
+            }),
+            (None, Some(value)) => out.push(Difference::AttributeAdded {
+                path: attribute,
+                value: value.clone(),
+            }),
+            _ => {}
+        }
+    }
+}
+
+/// Returns the path of `element` and advances its sibling count.
+fn child_path<'a>(
+    parent: &TeiPath,
+    element: &'a Element,
+    positions: &mut HashMap<&'a str, usize>,
+) -> TeiPath {
+    let position = count(element, positions);
+    parent.clone().nth_child(&element.name, position)
+}
+
+fn count<'a>(element: &'a Element, positions: &mut HashMap<&'a str, usize>) -> usize {
+    let position = positions.entry(element.name.as_str()).or_default();
+    *position += 1;
+    *position
+}
+
+fn text_change(path: &TeiPath, before: &str, after: &str) -> Difference {
+    Difference::TextChanged {
+        path: path.clone(),
+        before: before.to_owned(),
+        after: after.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(body: &str) -> String {
+        format!(
+            "<TEI><teiHeader><fileDesc><title>Wolf 359</title></fileDesc></teiHeader><text><body>{body}</body></text></TEI>"
+        )
+    }
+
+    fn rendered(before: &str, after: &str) -> Vec<String> {
+        diff(&document(before), &document(after))
+            .expect("documents should parse")
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn ignores_insignificant_differences() {
+        let before = document("<u who=\"eiffel\" xml:id=\"u1\">Fish &amp; chips.</u>");
+        let after =
+            document("\n  <u xml:id='u1'   who='eiffel'>\n    <![CDATA[Fish & chips.]]>\n  </u>\n");
+
+        assert_eq!(diff(&before, &after).expect("documents should parse"), []);
+    }
+
+    #[test]
+    fn reports_inserted_blocks_without_shifting_siblings() {
+        assert_eq!(
+            rendered(
+                "<u who=\"a\">One.</u><u who=\"a\">Three.</u>",
+                "<u who=\"a\">One.</u><p>Two.</p><u who=\"a\">Three.</u>"
+            ),
+            ["added element /TEI/text[1]/body[1]/p[1]"]
+        );
+    }
+
+    #[test]
+    fn reports_text_and_attribute_changes() {
+        assert_eq!(
+            rendered(
+                "<p>Day 1.</p><u who=\"a\" xml:lang=\"en\">Hi.</u>",
+                "<p>Day 2.</p><u who=\"a\">Hi.<pause/></u>"
+            ),
+            [
+                "changed text in /TEI/text[1]/body[1]/p[1]: \"Day 1.\" -> \"Day 2.\"",
+                "removed attribute /TEI/text[1]/body[1]/u[1]/@xml:lang = \"en\"",
+                "added element /TEI/text[1]/body[1]/u[1]/pause[1]",
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert!(diff(&document(""), "<TEI>").is_err());
+    }
+}
@@ -0,0 +1,293 @@
+//! Element trees read from TEI markup, and alignment of their children.
+//!
+//! Trees are normalised the way the parser reads markup: elements go by
+//! local name, namespace declarations are dropped, CDATA sections merge with
+//! the text around them, and each run of text is trimmed, with whitespace-only
+//! runs dropped.
+
+use std::collections::BTreeMap;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+use tei_core::TeiError;
+
+/// An element with its attributes and children.
+#[derive(Debug, Default)]
+pub(super) struct Element {
+    pub(super) name: String,
+    pub(super) attributes: BTreeMap<String, String>,
+    pub(super) children: Vec<Node>,
+}
+
+/// A child of an element.
+#[derive(Debug)]
+pub(super) enum Node {
+    Element(Element),
+    Text(String),
+}
+
+/// Identity used to pair children: elements match by name and `xml:id`, and
+/// text matches text.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Key<'a> {
+    Text,
+    Element(&'a str, Option<&'a str>),
+}
+
+impl Node {
+    fn key(&self) -> Key<'_> {
+        match self {
+            Self::Text(_) => Key::Text,
+            Self::Element(element) => Key::Element(
+                &element.name,
+                element.attributes.get("xml:id").map(String::as_str),
+            ),
+        }
+    }
+}
+
+/// One step of an alignment between two child lists.
+#[derive(Clone, Copy, Debug)]
+pub(super) enum Step<'a> {
+    Both(&'a Node, &'a Node),
+    Removed(&'a Node),
+    Added(&'a Node),
+}
+
+/// Reads the root element of well-formed markup.
+pub(super) fn read(xml: &str) -> Result<Element, TeiError> {
+    let mut reader = Reader::from_str(xml);
+    let mut open = vec![Element::default()];
+    let mut text = String::new();
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|error| TeiError::xml(error.to_string()))?;
+        match event {
+            Event::Text(content) => text.push_str(
+                &content
+                    .unescape()
+                    .map_err(|error| TeiError::xml(error.to_string()))?,
+            ),
+            Event::CData(content) => text.push_str(&String::from_utf8_lossy(&content)),
+            Event::Start(_) | Event::Empty(_) | Event::End(_) | Event::Eof => {
+                flush(&mut open, &mut text);
+                match event {
+                    Event::Start(start) => open.push(element(&start)),
+                    Event::Empty(start) => push(&mut open, Node::Element(element(&start))),
+                    Event::End(_) => close(&mut open),
+                    _ => break,
+                }
+            }
+            _ => {}
+        }
+    }
+
+    open.pop()
+        .into_iter()
+        .flat_map(|document| document.children)
+        .find_map(|node| match node {
+            Node::Element(root) => Some(root),
+            Node::Text(_) => None,
+        })
+        .ok_or_else(|| TeiError::xml("markup has no root element"))
+}
+
+fn element(start: &BytesStart<'_>) -> Element {
+    let attributes = start
+        .attributes()
+        .flatten()
+        .filter(|attribute| {
+            let key = attribute.key.as_ref();
+            key != b"xmlns" && !key.starts_with(b"xmlns:")
+        })
+        .map(|attribute| {
+            let value = attribute.unescape_value().map_or_else(
+                |_| String::from_utf8_lossy(&attribute.value).into_owned(),
+                std::borrow::Cow::into_owned,
+            );
+            (
+                String::from_utf8_lossy(attribute.key.as_ref()).into_owned(),
+                value,
+            )
+        })
+        .collect();
+
+    Element {
+        name: String::from_utf8_lossy(start.local_name().as_ref()).into_owned(),
+        attributes,
+        children: Vec::new(),
+    }
+}
+
+fn flush(open: &mut [Element], text: &mut String) {
+    let trimmed = text.trim();
+    if !trimmed.is_empty() {
+        push(open, Node::Text(trimmed.to_owned()));
+    }
+    text.clear();
+}
+
+fn close(open: &mut Vec<Element>) {
+    if let Some(closed) = open.pop() {
+        push(open, Node::Element(closed));
+    }
+}
+
+fn push(open: &mut [Element], node: Node) {
+    if let Some(parent) = open.last_mut() {
+        parent.children.push(node);
+    }
+}
+
+/// Aligns two child lists by their longest common subsequence of keys.
+///
+/// Common leading and trailing children are matched first, so the quadratic
+/// table only covers the region that actually changed.
+pub(super) fn align<'a>(before: &'a [Node], after: &'a [Node]) -> Vec<Step<'a>> {
+    let prefix = before
+        .iter()
+        .zip(after)
+        .take_while(|(left, right)| left.key() == right.key())
+        .count();
+    let (before_rest, after_rest) = (
+        before.get(prefix..).unwrap_or_default(),
+        after.get(prefix..).unwrap_or_default(),
+    );
+    let suffix = before_rest
+        .iter()
+        .rev()
+        .zip(after_rest.iter().rev())
+        .take_while(|(left, right)| left.key() == right.key())
+        .count();
+    let (left, left_tail) = before_rest.split_at(before_rest.len() - suffix);
+    let (right, right_tail) = after_rest.split_at(after_rest.len() - suffix);
+
+    let mut steps: Vec<Step<'a>> = before
+        .iter()
+        .zip(after)
+        .take(prefix)
+        .map(|(left_node, right_node)| Step::Both(left_node, right_node))
+        .collect();
+    steps.extend(middle(left, right));
+    steps.extend(
+        left_tail
+            .iter()
+            .zip(right_tail)
+            .map(|(left_node, right_node)| Step::Both(left_node, right_node)),
+    );
+    steps
+}
+
+/// Longest common subsequence lengths for every pair of suffixes.
+struct Table {
+    width: usize,
+    cells: Vec<usize>,
+}
+
+impl Table {
+    fn get(&self, row: usize, column: usize) -> usize {
+        self.cells
+            .get(row * self.width + column)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn set(&mut self, row: usize, column: usize, value: usize) {
+        if let Some(cell) = self.cells.get_mut(row * self.width + column) {
+            *cell = value;
+        }
+    }
+}
+
+fn middle<'a>(left: &'a [Node], right: &'a [Node]) -> Vec<Step<'a>> {
+    let width = right.len() + 1;
+    let mut table = Table {
+        width,
+        cells: vec![0; (left.len() + 1) * width],
+    };
+    for (row, left_node) in left.iter().enumerate().rev() {
+        for (column, right_node) in right.iter().enumerate().rev() {
+            let length = if left_node.key() == right_node.key() {
+                table.get(row + 1, column + 1) + 1
+            } else {
+                table.get(row + 1, column).max(table.get(row, column + 1))
+            };
+            table.set(row, column, length);
+        }
+    }
+
+    let (mut row, mut column) = (0, 0);
+    let mut steps = Vec::with_capacity(left.len().max(right.len()));
+    while let (Some(left_node), Some(right_node)) = (left.get(row), right.get(column)) {
+        if left_node.key() == right_node.key() {
+            steps.push(Step::Both(left_node, right_node));
+            row += 1;
+            column += 1;
+        } else if table.get(row + 1, column) >= table.get(row, column + 1) {
+            steps.push(Step::Removed(left_node));
+            row += 1;
+        } else {
+            steps.push(Step::Added(right_node));
+            column += 1;
+        }
+    }
+    steps.extend(left.iter().skip(row).map(Step::Removed));
+    steps.extend(right.iter().skip(column).map(Step::Added));
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn children(markup: &str) -> Vec<Node> {
+        read(markup).expect("markup should read").children
+    }
+
+    fn shape(steps: &[Step<'_>]) -> String {
+        steps
+            .iter()
+            .map(|step| match step {
+                Step::Both(..) => '=',
+                Step::Removed(_) => '-',
+                Step::Added(_) => '+',
+            })
+            .collect()
+    }
+
+    #[test]
+    fn aligns_insertions_and_removals() {
+        let before = children("<body><u>a</u><p>b</p><u>c</u><p>e</p></body>");
+        let after = children("<body><u>a</u><u>c</u><u>d</u><p>e</p></body>");
+
+        assert_eq!(shape(&align(&before, &after)), "=-+==");
+    }
+
+    #[test]
+    fn pairs_elements_by_identifier() {
+        let before = children("<body><u xml:id=\"u1\">a</u><u xml:id=\"u2\">b</u></body>");
+        let after = children("<body><u xml:id=\"u2\">b</u></body>");
+
+        assert_eq!(shape(&align(&before, &after)), "-=");
+    }
+
+    #[test]
+    fn normalises_markup_like_the_parser() {
+        let root = read(concat!(
+            "<tei:p xmlns:tei=\"http://www.tei-c.org/ns/1.0\" n='1'>\n",
+            "  Fish <![CDATA[& chips]]>\n",
+            "  <tei:pause/>\n",
+            "</tei:p>",
+        ))
+        .expect("markup should read");
+
+        assert_eq!(root.name, "p");
+        assert_eq!(root.attributes.keys().collect::<Vec<_>>(), ["n"]);
+        assert!(matches!(
+            root.children.as_slice(),
+            [Node::Text(text), Node::Element(pause)] if text == "Fish & chips" && pause.name == "pause"
+        ));
+    }
+}
@@ -0,0 +1,219 @@
+//! Character encodings of TEI input.
+//!
+//! Transcription tools on Windows routinely export UTF-16, with or without a
+//! byte order mark, or declare a legacy single-byte encoding.
+//! [`parse_xml_bytes`] tells UTF-16 from 8-bit input by the byte order mark or
+//! the first `<`, then honours the `encoding` named in the XML declaration.
+//! UTF-8, UTF-16, US-ASCII, ISO-8859-1 and windows-1252 are read; any other
+//! declared encoding fails with [`TeiError::UnsupportedEncoding`] rather than
+//! being misread.
+
+use std::borrow::Cow;
+
+use tei_core::{TeiDocument, TeiError};
+
+use crate::parse_xml;
+
+/// Characters windows-1252 assigns to bytes `0x80` to `0x9F`. Unassigned
+/// bytes map to the C1 control with the same value, as browsers do.
+const WINDOWS_1252: [char; 32] = [
+    '\u{20AC}', '\u{81}', '\u{201A}', '\u{192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2C6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8D}', '\u{17D}', '\u{8F}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2DC}', '\u{2122}', '\u{161}', '\u{203A}', '\u{153}', '\u{9D}', '\u{17E}', '\u{178}',
+];
+
+/// Parses TEI XML bytes in any supported encoding.
+///
+/// # Errors
+///
+/// Returns [`TeiError::UnsupportedEncoding`] when the XML declaration names
+/// an encoding other than UTF-8, UTF-16, US-ASCII, ISO-8859-1 or
+/// windows-1252, [`TeiError::Xml`] when the bytes are not valid in the
+/// detected encoding, and otherwise fails in the same cases as
+/// [`parse_xml`].
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::TeiError;
+/// use tei_xml::parse_xml_bytes;
+///
+/// let xml = concat!(
+///     "\u{feff}<?xml version=\"1.0\" encoding=\"UTF-16\"?>",
+///     "<TEI><teiHeader><fileDesc><title>Wolf 359</title></fileDesc></teiHeader>",
+///     "<text><body/></text></TEI>",
+/// );
+/// let bytes: Vec<u8> = xml.encode_utf16().flat_map(u16::to_le_bytes).collect();
+///
+/// let document = parse_xml_bytes(&bytes)?;
+/// assert_eq!(document.title().as_str(), "Wolf 359");
+/// # Ok::<(), TeiError>(())
+/// ```
+pub fn parse_xml_bytes(bytes: &[u8]) -> Result<TeiDocument, TeiError> {
+    parse_xml(&decode(bytes)?)
+}
+
+/// Decodes XML bytes into text.
+pub(crate) fn decode(bytes: &[u8]) -> Result<Cow<'_, str>, TeiError> {
+    match bytes {
+        [0xEF, 0xBB, 0xBF, rest @ ..] => decode_8bit(rest),
+        [0xFF, 0xFE, rest @ ..] => decode_utf16(rest, u16::from_le_bytes),
+        [0xFE, 0xFF, rest @ ..] => decode_utf16(rest, u16::from_be_bytes),
+        [b'<', 0x00, ..] => decode_utf16(bytes, u16::from_le_bytes),
+        [0x00, b'<', ..] => decode_utf16(bytes, u16::from_be_bytes),
+        _ => decode_8bit(bytes),
+    }
+}
+
+fn decode_utf16(bytes: &[u8], unit: fn([u8; 2]) -> u16) -> Result<Cow<'static, str>, TeiError> {
+    if !bytes.len().is_multiple_of(2) {
+        return Err(TeiError::xml(
+            "UTF-16 input ends part-way through a character",
+        ));
+    }
+
+    let units = bytes
+        .chunks_exact(2)
+        .filter_map(|pair| <[u8; 2]>::try_from(pair).ok())
+        .map(unit);
+    let text = char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .map_err(|error| TeiError::xml(format!("invalid UTF-16 input: {error}")))?;
+    // The byte order already fixed the encoding; the declaration only has to
+    // name one that exists.
+    if let Some(name) = declared_encoding(&text) {
+        supported(name)?;
+    }
+    Ok(Cow::Owned(text))
+}
+
+fn decode_8bit(bytes: &[u8]) -> Result<Cow<'_, str>, TeiError> {
+    // The declaration is ASCII in every 8-bit encoding, so it can be read
+    // before the encoding is known.
+    let head = String::from_utf8_lossy(bytes.get(..bytes.len().min(256)).unwrap_or(bytes));
+    let family = declared_encoding(&head).map_or(Ok(Family::Utf8), supported)?;
+
+    match family {
+        Family::Utf8 => std::str::from_utf8(bytes)
+            .map(Cow::Borrowed)
+            .map_err(|error| {
+                TeiError::xml(format!("input is not valid UTF-8: {error}")).with_source(error)
+            }),
+        Family::Latin1 => Ok(Cow::Owned(bytes.iter().copied().map(char::from).collect())),
+        Family::Windows1252 => Ok(Cow::Owned(
+            bytes.iter().copied().map(windows_1252).collect(),
+        )),
+    }
+}
+
+/// How an 8-bit input is decoded.
+#[derive(Clone, Copy)]
+enum Family {
+    Utf8,
+    Latin1,
+    Windows1252,
+}
+
+/// Resolves a declared encoding name. UTF-16 declarations on 8-bit input are
+/// read as UTF-8, since such files were re-encoded without updating their
+/// declaration.
+fn supported(name: &str) -> Result<Family, TeiError> {
+    let normalised = name.to_ascii_lowercase();
+    match normalised.as_str() {
+        "utf-8" | "utf8" | "us-ascii" | "ascii" | "utf-16" | "utf-16le" | "utf-16be" => {
+            Ok(Family::Utf8)
+        }
+        "iso-8859-1" | "iso_8859-1" | "latin1" | "l1" => Ok(Family::Latin1),
+        "windows-1252" | "cp1252" => Ok(Family::Windows1252),
+        _ => Err(TeiError::unsupported_encoding(name)),
+    }
+}
+
+/// Returns the `encoding` pseudo-attribute of a leading XML declaration.
+fn declared_encoding(text: &str) -> Option<&str> {
+    let declaration = text
+        .trim_start_matches('\u{feff}')
+        .strip_prefix("<?xml")?
+        .split("?>")
+        .next()?;
+    let value = declaration
+        .split_once("encoding")?
+        .1
+        .trim_start()
+        .strip_prefix('=')?
+        .trim_start();
+    let quote = value
+        .chars()
+        .next()
+        .filter(|quote| matches!(quote, '"' | '\''))?;
+    value.get(1..)?.split(quote).next()
+}
+
+fn windows_1252(byte: u8) -> char {
+    (byte >= 0x80)
+        .then(|| WINDOWS_1252.get(usize::from(byte - 0x80)).copied())
+        .flatten()
+        .unwrap_or_else(|| char::from(byte))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const DOCUMENT: &str = "<TEI><teiHeader><fileDesc><title>Caf\u{e9} \u{2013} 359</title></fileDesc></teiHeader><text><body/></text></TEI>";
+
+    fn declared(encoding: &str) -> String {
+        format!("<?xml version=\"1.0\" encoding=\"{encoding}\"?>\n{DOCUMENT}")
+    }
+
+    fn utf16(text: &str, unit: fn(u16) -> [u8; 2], bom: bool) -> Vec<u8> {
+        let prefix = if bom { "\u{feff}" } else { "" };
+        format!("{prefix}{text}")
+            .encode_utf16()
+            .flat_map(unit)
+            .collect()
+    }
+
+    #[rstest]
+    #[case(declared("UTF-8").into_bytes())]
+    #[case([&[0xEF, 0xBB, 0xBF][..], DOCUMENT.as_bytes()].concat())]
+    #[case(utf16(&declared("UTF-16"), u16::to_le_bytes, true))]
+    #[case(utf16(&declared("UTF-16"), u16::to_be_bytes, true))]
+    #[case(utf16(&declared("utf-16"), u16::to_le_bytes, false))]
+    #[case(utf16(DOCUMENT, u16::to_be_bytes, false))]
+    fn decodes_unicode_input(#[case] bytes: Vec<u8>) {
+        let document = parse_xml_bytes(&bytes).expect("input should parse");
+
+        assert_eq!(document.title().as_str(), "Caf\u{e9} \u{2013} 359");
+    }
+
+    #[rstest]
+    #[case("ISO-8859-1", b'-', "Caf\u{e9} - 359")]
+    #[case("windows-1252", 0x96, "Caf\u{e9} \u{2013} 359")]
+    fn decodes_single_byte_input(#[case] encoding: &str, #[case] dash: u8, #[case] title: &str) {
+        let bytes: Vec<u8> = declared(encoding)
+            .chars()
+            .map(|character| match character {
+                '\u{2013}' => dash,
+                other => u8::try_from(u32::from(other)).expect("test text is Latin-1"),
+            })
+            .collect();
+
+        let document = parse_xml_bytes(&bytes).expect("input should parse");
+
+        assert_eq!(document.title().as_str(), title);
+    }
+
+    #[rstest]
+    #[case(declared("Shift_JIS").into_bytes(), "unsupported character encoding `Shift_JIS`")]
+    #[case(utf16(&declared("EBCDIC"), u16::to_le_bytes, true), "`EBCDIC`")]
+    #[case(vec![b'<', 0x00, b'T'], "part-way through a character")]
+    #[case(b"<TEI>\xFF</TEI>".to_vec(), "not valid UTF-8")]
+    fn rejects_unreadable_input(#[case] bytes: Vec<u8>, #[case] expected: &str) {
+        let error = parse_xml_bytes(&bytes).expect_err("input must fail");
+
+        assert!(error.to_string().contains(expected), "found {error}");
+    }
+}
@@ -0,0 +1,289 @@
+//! Handling of entity and character references in parsed markup.
+//!
+//! `quick-xml` resolves the five predefined entities and character
+//! references, and never fetches anything: a DTD is skipped and entities it
+//! declares are unknown. Documents declaring an external entity are rejected
+//! outright, whatever the [`EntityPolicy`], so XXE payloads fail loudly
+//! rather than being silently ignored. [`EntityPolicy::PreserveUnknown`]
+//! escapes the `&` of every other reference before deserialising, which
+//! keeps references such as `&nbsp;` as literal text.
+
+use std::borrow::Cow;
+
+use tei_core::TeiError;
+
+use crate::locate;
+
+/// How references other than the predefined entities are treated.
+///
+/// # Examples
+///
+/// ```
+/// use tei_xml::{EntityPolicy, ParseOptions};
+///
+/// let options = ParseOptions::new().with_entity_policy(EntityPolicy::PreserveUnknown);
+/// assert_eq!(options.entity_policy(), EntityPolicy::PreserveUnknown);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum EntityPolicy {
+    /// Resolve `&lt;`, `&gt;`, `&amp;`, `&apos;`, `&quot;` and character
+    /// references, and reject any other reference.
+    #[default]
+    Resolve,
+    /// Resolve as [`EntityPolicy::Resolve`] does, but keep any other
+    /// reference, such as `&nbsp;`, as literal text.
+    PreserveUnknown,
+}
+
+/// Markup prepared for deserialisation under an [`EntityPolicy`].
+pub(crate) struct Prepared<'a> {
+    pub(crate) markup: Cow<'a, str>,
+    /// Offsets in the original markup where `amp;` was inserted.
+    escaped: Vec<usize>,
+}
+
+impl Prepared<'_> {
+    /// Points an error raised on the prepared markup back at `original`.
+    pub(crate) fn relocate(&self, original: &str, mut error: TeiError) -> TeiError {
+        let TeiError::Xml {
+            location: Some(location),
+            ..
+        } = &mut error
+        else {
+            return error;
+        };
+        let Some(offset) = location.offset().filter(|_| !self.escaped.is_empty()) else {
+            return error;
+        };
+
+        let shift = self
+            .escaped
+            .iter()
+            .enumerate()
+            .take_while(|(index, at)| **at + (index + 1) * "amp;".len() < offset)
+            .count()
+            * "amp;".len();
+        let mapped = u64::try_from(offset - shift).unwrap_or(u64::MAX);
+        *location = locate::locate_offset(original, mapped).with_line_column_in(original);
+        error
+    }
+}
+
+/// Rejects external entity declarations, then applies `policy` to the other
+/// references in `xml`.
+pub(crate) fn prepare(xml: &str, policy: EntityPolicy) -> Result<Prepared<'_>, TeiError> {
+    let references = scan(xml)?;
+    let unknown: Vec<usize> = match policy {
+        EntityPolicy::Resolve => Vec::new(),
+        EntityPolicy::PreserveUnknown => references,
+    };
+    if unknown.is_empty() {
+        return Ok(Prepared {
+            markup: Cow::Borrowed(xml),
+            escaped: unknown,
+        });
+    }
+
+    let mut markup = String::with_capacity(xml.len() + unknown.len() * "amp;".len());
+    let mut copied = 0;
+    for at in &unknown {
+        markup.push_str(xml.get(copied..=*at).unwrap_or_default());
+        markup.push_str("amp;");
+        copied = at + 1;
+    }
+    markup.push_str(xml.get(copied..).unwrap_or_default());
+    Ok(Prepared {
+        markup: Cow::Owned(markup),
+        escaped: unknown,
+    })
+}
+
+/// Returns the offsets of references to unknown entities in text and
+/// attribute values, skipping comments, CDATA and processing instructions.
+fn scan(xml: &str) -> Result<Vec<usize>, TeiError> {
+    let mut unknown = Vec::new();
+    let mut position = 0;
+    while let Some(found) = xml.get(position..).and_then(|rest| rest.find(['<', '&'])) {
+        let at = position + found;
+        let rest = xml.get(at..).unwrap_or_default();
+        let skipped = if let Some(reference) = rest.strip_prefix('&') {
+            if is_unknown_reference(reference) {
+                unknown.push(at);
+            }
+            1
+        } else if rest.starts_with("<!DOCTYPE") {
+            doctype(xml, at, rest)?.len()
+        } else {
+            [("<!--", "-->"), ("<![CDATA[", "]]>"), ("<?", "?>")]
+                .iter()
+                .find(|(open, _)| rest.starts_with(*open))
+                .map_or(1, |(_, close)| {
+                    rest.find(close).map_or(rest.len(), |end| end + close.len())
+                })
+        };
+        position = at + skipped;
+    }
+    Ok(unknown)
+}
+
+/// Reports whether the text after an `&` is a well-formed reference to an
+/// entity the parser does not know. Malformed references are left for the
+/// parser to report.
+fn is_unknown_reference(reference: &str) -> bool {
+    let Some((name, _)) = reference.split_once(';') else {
+        return false;
+    };
+    let well_formed = name
+        .chars()
+        .next()
+        .is_some_and(|first| first.is_alphabetic() || matches!(first, '_' | ':'))
+        && name.chars().all(|character| {
+            character.is_alphanumeric() || matches!(character, '_' | ':' | '-' | '.')
+        });
+    well_formed && !matches!(name, "lt" | "gt" | "amp" | "apos" | "quot")
+}
+
+/// Returns the document type declaration at the start of `rest`, which
+/// begins at `start` in `xml`, including any internal subset. External
+/// entities declared in the subset are rejected.
+fn doctype<'a>(xml: &str, start: usize, rest: &'a str) -> Result<&'a str, TeiError> {
+    let end = match unquoted(rest, &['[', '>']) {
+        Some(open) if rest.get(open..).is_some_and(|tail| tail.starts_with('[')) => {
+            subset_end(xml, start, rest, open)?
+        }
+        Some(close) => close + 1,
+        None => rest.len(),
+    };
+    Ok(rest.get(..end).unwrap_or(rest))
+}
+
+/// Walks the internal subset opening at `open` one declaration at a time,
+/// so a `]` inside a literal or comment does not end it early, and returns
+/// the offset just past the `>` closing the document type declaration.
+fn subset_end(xml: &str, start: usize, rest: &str, open: usize) -> Result<usize, TeiError> {
+    let mut position = open + 1;
+    while let Some(tail) = rest.get(position..).filter(|tail| !tail.is_empty()) {
+        if tail.starts_with(']') {
+            return Ok(tail.find('>').map_or(rest.len(), |end| position + end + 1));
+        }
+        position += if let Some((_, close)) = [("<!--", "-->"), ("<?", "?>")]
+            .iter()
+            .find(|(opening, _)| tail.starts_with(*opening))
+        {
+            tail.find(close).map_or(tail.len(), |end| end + close.len())
+        } else if tail.starts_with('<') {
+            let length = unquoted(tail, &['>']).map_or(tail.len(), |close| close + 1);
+            reject_external_entity(xml, start + position, tail.get(..length).unwrap_or(tail))?;
+            length
+        } else {
+            tail.chars().next().map_or(1, char::len_utf8)
+        };
+    }
+    Ok(rest.len())
+}
+
+/// Returns the offset of the first of `targets` in `markup` that is not
+/// inside a quoted literal.
+fn unquoted(markup: &str, targets: &[char]) -> Option<usize> {
+    let mut quote = None;
+    for (index, character) in markup.char_indices() {
+        if quote.is_some() {
+            quote = quote.filter(|open| *open != character);
+        } else if matches!(character, '"' | '\'') {
+            quote = Some(character);
+        } else if targets.contains(&character) {
+            return Some(index);
+        }
+    }
+    None
+}
+
+/// Rejects `declaration`, found at `at` in `xml`, when it declares an
+/// external entity.
+fn reject_external_entity(xml: &str, at: usize, declaration: &str) -> Result<(), TeiError> {
+    let Some(entity) = declaration.strip_prefix("<!ENTITY") else {
+        return Ok(());
+    };
+    let mut tokens = entity.split_whitespace().skip_while(|token| *token == "%");
+    let name = tokens.next().unwrap_or_default();
+    if tokens
+        .next()
+        .is_some_and(|keyword| matches!(keyword, "SYSTEM" | "PUBLIC"))
+    {
+        let offset = u64::try_from(at).unwrap_or(u64::MAX);
+        return Err(TeiError::xml_at(
+            format!("external entity `{name}` is not allowed"),
+            locate::locate_offset(xml, offset).with_line_column_in(xml),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("<p>R&amp;D &#233; &#x41;</p>", &[])]
+    #[case("<p>&nbsp;</p>", &[3])]
+    #[case("<p rend=\"&rarr;\">&copy;</p>", &[9, 17])]
+    #[case("<!-- &c; --><![CDATA[&d;]]><?pi &e;?><p>& x;</p>", &[])]
+    #[case("<!DOCTYPE TEI [<!ENTITY c \"&d;\">]><TEI>&c;</TEI>", &[39])]
+    #[case("<!DOCTYPE TEI [<!ENTITY c \"]>&d;\">]><TEI>&c;</TEI>", &[41])]
+    fn finds_unknown_references(#[case] xml: &str, #[case] expected: &[usize]) {
+        assert_eq!(scan(xml).expect("no external entities"), expected);
+    }
+
+    #[rstest]
+    #[case(
+        "<!DOCTYPE TEI [<!ENTITY xxe SYSTEM \"file:///etc/passwd\">]><TEI/>",
+        "xxe"
+    )]
+    #[case(
+        "<!DOCTYPE TEI [\n<!ENTITY % remote PUBLIC \"-//X//\" \"http://x/\">\n]><TEI/>",
+        "remote"
+    )]
+    #[case(
+        "<!DOCTYPE TEI [<!ENTITY a \"]\"><!ENTITY x SYSTEM \"file:///etc/passwd\">]><TEI/>",
+        "x"
+    )]
+    #[case(
+        "<!DOCTYPE TEI [<!-- ]> --><!ENTITY y SYSTEM 'file:///etc/passwd'>]><TEI/>",
+        "y"
+    )]
+    #[case(
+        "<!DOCTYPE TEI SYSTEM \"tei[1].dtd\" [<!ENTITY z PUBLIC \"-//X//\" \"x\">]><TEI/>",
+        "z"
+    )]
+    fn rejects_external_entities(#[case] xml: &str, #[case] name: &str) {
+        let error = scan(xml).expect_err("external entities must fail");
+
+        assert!(
+            error
+                .to_string()
+                .contains(&format!("external entity `{name}`")),
+            "found {error}"
+        );
+    }
+
+    #[test]
+    fn escapes_unknown_references_and_relocates_errors() {
+        let xml = "<p>&nbsp;a&rarr;</p><";
+        let prepared = prepare(xml, EntityPolicy::PreserveUnknown).expect("no external entities");
+
+        assert_eq!(prepared.markup, "<p>&amp;nbsp;a&amp;rarr;</p><");
+        let error = TeiError::xml_at(
+            "unexpected end",
+            tei_core::SourceLocation::new().with_offset(prepared.markup.len() - 1),
+        );
+        assert_eq!(
+            prepared
+                .relocate(xml, error)
+                .location()
+                .and_then(tei_core::SourceLocation::offset),
+            Some(xml.len() - 1)
+        );
+    }
+}
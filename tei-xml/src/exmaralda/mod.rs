@@ -0,0 +1,297 @@
+//! `EXMARaLDA` basic transcriptions as timed utterances.
+//!
+//! The Partitur-Editor saves a transcript as a `.exb` file: a speaker table
+//! in the head, then a common timeline of points and one tier per speaker
+//! and category, holding events that run from one point to another. Each
+//! event on a transcription tier (`type="t"`) becomes an utterance by the
+//! tier's speaker spanning its two points; annotation and description tiers
+//! are skipped. Speakers are named by their abbreviation, or by their ID when
+//! it is blank, and every speaker in the table is listed in the profile
+//! description, including those without events.
+//!
+//! Timeline points may leave out their time. As `EXMARaLDA` does, such a point
+//! is placed between its nearest timed neighbours in proportion to its
+//! position, and events touching a point that cannot be placed stay untimed.
+//! Utterances follow the order in which their events start on the timeline.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use tei_core::import::TranscriptBuilder;
+use tei_core::{Inline, SourceLocation, TeiDocument, TeiError, TeiPath};
+
+use crate::locate;
+
+mod tier;
+mod timeline;
+
+use tier::{Tier, read_tier};
+use timeline::{CommonTimeline, Points, resolve_times};
+
+const FORMAT: &str = "EXMARaLDA";
+
+/// Tier type of the transcription tiers read as utterances.
+const TRANSCRIPTION: &str = "t";
+
+#[derive(Deserialize)]
+struct BasicTranscription {
+    head: Head,
+    #[serde(rename = "basic-body")]
+    body: BasicBody,
+}
+
+#[derive(Deserialize)]
+struct Head {
+    #[serde(default)]
+    speakertable: SpeakerTable,
+}
+
+#[derive(Default, Deserialize)]
+struct SpeakerTable {
+    #[serde(rename = "speaker", default)]
+    speakers: Vec<SpeakerEntry>,
+}
+
+#[derive(Deserialize)]
+struct SpeakerEntry {
+    #[serde(rename = "@id")]
+    id: String,
+    #[serde(default)]
+    abbreviation: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct BasicBody {
+    #[serde(rename = "common-timeline")]
+    timeline: CommonTimeline,
+    #[serde(rename = "tier", default)]
+    tiers: Vec<Tier>,
+}
+
+/// Converts an `EXMARaLDA` basic transcription into a document titled `title`.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Xml`] when the markup is not well-formed or is not a
+/// basic transcription. Returns [`TeiError::Import`], located by the path of
+/// the offending element, when a point has a malformed time, or an event
+/// refers to an unknown point or speaker or ends before it starts. Returns
+/// [`TeiError::DocumentTitle`] when `title` is blank.
+///
+/// # Examples
+///
+/// ```
+/// use tei_xml::exmaralda;
+///
+/// let exb = r#"<basic-transcription>
+///   <head><speakertable>
+///     <speaker id="SPK0"><abbreviation>Eiffel</abbreviation></speaker>
+///   </speakertable></head>
+///   <basic-body>
+///     <common-timeline><tli id="T0" time="0.5"/><tli id="T1" time="2"/></common-timeline>
+///     <tier id="TIE0" speaker="SPK0" category="v" type="t">
+///       <event start="T0" end="T1">Day 1. </event>
+///     </tier>
+///   </basic-body>
+/// </basic-transcription>"#;
+/// let document = exmaralda::parse(exb, "Wolf 359")?;
+///
+/// let utterance = document.text().body().utterances().next().expect("one utterance");
+/// assert_eq!(utterance.speaker().map(|s| s.as_str()), Some("Eiffel"));
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn parse(input: &str, title: &str) -> Result<TeiDocument, TeiError> {
+    let transcription: BasicTranscription = locate::deserialize(input)?;
+    let mut builder = TranscriptBuilder::new(title)?;
+    let mut names = HashMap::new();
+    for entry in &transcription.head.speakertable.speakers {
+        let name = entry
+            .abbreviation
+            .as_deref()
+            .map(str::trim)
+            .filter(|abbreviation| !abbreviation.is_empty())
+            .unwrap_or(&entry.id);
+        builder.add_speaker(name)?;
+        names.insert(entry.id.as_str(), name);
+    }
+
+    let body = &transcription.body;
+    let points = Points {
+        positions: body
+            .timeline
+            .points
+            .iter()
+            .enumerate()
+            .map(|(index, point)| (point.id.as_str(), index))
+            .collect(),
+        times: resolve_times(&body.timeline.points)?,
+    };
+    let mut turns = Vec::new();
+    for (index, tier) in body.tiers.iter().enumerate() {
+        if tier.kind != TRANSCRIPTION {
+            continue;
+        }
+        let path = body_path().nth_child("tier", index + 1);
+        let speaker = match tier.speaker.as_deref() {
+            Some(id) => Some(*names.get(id).ok_or_else(|| {
+                failure(
+                    format!("tier refers to unknown speaker `{id}`"),
+                    path.clone().attribute("speaker"),
+                )
+            })?),
+            None => None,
+        };
+        turns.extend(read_tier(tier, &path, speaker, &points)?);
+    }
+
+    // The sort is stable, so events starting together keep their tier order.
+    turns.sort_by_key(|turn| turn.start);
+    for turn in turns {
+        let speaker = turn.speaker.as_deref();
+        let content = [Inline::text(turn.text)];
+        match turn.timing {
+            Some(timing) => builder.push_utterance(speaker, timing, content),
+            None => builder.push_untimed_utterance(speaker, content),
+        }
+        .map_err(|error| failure(error.to_string(), turn.path))?;
+    }
+    Ok(builder.finish())
+}
+
+fn body_path() -> TeiPath {
+    TeiPath::new()
+        .child("basic-transcription")
+        .child("basic-body")
+}
+
+fn failure(message: impl Into<String>, path: TeiPath) -> TeiError {
+    TeiError::import_at(FORMAT, message, SourceLocation::new().with_path(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    const TRANSCRIPT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<basic-transcription>
+  <head>
+    <meta-information><project-name>Wolf 359</project-name></meta-information>
+    <speakertable>
+      <speaker id="SPK0"><abbreviation>Eiffel</abbreviation><sex value="m"/></speaker>
+      <speaker id="SPK1"><abbreviation>Hera</abbreviation></speaker>
+      <speaker id="SPK2"><abbreviation> </abbreviation></speaker>
+    </speakertable>
+  </head>
+  <basic-body>
+    <common-timeline>
+      <tli id="T0" time="0.0"/>
+      <tli id="T1"/>
+      <tli id="T2" time="3.0"/>
+      <tli id="T3"/>
+    </common-timeline>
+    <tier id="TIE1" speaker="SPK1" category="v" type="t">
+      <event start="T1" end="T2">Hello, Eiffel.</event>
+      <event start="T2" end="T3">Still here.</event>
+    </tier>
+    <tier id="TIE0" speaker="SPK0" category="v" type="t">
+      <event start="T0" end="T1">Day 1. </event>
+    </tier>
+    <tier id="TIE2" speaker="SPK0" category="en" type="a">
+      <event start="T0" end="T1">Translation.</event>
+    </tier>
+  </basic-body>
+</basic-transcription>"#;
+
+    #[test]
+    fn reads_transcription_tiers_in_timeline_order() {
+        let document = parse(TRANSCRIPT, "Wolf 359").expect("transcript should import");
+
+        let timeline = document.text().timeline().expect("timeline recorded");
+        let summary: Vec<_> = document
+            .text()
+            .body()
+            .utterances()
+            .map(|utterance| {
+                (
+                    utterance.speaker().map(tei_core::Speaker::as_str),
+                    utterance.start().and_then(|point| timeline.offset(point)),
+                    utterance.content().to_vec(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (
+                    Some("Eiffel"),
+                    Some(Duration::ZERO),
+                    vec![Inline::text("Day 1.")]
+                ),
+                (
+                    Some("Hera"),
+                    Some(Duration::from_millis(1_500)),
+                    vec![Inline::text("Hello, Eiffel.")]
+                ),
+                (Some("Hera"), None, vec![Inline::text("Still here.")]),
+            ]
+        );
+        let speakers: Vec<_> = document
+            .header()
+            .profile_desc()
+            .map(|profile| {
+                profile
+                    .speakers()
+                    .iter()
+                    .map(tei_core::SpeakerName::as_str)
+                    .collect()
+            })
+            .unwrap_or_default();
+        assert_eq!(speakers, ["Eiffel", "Hera", "SPK2"]);
+    }
+
+    #[rstest]
+    #[case(
+        r#"<tli id="T0" time="soon"/>"#,
+        "",
+        "/basic-transcription/basic-body/common-timeline/tli[1]/@time"
+    )]
+    #[case(
+        r#"<tli id="T0" time="0"/>"#,
+        r#"<tier speaker="SPK0" type="t"><event start="T0" end="T9">Hi.</event></tier>"#,
+        "/basic-transcription/basic-body/tier[1]/event[1]/@end"
+    )]
+    #[case(
+        r#"<tli id="T0" time="0"/><tli id="T1" time="1"/>"#,
+        r#"<tier speaker="SPK0" type="t"><event start="T1" end="T0">Hi.</event></tier>"#,
+        "/basic-transcription/basic-body/tier[1]/event[1]"
+    )]
+    #[case(
+        r#"<tli id="T0" time="0"/>"#,
+        r#"<tier speaker="SPK9" type="t"></tier>"#,
+        "/basic-transcription/basic-body/tier[1]/@speaker"
+    )]
+    fn reports_broken_references_by_path(
+        #[case] points: &str,
+        #[case] tiers: &str,
+        #[case] expected: &str,
+    ) {
+        let input = format!(
+            "<basic-transcription><head><speakertable><speaker id=\"SPK0\"/></speakertable>\
+             </head><basic-body><common-timeline>{points}</common-timeline>{tiers}\
+             </basic-body></basic-transcription>"
+        );
+
+        let error = parse(&input, "Wolf 359").expect_err("input must be rejected");
+
+        assert!(
+            matches!(error, TeiError::Import { format: FORMAT, .. }),
+            "{error:?}"
+        );
+        let path = error.location().and_then(SourceLocation::path);
+        assert_eq!(path.map(ToString::to_string).as_deref(), Some(expected));
+    }
+}
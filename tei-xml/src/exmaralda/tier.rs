@@ -0,0 +1,88 @@
+//! Transcription tiers and the events they hold.
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::time::Duration;
+
+use serde::Deserialize;
+use tei_core::{TeiError, TeiPath};
+
+use super::failure;
+use super::timeline::{Points, time_span};
+
+#[derive(Deserialize)]
+pub(super) struct Tier {
+    #[serde(rename = "@speaker", default)]
+    pub(super) speaker: Option<String>,
+    #[serde(rename = "@type")]
+    pub(super) kind: String,
+    #[serde(rename = "event", default)]
+    events: Vec<Event>,
+}
+
+#[derive(Deserialize)]
+struct Event {
+    #[serde(rename = "@start")]
+    start: String,
+    #[serde(rename = "@end")]
+    end: String,
+    #[serde(rename = "$text", default)]
+    text: String,
+}
+
+/// An event on its way to becoming an utterance.
+pub(super) struct Turn {
+    /// Position of the start point on the timeline.
+    pub(super) start: usize,
+    pub(super) speaker: Option<String>,
+    pub(super) timing: Option<Range<Duration>>,
+    pub(super) text: String,
+    pub(super) path: TeiPath,
+}
+
+/// Returns a turn for each event with text on a transcription tier.
+pub(super) fn read_tier(
+    tier: &Tier,
+    path: &TeiPath,
+    speaker: Option<&str>,
+    points: &Points<'_>,
+) -> Result<Vec<Turn>, TeiError> {
+    let mut turns = Vec::new();
+    for (index, event) in tier.events.iter().enumerate() {
+        let event_path = path.clone().nth_child("event", index + 1);
+        let span = locate_event(event, &points.positions, event_path.clone())?;
+        let text = event.text.trim();
+        if !text.is_empty() {
+            turns.push(Turn {
+                start: span.start,
+                speaker: speaker.map(str::to_owned),
+                timing: time_span(&points.times, &span),
+                text: text.to_owned(),
+                path: event_path,
+            });
+        }
+    }
+    Ok(turns)
+}
+
+/// Returns the positions on the timeline of the points an event spans.
+fn locate_event(
+    event: &Event,
+    positions: &HashMap<&str, usize>,
+    path: TeiPath,
+) -> Result<Range<usize>, TeiError> {
+    let position = |id: &str, attribute: &str| {
+        positions.get(id).copied().ok_or_else(|| {
+            failure(
+                format!("event refers to unknown timeline point `{id}`"),
+                path.clone().attribute(attribute),
+            )
+        })
+    };
+    let start = position(&event.start, "start")?;
+    let end = position(&event.end, "end")?;
+    if end <= start {
+        return Err(failure("event ends before it starts", path));
+    }
+    Ok(start..end)
+}
@@ -0,0 +1,91 @@
+//! The common timeline and the times of its points.
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::time::Duration;
+
+use serde::Deserialize;
+use tei_core::TeiError;
+
+use super::{body_path, failure};
+
+#[derive(Deserialize)]
+pub(super) struct CommonTimeline {
+    #[serde(rename = "tli", default)]
+    pub(super) points: Vec<Point>,
+}
+
+#[derive(Deserialize)]
+pub(super) struct Point {
+    #[serde(rename = "@id")]
+    pub(super) id: String,
+    #[serde(rename = "@time", default)]
+    time: Option<String>,
+}
+
+/// The common timeline, indexed for looking up events.
+pub(super) struct Points<'a> {
+    pub(super) positions: HashMap<&'a str, usize>,
+    pub(super) times: Vec<Option<Duration>>,
+}
+
+/// Returns the times of the points `span` runs between, when both are known
+/// and in order.
+pub(super) fn time_span(
+    times: &[Option<Duration>],
+    span: &Range<usize>,
+) -> Option<Range<Duration>> {
+    let start = times.get(span.start).copied().flatten()?;
+    let end = times.get(span.end).copied().flatten()?;
+    (start <= end).then_some(start..end)
+}
+
+/// Reads the time of every point, placing untimed points between their
+/// nearest timed neighbours.
+pub(super) fn resolve_times(points: &[Point]) -> Result<Vec<Option<Duration>>, TeiError> {
+    let mut times = Vec::with_capacity(points.len());
+    for (index, point) in points.iter().enumerate() {
+        let time = match point.time.as_deref() {
+            Some(text) => Some(seconds(text).ok_or_else(|| {
+                let path = body_path()
+                    .child("common-timeline")
+                    .nth_child("tli", index + 1)
+                    .attribute("time");
+                failure(format!("malformed time `{text}`"), path)
+            })?),
+            None => None,
+        };
+        times.push(time);
+    }
+
+    let anchors: Vec<(usize, Duration)> = times
+        .iter()
+        .enumerate()
+        .filter_map(|(index, time)| time.map(|known| (index, known)))
+        .collect();
+    for pair in anchors.windows(2) {
+        let [(from, start), (to, end)] = *pair else {
+            continue;
+        };
+        let untimed = times.iter_mut().enumerate().take(to).skip(from + 1);
+        for (index, slot) in untimed {
+            *slot = between(start, end, index - from, to - from);
+        }
+    }
+    Ok(times)
+}
+
+/// Returns the time `step` of `steps` equal steps from `start` to `end`, or
+/// `None` when the steps are too many to count.
+fn between(start: Duration, end: Duration, step: usize, steps: usize) -> Option<Duration> {
+    let offset = end
+        .saturating_sub(start)
+        .checked_mul(u32::try_from(step).ok()?)?
+        .checked_div(u32::try_from(steps).ok()?)?;
+    start.checked_add(offset)
+}
+
+fn seconds(text: &str) -> Option<Duration> {
+    let value: f64 = text.trim().parse().ok()?;
+    Duration::try_from_secs_f64(value).ok()
+}
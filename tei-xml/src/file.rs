@@ -0,0 +1,178 @@
+//! File-oriented wrappers around [`parse_xml_bytes`](crate::parse_xml_bytes) and
+//! [`emit_xml`](crate::emit_xml).
+//!
+//! Emission writes to a sibling temporary file and renames it over the target
+//! once the bytes are flushed, so readers never observe a half-written
+//! document and a failed write leaves any previous file untouched.
+
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tei_core::{TeiDocument, TeiError};
+
+use crate::{EmitOptions, emit_xml_with_options, parse_xml_bytes};
+
+static TEMPORARY_FILES: AtomicUsize = AtomicUsize::new(0);
+
+/// Reads and parses the TEI document stored at `path`.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Io`] when the file cannot be opened or read, and
+/// otherwise fails in the same cases as [`parse_xml_bytes`], which detects
+/// UTF-16 and declared single-byte encodings.
+///
+/// # Examples
+///
+/// ```no_run
+/// use tei_xml::parse_file;
+///
+/// let document = parse_file("episodes/wolf-359.xml")?;
+/// println!("{}", document.title().as_str());
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn parse_file(path: impl AsRef<Path>) -> Result<TeiDocument, TeiError> {
+    let target = path.as_ref();
+    let mut bytes = Vec::new();
+    File::open(target)
+        .and_then(|file| BufReader::new(file).read_to_end(&mut bytes))
+        .map_err(|error| TeiError::io(target, &error))?;
+    parse_xml_bytes(&bytes)
+}
+
+/// Serializes a document and atomically writes it to `path`.
+///
+/// The markup matches [`emit_xml_with_options`] for the same options.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Xml`] when the document cannot be serialized and
+/// [`TeiError::Io`] when the temporary file cannot be written or renamed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use tei_core::TeiDocument;
+/// use tei_xml::{EmitOptions, emit_to_path};
+///
+/// let document = TeiDocument::from_title_str("Wolf 359")?;
+/// emit_to_path(&document, "episodes/wolf-359.xml", EmitOptions::new().with_declaration(true))?;
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn emit_to_path(
+    document: &TeiDocument,
+    path: impl AsRef<Path>,
+    options: EmitOptions,
+) -> Result<(), TeiError> {
+    let target = path.as_ref();
+    let xml = emit_xml_with_options(document, options)?;
+    let temporary = temporary_path(target);
+
+    write_synced(&temporary, xml.as_bytes())
+        .and_then(|()| fs::rename(&temporary, target))
+        .map_err(|error| {
+            // Best effort: the temporary file may never have been created.
+            drop(fs::remove_file(&temporary));
+            TeiError::io(target, &error)
+        })
+}
+
+fn write_synced(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    writer.write_all(bytes)?;
+    writer.flush()?;
+    writer.get_ref().sync_all()
+}
+
+/// Names a temporary file beside `target` so the final rename stays on one
+/// filesystem.
+fn temporary_path(target: &Path) -> PathBuf {
+    let name = target
+        .file_name()
+        .map_or_else(|| "tei".into(), |value| value.to_string_lossy());
+    let sequence = TEMPORARY_FILES.fetch_add(1, Ordering::Relaxed);
+    target.with_file_name(format!(".{name}.{}.{sequence}.tmp", process::id()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::ErrorKind;
+
+    fn scratch_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("tei-xml-{name}-{}", process::id()));
+        fs::create_dir_all(&directory).expect("scratch directory should be created");
+        directory
+    }
+
+    #[test]
+    fn round_trips_through_a_file() {
+        let directory = scratch_directory("round-trip");
+        let path = directory.join("episode.xml");
+        let document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+
+        emit_to_path(
+            &document,
+            &path,
+            EmitOptions::new().with_trailing_newline(true),
+        )
+        .expect("document should be written");
+        let parsed = parse_file(&path).expect("document should be read back");
+        let entries = fs::read_dir(&directory)
+            .expect("directory should list")
+            .count();
+        fs::remove_dir_all(&directory).expect("scratch directory should be removed");
+
+        assert_eq!(parsed, document);
+        assert_eq!(entries, 1, "temporary files must not be left behind");
+    }
+
+    #[test]
+    fn reads_utf16_files() {
+        let directory = scratch_directory("utf16");
+        let path = directory.join("episode.xml");
+        let xml = "\u{feff}<TEI><teiHeader><fileDesc><title>Wolf 359</title></fileDesc></teiHeader><text><body/></text></TEI>";
+        let bytes: Vec<u8> = xml.encode_utf16().flat_map(u16::to_be_bytes).collect();
+        fs::write(&path, bytes).expect("file should be written");
+
+        let parsed = parse_file(&path).expect("document should be read");
+        fs::remove_dir_all(&directory).expect("scratch directory should be removed");
+
+        assert_eq!(parsed.title().as_str(), "Wolf 359");
+    }
+
+    #[test]
+    fn reports_missing_files_with_their_path() {
+        let directory = scratch_directory("missing");
+        let path = directory.join("absent.xml");
+
+        let error = parse_file(&path).expect_err("missing file must fail");
+        fs::remove_dir_all(&directory).expect("scratch directory should be removed");
+
+        assert!(matches!(
+            &error,
+            TeiError::Io { path: failed, kind: ErrorKind::NotFound, .. } if failed == &path
+        ));
+    }
+
+    #[test]
+    fn failed_writes_leave_no_temporary_file() {
+        let directory = scratch_directory("failed-write");
+        let target = directory.join("occupied");
+        fs::create_dir_all(target.join("child")).expect("directory should be created");
+        let document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+
+        let error = emit_to_path(&document, &target, EmitOptions::new())
+            .expect_err("renaming over a non-empty directory must fail");
+        let entries = fs::read_dir(&directory)
+            .expect("directory should list")
+            .count();
+        fs::remove_dir_all(&directory).expect("scratch directory should be removed");
+
+        assert!(matches!(error, TeiError::Io { .. }), "found {error}");
+        assert_eq!(entries, 1);
+    }
+}
@@ -0,0 +1,179 @@
+//! Parsing and emission of single body elements outside a full document.
+//!
+//! Editor tooling patches one `<u>` or `<p>` at a time, so wrapping each in a
+//! `<TEI>` shell just to reuse [`parse_xml`](crate::parse_xml) is wasteful.
+//! [`parse_fragment`] reads the element on its own, with the same validation
+//! and error locations as a full parse, and [`emit_fragment`] writes the
+//! canonical markup of one block for diff views and API responses.
+
+use serde::de::DeserializeOwned;
+use tei_core::{BodyBlock, P, TeiError, Utterance};
+
+use crate::{EmptyElements, checked, namespace, raw, whitespace};
+
+/// Body elements that can be parsed on their own.
+pub trait Fragment: DeserializeOwned {
+    /// Local name of the element the type is read from.
+    const ELEMENT: &'static str;
+}
+
+impl Fragment for P {
+    const ELEMENT: &'static str = "p";
+}
+
+impl Fragment for Utterance {
+    const ELEMENT: &'static str = "u";
+}
+
+/// Parses a single `<u>` or `<p>` element.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Xml`] when the markup is not well-formed, when the
+/// root is not the element `T` expects, or when its content fails the same
+/// validation [`parse_xml`](crate::parse_xml) applies.
+///
+/// Like [`parse_xml`](crate::parse_xml), text keeps its leading and trailing
+/// whitespace only where `xml:space="preserve"` is in scope.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{P, TeiError, Utterance};
+/// use tei_xml::parse_fragment;
+///
+/// let utterance = parse_fragment::<Utterance>(r#"<u who="eiffel">Day 1.</u>"#)?;
+/// assert_eq!(utterance.speaker().map(|speaker| speaker.as_str()), Some("eiffel"));
+///
+/// assert!(parse_fragment::<P>(r#"<u who="eiffel">Day 1.</u>"#).is_err());
+/// # Ok::<(), TeiError>(())
+/// ```
+pub fn parse_fragment<T: Fragment>(xml: &str) -> Result<T, TeiError> {
+    namespace::ensure_root(xml, T::ELEMENT)?;
+    raw::reject_placeholders(xml)?;
+    whitespace::deserialize(xml, false)
+}
+
+/// Serializes a single body block into canonical markup.
+///
+/// The output is byte-for-byte what [`emit_xml`](crate::emit_xml) writes for
+/// the block inside a document.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Xml`] when the block cannot be serialized, contains
+/// characters XML 1.0 forbids, or holds raw markup that is not well-formed.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{BodyBlock, TeiError, Utterance};
+/// use tei_xml::emit_fragment;
+///
+/// let block = BodyBlock::Utterance(Utterance::from_text_segments(Some("eiffel"), ["Day 1."])?);
+/// assert_eq!(emit_fragment(&block)?, r#"<u who="eiffel">Day 1.</u>"#);
+/// # Ok::<(), TeiError>(())
+/// ```
+pub fn emit_fragment(block: &BodyBlock) -> Result<String, TeiError> {
+    let markup = match block {
+        BodyBlock::Paragraph(paragraph) => {
+            checked::to_string(paragraph, None, EmptyElements::SelfClosing)
+        }
+        BodyBlock::Utterance(utterance) => {
+            checked::to_string(utterance, None, EmptyElements::SelfClosing)
+        }
+        BodyBlock::Raw(stored) => checked::to_string(stored, None, EmptyElements::SelfClosing),
+    }?;
+    raw::restore(markup)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tei_core::SourceLocation;
+
+    use crate::parse_xml;
+
+    #[test]
+    fn matches_blocks_parsed_from_a_document() {
+        let fragment = r#"<p xml:id="p1">Previously on Wolf 359.</p>"#;
+        let document = parse_xml(&format!(
+            "<TEI><teiHeader><fileDesc><title>Wolf 359</title></fileDesc></teiHeader><text><body>{fragment}</body></text></TEI>"
+        ))
+        .expect("document should parse");
+
+        let paragraph = parse_fragment::<P>(fragment).expect("fragment should parse");
+
+        assert_eq!(
+            document.text().body().blocks().collect::<Vec<_>>(),
+            [&BodyBlock::Paragraph(paragraph)]
+        );
+    }
+
+    #[test]
+    fn raw_elements_are_rejected() {
+        let error = parse_fragment::<P>(r#"<p>a <raw markup="&lt;b/&gt;"/></p>"#)
+            .expect_err("raw element should fail");
+
+        assert_eq!(
+            error.location().and_then(SourceLocation::offset),
+            Some("<p>a ".len())
+        );
+    }
+
+    #[test]
+    fn emitted_fragments_parse_back() {
+        let paragraph = P::from_text_segments(["Fish & chips."]).expect("valid paragraph");
+        let markup =
+            emit_fragment(&BodyBlock::Paragraph(paragraph.clone())).expect("block should emit");
+
+        assert_eq!(markup, "<p>Fish &amp; chips.</p>");
+        assert_eq!(
+            parse_fragment::<P>(&markup).expect("fragment should parse"),
+            paragraph
+        );
+    }
+
+    #[test]
+    fn emission_rejects_forbidden_characters() {
+        let block = BodyBlock::Paragraph(
+            P::from_text_segments(["bell\u{7}"]).expect("control characters still build"),
+        );
+
+        let error = emit_fragment(&block).expect_err("control characters must fail");
+
+        assert!(error.to_string().contains("U+0007"), "found {error}");
+    }
+
+    #[test]
+    fn accepts_namespaced_fragments() {
+        let fragment = r#"<tei:u xmlns:tei="http://www.tei-c.org/ns/1.0" who="eiffel">Hi.</tei:u>"#;
+
+        assert!(parse_fragment::<Utterance>(fragment).is_ok());
+    }
+
+    #[test]
+    fn reports_the_wrong_element() {
+        let error = parse_fragment::<Utterance>("<p>Previously.</p>")
+            .expect_err("paragraphs are not utterances");
+
+        assert!(
+            error
+                .to_string()
+                .contains("expected a <u> root element, found <p>"),
+            "found {error}"
+        );
+    }
+
+    #[test]
+    fn locates_invalid_content() {
+        let error = parse_fragment::<Utterance>("<u who=\"  \">Hi.</u>")
+            .expect_err("blank speakers must fail");
+
+        assert_eq!(
+            error.location().and_then(SourceLocation::line),
+            Some(1),
+            "found {error}"
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! Best-effort parsing for bulk ingestion and third-party TEI.
+//!
+//! [`parse_xml`](crate::parse_xml) stops at the first problem, which suits
+//! interactive use but forces ingestion jobs to abort on files that are mostly
+//! sound. [`parse_xml_lenient`] repairs the problems it knows how to work
+//! around before parsing, and reports each repair so the file can be triaged
+//! later:
+//!
+//! - blank `<title>` elements in `<fileDesc>` receive the placeholder title
+//!   `Untitled`;
+//! - elements the data model does not recognise inside `<body>`, `<p>`, `<u>`
+//!   or `<hi>` are skipped together with their content.
+//!
+//! [`import_xml`] goes further for TEI P5 written elsewhere, such as
+//! Transcriber exports, converted word-processor files or hand-authored
+//! documents, mapping it onto the Episodic profile:
+//!
+//! - `<titleStmt>` and `<div>` wrappers are unwrapped, so their titles,
+//!   statements and blocks land where the data model expects them;
+//! - unrecognised inline elements inside `<p>`, `<u>` or `<hi>` are unwrapped,
+//!   keeping their text, except notes and non-verbal events (`<note>`,
+//!   `<vocal>`, `<incident>` and `<kinesic>`), which are skipped;
+//! - a `<publicationStmt>` without a `<publisher>` is skipped;
+//! - any other element the data model does not use is skipped together with
+//!   its content, and attributes it does not read are dropped.
+//!
+//! Anything else, such as markup that is not well-formed, still fails.
+
+use tei_core::{TeiDocument, TeiError};
+
+use crate::{locate, namespace, whitespace};
+
+mod repairer;
+pub(crate) mod vocabulary;
+
+use repairer::{Mode, Repairer};
+
+const PLACEHOLDER_TITLE: &str = "Untitled";
+
+/// A document recovered by [`parse_xml_lenient`] and the problems worked
+/// around to produce it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LenientParse {
+    document: TeiDocument,
+    problems: Vec<TeiError>,
+}
+
+impl LenientParse {
+    /// Returns the recovered document.
+    #[must_use]
+    pub const fn document(&self) -> &TeiDocument {
+        &self.document
+    }
+
+    /// Returns the repaired problems in document order.
+    ///
+    /// Each is a [`TeiError::Xml`] located in the original markup.
+    #[must_use]
+    pub const fn problems(&self) -> &[TeiError] {
+        self.problems.as_slice()
+    }
+
+    /// Reports whether the markup parsed without repairs.
+    #[must_use]
+    pub const fn is_clean(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    /// Consumes the result and returns the document and the problems.
+    #[must_use]
+    pub fn into_parts(self) -> (TeiDocument, Vec<TeiError>) {
+        (self.document, self.problems)
+    }
+}
+
+/// Parses a TEI XML string, repairing recoverable problems instead of failing.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Xml`] when the markup is not well-formed or still fails
+/// to parse once the recoverable problems are repaired. The location of such
+/// errors refers to `xml`, not to the repaired markup.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::TeiError;
+/// use tei_xml::parse_xml_lenient;
+///
+/// let xml = concat!(
+///     "<TEI><teiHeader><fileDesc><title> </title></fileDesc></teiHeader>",
+///     "<text><body><note>Aside.</note><u who=\"eiffel\">Day 1.</u></body></text></TEI>",
+/// );
+/// let parsed = parse_xml_lenient(xml)?;
+///
+/// assert_eq!(parsed.document().title().as_str(), "Untitled");
+/// assert_eq!(parsed.document().text().body().blocks().len(), 1);
+/// assert_eq!(parsed.problems().len(), 2);
+/// # Ok::<(), TeiError>(())
+/// ```
+pub fn parse_xml_lenient(xml: &str) -> Result<LenientParse, TeiError> {
+    recover(xml, Mode::Lenient)
+}
+
+/// Imports TEI P5 from other tools, mapping what the Episodic profile can hold
+/// and reporting everything else it skipped.
+///
+/// The result reports each change, located in `xml`, as
+/// [`parse_xml_lenient`] does.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Xml`] in the same cases as [`parse_xml_lenient`].
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::TeiError;
+/// use tei_xml::import_xml;
+///
+/// let xml = concat!(
+///     "<TEI><teiHeader><fileDesc><titleStmt><title>Wolf 359</title>",
+///     "<author>Gabriel Urbina</author></titleStmt></fileDesc></teiHeader>",
+///     "<text><body><div><u who=\"eiffel\" start=\"#T0\">Day <emph>one</emph>.</u></div>",
+///     "</body></text></TEI>",
+/// );
+/// let imported = import_xml(xml)?;
+///
+/// assert_eq!(imported.document().title().as_str(), "Wolf 359");
+/// assert_eq!(imported.document().text().body().blocks().len(), 1);
+/// assert_eq!(imported.problems().len(), 4);
+/// # Ok::<(), TeiError>(())
+/// ```
+pub fn import_xml(xml: &str) -> Result<LenientParse, TeiError> {
+    recover(xml, Mode::Import)
+}
+
+fn recover(xml: &str, mode: Mode) -> Result<LenientParse, TeiError> {
+    namespace::ensure_root(xml, "TEI")?;
+    let repair = Repairer::new(xml, mode)
+        .run()
+        .unwrap_or_else(|| Repair::unchanged(xml));
+    let document = whitespace::deserialize(&repair.markup, false)
+        .map_err(|error| repair.relocate(xml, error))?;
+
+    Ok(LenientParse {
+        document,
+        problems: repair.problems,
+    })
+}
+
+/// Repaired markup plus what is needed to map its offsets back to the input.
+struct Repair {
+    markup: String,
+    problems: Vec<TeiError>,
+    /// `(repaired, original)` offset pairs recorded wherever the two drift.
+    anchors: Vec<(usize, usize)>,
+}
+
+impl Repair {
+    /// Leaves markup the repair pass cannot read for the parser to report.
+    fn unchanged(xml: &str) -> Self {
+        Self {
+            markup: xml.to_owned(),
+            problems: Vec::new(),
+            anchors: Vec::new(),
+        }
+    }
+
+    fn relocate(&self, xml: &str, error: TeiError) -> TeiError {
+        locate::relocate(&self.anchors, xml, error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tei_core::{SourceLocation, TeiPath};
+
+    fn document(title: &str, body: &str) -> String {
+        format!(
+            "<TEI><teiHeader><fileDesc>{title}</fileDesc></teiHeader><text><body>{body}</body></text></TEI>"
+        )
+    }
+
+    fn paths(parsed: &LenientParse) -> Vec<String> {
+        parsed
+            .problems()
+            .iter()
+            .filter_map(TeiError::location)
+            .filter_map(SourceLocation::path)
+            .map(TeiPath::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn clean_documents_report_no_problems() {
+        let xml = document("<title>Wolf 359</title>", "<u who=\"eiffel\">Day 1.</u>");
+
+        let parsed = parse_xml_lenient(&xml).expect("document should parse");
+
+        assert!(parsed.is_clean());
+        assert_eq!(
+            parsed.into_parts().0,
+            crate::parse_xml(&xml).expect("document should parse")
+        );
+    }
+
+    #[test]
+    fn repairs_every_recoverable_problem() {
+        let xml = document(
+            "<title> </title>",
+            "<note>Aside.</note><p>Day <gap/>1.</p><div><p>Nested.</p></div>",
+        );
+
+        let parsed = parse_xml_lenient(&xml).expect("problems should be repaired");
+
+        assert_eq!(parsed.document().title().as_str(), PLACEHOLDER_TITLE);
+        assert_eq!(parsed.document().text().body().blocks().len(), 1);
+        assert_eq!(
+            paths(&parsed),
+            [
+                "/TEI/teiHeader[1]/fileDesc[1]/title[1]",
+                "/TEI/text[1]/body[1]/note[1]",
+                "/TEI/text[1]/body[1]/p[1]/gap[1]",
+                "/TEI/text[1]/body[1]/div[1]",
+            ]
+        );
+        let second = parsed.problems().get(1).map(ToString::to_string);
+        assert!(
+            second.as_deref().is_some_and(
+                |problem| problem.contains("skipped unexpected element <note> inside <body>")
+            ),
+            "found {second:?}"
+        );
+    }
+
+    #[test]
+    fn locates_remaining_errors_in_the_original_markup() {
+        let xml = document(
+            "<title/>",
+            "<note>A long aside that shifts offsets.</note><u who=\" \">Hi.</u>",
+        );
+
+        let error = parse_xml_lenient(&xml).expect_err("blank speakers cannot be repaired");
+        let offset = error
+            .location()
+            .and_then(SourceLocation::offset)
+            .expect("errors carry an offset");
+
+        assert!(
+            xml.find("<u who").is_some_and(|start| offset >= start),
+            "offset {offset} should point into the utterance in {xml}"
+        );
+    }
+
+    #[test]
+    fn malformed_markup_still_fails() {
+        let xml = document("<title>Wolf 359</title>", "<u>Day 1.</p>");
+
+        assert!(parse_xml_lenient(&xml).is_err());
+    }
+}
@@ -0,0 +1,315 @@
+//! The streaming pass that copies markup and repairs it on the way.
+
+use quick_xml::Writer;
+use quick_xml::events::attributes::Attribute;
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::reader::Reader;
+use tei_core::TeiError;
+
+use super::{PLACEHOLDER_TITLE, Repair, vocabulary};
+use crate::locate::OpenElements;
+
+/// How far the pass goes in fitting markup to the data model.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum Mode {
+    /// Repair blank titles and unexpected content in the body.
+    Lenient,
+    /// Also map third-party TEI P5 onto the Episodic profile.
+    Import,
+}
+
+/// What to do with an element.
+enum Decision {
+    Keep,
+    /// Drop the tags but keep the content in place.
+    Unwrap,
+    /// Drop the element and its content, reporting why.
+    Skip(String),
+}
+
+/// An element copied, or unwrapped, into the repaired markup.
+struct Frame {
+    /// Local name its children are checked against; an unwrapped element
+    /// passes on its parent's.
+    context: Vec<u8>,
+    /// Whether the start tag was written, so the end tag must be too.
+    written: bool,
+}
+
+pub(super) struct Repairer<'a> {
+    xml: &'a str,
+    mode: Mode,
+    reader: Reader<&'a [u8]>,
+    writer: Writer<Vec<u8>>,
+    open: OpenElements,
+    frames: Vec<Frame>,
+    problems: Vec<TeiError>,
+    anchors: Vec<(usize, usize)>,
+}
+
+impl<'a> Repairer<'a> {
+    pub(super) fn new(xml: &'a str, mode: Mode) -> Self {
+        Self {
+            xml,
+            mode,
+            reader: Reader::from_str(xml),
+            writer: Writer::new(Vec::with_capacity(xml.len())),
+            open: OpenElements::new(),
+            frames: Vec::new(),
+            problems: Vec::new(),
+            anchors: Vec::new(),
+        }
+    }
+
+    /// Copies the markup, repairing what it can; `None` when the markup is
+    /// not well-formed.
+    pub(super) fn run(mut self) -> Option<Repair> {
+        loop {
+            let offset = self.reader.buffer_position();
+            match self.reader.read_event().ok()? {
+                Event::Eof => break,
+                Event::Start(start) => self.start(start, offset)?,
+                Event::Empty(start) => self.empty(start, offset)?,
+                Event::End(end) => self.end(end)?,
+                other => self.write(other)?,
+            }
+            self.anchor();
+        }
+
+        Some(Repair {
+            markup: String::from_utf8(self.writer.into_inner()).ok()?,
+            problems: self.problems,
+            anchors: self.anchors,
+        })
+    }
+
+    fn start(&mut self, start: BytesStart<'a>, offset: u64) -> Option<()> {
+        self.open
+            .enter(&String::from_utf8_lossy(start.name().as_ref()));
+
+        match self.decide(&start) {
+            Decision::Keep => {}
+            Decision::Unwrap => {
+                let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+                let context = self.context().to_vec();
+                self.problem(
+                    format!(
+                        "unwrapped <{name}> inside <{}>, keeping its content",
+                        String::from_utf8_lossy(&context)
+                    ),
+                    offset,
+                );
+                self.frames.push(Frame {
+                    context,
+                    written: false,
+                });
+                return Some(());
+            }
+            Decision::Skip(message) => {
+                self.problem(message, offset);
+                self.reader.read_to_end(start.name()).ok()?;
+                self.open.leave();
+                return Some(());
+            }
+        }
+
+        let kept = self.read_attributes(start, offset);
+        if self.is_file_title(&kept) {
+            let text = self.reader.read_text(kept.name()).ok()?;
+            let written = if text.trim().is_empty() {
+                self.placeholder(&kept, offset)
+            } else {
+                self.write(Event::Start(kept.borrow()))
+                    .and_then(|()| self.write(Event::Text(BytesText::from_escaped(text))))
+                    .and_then(|()| self.write(Event::End(kept.to_end())))
+            };
+            self.open.leave();
+            return written;
+        }
+
+        self.frames.push(Frame {
+            context: kept.local_name().as_ref().to_vec(),
+            written: true,
+        });
+        self.write(Event::Start(kept))
+    }
+
+    fn end(&mut self, end: BytesEnd<'a>) -> Option<()> {
+        self.open.leave();
+        match self.frames.pop() {
+            Some(Frame { written: false, .. }) => Some(()),
+            _ => self.write(Event::End(end)),
+        }
+    }
+
+    fn empty(&mut self, start: BytesStart<'a>, offset: u64) -> Option<()> {
+        self.open
+            .enter(&String::from_utf8_lossy(start.name().as_ref()));
+        let written = match self.decide(&start) {
+            Decision::Keep => {
+                let kept = self.read_attributes(start, offset);
+                if self.is_file_title(&kept) {
+                    self.placeholder(&kept, offset)
+                } else {
+                    self.write(Event::Empty(kept))
+                }
+            }
+            // An empty wrapper has no content to keep.
+            Decision::Unwrap => {
+                self.problem(self.unexpected(&start), offset);
+                Some(())
+            }
+            Decision::Skip(message) => {
+                self.problem(message, offset);
+                Some(())
+            }
+        };
+        self.open.leave();
+        written
+    }
+
+    fn decide(&self, start: &BytesStart<'_>) -> Decision {
+        let Some(frame) = self.frames.last() else {
+            return Decision::Keep;
+        };
+        let local = start.local_name();
+        let import = self.mode == Mode::Import;
+
+        if let Some(allowed) = vocabulary::children(&frame.context) {
+            return if allowed.contains(&local.as_ref()) {
+                Decision::Keep
+            } else if import && vocabulary::unwraps_in(&frame.context, local.as_ref()) {
+                Decision::Unwrap
+            } else {
+                Decision::Skip(self.unexpected(start))
+            };
+        }
+        if !import {
+            return Decision::Keep;
+        }
+
+        match local.as_ref() {
+            b"titleStmt" if frame.context == b"fileDesc" => Decision::Unwrap,
+            b"publicationStmt" if !self.has_child(b"publisher") => {
+                Decision::Skip("skipped <publicationStmt> without a <publisher>".to_owned())
+            }
+            name if vocabulary::is_known(name) => Decision::Keep,
+            _ => Decision::Skip(self.unexpected(start)),
+        }
+    }
+
+    fn context(&self) -> &[u8] {
+        self.frames
+            .last()
+            .map_or(&[], |frame| frame.context.as_slice())
+    }
+
+    fn unexpected(&self, start: &BytesStart<'_>) -> String {
+        format!(
+            "skipped unexpected element <{}> inside <{}>",
+            String::from_utf8_lossy(start.name().as_ref()),
+            String::from_utf8_lossy(self.context())
+        )
+    }
+
+    /// Reports whether the element just opened has a child called `name`,
+    /// reading ahead without consuming anything.
+    fn has_child(&self, name: &[u8]) -> bool {
+        let mut lookahead = self.reader.clone();
+        let mut depth = 0_usize;
+        loop {
+            match lookahead.read_event() {
+                Ok(Event::Start(child) | Event::Empty(child))
+                    if depth == 0 && child.local_name().as_ref() == name =>
+                {
+                    return true;
+                }
+                Ok(Event::Start(_)) => depth += 1,
+                Ok(Event::End(_)) if depth == 0 => return false,
+                Ok(Event::End(_)) => depth -= 1,
+                Ok(Event::Eof) | Err(_) => return false,
+                Ok(_) => {}
+            }
+        }
+    }
+
+    /// Drops the attributes the data model does not read, in import mode.
+    ///
+    /// Malformed attributes are left for the parser to report.
+    fn read_attributes(&mut self, start: BytesStart<'a>, offset: u64) -> BytesStart<'a> {
+        if self.mode == Mode::Lenient {
+            return start;
+        }
+        let Ok(attributes) = start
+            .attributes()
+            .collect::<Result<Vec<Attribute<'_>>, _>>()
+        else {
+            return start;
+        };
+        let (kept, dropped): (Vec<_>, Vec<_>) = attributes.into_iter().partition(|attribute| {
+            vocabulary::reads_attribute(
+                start.local_name().as_ref(),
+                &String::from_utf8_lossy(attribute.key.as_ref()),
+            )
+        });
+        if dropped.is_empty() {
+            return start;
+        }
+
+        let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+        for attribute in dropped {
+            let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+            let mut located = self.open.location(offset).with_line_column_in(self.xml);
+            if let Some(path) = self.open.path() {
+                located = located.with_path(path.attribute(&key));
+            }
+            self.problems.push(TeiError::xml_at(
+                format!(
+                    "dropped attribute `{key}` on <{name}>, which the data model does not read"
+                ),
+                located,
+            ));
+        }
+        let mut repaired = start.to_owned();
+        repaired.clear_attributes();
+        repaired.extend_attributes(kept);
+        repaired
+    }
+
+    fn is_file_title(&self, start: &BytesStart<'_>) -> bool {
+        start.local_name().as_ref() == b"title" && self.context() == b"fileDesc"
+    }
+
+    fn placeholder(&mut self, start: &BytesStart<'_>, offset: u64) -> Option<()> {
+        self.problem(
+            format!("replaced blank <title> with the placeholder `{PLACEHOLDER_TITLE}`"),
+            offset,
+        );
+        self.write(Event::Start(start.borrow()))?;
+        self.write(Event::Text(BytesText::new(PLACEHOLDER_TITLE)))?;
+        self.write(Event::End(start.to_end()))
+    }
+
+    /// Records a problem located at the element most recently entered.
+    fn problem(&mut self, message: String, offset: u64) {
+        let located = self.open.location(offset).with_line_column_in(self.xml);
+        self.problems.push(TeiError::xml_at(message, located));
+    }
+
+    fn write(&mut self, event: Event<'_>) -> Option<()> {
+        self.writer.write_event(event).ok()
+    }
+
+    /// Records an anchor whenever the repaired and original offsets drift
+    /// apart.
+    fn anchor(&mut self) {
+        let repaired = self.writer.get_ref().len();
+        let Ok(original) = usize::try_from(self.reader.buffer_position()) else {
+            return;
+        };
+        let (last_repaired, last_original) = self.anchors.last().copied().unwrap_or((0, 0));
+        if repaired + last_original != original + last_repaired {
+            self.anchors.push((repaired, original));
+        }
+    }
+}
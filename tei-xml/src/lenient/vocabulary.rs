@@ -0,0 +1,159 @@
+//! The elements and attributes the Episodic profile reads.
+//!
+//! The deserialiser ignores most names it does not know, so these tables let
+//! the import pass report them instead of dropping them silently. They mirror
+//! the serde names in `tei-core` and must grow with the data model.
+
+/// Elements the data model reads somewhere in a document, in byte order.
+const ELEMENTS: [&[u8]; 71] = [
+    b"TEI",
+    b"abstract",
+    b"annotationSystem",
+    b"appInfo",
+    b"application",
+    b"availability",
+    b"biblScope",
+    b"body",
+    b"catDesc",
+    b"catRef",
+    b"category",
+    b"change",
+    b"citeStructure",
+    b"classDecl",
+    b"creation",
+    b"date",
+    b"desc",
+    b"edition",
+    b"editionStmt",
+    b"editorialDecl",
+    b"encodingDesc",
+    b"extent",
+    b"fileDesc",
+    b"funder",
+    b"hi",
+    b"hyphenation",
+    b"idno",
+    b"interpretation",
+    b"keywords",
+    b"lang",
+    b"licence",
+    b"measure",
+    b"media",
+    b"name",
+    b"normalization",
+    b"note",
+    b"notesStmt",
+    b"p",
+    b"pause",
+    b"placeName",
+    b"principal",
+    b"profileDesc",
+    b"projectDesc",
+    b"publicationStmt",
+    b"publisher",
+    b"quotation",
+    b"recording",
+    b"recordingStmt",
+    b"refsDecl",
+    b"rend",
+    b"rendition",
+    b"resp",
+    b"respStmt",
+    b"revisionDesc",
+    b"samplingDecl",
+    b"seriesStmt",
+    b"sourceDesc",
+    b"speaker",
+    b"sponsor",
+    b"synopsis",
+    b"tagsDecl",
+    b"taxonomy",
+    b"teiHeader",
+    b"term",
+    b"text",
+    b"textClass",
+    b"timeline",
+    b"title",
+    b"u",
+    b"when",
+    b"xenoData",
+];
+
+/// Attributes the header elements read.
+const HEADER_ATTRIBUTES: [&str; 22] = [
+    "delim", "dur", "eol", "id", "ident", "marks", "match", "method", "mimeType", "quantity",
+    "role", "scheme", "selector", "target", "type", "unit", "url", "use", "version", "when",
+    "xml:id", "xml:lang",
+];
+
+/// Returns the children the data model accepts inside `parent`, for the
+/// parents whose content it checks strictly.
+pub(crate) const fn children(parent: &[u8]) -> Option<&'static [&'static [u8]]> {
+    match parent {
+        b"body" => Some(&[b"p", b"u"]),
+        b"p" | b"u" | b"hi" => Some(&[b"hi", b"pause"]),
+        _ => None,
+    }
+}
+
+/// Reports whether the data model reads `element` anywhere.
+pub(super) fn is_known(element: &[u8]) -> bool {
+    ELEMENTS.binary_search(&element).is_ok()
+}
+
+/// Reports whether an unexpected `child` of `parent` is a wrapper whose
+/// content can be kept in its place.
+pub(super) fn unwraps_in(parent: &[u8], child: &[u8]) -> bool {
+    match parent {
+        b"body" => {
+            child == b"div"
+                || child
+                    .strip_prefix(b"div")
+                    .is_some_and(|level| matches!(level, [b'1'..=b'7']))
+        }
+        // Notes and non-verbal events describe the speech rather than
+        // transcribe it.
+        b"p" | b"u" | b"hi" => !matches!(child, b"note" | b"vocal" | b"incident" | b"kinesic"),
+        _ => false,
+    }
+}
+
+/// Reports whether the data model reads the attribute `name` on `element`.
+pub(super) fn reads_attribute(element: &[u8], name: &str) -> bool {
+    if name == "xmlns" || name.starts_with("xmlns:") || name == "xml:space" {
+        return true;
+    }
+    match element {
+        b"TEI" | b"teiHeader" | b"text" | b"body" => false,
+        b"p" => matches!(name, "xml:id" | "xml:lang"),
+        b"u" => matches!(name, "xml:id" | "xml:lang" | "who" | "start" | "end"),
+        b"timeline" => matches!(name, "unit" | "origin"),
+        b"when" => matches!(name, "xml:id" | "id" | "interval" | "since"),
+        b"hi" => matches!(name, "xml:lang" | "lang" | "space"),
+        b"pause" => matches!(name, "dur" | "type"),
+        _ => HEADER_ATTRIBUTES.contains(&name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn element_table_stays_sorted_and_unique() {
+        assert!(ELEMENTS.windows(2).all(|pair| pair.first() < pair.last()));
+    }
+
+    #[rstest]
+    #[case(b"body", b"div1", true)]
+    #[case(b"body", b"div8", false)]
+    #[case(b"body", b"head", false)]
+    #[case(b"u", b"emph", true)]
+    #[case(b"u", b"note", false)]
+    #[case(b"u", b"vocal", false)]
+    #[case(b"fileDesc", b"titleStmt", false)]
+    fn recognises_wrappers(#[case] parent: &[u8], #[case] child: &[u8], #[case] expected: bool) {
+        assert_eq!(unwraps_in(parent, child), expected);
+    }
+}
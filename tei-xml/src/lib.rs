@@ -1,49 +1,50 @@
-//! XML helpers for TEI-Rapporteur.
+//! XML parsing and emission for TEI-Rapporteur.
 //!
-//! The module currently focuses on a title serialization shim that exercises the
-//! crate graph created during workspace scaffolding.
+//! [`parse_xml`] and [`emit_xml`] convert between TEI P5 XML and the
+//! `tei-core` data model, with [`ParseOptions`] and [`EmitOptions`] for
+//! resource limits and output layout. The crate also parses files and
+//! fragments, compares documents with [`diff`], streams utterances through
+//! [`reader`] and [`writer`], and imports podcast RSS and `EXMARaLDA`
+//! transcriptions. Text and attribute escaping is shared with `tei-core`
+//! and re-exported here as [`escape_xml_text`] and [`escape_xml_attr`].
+
+mod canonical;
+mod cdata;
+mod checked;
+mod diff;
+mod encoding;
+mod entities;
+pub mod exmaralda;
+mod file;
+mod fragment;
+mod lenient;
+mod limits;
+mod locate;
+mod namespace;
+mod options;
+mod parse_options;
+mod pretty;
+mod raw;
+pub mod reader;
+pub mod rss;
+mod whitespace;
+pub mod writer;
+
+pub use diff::{Difference, diff};
+pub use encoding::parse_xml_bytes;
+pub use entities::EntityPolicy;
+pub use file::{emit_to_path, parse_file};
+pub use fragment::{Fragment, emit_fragment, parse_fragment};
+pub use lenient::{LenientParse, import_xml, parse_xml_lenient};
+pub use namespace::TEI_NAMESPACE;
+pub use options::{AttributeOrder, EmitOptions, EmptyElements, emit_xml_with_options};
+pub use parse_options::{ParseOptions, parse_xml_with_options};
+pub use pretty::{PrettyOptions, emit_xml_pretty};
+pub use raw::UnknownElements;
+pub use tei_core::escape::{attribute as escape_xml_attr, text as escape_xml_text};
 
-use quick_xml::{de, se};
 use tei_core::{TeiDocument, TeiError};
 
-/// Encodes text for inclusion in XML content.
-///
-/// The helper escapes markup-significant characters to keep the resulting
-/// document well-formed. It intentionally mirrors the narrow surface required
-/// for text nodes, not attributes.
-///
-/// # Examples
-///
-/// ```
-/// use tei_xml::escape_xml_text;
-///
-/// assert_eq!(escape_xml_text("R&D <Test>"), "R&amp;D &lt;Test&gt;");
-/// ```
-#[must_use]
-pub fn escape_xml_text(input: &str) -> String {
-    if !input
-        .chars()
-        .any(|character| matches!(character, '&' | '<' | '>' | '"' | '\''))
-    {
-        return input.to_owned();
-    }
-
-    let mut escaped = String::with_capacity(input.len());
-
-    for character in input.chars() {
-        match character {
-            '&' => escaped.push_str("&amp;"),
-            '<' => escaped.push_str("&lt;"),
-            '>' => escaped.push_str("&gt;"),
-            '"' => escaped.push_str("&quot;"),
-            '\'' => escaped.push_str("&apos;"),
-            other => escaped.push(other),
-        }
-    }
-
-    escaped
-}
-
 /// Serializes the document title into a minimal TEI snippet.
 ///
 /// # Examples
@@ -97,7 +98,23 @@ pub fn serialize_document_title(raw_title: &str) -> Result<String, TeiError> {
 /// # Errors
 ///
 /// Returns [`TeiError::Xml`] when the XML is not well-formed or does not match
-/// the profiled TEI structure expected by the data model.
+/// the profiled TEI structure expected by the data model. Every such error
+/// carries a [`SourceLocation`](tei_core::SourceLocation) with the byte
+/// offset, line and column, and the path of the open element: the failing
+/// token for syntax errors, or where the deserialiser stopped for structural
+/// ones. The `quick-xml` error is kept as the
+/// [`source`](std::error::Error::source). Documents may declare the
+/// [`TEI_NAMESPACE`] as their default namespace or through a prefix; a root in
+/// any other namespace is rejected.
+///
+/// Returns [`TeiError::LimitExceeded`] when elements nest deeper than
+/// [`ParseOptions::DEFAULT_MAX_DEPTH`] or the input holds more than
+/// [`ParseOptions::DEFAULT_MAX_ENTITY_EXPANSIONS`] references; use
+/// [`parse_xml_with_options`] to change either limit or to bound the size.
+///
+/// Leading and trailing whitespace in text segments is trimmed except where
+/// `xml:space="preserve"` is in scope; use [`parse_xml_with_options`] to keep
+/// it everywhere.
 ///
 /// # Examples
 ///
@@ -122,21 +139,25 @@ pub fn serialize_document_title(raw_title: &str) -> Result<String, TeiError> {
 /// # Ok::<(), TeiError>(())
 /// ```
 pub fn parse_xml(xml: &str) -> Result<TeiDocument, TeiError> {
-    de::from_str(xml).map_err(|error| TeiError::xml(error.to_string()))
+    parse_xml_with_options(xml, ParseOptions::new())
 }
 
 /// Serializes a [`TeiDocument`] into TEI XML markup.
 ///
 /// This helper keeps XML-specific logic scoped to the `tei-xml` crate while
 /// surfacing any serializer failures through [`TeiError::Xml`]. It produces a
-/// canonicalized string using `quick_xml::se::to_string`, ensuring downstream
+/// canonicalized string using the `quick-xml` serializer, ensuring downstream
 /// consumers receive stable output regardless of how the document was
-/// constructed.
+/// constructed. The output carries no XML declaration; use
+/// [`emit_xml_with_options`] to add one.
 ///
 /// # Errors
 ///
 /// Returns [`TeiError::Xml`] when the document contains data that cannot be
 /// represented as XML (for example, control characters that XML 1.0 forbids).
+/// Forbidden characters are caught as each text node or attribute is
+/// written, and the error's [`location`](TeiError::location) carries the
+/// path of the element or attribute holding the first one.
 ///
 /// # Examples
 ///
@@ -150,58 +171,11 @@ pub fn parse_xml(xml: &str) -> Result<TeiDocument, TeiError> {
 /// # Ok::<(), tei_core::TeiError>(())
 /// ```
 pub fn emit_xml(document: &TeiDocument) -> Result<String, TeiError> {
-    let xml = se::to_string(document).map_err(|error| TeiError::xml(error.to_string()))?;
-
-    if let Some(character) = first_forbidden_xml_char(xml.as_str()) {
-        let codepoint = u32::from(character);
-        return Err(TeiError::xml(format!(
-            "document contains XML 1.0 forbidden character U+{codepoint:04X}"
-        )));
-    }
-
-    Ok(xml)
-}
-
-fn first_forbidden_xml_char(value: &str) -> Option<char> {
-    value
-        .chars()
-        .find(|character| is_forbidden_xml_char(*character))
-}
-
-fn is_forbidden_xml_char(character: char) -> bool {
-    let codepoint = u32::from(character);
-    is_surrogate(codepoint)
-        || is_forbidden_control_char(codepoint)
-        || is_noncharacter(codepoint)
-        || !is_in_xml_allowed_range(codepoint)
-}
-
-fn is_surrogate(codepoint: u32) -> bool {
-    (0xD800..=0xDFFF).contains(&codepoint)
+    emit_xml_with_options(document, EmitOptions::new())
 }
 
-const fn is_forbidden_control_char(codepoint: u32) -> bool {
-    codepoint < 0x20 && !is_allowed_control_char(codepoint)
-}
-
-const fn is_allowed_control_char(codepoint: u32) -> bool {
-    matches!(codepoint, 0x9 | 0xA | 0xD)
-}
-
-fn is_noncharacter(codepoint: u32) -> bool {
-    // Noncharacters (FFFE/FFFF, FDD0-FDEF, and last two of each plane)
-    codepoint == 0xFFFE
-        || codepoint == 0xFFFF
-        || (0xFDD0..=0xFDEF).contains(&codepoint)
-        || (codepoint >= 0x1_0000 && codepoint & 0xFFFE == 0xFFFE)
-}
-
-fn is_in_xml_allowed_range(codepoint: u32) -> bool {
-    // XML 1.0 permits: #x9 | #xA | #xD | [#x20-#xD7FF] | [#xE000-#xFFFD] | [#x10000-#x10FFFF]
-    matches!(codepoint, 0x9 | 0xA | 0xD)
-        || (0x20..=0xD7FF).contains(&codepoint)
-        || (0xE000..=0xFFFD).contains(&codepoint)
-        || (0x1_0000..=0x10_FFFF).contains(&codepoint)
+fn emit_markup(document: &TeiDocument, empty: EmptyElements) -> Result<String, TeiError> {
+    checked::to_string(document, None, empty)
 }
 
 #[cfg(test)]
@@ -295,12 +269,6 @@ mod tests {
         assert_eq!(xml, MINIMAL_TEI);
     }
 
-    #[test]
-    fn detects_forbidden_characters() {
-        assert!(first_forbidden_xml_char("Valid").is_none());
-        assert_eq!(first_forbidden_xml_char("\u{0}broken"), Some('\u{0}'));
-    }
-
     #[test]
     fn surfaces_quick_xml_errors() {
         let Err(error) = parse_xml(MISSING_HEADER_TEI) else {
@@ -308,7 +276,7 @@ mod tests {
         };
 
         match error {
-            TeiError::Xml { message } => assert!(
+            TeiError::Xml { message, .. } => assert!(
                 message.contains("teiHeader"),
                 "missing header error should mention field, found {message}"
             ),
@@ -323,7 +291,7 @@ mod tests {
         };
 
         match error {
-            TeiError::Xml { message } => assert!(
+            TeiError::Xml { message, .. } => assert!(
                 message.contains("document title may not be empty"),
                 "error should mention empty title, found {message}"
             ),
@@ -341,7 +309,7 @@ mod tests {
         };
 
         match error {
-            TeiError::Xml { message } => assert!(
+            TeiError::Xml { message, .. } => assert!(
                 message.contains("U+0000"),
                 "expected message to mention control character, found {message}"
             ),
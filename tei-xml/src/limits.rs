@@ -0,0 +1,183 @@
+//! Resource limits enforced before untrusted markup is deserialised.
+//!
+//! The size limit is checked before anything is read. Nesting depth and
+//! entity references are counted in a single pass of the streaming reader,
+//! which allocates nothing per element, so pathological input is rejected
+//! before the deserialiser builds any of it. `quick-xml` never expands
+//! entities declared in a DTD, so each reference expands to at most one
+//! character and counting references bounds the expansion.
+
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+use tei_core::{ResourceLimit, TeiError};
+
+use crate::ParseOptions;
+use crate::locate::locate_offset;
+
+/// Checks `xml` against the limits in `options`.
+///
+/// Markup that is not well-formed passes unchecked up to the syntax error,
+/// which the parser then reports with its own location.
+pub(crate) fn check(xml: &str, options: ParseOptions) -> Result<(), TeiError> {
+    if let Some(maximum) = options
+        .max_document_size()
+        .filter(|maximum| xml.len() > *maximum)
+    {
+        return Err(TeiError::limit_exceeded(
+            ResourceLimit::DocumentSize,
+            maximum,
+            None,
+        ));
+    }
+    if options.max_depth().is_none() && options.max_entity_expansions().is_none() {
+        return Ok(());
+    }
+
+    let mut counts = Counts {
+        options,
+        depth: 0,
+        references: 0,
+    };
+    let mut reader = Reader::from_str(xml);
+    loop {
+        let start = reader.buffer_position();
+        let Ok(event) = reader.read_event() else {
+            return Ok(());
+        };
+        let exceeded = match event {
+            Event::Start(element) => counts.open(&element, true),
+            Event::Empty(element) => counts.open(&element, false),
+            Event::Text(text) => counts.refer(&text),
+            Event::End(_) => {
+                counts.depth = counts.depth.saturating_sub(1);
+                None
+            }
+            Event::Eof => return Ok(()),
+            _ => None,
+        };
+        if let Some((limit, maximum)) = exceeded {
+            // Locating from the end of the event puts an offending open element
+            // in the path; the offset still points at where the event starts.
+            let location = locate_offset(xml, reader.buffer_position())
+                .with_offset(usize::try_from(start).unwrap_or(usize::MAX))
+                .with_line_column_in(xml);
+            return Err(TeiError::limit_exceeded(limit, maximum, Some(location)));
+        }
+    }
+}
+
+/// Running totals compared against the configured limits.
+struct Counts {
+    options: ParseOptions,
+    depth: usize,
+    references: usize,
+}
+
+impl Counts {
+    /// Records an element, which stays open unless it was self-closing.
+    fn open(&mut self, markup: &[u8], stays_open: bool) -> Option<(ResourceLimit, usize)> {
+        let depth = self.depth + 1;
+        if stays_open {
+            self.depth = depth;
+        }
+        self.options
+            .max_depth()
+            .filter(|maximum| depth > *maximum)
+            .map(|maximum| (ResourceLimit::Depth, maximum))
+            .or_else(|| self.refer(markup))
+    }
+
+    /// Counts the references in raw text or attribute markup.
+    fn refer(&mut self, markup: &[u8]) -> Option<(ResourceLimit, usize)> {
+        self.references += markup.split(|byte| *byte == b'&').count() - 1;
+        self.options
+            .max_entity_expansions()
+            .filter(|maximum| self.references > *maximum)
+            .map(|maximum| (ResourceLimit::EntityExpansions, maximum))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use tei_core::SourceLocation;
+
+    fn document(body: &str) -> String {
+        format!(
+            "<TEI><teiHeader><fileDesc><title>Wolf 359</title></fileDesc></teiHeader><text><body>{body}</body></text></TEI>"
+        )
+    }
+
+    fn limited(xml: &str, options: ParseOptions) -> (ResourceLimit, usize, String) {
+        match check(xml, options).expect_err("limit must trip") {
+            TeiError::LimitExceeded {
+                limit,
+                maximum,
+                location,
+            } => (
+                limit,
+                maximum,
+                location
+                    .as_ref()
+                    .and_then(SourceLocation::path)
+                    .map(ToString::to_string)
+                    .unwrap_or_default(),
+            ),
+            other => panic!("expected a limit error, found {other}"),
+        }
+    }
+
+    #[test]
+    fn accepts_documents_within_limits() {
+        let options = ParseOptions::new()
+            .with_max_depth(Some(6))
+            .with_max_entity_expansions(Some(2))
+            .with_max_document_size(Some(200));
+
+        assert!(check(&document("<p>R&amp;D <hi>&lt;</hi></p>"), options).is_ok());
+    }
+
+    #[rstest]
+    #[case(
+        ParseOptions::new().with_max_depth(Some(4)),
+        "<p>Day <hi>1</hi></p>",
+        (ResourceLimit::Depth, 4, "/TEI/text[1]/body[1]/p[1]/hi[1]")
+    )]
+    #[case(
+        ParseOptions::new().with_max_depth(Some(5)),
+        "<p><hi>Day <pause/></hi></p>",
+        (ResourceLimit::Depth, 5, "/TEI/text[1]/body[1]/p[1]/hi[1]")
+    )]
+    #[case(
+        ParseOptions::new().with_max_entity_expansions(Some(2)),
+        "<u who=\"a&amp;b\">&lt;&gt;</u>",
+        (ResourceLimit::EntityExpansions, 2, "/TEI/text[1]/body[1]/u[1]")
+    )]
+    fn reports_where_limits_trip(
+        #[case] options: ParseOptions,
+        #[case] body: &str,
+        #[case] expected: (ResourceLimit, usize, &str),
+    ) {
+        let (limit, maximum, path) = limited(&document(body), options);
+
+        assert_eq!((limit, maximum, path.as_str()), expected);
+    }
+
+    #[test]
+    fn rejects_oversized_input_before_reading_it() {
+        let options = ParseOptions::new().with_max_document_size(Some(8));
+
+        assert_eq!(
+            limited("<TEI><unclosed", options),
+            (ResourceLimit::DocumentSize, 8, String::new())
+        );
+    }
+
+    #[test]
+    fn leaves_syntax_errors_to_the_parser() {
+        let options = ParseOptions::new().with_max_depth(Some(1));
+
+        assert!(check("<TEI></p>", options).is_ok());
+    }
+}
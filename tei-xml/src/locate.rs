@@ -0,0 +1,235 @@
+//! Source locations for XML that fails to parse.
+//!
+//! The serde layer in `quick-xml` reports what went wrong but not where. When
+//! parsing fails, the input is re-read with the streaming reader, which tracks
+//! byte offsets, so syntax errors can be pinned to an offset and to the path
+//! of the element that was open at the time. Structural errors are pinned to
+//! the offset where the deserialiser stopped reading instead.
+
+use std::collections::HashMap;
+
+use quick_xml::de::Deserializer;
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+use serde::de::DeserializeOwned;
+use tei_core::{SourceLocation, TeiError, TeiPath};
+
+/// Deserialises `xml`, attaching a location and the `quick-xml` source to
+/// failures.
+pub(crate) fn deserialize<T: DeserializeOwned>(xml: &str) -> Result<T, TeiError> {
+    let mut deserializer = Deserializer::from_str(xml);
+    T::deserialize(&mut deserializer).map_err(|error| {
+        let stopped_at = deserializer.get_ref().get_ref().buffer_position();
+        let location = locate_syntax_error(xml)
+            .unwrap_or_else(|| locate_offset(xml, stopped_at))
+            .with_line_column_in(xml);
+        TeiError::xml_at(error.to_string(), location).with_source(error)
+    })
+}
+
+/// Returns the location of the first syntax error in `xml`, if any.
+///
+/// Well-formed input yields `None`: its failures are structural, and the
+/// deserialiser does not expose where it was when it gave up.
+pub(crate) fn locate_syntax_error(xml: &str) -> Option<SourceLocation> {
+    let mut reader = Reader::from_str(xml);
+    let mut open = OpenElements::new();
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(start)) => open.enter(&String::from_utf8_lossy(start.name().as_ref())),
+            Ok(Event::Empty(empty)) => {
+                open.enter(&String::from_utf8_lossy(empty.name().as_ref()));
+                open.leave();
+            }
+            Ok(Event::End(_)) => open.leave(),
+            Ok(Event::Eof) => return None,
+            Ok(_) => {}
+            Err(_) => return Some(open.location(reader.error_position())),
+        }
+    }
+}
+
+/// Returns the location of `offset` in `xml`, with the path of the element
+/// open there.
+pub(crate) fn locate_offset(xml: &str, offset: u64) -> SourceLocation {
+    let mut reader = Reader::from_str(xml);
+    let mut open = OpenElements::new();
+
+    while reader.buffer_position() < offset {
+        match reader.read_event() {
+            Ok(Event::Start(start)) => open.enter(&String::from_utf8_lossy(start.name().as_ref())),
+            Ok(Event::Empty(empty)) => {
+                open.enter(&String::from_utf8_lossy(empty.name().as_ref()));
+                open.leave();
+            }
+            Ok(Event::End(_)) => open.leave(),
+            Ok(Event::Eof) | Err(_) => break,
+            Ok(_) => {}
+        }
+    }
+    open.location(offset)
+}
+
+/// Points an error raised on rewritten markup back at `original`.
+///
+/// `anchors` pairs rewritten and original offsets, recorded wherever the two
+/// drift apart.
+pub(crate) fn relocate(
+    anchors: &[(usize, usize)],
+    original: &str,
+    mut error: TeiError,
+) -> TeiError {
+    let TeiError::Xml {
+        location: Some(location),
+        ..
+    } = &mut error
+    else {
+        return error;
+    };
+    let Some(offset) = location.offset().filter(|_| !anchors.is_empty()) else {
+        return error;
+    };
+
+    let (rewritten, from) = anchors
+        .iter()
+        .rev()
+        .find(|(rewritten, _)| *rewritten <= offset)
+        .copied()
+        .unwrap_or((0, 0));
+    let mapped = u64::try_from(from + (offset - rewritten)).unwrap_or(u64::MAX);
+    *location = locate_offset(original, mapped).with_line_column_in(original);
+    error
+}
+
+/// Stack of open elements with per-level sibling counts.
+pub(crate) struct OpenElements {
+    base: Option<TeiPath>,
+    steps: Vec<(String, usize)>,
+    sibling_counts: Vec<HashMap<String, usize>>,
+}
+
+impl OpenElements {
+    pub(crate) fn new() -> Self {
+        Self {
+            base: None,
+            steps: Vec::new(),
+            sibling_counts: vec![HashMap::new()],
+        }
+    }
+
+    /// Tracks markup nested inside `base`, so the outermost element gets a
+    /// position like any other child.
+    pub(crate) fn within(base: TeiPath) -> Self {
+        Self {
+            base: Some(base),
+            ..Self::new()
+        }
+    }
+
+    pub(crate) fn enter(&mut self, name: &str) {
+        let position = self.sibling_counts.last_mut().map_or(1, |counts| {
+            let count = counts.entry(name.to_owned()).or_default();
+            *count += 1;
+            *count
+        });
+        self.steps.push((name.to_owned(), position));
+        self.sibling_counts.push(HashMap::new());
+    }
+
+    pub(crate) fn leave(&mut self) {
+        self.steps.pop();
+        if self.sibling_counts.len() > 1 {
+            self.sibling_counts.pop();
+        }
+    }
+
+    /// Returns the path of the innermost open element, if any is open.
+    pub(crate) fn path(&self) -> Option<TeiPath> {
+        let mut steps = self.steps.iter();
+        let start = match &self.base {
+            Some(base) if !self.steps.is_empty() => base.clone(),
+            _ => steps.next().map(|(root, _)| TeiPath::new().child(root))?,
+        };
+        Some(steps.fold(start, |path, (name, position)| {
+            path.nth_child(name, *position)
+        }))
+    }
+
+    pub(crate) fn location(&self, offset: u64) -> SourceLocation {
+        let path = self.path();
+        let location = usize::try_from(offset).map_or_else(
+            |_| SourceLocation::new(),
+            |value| SourceLocation::new().with_offset(value),
+        );
+
+        match path {
+            Some(open_path) => location.with_path(open_path),
+            None => location,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn well_formed_input_has_no_syntax_error() {
+        assert!(locate_syntax_error("<TEI><teiHeader/></TEI>").is_none());
+    }
+
+    #[test]
+    fn reports_offset_and_open_path_for_mismatched_tags() {
+        let xml = "<TEI><text><body><u>Hi</u><u>Bye</p></body></text></TEI>";
+
+        let location =
+            locate_syntax_error(xml).unwrap_or_else(|| panic!("mismatched tags should fail"));
+
+        assert_eq!(
+            location.path().map(ToString::to_string).as_deref(),
+            Some("/TEI/text[1]/body[1]/u[2]")
+        );
+        assert_eq!(location.offset(), xml.find("</p>"));
+    }
+
+    #[test]
+    fn parse_errors_carry_the_location() {
+        let xml = "<TEI><teiHeader><fileDesc><title>Wolf 359</fileDesc></teiHeader></TEI>";
+
+        let error = crate::parse_xml(xml).expect_err("mismatched tags should not parse");
+
+        assert_eq!(
+            error.location().and_then(SourceLocation::offset),
+            xml.find("</fileDesc>")
+        );
+        assert!(
+            error.to_string().ends_with(
+                "(line 1, column 42, byte 41 in /TEI/teiHeader[1]/fileDesc[1]/title[1])"
+            ),
+            "error should name the open element, found {error}"
+        );
+    }
+
+    #[test]
+    fn structural_errors_carry_line_column_and_source() {
+        let xml = concat!(
+            "<TEI>\n",
+            "  <teiHeader><fileDesc><title>Wolf 359</title></fileDesc></teiHeader>\n",
+            "  <text><body>\n",
+            "    <u who=\"eiffel\">Day 1.</u>\n",
+            "    <note>Unsupported.</note>\n",
+            "  </body></text>\n",
+            "</TEI>",
+        );
+
+        let error = crate::parse_xml(xml).expect_err("unknown blocks should not parse");
+        let location = error.location().expect("structural errors are located");
+        let source = std::error::Error::source(&error)
+            .and_then(|cause| cause.downcast_ref::<tei_core::ErrorSource>())
+            .and_then(|cause| cause.get().downcast_ref::<quick_xml::DeError>());
+
+        assert_eq!(location.line(), Some(5), "found {error}");
+        assert!(source.is_some(), "the quick-xml error should be the source");
+    }
+}
@@ -0,0 +1,120 @@
+//! TEI P5 namespace handling.
+//!
+//! TEI files produced by other tools declare the TEI namespace on the root,
+//! either as the default namespace or through a prefix such as `tei:`. The
+//! data model matches elements by local name, so both forms parse like
+//! unqualified markup; this module only rejects roots bound to some other
+//! namespace, or with an unexpected local name, which would otherwise be read
+//! as TEI by accident.
+
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+use tei_core::{SourceLocation, TeiError, TeiPath};
+
+/// Namespace URI of TEI P5 elements.
+pub const TEI_NAMESPACE: &str = "http://www.tei-c.org/ns/1.0";
+
+/// Checks that the root element is named `element` and is unqualified or in
+/// the TEI namespace.
+///
+/// Input that is not well-formed before the root is left for the parser to
+/// report.
+pub(crate) fn ensure_root(xml: &str, element: &str) -> Result<(), TeiError> {
+    let mut reader = Reader::from_str(xml);
+
+    loop {
+        let offset = reader.buffer_position();
+        match reader.read_event() {
+            Ok(Event::Start(start) | Event::Empty(start)) => {
+                return check_name(&start, element)
+                    .and_then(|()| check_root(&start))
+                    .map_err(|message| root_error(xml, offset, element, message));
+            }
+            Ok(Event::Eof) | Err(_) => return Ok(()),
+            Ok(_) => {}
+        }
+    }
+}
+
+fn root_error(xml: &str, offset: u64, element: &str, message: String) -> TeiError {
+    let location = usize::try_from(offset).map_or_else(
+        |_| SourceLocation::new(),
+        |value| SourceLocation::new().with_offset(value),
+    );
+    let located = location
+        .with_path(TeiPath::new().child(element))
+        .with_line_column_in(xml);
+    TeiError::xml_at(message, located)
+}
+
+fn check_name(start: &BytesStart<'_>, element: &str) -> Result<(), String> {
+    if start.local_name().as_ref() == element.as_bytes() {
+        return Ok(());
+    }
+
+    let found = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+    Err(format!(
+        "expected a <{element}> root element, found <{found}>"
+    ))
+}
+
+/// Resolves the namespace of a root start tag from its own declarations.
+///
+/// The root has no ancestors, so its `xmlns` attributes are the only
+/// bindings in scope.
+pub(crate) fn check_root(start: &BytesStart<'_>) -> Result<(), String> {
+    let name = start.name();
+    let prefix = name
+        .prefix()
+        .map(|prefix| String::from_utf8_lossy(prefix.as_ref()).into_owned());
+    let declaration = prefix
+        .as_ref()
+        .map_or_else(|| "xmlns".to_owned(), |value| format!("xmlns:{value}"));
+
+    let bound = start
+        .attributes()
+        .flatten()
+        .find(|attribute| attribute.key.as_ref() == declaration.as_bytes())
+        .map(|attribute| String::from_utf8_lossy(&attribute.value).into_owned());
+    let element = String::from_utf8_lossy(name.as_ref()).into_owned();
+
+    match (bound, prefix) {
+        (Some(namespace), _) if namespace == TEI_NAMESPACE => Ok(()),
+        (Some(namespace), _) if !namespace.is_empty() => Err(format!(
+            "root element <{element}> is in namespace `{namespace}`, expected the TEI namespace `{TEI_NAMESPACE}`"
+        )),
+        (_, Some(value)) => Err(format!(
+            "root element <{element}> uses undeclared namespace prefix `{value}`"
+        )),
+        (_, None) => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("<TEI/>")]
+    #[case("<TEI xmlns=\"http://www.tei-c.org/ns/1.0\"/>")]
+    #[case("<tei:TEI xmlns:tei=\"http://www.tei-c.org/ns/1.0\"/>")]
+    #[case("<?xml version=\"1.0\"?>\n<TEI xmlns=\"\"/>")]
+    fn accepts_unqualified_and_tei_roots(#[case] xml: &str) {
+        assert!(ensure_root(xml, "TEI").is_ok());
+    }
+
+    #[rstest]
+    #[case("<TEI xmlns=\"urn:example\"/>", "is in namespace `urn:example`")]
+    #[case("<tei:TEI/>", "undeclared namespace prefix `tei`")]
+    #[case("<teiCorpus/>", "expected a <TEI> root element, found <teiCorpus>")]
+    fn rejects_foreign_roots(#[case] xml: &str, #[case] expected: &str) {
+        let error = ensure_root(xml, "TEI").expect_err("foreign root must fail");
+
+        assert!(error.to_string().contains(expected), "found {error}");
+        assert_eq!(
+            error.location().and_then(SourceLocation::path),
+            Some(&TeiPath::root())
+        );
+    }
+}
@@ -0,0 +1,371 @@
+//! Document-level options for emitted TEI.
+//!
+//! The options cover the XML and document type declarations, the root
+//! namespace and schema location, CDATA sections, empty elements, attribute
+//! order, and the final newline.
+//!
+//! [`emit_xml`](crate::emit_xml) writes bare markup, which suits embedding and
+//! byte-for-byte comparisons. Some downstream validators reject documents
+//! without a declaration or outside the TEI namespace, and XML editors only
+//! validate documents that name their schema, so [`emit_xml_with_options`]
+//! can add any of these. It can also wrap text dense with markup characters
+//! in CDATA sections, or switch to the stable canonical form described in the
+//! `canonical` module for content hashing. Some XSLT pipelines mishandle
+//! self-closing tags, so empty elements can be written as start and end tag
+//! pairs.
+
+use tei_core::{TeiDocument, TeiError};
+
+mod order;
+mod prolog;
+mod root;
+
+pub use order::AttributeOrder;
+pub(crate) use root::XSI_NAMESPACE;
+
+use root::SchemaLocation;
+
+use crate::{canonical, cdata, emit_markup, raw};
+
+/// Framing applied around the serialized document by
+/// [`emit_xml_with_options`].
+///
+/// # Examples
+///
+/// ```
+/// use tei_xml::EmitOptions;
+///
+/// let options = EmitOptions::new().with_declaration(true).with_standalone(true);
+/// assert_eq!(
+///     options.declaration(),
+///     Some("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>".to_owned()),
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EmitOptions {
+    declaration: bool,
+    standalone: Option<bool>,
+    namespace: bool,
+    schema: Option<SchemaLocation>,
+    doctype: Option<String>,
+    trailing_newline: bool,
+    text: TextStyle,
+    empty_elements: EmptyElements,
+    attributes: AttributeOrder,
+}
+
+/// How [`emit_xml_with_options`] writes elements without content.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum EmptyElements {
+    /// A single self-closing tag, such as `<body/>`.
+    #[default]
+    SelfClosing,
+    /// A start and end tag pair, such as `<body></body>`.
+    Expanded,
+}
+
+/// How element text is written; CDATA wrapping and the canonical form are
+/// mutually exclusive.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum TextStyle {
+    #[default]
+    Escaped,
+    Cdata(usize),
+    Canonical,
+}
+
+impl EmitOptions {
+    /// Creates options matching [`emit_xml`](crate::emit_xml): no declaration
+    /// and no trailing newline.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            declaration: false,
+            standalone: None,
+            namespace: false,
+            schema: None,
+            doctype: None,
+            trailing_newline: false,
+            text: TextStyle::Escaped,
+            empty_elements: EmptyElements::SelfClosing,
+            attributes: AttributeOrder::Model,
+        }
+    }
+
+    /// Controls whether `<?xml version="1.0" encoding="UTF-8"?>` precedes the
+    /// document.
+    #[must_use]
+    pub const fn with_declaration(mut self, declaration: bool) -> Self {
+        self.declaration = declaration;
+        self
+    }
+
+    /// Sets the `standalone` pseudo-attribute of the declaration.
+    ///
+    /// The flag is only written when the declaration is enabled.
+    #[must_use]
+    pub const fn with_standalone(mut self, standalone: bool) -> Self {
+        self.standalone = Some(standalone);
+        self
+    }
+
+    /// Controls whether the root declares the
+    /// [`TEI_NAMESPACE`](crate::TEI_NAMESPACE) as its default namespace.
+    #[must_use]
+    pub const fn with_namespace(mut self, namespace: bool) -> Self {
+        self.namespace = namespace;
+        self
+    }
+
+    /// Reports whether the root declares the TEI namespace.
+    #[must_use]
+    pub const fn namespace(&self) -> bool {
+        self.namespace
+    }
+
+    /// Controls whether the output ends with a newline.
+    #[must_use]
+    pub const fn with_trailing_newline(mut self, trailing_newline: bool) -> Self {
+        self.trailing_newline = trailing_newline;
+        self
+    }
+
+    /// Reports whether a trailing newline is written.
+    #[must_use]
+    pub const fn trailing_newline(&self) -> bool {
+        self.trailing_newline
+    }
+
+    /// Writes text nodes holding at least `threshold` of `&`, `<` and `>` as
+    /// CDATA sections instead of escaping each character.
+    ///
+    /// Text containing `]]>` stays escaped, and attribute values are never
+    /// wrapped. The output parses to the same document either way. This
+    /// replaces the canonical form if it was enabled.
+    #[must_use]
+    pub const fn with_cdata_threshold(mut self, threshold: usize) -> Self {
+        self.text = TextStyle::Cdata(threshold);
+        self
+    }
+
+    /// Returns the CDATA threshold, if CDATA wrapping is enabled.
+    #[must_use]
+    pub const fn cdata_threshold(&self) -> Option<usize> {
+        match self.text {
+            TextStyle::Cdata(threshold) => Some(threshold),
+            TextStyle::Escaped | TextStyle::Canonical => None,
+        }
+    }
+
+    /// Writes the stable canonical form used for content hashing.
+    ///
+    /// Canonical output orders attributes by namespace and name, expands
+    /// empty elements into start and end tags, normalises newlines to `\n`,
+    /// and escapes text and attributes the same way every time. These rules
+    /// are covered by the crate's stability guarantee, so equal documents
+    /// hash equally across releases. Enabling it replaces any CDATA
+    /// threshold. Leave the declaration and trailing newline off for strict
+    /// Canonical XML.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::TeiDocument;
+    /// use tei_xml::{EmitOptions, emit_xml_with_options};
+    ///
+    /// let document = TeiDocument::from_title_str("Wolf 359")?;
+    /// let xml = emit_xml_with_options(&document, EmitOptions::new().with_canonical_form(true))?;
+    /// assert!(xml.ends_with("<text><body></body></text></TEI>"));
+    /// # Ok::<(), tei_core::TeiError>(())
+    /// ```
+    #[must_use]
+    pub const fn with_canonical_form(mut self, canonical: bool) -> Self {
+        if canonical {
+            self.text = TextStyle::Canonical;
+        } else if matches!(self.text, TextStyle::Canonical) {
+            self.text = TextStyle::Escaped;
+        }
+        self
+    }
+
+    /// Reports whether the canonical form is written.
+    #[must_use]
+    pub const fn canonical_form(&self) -> bool {
+        matches!(self.text, TextStyle::Canonical)
+    }
+
+    /// Chooses how elements without content are written.
+    ///
+    /// The canonical form always expands empty elements, whatever this is
+    /// set to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::TeiDocument;
+    /// use tei_xml::{EmitOptions, EmptyElements, emit_xml_with_options};
+    ///
+    /// let document = TeiDocument::from_title_str("Wolf 359")?;
+    /// let options = EmitOptions::new().with_empty_elements(EmptyElements::Expanded);
+    /// let xml = emit_xml_with_options(&document, options)?;
+    /// assert!(xml.ends_with("<text><body></body></text></TEI>"));
+    /// # Ok::<(), tei_core::TeiError>(())
+    /// ```
+    #[must_use]
+    pub const fn with_empty_elements(mut self, style: EmptyElements) -> Self {
+        self.empty_elements = style;
+        self
+    }
+
+    /// Returns how elements without content are written.
+    #[must_use]
+    pub const fn empty_elements(&self) -> EmptyElements {
+        self.empty_elements
+    }
+}
+
+/// Serializes a [`TeiDocument`] with the given declaration, root attribute and
+/// newline options.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Xml`] in the same cases as [`emit_xml`](crate::emit_xml).
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::TeiDocument;
+/// use tei_xml::{EmitOptions, emit_xml_with_options};
+///
+/// let document = TeiDocument::from_title_str("Wolf 359")?;
+/// let options = EmitOptions::new()
+///     .with_declaration(true)
+///     .with_trailing_newline(true);
+/// let xml = emit_xml_with_options(&document, options)?;
+///
+/// assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<TEI>"));
+/// assert!(xml.ends_with("</TEI>\n"));
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn emit_xml_with_options(
+    document: &TeiDocument,
+    options: EmitOptions,
+) -> Result<String, TeiError> {
+    let markup =
+        raw::restore(options.order_attributes(
+            options.qualify_root(emit_markup(document, options.empty_elements)?),
+        )?)?;
+    let body = match options.text {
+        TextStyle::Escaped => markup,
+        TextStyle::Cdata(threshold) => cdata::wrap_escaped_text(&markup, threshold)?,
+        TextStyle::Canonical => canonical::canonicalize(&markup)?,
+    };
+    Ok(options.frame(&body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{emit_xml, parse_xml};
+    use rstest::rstest;
+
+    fn document() -> TeiDocument {
+        TeiDocument::from_title_str("Wolf 359").expect("valid title")
+    }
+
+    #[test]
+    fn default_options_match_emit_xml() {
+        let xml = emit_xml_with_options(&document(), EmitOptions::default())
+            .expect("document should emit");
+
+        assert_eq!(xml, emit_xml(&document()).expect("document should emit"));
+    }
+
+    #[rstest]
+    #[case(EmitOptions::new().with_standalone(true), None)]
+    #[case(
+        EmitOptions::new().with_declaration(true),
+        Some("<?xml version=\"1.0\" encoding=\"UTF-8\"?>")
+    )]
+    #[case(
+        EmitOptions::new().with_declaration(true).with_standalone(false),
+        Some("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"no\"?>")
+    )]
+    fn writes_the_configured_declaration(
+        #[case] options: EmitOptions,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(options.declaration().as_deref(), expected);
+    }
+
+    #[test]
+    fn declares_the_tei_namespace_on_the_root() {
+        let xml = emit_xml_with_options(&document(), EmitOptions::new().with_namespace(true))
+            .expect("document should emit");
+
+        assert!(
+            xml.starts_with("<TEI xmlns=\"http://www.tei-c.org/ns/1.0\"><teiHeader>"),
+            "{xml}"
+        );
+    }
+
+    #[test]
+    fn wraps_dense_text_in_cdata() {
+        let document = TeiDocument::from_title_str("<R&D> & <QA>").expect("valid title");
+        let options = EmitOptions::new().with_cdata_threshold(4);
+
+        let xml = emit_xml_with_options(&document, options).expect("document should emit");
+
+        assert!(
+            xml.contains("<title><![CDATA[<R&D> & <QA>]]></title>"),
+            "{xml}"
+        );
+        assert_eq!(parse_xml(&xml).expect("CDATA should parse"), document);
+    }
+
+    #[test]
+    fn canonical_form_is_stable_and_parses_back() {
+        let options = EmitOptions::new()
+            .with_cdata_threshold(1)
+            .with_canonical_form(true);
+        let first =
+            emit_xml_with_options(&document(), options.clone()).expect("document should emit");
+        let second =
+            emit_xml_with_options(&document(), options.clone()).expect("document should emit");
+
+        assert_eq!(options.cdata_threshold(), None);
+        assert_eq!(first, second);
+        assert_eq!(
+            parse_xml(&first).expect("canonical output should parse"),
+            document()
+        );
+    }
+
+    #[test]
+    fn expands_empty_elements_and_parses_back() {
+        let options = EmitOptions::new().with_empty_elements(EmptyElements::Expanded);
+        let xml = emit_xml_with_options(&document(), options).expect("document should emit");
+
+        assert!(xml.contains("<body></body>"), "{xml}");
+        assert!(!xml.contains("/>"), "{xml}");
+        assert_eq!(
+            parse_xml(&xml).expect("expanded output should parse"),
+            document()
+        );
+    }
+
+    #[test]
+    fn framed_output_parses_back() {
+        let options = EmitOptions::new()
+            .with_declaration(true)
+            .with_standalone(true)
+            .with_namespace(true)
+            .with_trailing_newline(true);
+        let xml = emit_xml_with_options(&document(), options).expect("document should emit");
+
+        assert_eq!(
+            parse_xml(&xml).expect("framed output should parse"),
+            document()
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! The order attributes are written in each start tag.
+//!
+//! By default the root's namespace and schema attributes come first, and every
+//! other element writes its attributes in the order the `tei-core` model
+//! declares them: `xml:id`, then `xml:lang`, then element attributes such as
+//! `who`. That order is pinned by tests and, like the canonical rules, changing
+//! it is a breaking change. [`AttributeOrder::Sorted`] and
+//! [`AttributeOrder::Leading`] rewrite each start tag instead, moving the
+//! escaped values as they are, so only the order changes.
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+use tei_core::TeiError;
+
+use super::EmitOptions;
+use crate::canonical;
+
+/// The order in which [`emit_xml_with_options`](super::emit_xml_with_options)
+/// writes attributes.
+///
+/// The canonical form always sorts attributes, whatever this is set to.
+///
+/// # Examples
+///
+/// ```
+/// use tei_xml::{AttributeOrder, EmitOptions};
+///
+/// let options = EmitOptions::new().with_attribute_order(AttributeOrder::leading(["who"]));
+/// assert_eq!(
+///     options.attribute_order(),
+///     &AttributeOrder::Leading(vec!["who".to_owned()]),
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum AttributeOrder {
+    /// Namespace and schema attributes on the root first, then the order the
+    /// data model declares.
+    #[default]
+    Model,
+    /// Namespace declarations first, then attributes by namespace URI and
+    /// local name, as in the canonical form.
+    Sorted,
+    /// The named attributes first, in the order given, then the rest as
+    /// [`AttributeOrder::Sorted`] orders them.
+    Leading(Vec<String>),
+}
+
+impl AttributeOrder {
+    /// Builds an [`AttributeOrder::Leading`] order from qualified names such
+    /// as `xml:id`.
+    #[must_use]
+    pub fn leading<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::Leading(names.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns where `name` sorts under this order.
+    fn key(&self, name: &str) -> (usize, (u8, String, String)) {
+        let listed = match self {
+            Self::Leading(names) => names
+                .iter()
+                .position(|listed| listed == name)
+                .unwrap_or(names.len()),
+            Self::Model | Self::Sorted => 0,
+        };
+        (listed, canonical::sort_key(name))
+    }
+}
+
+impl EmitOptions {
+    /// Chooses the order attributes are written in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::{TeiDocument, TeiText, Utterance};
+    /// use tei_xml::{AttributeOrder, EmitOptions, emit_xml_with_options};
+    ///
+    /// let mut utterance = Utterance::from_text_segments(Some("keller"), ["Hello"])?;
+    /// utterance.set_id("u1")?;
+    /// let mut text = TeiText::empty();
+    /// text.push_utterance(utterance);
+    /// let mut document = TeiDocument::from_title_str("Wolf 359")?;
+    /// *document.text_mut() = text;
+    ///
+    /// let options = EmitOptions::new().with_attribute_order(AttributeOrder::leading(["who"]));
+    /// let xml = emit_xml_with_options(&document, options)?;
+    /// assert!(xml.contains("<u who=\"keller\" xml:id=\"u1\">"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn with_attribute_order(mut self, order: AttributeOrder) -> Self {
+        self.attributes = order;
+        self
+    }
+
+    /// Returns the order attributes are written in.
+    #[must_use]
+    pub const fn attribute_order(&self) -> &AttributeOrder {
+        &self.attributes
+    }
+
+    /// Rewrites start tags in `markup` into the configured order.
+    pub(super) fn order_attributes(&self, markup: String) -> Result<String, TeiError> {
+        if self.attributes == AttributeOrder::Model || self.canonical_form() {
+            return Ok(markup);
+        }
+        reorder(&markup, &self.attributes)
+    }
+}
+
+fn reorder(markup: &str, order: &AttributeOrder) -> Result<String, TeiError> {
+    let mut reader = Reader::from_str(markup);
+    let mut output = String::with_capacity(markup.len());
+    let mut copied = 0;
+
+    loop {
+        let start = offset(&reader);
+        let (tag, closing) = match reader
+            .read_event()
+            .map_err(|error| TeiError::xml(error.to_string()))?
+        {
+            Event::Eof => break,
+            Event::Start(tag) => (tag, ">"),
+            Event::Empty(tag) => (tag, "/>"),
+            _ => continue,
+        };
+        let attributes = sorted_attributes(&tag, order)?;
+        if attributes.len() < 2 {
+            continue;
+        }
+
+        output.push_str(markup.get(copied..start).unwrap_or_default());
+        output.push('<');
+        output.push_str(&String::from_utf8_lossy(tag.name().as_ref()));
+        for (name, value) in attributes {
+            output.push(' ');
+            output.push_str(&name);
+            output.push_str("=\"");
+            output.push_str(&value);
+            output.push('"');
+        }
+        output.push_str(closing);
+        copied = offset(&reader);
+    }
+    output.push_str(markup.get(copied..).unwrap_or_default());
+    Ok(output)
+}
+
+/// Returns the attributes of `tag` in `order`, with their values still
+/// escaped.
+fn sorted_attributes(
+    tag: &BytesStart<'_>,
+    order: &AttributeOrder,
+) -> Result<Vec<(String, String)>, TeiError> {
+    let mut attributes = Vec::new();
+    for attribute in tag.attributes() {
+        let parsed = attribute.map_err(|error| TeiError::xml(error.to_string()))?;
+        let name = String::from_utf8_lossy(parsed.key.as_ref()).into_owned();
+        let value = String::from_utf8_lossy(&parsed.value).into_owned();
+        attributes.push((order.key(&name), name, value));
+    }
+    attributes.sort_by(|left, right| left.0.cmp(&right.0));
+    Ok(attributes
+        .into_iter()
+        .map(|(_, name, value)| (name, value))
+        .collect())
+}
+
+fn offset(reader: &Reader<&[u8]>) -> usize {
+    usize::try_from(reader.buffer_position()).unwrap_or(usize::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use tei_core::{LanguageTag, TeiDocument, TeiText, Utterance};
+
+    use crate::{emit_xml_with_options, parse_xml};
+
+    fn document() -> TeiDocument {
+        let mut utterance =
+            Utterance::from_text_segments(Some("keller"), ["Hello"]).expect("valid utterance");
+        utterance.set_id("u1").expect("valid identifier");
+        utterance.set_lang(LanguageTag::new("en").expect("valid language"));
+        let mut text = TeiText::empty();
+        text.push_utterance(utterance);
+        let mut document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        *document.text_mut() = text;
+        document
+    }
+
+    #[rstest]
+    #[case(
+        AttributeOrder::Model,
+        "<TEI xmlns=\"http://www.tei-c.org/ns/1.0\" xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xsi:noNamespaceSchemaLocation=\"a&amp;b.xsd\">",
+        "<u xml:id=\"u1\" xml:lang=\"en\" who=\"keller\">Hello</u>"
+    )]
+    #[case(
+        AttributeOrder::Sorted,
+        "<TEI xmlns=\"http://www.tei-c.org/ns/1.0\" xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xsi:noNamespaceSchemaLocation=\"a&amp;b.xsd\">",
+        "<u who=\"keller\" xml:id=\"u1\" xml:lang=\"en\">Hello</u>"
+    )]
+    #[case(
+        AttributeOrder::leading(["xsi:noNamespaceSchemaLocation", "xml:lang"]),
+        "<TEI xsi:noNamespaceSchemaLocation=\"a&amp;b.xsd\" xmlns=\"http://www.tei-c.org/ns/1.0\" xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\">",
+        "<u xml:lang=\"en\" who=\"keller\" xml:id=\"u1\">Hello</u>"
+    )]
+    fn writes_attributes_in_the_configured_order(
+        #[case] order: AttributeOrder,
+        #[case] root: &str,
+        #[case] utterance: &str,
+    ) {
+        let options = EmitOptions::new()
+            .with_namespace(true)
+            .with_no_namespace_schema_location("a&b.xsd")
+            .with_attribute_order(order);
+
+        let xml = emit_xml_with_options(&document(), options).expect("document should emit");
+
+        assert!(xml.starts_with(root), "{xml}");
+        assert!(xml.contains(utterance), "{xml}");
+        assert_eq!(parse_xml(&xml).expect("output should parse"), document());
+    }
+}
@@ -0,0 +1,132 @@
+//! The prolog written before the root element: the XML declaration and the
+//! document type declaration some archive ingestion pipelines still require.
+
+use super::EmitOptions;
+
+impl EmitOptions {
+    /// Writes `<!DOCTYPE TEI SYSTEM "...">` before the root, naming the DTD at
+    /// `system_id`.
+    ///
+    /// A system identifier holding both kinds of quote is written with `"`
+    /// percent-encoded as `%22`, since XML cannot quote it otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::TeiDocument;
+    /// use tei_xml::{EmitOptions, emit_xml_with_options};
+    ///
+    /// let document = TeiDocument::from_title_str("Wolf 359")?;
+    /// let options = EmitOptions::new()
+    ///     .with_declaration(true)
+    ///     .with_doctype("tei_all.dtd");
+    /// let xml = emit_xml_with_options(&document, options)?;
+    ///
+    /// assert!(xml.starts_with(concat!(
+    ///     "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+    ///     "<!DOCTYPE TEI SYSTEM \"tei_all.dtd\">\n<TEI>",
+    /// )));
+    /// # Ok::<(), tei_core::TeiError>(())
+    /// ```
+    #[must_use]
+    pub fn with_doctype(mut self, system_id: impl Into<String>) -> Self {
+        self.doctype = Some(system_id.into());
+        self
+    }
+
+    /// Returns the system identifier written in the document type
+    /// declaration, if any.
+    #[must_use]
+    pub fn doctype_system_id(&self) -> Option<&str> {
+        self.doctype.as_deref()
+    }
+
+    /// Returns the declaration these options write, if any.
+    #[must_use]
+    pub fn declaration(&self) -> Option<String> {
+        if !self.declaration {
+            return None;
+        }
+
+        let standalone = self.standalone.map_or("", |standalone| {
+            if standalone {
+                " standalone=\"yes\""
+            } else {
+                " standalone=\"no\""
+            }
+        });
+        Some(format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"{standalone}?>"
+        ))
+    }
+
+    /// Returns the document type declaration these options write, if any.
+    #[must_use]
+    pub fn doctype(&self) -> Option<String> {
+        self.doctype.as_deref().map(|system_id| {
+            if !system_id.contains('"') {
+                format!("<!DOCTYPE TEI SYSTEM \"{system_id}\">")
+            } else if !system_id.contains('\'') {
+                format!("<!DOCTYPE TEI SYSTEM '{system_id}'>")
+            } else {
+                format!(
+                    "<!DOCTYPE TEI SYSTEM \"{}\">",
+                    system_id.replace('"', "%22")
+                )
+            }
+        })
+    }
+
+    /// Wraps serialized markup in the configured prolog and newline.
+    pub(crate) fn frame(self, markup: &str) -> String {
+        let mut framed = String::new();
+        for line in [self.declaration(), self.doctype()].into_iter().flatten() {
+            framed.push_str(&line);
+            framed.push('\n');
+        }
+        framed.push_str(markup);
+        if self.trailing_newline {
+            framed.push('\n');
+        }
+        framed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use tei_core::TeiDocument;
+
+    use crate::{emit_xml_with_options, parse_xml};
+
+    #[rstest]
+    #[case("tei_all.dtd", "<!DOCTYPE TEI SYSTEM \"tei_all.dtd\">")]
+    #[case("say \"hi\".dtd", "<!DOCTYPE TEI SYSTEM 'say \"hi\".dtd'>")]
+    #[case("it's \"x\".dtd", "<!DOCTYPE TEI SYSTEM \"it's %22x%22.dtd\">")]
+    fn quotes_the_system_identifier(#[case] system_id: &str, #[case] expected: &str) {
+        let options = EmitOptions::new().with_doctype(system_id);
+
+        assert_eq!(options.doctype_system_id(), Some(system_id));
+        assert_eq!(options.doctype().as_deref(), Some(expected));
+    }
+
+    #[test]
+    fn doctype_output_parses_back() {
+        let document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        let options = EmitOptions::new()
+            .with_namespace(true)
+            .with_doctype("https://example.org/tei_all.dtd");
+
+        let xml = emit_xml_with_options(&document, options).expect("document should emit");
+
+        assert!(
+            xml.starts_with("<!DOCTYPE TEI SYSTEM \"https://example.org/tei_all.dtd\">\n<TEI "),
+            "{xml}"
+        );
+        assert_eq!(
+            parse_xml(&xml).expect("DOCTYPE output should parse"),
+            document
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! Attributes written on the root element: the TEI namespace and the schema
+//! location XML editors use to validate the document.
+
+use super::EmitOptions;
+use crate::{TEI_NAMESPACE, escape_xml_attr};
+
+/// Namespace URI of the `xsi:` schema location attributes.
+pub(crate) const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+/// Which `xsi:` attribute names the schema.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) enum SchemaLocation {
+    Namespaced(String),
+    NoNamespace(String),
+}
+
+impl EmitOptions {
+    /// Writes `xsi:schemaLocation` on the root, pairing the [`TEI_NAMESPACE`]
+    /// with the schema at `location`.
+    ///
+    /// Use it together with [`with_namespace`](Self::with_namespace), since
+    /// the attribute only applies to namespaced elements. This replaces any
+    /// `xsi:noNamespaceSchemaLocation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tei_core::TeiDocument;
+    /// use tei_xml::{EmitOptions, emit_xml_with_options};
+    ///
+    /// let document = TeiDocument::from_title_str("Wolf 359")?;
+    /// let options = EmitOptions::new()
+    ///     .with_namespace(true)
+    ///     .with_schema_location("https://example.org/schema/episodic.xsd");
+    /// let xml = emit_xml_with_options(&document, options)?;
+    ///
+    /// assert!(xml.contains(concat!(
+    ///     " xsi:schemaLocation=\"http://www.tei-c.org/ns/1.0 ",
+    ///     "https://example.org/schema/episodic.xsd\"",
+    /// )));
+    /// # Ok::<(), tei_core::TeiError>(())
+    /// ```
+    #[must_use]
+    pub fn with_schema_location(mut self, location: impl Into<String>) -> Self {
+        self.schema = Some(SchemaLocation::Namespaced(location.into()));
+        self
+    }
+
+    /// Writes `xsi:noNamespaceSchemaLocation` on the root, naming the schema
+    /// for unqualified output.
+    ///
+    /// This replaces any `xsi:schemaLocation`.
+    #[must_use]
+    pub fn with_no_namespace_schema_location(mut self, location: impl Into<String>) -> Self {
+        self.schema = Some(SchemaLocation::NoNamespace(location.into()));
+        self
+    }
+
+    /// Returns the location written in `xsi:schemaLocation`, if any.
+    #[must_use]
+    pub fn schema_location(&self) -> Option<&str> {
+        match &self.schema {
+            Some(SchemaLocation::Namespaced(location)) => Some(location),
+            Some(SchemaLocation::NoNamespace(_)) | None => None,
+        }
+    }
+
+    /// Returns the location written in `xsi:noNamespaceSchemaLocation`, if
+    /// any.
+    #[must_use]
+    pub fn no_namespace_schema_location(&self) -> Option<&str> {
+        match &self.schema {
+            Some(SchemaLocation::NoNamespace(location)) => Some(location),
+            Some(SchemaLocation::Namespaced(_)) | None => None,
+        }
+    }
+
+    /// Adds the configured namespace and schema attributes to the root.
+    pub(crate) fn qualify_root(&self, markup: String) -> String {
+        let mut attributes = Vec::new();
+        if self.namespace {
+            attributes.push(("xmlns", TEI_NAMESPACE.to_owned()));
+        }
+        if let Some(schema) = &self.schema {
+            attributes.push(("xmlns:xsi", XSI_NAMESPACE.to_owned()));
+            attributes.push(match schema {
+                SchemaLocation::Namespaced(location) => {
+                    ("xsi:schemaLocation", format!("{TEI_NAMESPACE} {location}"))
+                }
+                SchemaLocation::NoNamespace(location) => {
+                    ("xsi:noNamespaceSchemaLocation", location.clone())
+                }
+            });
+        }
+
+        let Some(rest) = markup
+            .strip_prefix("<TEI")
+            .filter(|_| !attributes.is_empty())
+        else {
+            return markup;
+        };
+        let mut qualified = String::from("<TEI");
+        for (name, value) in attributes {
+            qualified.push(' ');
+            qualified.push_str(name);
+            qualified.push_str("=\"");
+            qualified.push_str(&escape_xml_attr(&value));
+            qualified.push('"');
+        }
+        qualified.push_str(rest);
+        qualified
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tei_core::TeiDocument;
+
+    use crate::{emit_xml_with_options, parse_xml};
+
+    fn document() -> TeiDocument {
+        TeiDocument::from_title_str("Wolf 359").expect("valid title")
+    }
+
+    #[test]
+    fn writes_the_namespaced_schema_location() {
+        let options = EmitOptions::new()
+            .with_no_namespace_schema_location("ignored.xsd")
+            .with_namespace(true)
+            .with_schema_location("episodic.xsd?v=1&lang=en");
+
+        let xml =
+            emit_xml_with_options(&document(), options.clone()).expect("document should emit");
+
+        assert_eq!(options.no_namespace_schema_location(), None);
+        assert!(
+            xml.starts_with(concat!(
+                "<TEI xmlns=\"http://www.tei-c.org/ns/1.0\"",
+                " xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\"",
+                " xsi:schemaLocation=\"http://www.tei-c.org/ns/1.0 episodic.xsd?v=1&amp;lang=en\">",
+            )),
+            "{xml}"
+        );
+        assert_eq!(parse_xml(&xml).expect("output should parse"), document());
+    }
+
+    #[test]
+    fn writes_the_no_namespace_schema_location() {
+        let options = EmitOptions::new()
+            .with_schema_location("ignored.xsd")
+            .with_no_namespace_schema_location("episodic.xsd")
+            .with_canonical_form(true);
+
+        let xml =
+            emit_xml_with_options(&document(), options.clone()).expect("document should emit");
+
+        assert_eq!(options.schema_location(), None);
+        assert!(
+            xml.starts_with(concat!(
+                "<TEI xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\"",
+                " xsi:noNamespaceSchemaLocation=\"episodic.xsd\">",
+            )),
+            "{xml}"
+        );
+        assert_eq!(parse_xml(&xml).expect("output should parse"), document());
+    }
+}
@@ -0,0 +1,327 @@
+//! Options controlling how TEI markup is read.
+//!
+//! [`parse_xml`](crate::parse_xml) honours `xml:space="preserve"` but
+//! otherwise trims the whitespace around each text segment, as most
+//! transcripts are indented for readability. Transcribers sometimes encode
+//! pauses or hesitation in spacing, so [`parse_xml_with_options`] can keep
+//! that whitespace throughout the document.
+//!
+//! References to entities other than the five XML predefines fail by
+//! default; [`EntityPolicy::PreserveUnknown`] keeps them as text instead.
+//! External entity declarations are always rejected.
+//!
+//! The options also carry resource limits for services that parse untrusted
+//! uploads. Nesting depth and entity references are bounded by default; the
+//! document size is only bounded on request.
+
+use tei_core::{TeiDocument, TeiError};
+
+use crate::{EntityPolicy, UnknownElements, entities, limits, namespace, raw, whitespace};
+
+/// Settings applied by [`parse_xml_with_options`].
+///
+/// # Examples
+///
+/// ```
+/// use tei_xml::ParseOptions;
+///
+/// let options = ParseOptions::new().with_preserved_whitespace(true);
+/// assert!(options.preserved_whitespace());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseOptions {
+    preserve_whitespace: bool,
+    max_depth: Option<usize>,
+    max_entity_expansions: Option<usize>,
+    max_document_size: Option<usize>,
+    entity_policy: EntityPolicy,
+    unknown_elements: UnknownElements,
+}
+
+impl ParseOptions {
+    /// Nesting depth allowed by default. TEI transcripts rarely nest more
+    /// than a dozen elements deep.
+    pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+    /// Entity and character references allowed by default.
+    pub const DEFAULT_MAX_ENTITY_EXPANSIONS: usize = 1_000_000;
+
+    /// Creates options matching [`parse_xml`](crate::parse_xml).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            preserve_whitespace: false,
+            max_depth: Some(Self::DEFAULT_MAX_DEPTH),
+            max_entity_expansions: Some(Self::DEFAULT_MAX_ENTITY_EXPANSIONS),
+            max_document_size: None,
+            entity_policy: EntityPolicy::Resolve,
+            unknown_elements: UnknownElements::Reject,
+        }
+    }
+
+    /// Keeps leading and trailing whitespace in every text segment, as if
+    /// the root declared `xml:space="preserve"`.
+    ///
+    /// Elements declaring `xml:space="default"` still trim their text.
+    /// Whitespace between block elements is never kept, since the data model
+    /// has nowhere to store it.
+    #[must_use]
+    pub const fn with_preserved_whitespace(mut self, preserve: bool) -> Self {
+        self.preserve_whitespace = preserve;
+        self
+    }
+
+    /// Reports whether whitespace is preserved throughout the document.
+    #[must_use]
+    pub const fn preserved_whitespace(&self) -> bool {
+        self.preserve_whitespace
+    }
+
+    /// Limits how many elements may be open at once; `None` lifts the limit.
+    #[must_use]
+    pub const fn with_max_depth(mut self, maximum: Option<usize>) -> Self {
+        self.max_depth = maximum;
+        self
+    }
+
+    /// Returns the nesting depth limit, if any.
+    #[must_use]
+    pub const fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    /// Limits how many entity and character references, such as `&amp;` or
+    /// `&#233;`, text and attributes may contain in total; `None` lifts the
+    /// limit.
+    #[must_use]
+    pub const fn with_max_entity_expansions(mut self, maximum: Option<usize>) -> Self {
+        self.max_entity_expansions = maximum;
+        self
+    }
+
+    /// Returns the entity reference limit, if any.
+    #[must_use]
+    pub const fn max_entity_expansions(&self) -> Option<usize> {
+        self.max_entity_expansions
+    }
+
+    /// Limits the length of the input in bytes; `None`, the default, lifts
+    /// the limit.
+    #[must_use]
+    pub const fn with_max_document_size(mut self, maximum: Option<usize>) -> Self {
+        self.max_document_size = maximum;
+        self
+    }
+
+    /// Returns the document size limit in bytes, if any.
+    #[must_use]
+    pub const fn max_document_size(&self) -> Option<usize> {
+        self.max_document_size
+    }
+}
+
+impl ParseOptions {
+    /// Chooses how references to entities other than the predefined ones
+    /// are treated.
+    #[must_use]
+    pub const fn with_entity_policy(mut self, policy: EntityPolicy) -> Self {
+        self.entity_policy = policy;
+        self
+    }
+
+    /// Returns the entity policy.
+    #[must_use]
+    pub const fn entity_policy(&self) -> EntityPolicy {
+        self.entity_policy
+    }
+
+    /// Chooses how elements inside the body that the data model does not
+    /// cover are treated.
+    ///
+    /// Only children of `<body>`, `<p>`, `<u>` and `<hi>` are affected;
+    /// unknown header elements are still ignored.
+    #[must_use]
+    pub const fn with_unknown_elements(mut self, policy: UnknownElements) -> Self {
+        self.unknown_elements = policy;
+        self
+    }
+
+    /// Returns how unknown body elements are treated.
+    #[must_use]
+    pub const fn unknown_elements(&self) -> UnknownElements {
+        self.unknown_elements
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parses a TEI XML string into a [`TeiDocument`] with the given options.
+///
+/// # Errors
+///
+/// Returns [`TeiError::LimitExceeded`] when the input exceeds a limit in
+/// `options`, and [`TeiError::Xml`] when it declares an external entity or,
+/// under [`EntityPolicy::Resolve`], refers to an unknown one. Otherwise fails
+/// in the same cases as [`parse_xml`](crate::parse_xml).
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{BodyBlock, Inline, TeiError};
+/// use tei_xml::{ParseOptions, parse_xml_with_options};
+///
+/// let xml = concat!(
+///     "<TEI><teiHeader><fileDesc><title>Wolf 359</title></fileDesc></teiHeader>",
+///     "<text><body><u who=\"eiffel\">Well ...  </u></body></text></TEI>",
+/// );
+/// let options = ParseOptions::new().with_preserved_whitespace(true);
+/// let document = parse_xml_with_options(xml, options)?;
+///
+/// let Some(BodyBlock::Utterance(utterance)) = document.text().body().block(0) else {
+///     unreachable!("the body holds one utterance");
+/// };
+/// assert_eq!(utterance.content(), [Inline::text("Well ...  ")]);
+/// # Ok::<(), TeiError>(())
+/// ```
+pub fn parse_xml_with_options(xml: &str, options: ParseOptions) -> Result<TeiDocument, TeiError> {
+    // A byte order mark survives decoding when text is read without
+    // `parse_xml_bytes`; it carries no meaning once the input is a `str`.
+    let markup = xml.strip_prefix('\u{feff}').unwrap_or(xml);
+    limits::check(markup, options)?;
+    let prepared = entities::prepare(markup, options.entity_policy)?;
+    namespace::ensure_root(&prepared.markup, "TEI")?;
+    let preserved = raw::prepare(&prepared.markup, options.unknown_elements)
+        .map_err(|error| prepared.relocate(markup, error))?;
+    whitespace::deserialize(&preserved.markup, options.preserve_whitespace)
+        .map_err(|error| prepared.relocate(markup, preserved.relocate(&prepared.markup, error)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tei_core::{BodyBlock, ErrorCode, Inline, SourceLocation};
+
+    use crate::parse_xml;
+
+    fn document(body: &str) -> String {
+        format!(
+            "<TEI><teiHeader><fileDesc><title>Wolf 359</title></fileDesc></teiHeader><text><body>{body}</body></text></TEI>"
+        )
+    }
+
+    fn first_block_content(document: &TeiDocument) -> Vec<Inline> {
+        match document.text().body().block(0) {
+            Some(BodyBlock::Paragraph(paragraph)) => paragraph.content().to_vec(),
+            Some(BodyBlock::Utterance(utterance)) => utterance.content().to_vec(),
+            Some(BodyBlock::Raw(_)) | None => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn default_parsing_trims_text() {
+        let parsed = parse_xml(&document("<p>  Day 1.  </p>")).expect("document should parse");
+
+        assert_eq!(first_block_content(&parsed), [Inline::text("Day 1.")]);
+    }
+
+    #[test]
+    fn honours_xml_space_preserve() {
+        let xml = document("<p xml:space=\"preserve\">  Day 1.<pause/> Over.</p>");
+
+        let parsed = parse_xml(&xml).expect("document should parse");
+
+        assert_eq!(
+            first_block_content(&parsed),
+            [
+                Inline::text("  Day 1."),
+                Inline::pause(),
+                Inline::text(" Over.")
+            ]
+        );
+    }
+
+    #[test]
+    fn preserves_whitespace_on_request() {
+        let xml = document("\n  <u who=\"eiffel\">  Um.  </u>\n");
+        let options = ParseOptions::new().with_preserved_whitespace(true);
+
+        let parsed = parse_xml_with_options(&xml, options).expect("document should parse");
+
+        assert_eq!(first_block_content(&parsed), [Inline::text("  Um.  ")]);
+    }
+
+    #[test]
+    fn ignores_byte_order_marks_and_declared_encodings() {
+        let xml = format!(
+            "\u{feff}<?xml version=\"1.0\" encoding=\"UTF-16\"?>{}",
+            document("<p>Day 1.</p>")
+        );
+
+        let parsed = parse_xml(&xml).expect("document should parse");
+
+        assert_eq!(first_block_content(&parsed), [Inline::text("Day 1.")]);
+    }
+
+    #[test]
+    fn enforces_limits_before_deserialising() {
+        let nested = "<p>".repeat(300) + &"</p>".repeat(300);
+        let options = ParseOptions::new().with_max_depth(None);
+
+        let limited = parse_xml(&document(&nested)).expect_err("deep nesting must fail");
+        let unlimited =
+            parse_xml_with_options(&document(&nested), options).expect_err("nested p is invalid");
+
+        assert_eq!(limited.code(), "TEI-LIMIT");
+        assert_eq!(unlimited.code(), "TEI-XML");
+    }
+
+    #[test]
+    fn preserves_unknown_entities_on_request() {
+        let xml = document("<p>Caf&eacute;&nbsp;&amp; bar</p>");
+        let options = ParseOptions::new().with_entity_policy(EntityPolicy::PreserveUnknown);
+
+        let strict = parse_xml(&xml).expect_err("unknown entities must fail by default");
+        let parsed = parse_xml_with_options(&xml, options).expect("document should parse");
+
+        assert_eq!(strict.code(), "TEI-XML");
+        assert_eq!(
+            first_block_content(&parsed),
+            [Inline::text("Caf&eacute;&nbsp;& bar")]
+        );
+    }
+
+    #[test]
+    fn rejects_external_entities_under_every_policy() {
+        let xml = format!(
+            "<!DOCTYPE TEI [<!ENTITY xxe SYSTEM \"file:///etc/passwd\">]>{}",
+            document("<p>&xxe;</p>")
+        );
+        let options = ParseOptions::new().with_entity_policy(EntityPolicy::PreserveUnknown);
+
+        for error in [
+            parse_xml(&xml).expect_err("external entities must fail"),
+            parse_xml_with_options(&xml, options).expect_err("external entities must fail"),
+        ] {
+            assert!(
+                error.to_string().contains("external entity `xxe`"),
+                "{error}"
+            );
+            assert_eq!(error.location().and_then(SourceLocation::line), Some(1));
+        }
+    }
+
+    #[test]
+    fn locates_errors_in_the_original_markup() {
+        let xml = document("<p xml:space=\"preserve\"> a </p><u who=\" \">b</u>");
+
+        let error = parse_xml(&xml).expect_err("blank speakers must fail");
+        let unprotected =
+            crate::locate::deserialize::<TeiDocument>(&xml).expect_err("blank speakers must fail");
+
+        assert_eq!(error.location(), unprotected.location());
+    }
+}
@@ -0,0 +1,297 @@
+//! Indented TEI output for human review and line-based diffs.
+//!
+//! [`emit_xml_pretty`] re-flows the canonical markup from
+//! [`emit_xml`](crate::emit_xml), placing each element-only child on its own
+//! line. Whitespace inside mixed content is significant, so by default any
+//! element holding text keeps its whole subtree on one line.
+
+use quick_xml::Writer;
+use quick_xml::events::{BytesEnd, BytesStart, Event};
+use quick_xml::reader::Reader;
+use tei_core::{TeiDocument, TeiError};
+
+use crate::emit_xml;
+
+/// Layout rules applied by [`emit_xml_pretty`].
+///
+/// # Examples
+///
+/// ```
+/// use tei_xml::PrettyOptions;
+///
+/// let tabs = PrettyOptions::new().with_indent('\t', 1);
+/// assert_eq!(tabs.indent_char(), '\t');
+/// assert_eq!(tabs.indent_width(), 1);
+/// assert!(tabs.preserves_mixed_content());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PrettyOptions {
+    indent_char: char,
+    indent_width: usize,
+    preserve_mixed_content: bool,
+}
+
+impl PrettyOptions {
+    /// Creates the default options: two spaces per level, with mixed content
+    /// left untouched.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            indent_char: ' ',
+            indent_width: 2,
+            preserve_mixed_content: true,
+        }
+    }
+
+    /// Indents each nesting level with `width` copies of `character`.
+    #[must_use]
+    pub const fn with_indent(mut self, character: char, width: usize) -> Self {
+        self.indent_char = character;
+        self.indent_width = width;
+        self
+    }
+
+    /// Controls whether elements holding text keep their subtree on one line.
+    ///
+    /// Disabling this also breaks mixed content across lines, which adds
+    /// whitespace to the text of paragraphs and utterances.
+    #[must_use]
+    pub const fn preserve_mixed_content(mut self, preserve: bool) -> Self {
+        self.preserve_mixed_content = preserve;
+        self
+    }
+
+    /// Returns the indentation character.
+    #[must_use]
+    pub const fn indent_char(&self) -> char {
+        self.indent_char
+    }
+
+    /// Returns the number of indentation characters per level.
+    #[must_use]
+    pub const fn indent_width(&self) -> usize {
+        self.indent_width
+    }
+
+    /// Reports whether mixed content is kept on one line.
+    #[must_use]
+    pub const fn preserves_mixed_content(&self) -> bool {
+        self.preserve_mixed_content
+    }
+
+    fn indent(&self, depth: usize) -> String {
+        std::iter::repeat_n(self.indent_char, depth * self.indent_width).collect()
+    }
+}
+
+impl Default for PrettyOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Serialises a [`TeiDocument`] as indented TEI XML.
+///
+/// With the default options the output parses back to the same document.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Xml`] in the same cases as [`emit_xml`].
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::TeiDocument;
+/// use tei_xml::{PrettyOptions, emit_xml_pretty};
+///
+/// let document = TeiDocument::from_title_str("Wolf 359")?;
+/// let xml = emit_xml_pretty(&document, PrettyOptions::new())?;
+///
+/// assert_eq!(
+///     xml,
+///     concat!(
+///         "<TEI>\n",
+///         "  <teiHeader>\n",
+///         "    <fileDesc>\n",
+///         "      <title>Wolf 359</title>\n",
+///         "    </fileDesc>\n",
+///         "  </teiHeader>\n",
+///         "  <text>\n",
+///         "    <body/>\n",
+///         "  </text>\n",
+///         "</TEI>",
+///     ),
+/// );
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn emit_xml_pretty(document: &TeiDocument, options: PrettyOptions) -> Result<String, TeiError> {
+    reflow(&emit_xml(document)?, options)
+}
+
+/// Re-indents canonical markup according to `options`.
+fn reflow(xml: &str, options: PrettyOptions) -> Result<String, TeiError> {
+    let nodes = read_nodes(xml)?;
+    let mut writer = Writer::new(Vec::new());
+
+    for node in &nodes {
+        write_node(&mut writer, node, 0, options)?;
+    }
+    String::from_utf8(writer.into_inner()).map_err(|error| TeiError::xml(error.to_string()))
+}
+
+/// Minimal element tree built from the canonical markup.
+enum Node {
+    Element {
+        start: BytesStart<'static>,
+        children: Vec<Self>,
+    },
+    Leaf(Event<'static>),
+}
+
+impl Node {
+    const fn is_text(&self) -> bool {
+        matches!(self, Self::Leaf(Event::Text(_) | Event::CData(_)))
+    }
+}
+
+fn read_nodes(xml: &str) -> Result<Vec<Node>, TeiError> {
+    let mut reader = Reader::from_str(xml);
+    let mut stack: Vec<(BytesStart<'static>, Vec<Node>)> = Vec::new();
+    let mut roots = Vec::new();
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|error| TeiError::xml(error.to_string()))?
+            .into_owned();
+        let node = match event {
+            Event::Eof => return Ok(roots),
+            Event::Start(start) => {
+                stack.push((start, Vec::new()));
+                continue;
+            }
+            Event::End(_) => {
+                let (start, children) = stack
+                    .pop()
+                    .ok_or_else(|| TeiError::xml("unbalanced end tag in emitted markup"))?;
+                Node::Element { start, children }
+            }
+            other => Node::Leaf(other),
+        };
+        match stack.last_mut() {
+            Some((_, children)) => children.push(node),
+            None => roots.push(node),
+        }
+    }
+}
+
+fn write_node(
+    writer: &mut Writer<Vec<u8>>,
+    node: &Node,
+    depth: usize,
+    options: PrettyOptions,
+) -> Result<(), TeiError> {
+    if depth > 0 {
+        let indent = options.indent(depth);
+        writer.get_mut().push(b'\n');
+        writer.get_mut().extend_from_slice(indent.as_bytes());
+    }
+
+    let Node::Element { start, children } = node else {
+        return write_inline(writer, node);
+    };
+    let has_text = children.iter().any(Node::is_text);
+    let text_only = children.len() == 1 && has_text;
+    if text_only || (has_text && options.preserve_mixed_content) {
+        return write_inline(writer, node);
+    }
+
+    write_event(writer, Event::Start(start.borrow()))?;
+    for child in children {
+        write_node(writer, child, depth + 1, options)?;
+    }
+    let indent = options.indent(depth);
+    writer.get_mut().push(b'\n');
+    writer.get_mut().extend_from_slice(indent.as_bytes());
+    write_event(writer, Event::End(end_of(start)))
+}
+
+fn write_inline(writer: &mut Writer<Vec<u8>>, node: &Node) -> Result<(), TeiError> {
+    match node {
+        Node::Leaf(event) => write_event(writer, event.borrow()),
+        Node::Element { start, children } => {
+            write_event(writer, Event::Start(start.borrow()))?;
+            for child in children {
+                write_inline(writer, child)?;
+            }
+            write_event(writer, Event::End(end_of(start)))
+        }
+    }
+}
+
+fn end_of<'a>(start: &'a BytesStart<'_>) -> BytesEnd<'a> {
+    BytesEnd::from(start.name())
+}
+
+fn write_event(writer: &mut Writer<Vec<u8>>, event: Event<'_>) -> Result<(), TeiError> {
+    writer
+        .write_event(event)
+        .map_err(|error| TeiError::xml(error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_xml;
+    use tei_core::{TeiText, Utterance};
+
+    const MIXED: &str = concat!(
+        "<TEI><text><body>",
+        "<u who=\"eiffel\">Day 1.</u>",
+        "<p>Previously on <hi>Wolf 359</hi></p>",
+        "</body></text></TEI>",
+    );
+
+    #[test]
+    fn keeps_mixed_content_on_one_line() {
+        let xml =
+            reflow(MIXED, PrettyOptions::new().with_indent('\t', 1)).expect("markup should reflow");
+
+        assert!(
+            xml.contains("\n\t\t\t<u who=\"eiffel\">Day 1.</u>\n"),
+            "{xml}"
+        );
+        assert!(
+            xml.contains("\n\t\t\t<p>Previously on <hi>Wolf 359</hi></p>\n"),
+            "{xml}"
+        );
+    }
+
+    #[test]
+    fn can_break_mixed_content_across_lines() {
+        let xml = reflow(MIXED, PrettyOptions::new().preserve_mixed_content(false))
+            .expect("markup should reflow");
+
+        assert!(
+            xml.contains("<p>\n        Previously on \n        <hi>Wolf 359</hi>\n      </p>"),
+            "{xml}"
+        );
+    }
+
+    #[test]
+    fn pretty_output_parses_back() {
+        let mut document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        let mut text = TeiText::empty();
+        text.push_utterance(
+            Utterance::from_text_segments(Some("eiffel"), ["Day 1."]).expect("valid utterance"),
+        );
+        *document.text_mut() = text;
+        let xml =
+            emit_xml_pretty(&document, PrettyOptions::default()).expect("document should emit");
+
+        assert_eq!(
+            parse_xml(&xml).expect("pretty output should parse"),
+            document
+        );
+    }
+}
@@ -0,0 +1,307 @@
+//! Verbatim round trips for elements the data model does not cover.
+//!
+//! Under [`UnknownElements::Preserve`], each unknown child of `<body>`, `<p>`,
+//! `<u>` or `<hi>` is replaced before deserialisation by a `<raw>`
+//! placeholder carrying its original markup, which `tei-core` reads as
+//! [`RawMarkup`](tei_core::RawMarkup). Emission swaps each placeholder back
+//! for the stored markup once everything else has been written, so attribute
+//! ordering and the other rewrites never touch it.
+//!
+//! Placeholders are trusted only when [`prepare`] wrote them. A `<raw>`
+//! element arriving in the input is rejected under
+//! [`UnknownElements::Reject`] and preserved verbatim like any other unknown
+//! element under [`UnknownElements::Preserve`], so its `markup` attribute is
+//! never emitted unescaped.
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+use tei_core::{SourceLocation, TeiError};
+
+use crate::lenient::vocabulary;
+use crate::{escape_xml_attr, locate};
+
+/// How [`parse_xml_with_options`](crate::parse_xml_with_options) treats
+/// elements inside the body that the data model does not cover.
+///
+/// # Examples
+///
+/// ```
+/// use tei_xml::{ParseOptions, UnknownElements};
+///
+/// let options = ParseOptions::new().with_unknown_elements(UnknownElements::Preserve);
+/// assert_eq!(options.unknown_elements(), UnknownElements::Preserve);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum UnknownElements {
+    /// Fail on them, as [`parse_xml`](crate::parse_xml) does.
+    #[default]
+    Reject,
+    /// Keep each one as [`RawMarkup`](tei_core::RawMarkup), to be emitted
+    /// exactly as it was read.
+    Preserve,
+}
+
+/// Element name of the placeholders standing in for raw markup.
+const PLACEHOLDER: &[u8] = b"raw";
+
+/// Markup with unknown elements replaced by placeholders.
+pub(crate) struct Preserved<'a> {
+    pub(crate) markup: Cow<'a, str>,
+    anchors: Vec<(usize, usize)>,
+}
+
+impl Preserved<'_> {
+    /// Points an error raised on the rewritten markup back at `original`.
+    pub(crate) fn relocate(&self, original: &str, error: TeiError) -> TeiError {
+        locate::relocate(&self.anchors, original, error)
+    }
+}
+
+/// Applies `policy` to the unknown elements in `xml`.
+///
+/// Markup that is not well-formed is left for the parser to report.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Xml`] when `policy` rejects unknown elements and `xml`
+/// holds a `<raw>` element of its own.
+pub(crate) fn prepare(xml: &str, policy: UnknownElements) -> Result<Preserved<'_>, TeiError> {
+    let found = match policy {
+        UnknownElements::Reject => {
+            reject_placeholders(xml)?;
+            None
+        }
+        UnknownElements::Preserve => unknown_spans(xml).filter(|spans| !spans.is_empty()),
+    };
+    let Some(spans) = found else {
+        return Ok(Preserved {
+            markup: Cow::Borrowed(xml),
+            anchors: Vec::new(),
+        });
+    };
+
+    let mut markup = String::with_capacity(xml.len() + spans.len() * "<raw markup=\"\"/>".len());
+    let mut anchors = Vec::with_capacity(spans.len());
+    let mut copied = 0;
+    for span in spans {
+        markup.push_str(xml.get(copied..span.start).unwrap_or_default());
+        markup.push_str("<raw markup=\"");
+        markup.push_str(&escape_xml_attr(xml.get(span.clone()).unwrap_or_default()));
+        markup.push_str("\"/>");
+        anchors.push((markup.len(), span.end));
+        copied = span.end;
+    }
+    markup.push_str(xml.get(copied..).unwrap_or_default());
+    Ok(Preserved {
+        markup: Cow::Owned(markup),
+        anchors,
+    })
+}
+
+/// Fails when `xml` holds a `<raw>` element, which only [`prepare`] may
+/// write.
+///
+/// Markup that is not well-formed is left for the parser to report.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Xml`] located at the first `<raw>` element.
+pub(crate) fn reject_placeholders(xml: &str) -> Result<(), TeiError> {
+    placeholder_offset(xml).map_or(Ok(()), |offset| {
+        Err(reserved_element(SourceLocation::new().with_offset(offset)))
+    })
+}
+
+/// Returns the byte offset of the first `<raw>` element in `xml`.
+pub(crate) fn placeholder_offset(xml: &str) -> Option<usize> {
+    if !xml.contains("raw") {
+        return None;
+    }
+    let mut reader = Reader::from_str(xml);
+    loop {
+        let start = offset(&reader);
+        match reader.read_event().ok()? {
+            Event::Eof => return None,
+            Event::Start(tag) | Event::Empty(tag) if is_placeholder(&tag) => return Some(start),
+            _ => {}
+        }
+    }
+}
+
+/// Builds the error reported for a `<raw>` element in the input.
+pub(crate) fn reserved_element(location: SourceLocation) -> TeiError {
+    TeiError::xml_at(
+        "element <raw> is reserved for preserved markup and cannot appear in the input",
+        location,
+    )
+}
+
+fn is_placeholder(tag: &BytesStart<'_>) -> bool {
+    tag.local_name().as_ref() == PLACEHOLDER
+}
+
+/// Returns the byte ranges of the elements the data model does not accept
+/// where they appear; `None` when the markup is not well-formed.
+fn unknown_spans(xml: &str) -> Option<Vec<Range<usize>>> {
+    let mut reader = Reader::from_str(xml);
+    let mut open: Vec<Vec<u8>> = Vec::new();
+    let mut spans = Vec::new();
+
+    loop {
+        let start = offset(&reader);
+        match reader.read_event().ok()? {
+            Event::Eof => return Some(spans),
+            Event::Start(tag) if is_unknown(&open, &tag) => {
+                reader.read_to_end(tag.name()).ok()?;
+                spans.push(start..offset(&reader));
+            }
+            Event::Empty(tag) if is_unknown(&open, &tag) => spans.push(start..offset(&reader)),
+            Event::Start(tag) => open.push(tag.local_name().as_ref().to_vec()),
+            Event::End(_) => {
+                open.pop();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Reports whether `tag` must be preserved: input `<raw>` elements always
+/// are, so they can never be read as placeholders.
+fn is_unknown(open: &[Vec<u8>], tag: &BytesStart<'_>) -> bool {
+    is_placeholder(tag)
+        || open
+            .last()
+            .and_then(|parent| vocabulary::children(parent))
+            .is_some_and(|allowed| !allowed.contains(&tag.local_name().as_ref()))
+}
+
+/// Replaces each `<raw>` placeholder in serialised `markup` with the markup
+/// it stores.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Xml`] when stored markup is not well-formed.
+pub(crate) fn restore(markup: String) -> Result<String, TeiError> {
+    if !markup.contains("<raw ") {
+        return Ok(markup);
+    }
+
+    let mut reader = Reader::from_str(&markup);
+    let mut output = String::with_capacity(markup.len());
+    let mut copied = 0;
+    loop {
+        let start = offset(&reader);
+        let stored = match reader
+            .read_event()
+            .map_err(|error| TeiError::xml(error.to_string()))?
+        {
+            Event::Eof => break,
+            Event::Empty(tag) if tag.name().as_ref() == PLACEHOLDER => stored_markup(&tag)?,
+            Event::Start(tag) if tag.name().as_ref() == PLACEHOLDER => {
+                reader
+                    .read_to_end(tag.name())
+                    .map_err(|error| TeiError::xml(error.to_string()))?;
+                stored_markup(&tag)?
+            }
+            _ => continue,
+        };
+        ensure_well_formed(&stored)?;
+        output.push_str(markup.get(copied..start).unwrap_or_default());
+        output.push_str(&stored);
+        copied = offset(&reader);
+    }
+    output.push_str(markup.get(copied..).unwrap_or_default());
+    Ok(output)
+}
+
+fn stored_markup(tag: &BytesStart<'_>) -> Result<String, TeiError> {
+    let attribute = tag
+        .try_get_attribute("markup")
+        .map_err(|error| TeiError::xml(error.to_string()))?
+        .ok_or_else(|| TeiError::xml("raw placeholder is missing its markup"))?;
+    Ok(attribute
+        .unescape_value()
+        .map_err(|error| TeiError::xml(error.to_string()))?
+        .into_owned())
+}
+
+fn ensure_well_formed(stored: &str) -> Result<(), TeiError> {
+    let mut reader = Reader::from_str(stored);
+    let mut depth = 0_usize;
+    let closed = loop {
+        match reader.read_event() {
+            Ok(Event::Eof) => break depth == 0,
+            Ok(Event::Start(_)) => depth += 1,
+            Ok(Event::End(_)) if depth > 0 => depth -= 1,
+            Ok(Event::Text(_) | Event::CData(_) | Event::Comment(_) | Event::Empty(_)) => {}
+            Ok(_) | Err(_) => break false,
+        }
+    };
+    if closed {
+        Ok(())
+    } else {
+        Err(TeiError::xml(format!(
+            "raw markup `{stored}` is not well-formed XML"
+        )))
+    }
+}
+
+fn offset(reader: &Reader<&[u8]>) -> usize {
+    usize::try_from(reader.buffer_position()).unwrap_or(usize::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("<p>Then <gap/> later.</p>", &["<gap/>"])]
+    #[case(
+        "<body><div><p>Inside.</p></div><p>Out.</p></body>",
+        &["<div><p>Inside.</p></div>"]
+    )]
+    #[case("<u>A <hi>b <foreign>c</foreign></hi> <pause/></u>", &["<foreign>c</foreign>"])]
+    #[case("<teiHeader><fileDesc><note/></fileDesc></teiHeader>", &[])]
+    fn finds_unknown_elements(#[case] xml: &str, #[case] expected: &[&str]) {
+        let spans = unknown_spans(xml).expect("markup is well-formed");
+
+        let found: Vec<&str> = spans.into_iter().filter_map(|span| xml.get(span)).collect();
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn restores_the_stored_markup() {
+        let prepared = prepare(
+            "<p>a <gap reason=\"x &amp; y\"/></p>",
+            UnknownElements::Preserve,
+        )
+        .expect("markup should prepare");
+
+        assert_eq!(
+            prepared.markup,
+            "<p>a <raw markup=\"&lt;gap reason=&quot;x &amp;amp; y&quot;/&gt;\"/></p>"
+        );
+        assert_eq!(
+            restore(prepared.markup.into_owned()).expect("markup should restore"),
+            "<p>a <gap reason=\"x &amp; y\"/></p>"
+        );
+    }
+
+    #[rstest]
+    #[case("<gap>")]
+    #[case("</gap>")]
+    #[case("<a><b></a>")]
+    #[case("<?xml version=\"1.0\"?><gap/>")]
+    fn rejects_stored_markup_that_is_not_well_formed(#[case] stored: &str) {
+        let markup = format!("<p><raw markup=\"{}\"/></p>", escape_xml_attr(stored));
+
+        let error = restore(markup).expect_err("malformed markup must fail");
+
+        assert!(error.to_string().contains("not well-formed"), "{error}");
+    }
+}
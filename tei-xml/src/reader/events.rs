@@ -0,0 +1,95 @@
+//! Event plumbing shared by the reader's header and body stages.
+
+use std::io::BufRead;
+
+use quick_xml::Writer;
+use quick_xml::de;
+use quick_xml::events::{BytesStart, Event};
+use tei_core::{SourceLocation, TeiError, TeiPath};
+
+use super::{Stage, TeiReader};
+
+impl<R: BufRead> TeiReader<R> {
+    /// Copies the subtree opened by `start` into a standalone XML fragment.
+    pub(super) fn capture(&mut self, start: BytesStart<'static>) -> Result<String, TeiError> {
+        let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+        let mut events = vec![Event::Start(start)];
+        let mut depth = 1_usize;
+
+        while depth > 0 {
+            let event = self.next_event()?;
+            match event {
+                Event::Start(_) => depth += 1,
+                Event::End(_) => depth -= 1,
+                Event::Eof => return Err(self.error(&format!("input ended inside <{name}>"))),
+                _ => {}
+            }
+            events.push(event);
+        }
+        write_fragment(events)
+    }
+
+    /// Drains the remaining input so trailing syntax errors still surface.
+    pub(super) fn finish(&mut self) -> Result<(), TeiError> {
+        self.stage = Stage::Finished;
+        while !matches!(self.next_event()?, Event::Eof) {}
+        Ok(())
+    }
+
+    pub(super) fn next_event(&mut self) -> Result<Event<'static>, TeiError> {
+        self.buffer.clear();
+        self.event_offset = self.reader.buffer_position();
+        match self.reader.read_event_into(&mut self.buffer) {
+            Ok(event) => Ok(event.into_owned()),
+            Err(error) => {
+                let location = offset_location(self.reader.error_position());
+                Err(TeiError::xml_at(error.to_string(), location))
+            }
+        }
+    }
+
+    pub(super) fn skip_insignificant(
+        &self,
+        event: &Event<'_>,
+        parent: &str,
+    ) -> Result<(), TeiError> {
+        match event {
+            Event::Text(text) if !text.iter().all(u8::is_ascii_whitespace) => {
+                Err(self.error(&format!("unexpected text inside <{parent}>")))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(super) fn unexpected(&self, start: &BytesStart<'_>, context: &str) -> TeiError {
+        let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+        self.error(&format!("unexpected element <{name}> {context}"))
+    }
+
+    pub(super) fn error(&self, message: &str) -> TeiError {
+        TeiError::xml_at(message, offset_location(self.event_offset))
+    }
+}
+
+pub(super) fn write_fragment<'a>(
+    events: impl IntoIterator<Item = Event<'a>>,
+) -> Result<String, TeiError> {
+    let mut writer = Writer::new(Vec::new());
+    for event in events {
+        writer
+            .write_event(event)
+            .map_err(|error| TeiError::xml(error.to_string()))?;
+    }
+    String::from_utf8(writer.into_inner()).map_err(|error| TeiError::xml(error.to_string()))
+}
+
+pub(super) fn offset_location(offset: u64) -> SourceLocation {
+    usize::try_from(offset).map_or_else(
+        |_| SourceLocation::new(),
+        |value| SourceLocation::new().with_offset(value),
+    )
+}
+
+pub(super) fn located(error: &de::DeError, offset: u64, path: TeiPath) -> TeiError {
+    TeiError::xml_at(error.to_string(), offset_location(offset).with_path(path))
+}
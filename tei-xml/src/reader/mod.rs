@@ -0,0 +1,376 @@
+//! Streaming pull parser for TEI documents.
+//!
+//! [`TeiReader`] reads from any [`io::Read`] source and hands back the header
+//! followed by body blocks one at a time, so multi-hour transcripts never need
+//! to be buffered in full. Each element is still validated by the same serde
+//! model that [`parse_xml`](crate::parse_xml) uses: the reader copies one
+//! header or block subtree at a time and deserialises just that fragment.
+
+use std::io::{self, BufRead, BufReader};
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, de};
+use tei_core::{BodyBlock, P, TeiError, TeiHeader, TeiPath, Timeline, Utterance};
+
+use crate::{namespace::check_root, raw, whitespace};
+
+mod events;
+
+use events::{located, offset_location, write_fragment};
+
+/// Pull parser yielding a document's header and then its body blocks.
+///
+/// Call [`TeiReader::header`] to read the `<teiHeader>`, then iterate the
+/// reader (or call [`TeiReader::next_block`]) to receive each `<p>` or `<u>`.
+/// Iteration reads the header first if it has not been requested yet. A
+/// `<timeline>` ahead of the body is available from [`TeiReader::timeline`]
+/// once the first block has been requested.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{BodyBlock, TeiError};
+/// use tei_xml::reader::TeiReader;
+///
+/// let xml = concat!(
+///     "<TEI><teiHeader><fileDesc><title>Wolf 359</title></fileDesc></teiHeader>",
+///     "<text><body><p>Previously.</p><u who=\"eiffel\">Day 1.</u></body></text></TEI>",
+/// );
+/// let mut reader = TeiReader::new(xml.as_bytes());
+///
+/// assert_eq!(reader.header()?.file_desc().title().as_str(), "Wolf 359");
+/// let blocks = reader.collect::<Result<Vec<_>, TeiError>>()?;
+/// assert!(matches!(blocks.as_slice(), [BodyBlock::Paragraph(_), BodyBlock::Utterance(_)]));
+/// # Ok::<(), TeiError>(())
+/// ```
+pub struct TeiReader<R> {
+    reader: Reader<R>,
+    buffer: Vec<u8>,
+    header: Option<TeiHeader>,
+    timeline: Option<Timeline>,
+    stage: Stage,
+    event_offset: u64,
+    paragraphs: usize,
+    utterances: usize,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Stage {
+    BeforeHeader,
+    BeforeBody,
+    InBody,
+    Finished,
+}
+
+impl<R: io::Read> TeiReader<BufReader<R>> {
+    /// Creates a reader over an unbuffered source.
+    #[must_use]
+    pub fn new(source: R) -> Self {
+        Self::from_buf_read(BufReader::new(source))
+    }
+}
+
+impl<R: BufRead> TeiReader<R> {
+    /// Creates a reader over a source that is already buffered.
+    #[must_use]
+    pub fn from_buf_read(source: R) -> Self {
+        Self {
+            reader: Reader::from_reader(source),
+            buffer: Vec::new(),
+            header: None,
+            timeline: None,
+            stage: Stage::BeforeHeader,
+            event_offset: 0,
+            paragraphs: 0,
+            utterances: 0,
+        }
+    }
+
+    /// Returns the document header, reading it on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TeiError::Xml`] when the input is malformed, lacks a
+    /// `<teiHeader>`, or the header fails validation. The error carries the
+    /// byte offset where the problem was found.
+    pub fn header(&mut self) -> Result<&TeiHeader, TeiError> {
+        let header = match self.header.take() {
+            Some(existing) => existing,
+            None => self.read_header()?,
+        };
+        Ok(self.header.insert(header))
+    }
+
+    /// Consumes the reader and returns the header, reading it if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`TeiReader::header`].
+    pub fn into_header(mut self) -> Result<TeiHeader, TeiError> {
+        self.header.take().map_or_else(|| self.read_header(), Ok)
+    }
+
+    /// Returns the `<timeline>` read ahead of the body, if the document has
+    /// one and the reader has reached the body.
+    #[must_use]
+    pub const fn timeline(&self) -> Option<&Timeline> {
+        self.timeline.as_ref()
+    }
+
+    /// Reads the next body block, or `None` once `</body>` is reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TeiError::Xml`] when the input is malformed, contains
+    /// elements outside the profiled structure, or a block fails validation.
+    /// Block errors carry the block's offset and path, such as
+    /// `/TEI/text/body/u[3]`.
+    pub fn next_block(&mut self) -> Result<Option<BodyBlock>, TeiError> {
+        if self.header.is_none() {
+            let header = self.read_header()?;
+            self.header = Some(header);
+        }
+        if self.stage == Stage::BeforeBody {
+            self.enter_body()?;
+        }
+
+        while self.stage == Stage::InBody {
+            match self.next_event()? {
+                Event::Start(start) => return self.read_block(start, false).map(Some),
+                Event::Empty(start) => return self.read_block(start, true).map(Some),
+                Event::End(_) => self.finish()?,
+                Event::Eof => return Err(self.error("input ended inside <body>")),
+                other => self.skip_insignificant(&other, "body")?,
+            }
+        }
+        Ok(None)
+    }
+
+    fn read_header(&mut self) -> Result<TeiHeader, TeiError> {
+        loop {
+            match self.next_event()? {
+                Event::Start(start) if start.local_name().as_ref() == b"TEI" => {
+                    check_root(&start).map_err(|message| self.error(&message))?;
+                }
+                Event::Start(start) if start.local_name().as_ref() == b"teiHeader" => {
+                    return self.read_header_element(start);
+                }
+                Event::Start(start) | Event::Empty(start) => {
+                    return Err(self.unexpected(&start, "before <teiHeader>"));
+                }
+                Event::Eof => return Err(self.error("missing field `teiHeader`")),
+                other => self.skip_insignificant(&other, "TEI")?,
+            }
+        }
+    }
+
+    fn read_header_element(&mut self, start: BytesStart<'static>) -> Result<TeiHeader, TeiError> {
+        let offset = self.event_offset;
+        let fragment = self.capture(start)?;
+        let path = TeiPath::root().child("teiHeader");
+        let header = de::from_str(&fragment).map_err(|error| located(&error, offset, path))?;
+        self.stage = Stage::BeforeBody;
+        Ok(header)
+    }
+
+    fn enter_body(&mut self) -> Result<(), TeiError> {
+        loop {
+            match self.next_event()? {
+                Event::Start(start) if start.local_name().as_ref() == b"text" => {}
+                Event::Start(start) if start.local_name().as_ref() == b"body" => {
+                    self.stage = Stage::InBody;
+                    return Ok(());
+                }
+                Event::Empty(start) if start.local_name().as_ref() == b"body" => {
+                    return self.finish();
+                }
+                Event::Start(start)
+                    if start.local_name().as_ref() == b"timeline" && self.timeline.is_none() =>
+                {
+                    self.read_timeline(start)?;
+                }
+                Event::Start(start) | Event::Empty(start) => {
+                    return Err(self.unexpected(&start, "before <body>"));
+                }
+                Event::Eof | Event::End(_) => return Err(self.error("missing field `body`")),
+                other => self.skip_insignificant(&other, "text")?,
+            }
+        }
+    }
+
+    fn read_timeline(&mut self, start: BytesStart<'static>) -> Result<(), TeiError> {
+        let offset = self.event_offset;
+        let fragment = self.capture(start)?;
+        let path = TeiPath::root().child("text").child("timeline");
+        let timeline = de::from_str(&fragment).map_err(|error| located(&error, offset, path))?;
+        self.timeline = Some(timeline);
+        Ok(())
+    }
+
+    fn read_block(
+        &mut self,
+        start: BytesStart<'static>,
+        empty: bool,
+    ) -> Result<BodyBlock, TeiError> {
+        let offset = self.event_offset;
+        let body = TeiPath::root().child("text").child("body");
+        let (path, is_paragraph) = match start.local_name().as_ref() {
+            b"p" => {
+                self.paragraphs += 1;
+                (body.nth_child("p", self.paragraphs), true)
+            }
+            b"u" => {
+                self.utterances += 1;
+                (body.nth_child("u", self.utterances), false)
+            }
+            _ => return Err(self.unexpected(&start, "inside <body>")),
+        };
+        let fragment = if empty {
+            write_fragment([Event::Empty(start)])?
+        } else {
+            self.capture(start)?
+        };
+        if raw::placeholder_offset(&fragment).is_some() {
+            return Err(raw::reserved_element(
+                offset_location(offset).with_path(path),
+            ));
+        }
+        let markup = whitespace::protect(&fragment, false)?;
+        let block = if is_paragraph {
+            de::from_str::<P>(&markup).map(BodyBlock::Paragraph)
+        } else {
+            de::from_str::<Utterance>(&markup).map(BodyBlock::Utterance)
+        };
+        block.map_err(|error| located(&error, offset, path))
+    }
+}
+
+impl<R: BufRead> Iterator for TeiReader<R> {
+    type Item = Result<BodyBlock, TeiError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stage == Stage::Finished {
+            return None;
+        }
+
+        let item = self.next_block().transpose();
+        if matches!(item, Some(Err(_))) {
+            self.stage = Stage::Finished;
+        }
+        item
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tei_core::SourceLocation;
+
+    use super::*;
+    use crate::parse_xml;
+
+    const DOCUMENT: &str = concat!(
+        "<?xml version=\"1.0\"?>\n",
+        "<TEI>\n  <teiHeader><fileDesc><title>Wolf 359</title></fileDesc></teiHeader>\n",
+        "  <text>\n    <body>\n",
+        "      <p>Previously on Wolf 359.</p>\n",
+        "      <u xml:id=\"u1\" who=\"eiffel\">Day 1.</u>\n",
+        "      <u who=\"hera\">Good morning, Eiffel.</u>\n",
+        "    </body>\n  </text>\n</TEI>\n",
+    );
+
+    fn stream(xml: &str) -> Result<(TeiHeader, Vec<BodyBlock>), TeiError> {
+        let mut reader = TeiReader::new(xml.as_bytes());
+        let header = reader.header()?.clone();
+        let blocks = reader.collect::<Result<Vec<_>, _>>()?;
+        Ok((header, blocks))
+    }
+
+    #[test]
+    fn streams_the_same_content_as_parse_xml() {
+        let document = parse_xml(DOCUMENT).expect("fixture should parse");
+        let (header, blocks) = stream(DOCUMENT).expect("fixture should stream");
+
+        assert_eq!(&header, document.header());
+        assert_eq!(
+            blocks.iter().collect::<Vec<_>>(),
+            document.text().body().blocks().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn iterating_reads_the_header_implicitly() {
+        let blocks = TeiReader::new(DOCUMENT.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("fixture should stream");
+
+        assert_eq!(blocks.len(), 3);
+    }
+
+    #[test]
+    fn empty_body_yields_no_blocks() {
+        let xml = "<TEI><teiHeader><fileDesc><title>T</title></fileDesc></teiHeader><text><body/></text></TEI>";
+
+        let (_, blocks) = stream(xml).expect("empty body should stream");
+
+        assert!(blocks.is_empty());
+    }
+
+    #[test]
+    fn invalid_blocks_report_their_path() {
+        let xml = concat!(
+            "<TEI><teiHeader><fileDesc><title>T</title></fileDesc></teiHeader>",
+            "<text><body><u>Hi</u><u who=\"  \">Bye</u></body></text></TEI>",
+        );
+
+        let error = stream(xml).expect_err("blank utterance should fail");
+
+        assert_eq!(
+            error
+                .location()
+                .and_then(SourceLocation::path)
+                .map(ToString::to_string),
+            Some("/TEI/text/body/u[2]".to_owned())
+        );
+        assert_eq!(
+            error.location().and_then(SourceLocation::offset),
+            xml.find("<u who")
+        );
+    }
+
+    #[test]
+    fn raw_elements_in_blocks_are_rejected() {
+        let xml = concat!(
+            "<TEI><teiHeader><fileDesc><title>T</title></fileDesc></teiHeader>",
+            "<text><body><p>a <raw markup=\"&lt;b/&gt;\"/></p></body></text></TEI>",
+        );
+
+        let error = stream(xml).expect_err("raw element should fail");
+
+        assert!(error.to_string().contains("reserved"), "{error}");
+        assert_eq!(
+            error.location().and_then(SourceLocation::offset),
+            xml.find("<p>")
+        );
+    }
+
+    #[test]
+    fn missing_header_is_reported() {
+        let error = TeiReader::new(b"<TEI><text><body/></text></TEI>".as_slice())
+            .into_header()
+            .expect_err("header is required");
+
+        assert!(
+            error.to_string().contains("unexpected element <text>"),
+            "error should name the stray element, found {error}"
+        );
+    }
+
+    #[test]
+    fn stops_after_the_first_error() {
+        let xml = "<TEI><teiHeader><fileDesc><title>T</title></fileDesc></teiHeader><text><body><q/></body></text></TEI>";
+        let mut reader = TeiReader::new(xml.as_bytes());
+
+        assert!(matches!(reader.next(), Some(Err(_))));
+        assert!(reader.next().is_none());
+    }
+}
@@ -0,0 +1,86 @@
+//! Conversion between RSS `pubDate` values and ISO calendar dates.
+//!
+//! RSS dates follow RFC 822, as in `Wed, 01 May 2024 08:00:00 +0000`. Only
+//! the calendar date survives the trip into the header, since
+//! `<publicationStmt>` dates are ISO days; the time of day and zone are
+//! dropped on import and written as midnight UTC on export.
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// Reads the calendar date of an RFC 822 `value` as `YYYY-MM-DD`.
+pub(super) fn iso_from_rfc822(value: &str) -> Option<String> {
+    let dated = value
+        .split_once(',')
+        .map_or(value, |(_, rest)| rest)
+        .trim_start();
+    let mut fields = dated.split_whitespace();
+    let day: u32 = fields.next()?.parse().ok()?;
+    let month_name = fields.next()?;
+    let year: u32 = fields.next().filter(|year| year.len() == 4)?.parse().ok()?;
+    let month = MONTHS
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(month_name))?
+        + 1;
+    (1..=31)
+        .contains(&day)
+        .then(|| format!("{year:04}-{month:02}-{day:02}"))
+}
+
+/// Writes an ISO `YYYY-MM-DD` date as an RFC 822 date at midnight UTC, or
+/// `None` when the date lacks a day.
+pub(super) fn rfc822_from_iso(value: &str) -> Option<String> {
+    let mut fields = value.split('-');
+    let year: u32 = fields.next()?.parse().ok()?;
+    let month: usize = fields.next()?.parse().ok()?;
+    let day: u32 = fields.next()?.parse().ok()?;
+    if fields.next().is_some() {
+        return None;
+    }
+    let month_name = MONTHS.get(month.checked_sub(1)?)?;
+    let weekday = WEEKDAYS.get(weekday(year, month, day)?)?;
+    Some(format!(
+        "{weekday}, {day:02} {month_name} {year:04} 00:00:00 +0000"
+    ))
+}
+
+/// Returns the day of the week, from Sunday as zero, by Sakamoto's method.
+fn weekday(year: u32, month: usize, day: u32) -> Option<usize> {
+    const OFFSETS: [u32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let shifted = if month < 3 {
+        year.checked_sub(1)?
+    } else {
+        year
+    };
+    let leap_days =
+        shifted.checked_div(4)? - shifted.checked_div(100)? + shifted.checked_div(400)?;
+    let total = shifted + leap_days + OFFSETS.get(month.checked_sub(1)?)? + day;
+    usize::try_from(total.checked_rem(7)?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("Wed, 01 May 2024 08:00:00 +0000", Some("2024-05-01"))]
+    #[case("1 may 2024 23:59:59 GMT", Some("2024-05-01"))]
+    #[case("Thu, 29 Feb 2024 00:00:00 EST", Some("2024-02-29"))]
+    #[case("Wed, 01 Mai 2024 08:00:00 +0000", None)]
+    #[case("2024-05-01", None)]
+    fn reads_rfc822_dates(#[case] value: &str, #[case] expected: Option<&str>) {
+        assert_eq!(iso_from_rfc822(value).as_deref(), expected);
+    }
+
+    #[rstest]
+    #[case("2024-05-01", Some("Wed, 01 May 2024 00:00:00 +0000"))]
+    #[case("2000-02-29", Some("Tue, 29 Feb 2000 00:00:00 +0000"))]
+    #[case("2024-05", None)]
+    fn writes_rfc822_dates(#[case] value: &str, #[case] expected: Option<&str>) {
+        assert_eq!(rfc822_from_iso(value).as_deref(), expected);
+    }
+}
@@ -0,0 +1,342 @@
+//! Header metadata from and for podcast RSS feeds.
+//!
+//! Producers already maintain episode metadata in their feed, so
+//! [`file_desc_from_item`] reads an RSS `<item>` into a [`FileDesc`]:
+//!
+//! - `<title>` becomes the title;
+//! - `<guid>` becomes an `<idno type="guid">`;
+//! - `<itunes:author>` becomes the publisher, falling back to the series
+//!   title, and the calendar date of `<pubDate>` the publication date;
+//! - `<itunes:season>` and `<itunes:episode>` number the episode within a
+//!   `<seriesStmt>` named after the series;
+//! - `<itunes:duration>` becomes the extent's running time;
+//! - `<enclosure>` becomes the `<media>` of the source description.
+//!
+//! [`item_from_file_desc`] writes the same fields back as an `<item>`
+//! snippet for pasting into a feed, which must declare the `itunes`
+//! namespace.
+
+mod date;
+
+use std::time::Duration;
+
+use serde::Deserialize;
+use tei_core::{
+    Extent, FileDesc, Idno, IdnoType, Media, PublicationStmt, SeriesStmt, SourceDesc,
+    SourceLocation, TeiError, TeiPath,
+};
+
+use crate::{escape_xml_attr, escape_xml_text, locate};
+
+const FORMAT: &str = "RSS";
+
+/// The fields of an `<item>` read into the header.
+///
+/// The deserializer matches elements by local name, so the `itunes:`
+/// elements are named without their prefix.
+#[derive(Deserialize)]
+struct Item {
+    title: String,
+    #[serde(default)]
+    guid: Option<Guid>,
+    #[serde(rename = "pubDate", default)]
+    pub_date: Option<String>,
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(default)]
+    duration: Option<String>,
+    #[serde(default)]
+    season: Option<u32>,
+    #[serde(default)]
+    episode: Option<u32>,
+    #[serde(default)]
+    enclosure: Option<Enclosure>,
+}
+
+#[derive(Deserialize)]
+struct Guid {
+    #[serde(rename = "$text", default)]
+    value: String,
+}
+
+#[derive(Deserialize)]
+struct Enclosure {
+    #[serde(rename = "@url")]
+    url: String,
+    #[serde(rename = "@type")]
+    mime_type: String,
+}
+
+/// Builds a file description from the RSS `<item>` markup in `item`, for an
+/// episode of the series titled `series`.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Xml`] when the markup is not well-formed or lacks a
+/// `<title>`, and [`TeiError::Import`] when `<pubDate>` or
+/// `<itunes:duration>` cannot be read. Returns [`TeiError::DocumentTitle`]
+/// or [`TeiError::Header`] when a title, the series, or the enclosure is
+/// blank or invalid.
+///
+/// # Examples
+///
+/// ```
+/// use tei_xml::rss;
+///
+/// let item = r#"<item>
+///   <title>Wolf 359: Succulent</title>
+///   <guid isPermaLink="false">wolf-359-ep12</guid>
+///   <pubDate>Wed, 01 May 2024 08:00:00 +0000</pubDate>
+///   <itunes:episode>12</itunes:episode>
+///   <itunes:duration>1:02:03</itunes:duration>
+///   <enclosure url="https://example.org/ep12.mp3" type="audio/mpeg" length="0"/>
+/// </item>"#;
+/// let file_desc = rss::file_desc_from_item(item, "Wolf 359")?;
+///
+/// assert_eq!(file_desc.title().as_str(), "Wolf 359: Succulent");
+/// assert_eq!(file_desc.series_stmt().and_then(|series| series.episode_number()), Some(12));
+/// assert_eq!(
+///     file_desc.publication_stmt().and_then(|publication| publication.date()),
+///     Some("2024-05-01"),
+/// );
+/// # Ok::<(), tei_core::TeiError>(())
+/// ```
+pub fn file_desc_from_item(item: &str, series: &str) -> Result<FileDesc, TeiError> {
+    let parsed: Item = locate::deserialize(item)?;
+    let mut file_desc = FileDesc::from_title_str(&parsed.title)?;
+
+    let mut series_stmt = SeriesStmt::new(series)?;
+    if let Some(season) = parsed.season {
+        series_stmt = series_stmt.with_season_number(season)?;
+    }
+    if let Some(episode) = parsed.episode {
+        series_stmt = series_stmt.with_episode_number(episode)?;
+    }
+    file_desc = file_desc.with_series_stmt(series_stmt);
+
+    let publisher = parsed
+        .author
+        .as_deref()
+        .filter(|author| !author.trim().is_empty())
+        .unwrap_or(series);
+    let mut publication = PublicationStmt::new(publisher)?;
+    if let Some(published) = parsed.pub_date.as_deref() {
+        let day = date::iso_from_rfc822(published)
+            .ok_or_else(|| failure("pubDate", format!("malformed date `{}`", published.trim())))?;
+        publication = publication.with_date(day);
+    }
+    file_desc = file_desc.with_publication_stmt(publication);
+
+    if let Some(guid) = parsed.guid.filter(|guid| !guid.value.trim().is_empty()) {
+        file_desc.add_idno(Idno::new(IdnoType::Guid, guid.value.trim())?);
+    }
+
+    let running_time = parsed
+        .duration
+        .as_deref()
+        .map(|text| {
+            itunes_duration(text).ok_or_else(|| {
+                failure(
+                    "itunes:duration",
+                    format!("malformed duration `{}`", text.trim()),
+                )
+            })
+        })
+        .transpose()?;
+    if let Some(duration) = running_time {
+        file_desc = file_desc.with_extent(Extent::new().with_duration(duration));
+    }
+    if let Some(enclosure) = parsed.enclosure {
+        let mut media = Media::new(enclosure.url, enclosure.mime_type)?;
+        if let Some(duration) = running_time {
+            media = media.with_duration(duration);
+        }
+        let mut source = SourceDesc::new();
+        source.add_media(media);
+        file_desc = file_desc.with_source_desc(source);
+    }
+    Ok(file_desc)
+}
+
+/// Writes the podcast metadata of `file_desc` as an RSS `<item>` snippet.
+///
+/// Fields the file description lacks are left out. The enclosure is
+/// written with a `length` of zero, since the header does not record the
+/// file size.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{FileDesc, PublicationStmt};
+/// use tei_xml::rss;
+///
+/// let file_desc = FileDesc::from_title_str("Wolf 359: Succulent")?
+///     .with_publication_stmt(PublicationStmt::new("Kinda Evil Genius")?.with_date("2024-05-01"));
+///
+/// assert_eq!(
+///     rss::item_from_file_desc(&file_desc),
+///     "<item>\n  <title>Wolf 359: Succulent</title>\n  \
+///      <pubDate>Wed, 01 May 2024 00:00:00 +0000</pubDate>\n  \
+///      <itunes:author>Kinda Evil Genius</itunes:author>\n</item>\n",
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[must_use]
+pub fn item_from_file_desc(file_desc: &FileDesc) -> String {
+    let mut lines = vec![
+        "<item>".to_owned(),
+        format!(
+            "  <title>{}</title>",
+            escape_xml_text(file_desc.title().as_str())
+        ),
+    ];
+    let guid = file_desc
+        .idnos()
+        .iter()
+        .find(|idno| idno.idno_type() == Some(IdnoType::Guid));
+    if let Some(idno) = guid {
+        lines.push(format!(
+            "  <guid isPermaLink=\"false\">{}</guid>",
+            escape_xml_text(idno.value())
+        ));
+    }
+    if let Some(publication) = file_desc.publication_stmt() {
+        if let Some(published) = publication.date().and_then(date::rfc822_from_iso) {
+            lines.push(format!("  <pubDate>{published}</pubDate>"));
+        }
+        lines.push(format!(
+            "  <itunes:author>{}</itunes:author>",
+            escape_xml_text(publication.publisher())
+        ));
+    }
+    if let Some(series) = file_desc.series_stmt() {
+        let numbers = [
+            ("season", series.season_number()),
+            ("episode", series.episode_number()),
+        ];
+        for (name, number) in numbers {
+            if let Some(value) = number {
+                lines.push(format!("  <itunes:{name}>{value}</itunes:{name}>"));
+            }
+        }
+    }
+    let media = file_desc
+        .source_desc()
+        .and_then(|source| source.media().first());
+    let running_time = media
+        .and_then(Media::duration)
+        .or_else(|| file_desc.extent().and_then(Extent::duration));
+    if let Some(duration) = running_time {
+        lines.push(format!(
+            "  <itunes:duration>{}</itunes:duration>",
+            duration.as_secs()
+        ));
+    }
+    if let Some(recording) = media {
+        lines.push(format!(
+            "  <enclosure url=\"{}\" type=\"{}\" length=\"0\"/>",
+            escape_xml_attr(recording.url()),
+            escape_xml_attr(recording.mime_type())
+        ));
+    }
+    lines.push("</item>".to_owned());
+    lines.join("\n") + "\n"
+}
+
+fn failure(element: &str, message: String) -> TeiError {
+    let path = TeiPath::new().child("item").child(element);
+    TeiError::import_at(FORMAT, message, SourceLocation::new().with_path(path))
+}
+
+/// Reads an `<itunes:duration>` written as seconds, `mm:ss`, or `h:mm:ss`.
+fn itunes_duration(text: &str) -> Option<Duration> {
+    let fields: Vec<&str> = text.trim().split(':').collect();
+    if fields.len() > 3 {
+        return None;
+    }
+    let mut seconds: u64 = 0;
+    for field in fields {
+        if field.is_empty() || !field.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+        let value: u64 = field.parse().ok()?;
+        seconds = seconds.checked_mul(60)?.checked_add(value)?;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const ITEM: &str = r#"<item>
+      <title>Wolf 359: Succulent &amp; Sweet</title>
+      <guid isPermaLink="false">wolf-359-ep12</guid>
+      <pubDate>Wed, 01 May 2024 08:00:00 +0000</pubDate>
+      <itunes:author>Kinda Evil Genius</itunes:author>
+      <itunes:season>1</itunes:season>
+      <itunes:episode>12</itunes:episode>
+      <itunes:duration>3723</itunes:duration>
+      <enclosure url="https://example.org/ep12.mp3?a=1&amp;b=2" type="audio/mpeg" length="1024"/>
+      <description>Not mapped.</description>
+    </item>"#;
+
+    #[test]
+    fn round_trips_item_metadata() {
+        let file_desc = file_desc_from_item(ITEM, "Wolf 359").expect("item should map");
+
+        let media = file_desc
+            .source_desc()
+            .and_then(|source| source.media().first());
+        assert_eq!(
+            media.map(Media::url),
+            Some("https://example.org/ep12.mp3?a=1&b=2")
+        );
+        assert_eq!(
+            file_desc.extent().and_then(Extent::duration),
+            Some(Duration::from_secs(3723))
+        );
+        assert_eq!(
+            item_from_file_desc(&file_desc),
+            "<item>\n\
+             \x20 <title>Wolf 359: Succulent &amp; Sweet</title>\n\
+             \x20 <guid isPermaLink=\"false\">wolf-359-ep12</guid>\n\
+             \x20 <pubDate>Wed, 01 May 2024 00:00:00 +0000</pubDate>\n\
+             \x20 <itunes:author>Kinda Evil Genius</itunes:author>\n\
+             \x20 <itunes:season>1</itunes:season>\n\
+             \x20 <itunes:episode>12</itunes:episode>\n\
+             \x20 <itunes:duration>3723</itunes:duration>\n\
+             \x20 <enclosure url=\"https://example.org/ep12.mp3?a=1&amp;b=2\" \
+             type=\"audio/mpeg\" length=\"0\"/>\n\
+             </item>\n"
+        );
+    }
+
+    #[rstest]
+    #[case("3723", Some(3723))]
+    #[case("62:03", Some(3723))]
+    #[case("1:02:03", Some(3723))]
+    #[case("1:02:03:04", None)]
+    #[case("1h2m", None)]
+    fn reads_itunes_durations(#[case] text: &str, #[case] seconds: Option<u64>) {
+        assert_eq!(itunes_duration(text), seconds.map(Duration::from_secs));
+    }
+
+    #[test]
+    fn rejects_malformed_publication_dates() {
+        let item = "<item><title>Succulent</title><pubDate>soon</pubDate></item>";
+
+        let error = file_desc_from_item(item, "Wolf 359").expect_err("date must be rejected");
+
+        assert!(
+            matches!(error, TeiError::Import { format: FORMAT, .. }),
+            "{error:?}"
+        );
+        let path = error.location().and_then(SourceLocation::path);
+        assert_eq!(
+            path.map(ToString::to_string).as_deref(),
+            Some("/item/pubDate")
+        );
+    }
+}
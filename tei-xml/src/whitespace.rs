@@ -0,0 +1,174 @@
+//! Significant whitespace in text segments.
+//!
+//! The deserialiser trims leading and trailing whitespace from every text
+//! node, which discards pauses a transcriber expressed through spacing. CDATA
+//! sections, and text adjoining them, are never trimmed, so this module
+//! rewrites the text nodes that must keep their whitespace as CDATA before the
+//! document is deserialised. A text node keeps its whitespace when an
+//! enclosing `xml:space="preserve"` is in scope, or when the caller asks for
+//! whitespace to be preserved everywhere; `xml:space="default"` restores
+//! trimming for a subtree.
+
+use std::borrow::Cow;
+
+use quick_xml::Writer;
+use quick_xml::events::{BytesCData, BytesStart, Event};
+use quick_xml::reader::Reader;
+use serde::de::DeserializeOwned;
+use tei_core::TeiError;
+
+use crate::locate;
+
+/// Elements whose content mixes text and inline markup. Whitespace-only text
+/// elsewhere separates elements and is never significant to the data model.
+const MIXED_CONTENT: [&[u8]; 3] = [b"p", b"u", b"hi"];
+
+/// One open element: whether whitespace is preserved and whether it holds
+/// mixed content.
+#[derive(Clone, Copy)]
+struct Scope {
+    preserve: bool,
+    mixed: bool,
+}
+
+/// Deserializes `xml` after protecting its significant whitespace.
+///
+/// Rewriting shifts byte offsets, so failures are located in the caller's
+/// markup whenever it fails the same way.
+pub(crate) fn deserialize<T: DeserializeOwned>(
+    xml: &str,
+    preserve_all: bool,
+) -> Result<T, TeiError> {
+    match protect(xml, preserve_all)? {
+        Cow::Borrowed(markup) => locate::deserialize(markup),
+        Cow::Owned(markup) => locate::deserialize(&markup)
+            .map_err(|error| locate::deserialize::<T>(xml).err().unwrap_or(error)),
+    }
+}
+
+/// Rewrites text that must keep its whitespace as CDATA.
+///
+/// Markup without `xml:space` is returned unchanged unless `preserve_all` is
+/// set, as is markup that is not well-formed, which is left for the parser
+/// to report.
+pub(crate) fn protect(xml: &str, preserve_all: bool) -> Result<Cow<'_, str>, TeiError> {
+    if !preserve_all && !xml.contains("xml:space") {
+        return Ok(Cow::Borrowed(xml));
+    }
+
+    let mut reader = Reader::from_str(xml);
+    let mut writer = Writer::new(Vec::with_capacity(xml.len()));
+    let mut scopes = vec![Scope {
+        preserve: preserve_all,
+        mixed: false,
+    }];
+
+    loop {
+        let Ok(event) = reader.read_event() else {
+            return Ok(Cow::Borrowed(xml));
+        };
+        let parent = scopes.last().copied().unwrap_or(Scope {
+            preserve: preserve_all,
+            mixed: false,
+        });
+        match event {
+            Event::Eof => break,
+            Event::Start(start) => {
+                scopes.push(open(&start, parent));
+                write(&mut writer, Event::Start(start))?;
+            }
+            Event::End(end) => {
+                scopes.pop();
+                write(&mut writer, Event::End(end))?;
+            }
+            Event::Text(text) if parent.preserve => {
+                let Ok(content) = text.unescape() else {
+                    return Ok(Cow::Borrowed(xml));
+                };
+                if parent.mixed || !content.chars().all(char::is_whitespace) {
+                    write_cdata(&mut writer, &content)?;
+                } else {
+                    write(&mut writer, Event::Text(text))?;
+                }
+            }
+            other => write(&mut writer, other)?,
+        }
+    }
+
+    String::from_utf8(writer.into_inner())
+        .map(Cow::Owned)
+        .map_err(|error| TeiError::xml(error.to_string()))
+}
+
+/// Resolves the scope of a start tag; values of `xml:space` other than
+/// `preserve` and `default` inherit the parent's setting.
+fn open(start: &BytesStart<'_>, parent: Scope) -> Scope {
+    let preserve = start
+        .attributes()
+        .flatten()
+        .find(|attribute| attribute.key.as_ref() == b"xml:space")
+        .map_or(parent.preserve, |attribute| {
+            match attribute.value.as_ref() {
+                b"preserve" => true,
+                b"default" => false,
+                _ => parent.preserve,
+            }
+        });
+
+    Scope {
+        preserve,
+        mixed: MIXED_CONTENT.contains(&start.local_name().as_ref()),
+    }
+}
+
+fn write_cdata(writer: &mut Writer<Vec<u8>>, content: &str) -> Result<(), TeiError> {
+    // `]]>` cannot appear inside one section, so split after `]]`.
+    let mut rest = content;
+    while let Some(index) = rest.find("]]>") {
+        let (section, tail) = rest.split_at(index + 2);
+        write(writer, Event::CData(BytesCData::new(section)))?;
+        rest = tail;
+    }
+    write(writer, Event::CData(BytesCData::new(rest)))
+}
+
+fn write(writer: &mut Writer<Vec<u8>>, event: Event<'_>) -> Result<(), TeiError> {
+    writer
+        .write_event(event)
+        .map_err(|error| TeiError::xml(error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("<u who=\"a\">  Hi  </u>", false, "<u who=\"a\">  Hi  </u>")]
+    #[case(
+        "<u xml:space=\"preserve\">  Hi &amp; bye  </u>",
+        false,
+        "<u xml:space=\"preserve\"><![CDATA[  Hi & bye  ]]></u>"
+    )]
+    #[case(
+        "<body xml:space=\"preserve\">\n  <p> a <hi> </hi></p>\n</body>",
+        false,
+        "<body xml:space=\"preserve\">\n  <p><![CDATA[ a ]]><hi><![CDATA[ ]]></hi></p>\n</body>"
+    )]
+    #[case(
+        "<p xml:space=\"preserve\"> a <hi xml:space=\"default\"> b </hi></p>",
+        false,
+        "<p xml:space=\"preserve\"><![CDATA[ a ]]><hi xml:space=\"default\"> b </hi></p>"
+    )]
+    #[case("<p> a]]&gt;b </p>", true, "<p><![CDATA[ a]]]]><![CDATA[>b ]]></p>")]
+    fn protects_preserved_text(
+        #[case] xml: &str,
+        #[case] preserve_all: bool,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            protect(xml, preserve_all).expect("markup should rewrite"),
+            expected
+        );
+    }
+}
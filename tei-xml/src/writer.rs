@@ -0,0 +1,355 @@
+//! Streaming TEI writer for pipelines that never hold a whole document.
+//!
+//! [`TeiWriter`] emits the header once, then one body block per call,
+//! flushing the sink after each so downstream consumers see output as soon as
+//! it is produced. The bytes match what [`emit_xml`](crate::emit_xml) would
+//! produce for the same header and blocks.
+//!
+//! The header goes on the wire before the first block, so live-transcription
+//! pipelines cannot know the final utterance and word counts when they write
+//! it. The writer keeps a [`StreamSummary`] of what it has streamed; once the
+//! body is complete, [`StreamSummary::refresh_extent`] records the counts in
+//! the header and [`emit_header`] writes it out as a trailing update.
+
+use std::io::Write;
+
+use tei_core::{BodyBlock, TeiError, TeiHeader, TeiPath};
+
+use crate::{EmptyElements, checked, emit_fragment};
+
+/// Incremental writer producing canonical TEI markup.
+///
+/// Call [`TeiWriter::write_header`] once, [`TeiWriter::write_block`] for each
+/// block, then [`TeiWriter::finish`] to close the document. Dropping the
+/// writer without calling `finish` leaves the output unterminated.
+///
+/// # Examples
+///
+/// ```
+/// use tei_core::{BodyBlock, FileDesc, TeiError, TeiHeader, Utterance};
+/// use tei_xml::writer::TeiWriter;
+///
+/// let header = TeiHeader::new(FileDesc::from_title_str("Wolf 359")?);
+/// let mut writer = TeiWriter::new(Vec::new());
+/// writer.write_header(&header)?;
+/// writer.write_block(&BodyBlock::Utterance(Utterance::from_text_segments(
+///     Some("eiffel"),
+///     ["Day 1."],
+/// )?))?;
+/// let output = String::from_utf8(writer.finish()?).map_err(|error| TeiError::xml(error.to_string()))?;
+///
+/// assert!(output.ends_with("<text><body><u who=\"eiffel\">Day 1.</u></body></text></TEI>"));
+/// # Ok::<(), TeiError>(())
+/// ```
+#[derive(Debug)]
+pub struct TeiWriter<W: Write> {
+    sink: W,
+    stage: Stage,
+    summary: StreamSummary,
+}
+
+/// Counts of the blocks a [`TeiWriter`] has streamed.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use tei_core::{BodyBlock, Extent, FileDesc, TeiError, TeiHeader, Utterance};
+/// use tei_xml::writer::{TeiWriter, emit_header};
+///
+/// let mut header = TeiHeader::new(
+///     FileDesc::from_title_str("Wolf 359")?.with_extent(
+///         Extent::new().with_duration(Duration::from_secs(1800)),
+///     ),
+/// );
+/// let mut writer = TeiWriter::new(Vec::new());
+/// writer.write_header(&header)?;
+/// writer.write_block(&BodyBlock::Utterance(Utterance::from_text_segments(
+///     Some("eiffel"),
+///     ["Day 1, still alive."],
+/// )?))?;
+/// let (_, summary) = writer.finish_with_summary()?;
+///
+/// summary.refresh_extent(&mut header);
+/// let update = emit_header(&header)?;
+/// assert!(update.contains("<measure unit=\"utterances\" quantity=\"1\"/>"));
+/// assert!(update.contains("<measure unit=\"words\" quantity=\"4\"/>"));
+/// # Ok::<(), TeiError>(())
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct StreamSummary {
+    paragraphs: usize,
+    utterances: usize,
+    words: usize,
+}
+
+impl StreamSummary {
+    /// Returns how many `<p>` blocks were written.
+    #[must_use]
+    pub const fn paragraph_count(&self) -> usize {
+        self.paragraphs
+    }
+
+    /// Returns how many `<u>` blocks were written.
+    #[must_use]
+    pub const fn utterance_count(&self) -> usize {
+        self.utterances
+    }
+
+    /// Returns the words across every block written, counted as
+    /// [`TeiBody::word_count`](tei_core::TeiBody::word_count) counts them.
+    #[must_use]
+    pub const fn word_count(&self) -> usize {
+        self.words
+    }
+
+    /// Records the streamed utterance and word counts in the header's
+    /// `<extent>`, as [`TeiDocument::refresh_extent`](tei_core::TeiDocument::refresh_extent)
+    /// does for a whole document. Any recorded audio duration is preserved.
+    pub fn refresh_extent(&self, header: &mut TeiHeader) {
+        let extent = header
+            .file_desc()
+            .extent()
+            .cloned()
+            .unwrap_or_default()
+            .with_utterance_count(self.utterances)
+            .with_word_count(self.words);
+        header.file_desc_mut().set_extent(extent);
+    }
+
+    fn record(&mut self, block: &BodyBlock) {
+        match block {
+            BodyBlock::Paragraph(_) => self.paragraphs += 1,
+            BodyBlock::Utterance(_) => self.utterances += 1,
+            BodyBlock::Raw(_) => {}
+        }
+        self.words += block.word_count();
+    }
+}
+
+/// Serializes a header on its own, exactly as [`TeiWriter::write_header`]
+/// writes it after the opening `<TEI>` tag.
+///
+/// Pipelines that stream the body use this to write a refreshed header as a
+/// trailing update once the episode is complete.
+///
+/// # Errors
+///
+/// Returns [`TeiError::Xml`] when the header contains characters XML 1.0
+/// forbids.
+pub fn emit_header(header: &TeiHeader) -> Result<String, TeiError> {
+    checked::to_string(header, Some(TeiPath::root()), EmptyElements::SelfClosing)
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Stage {
+    Start,
+    AfterHeader,
+    InBody,
+}
+
+impl<W: Write> TeiWriter<W> {
+    /// Creates a writer over the given sink.
+    #[must_use]
+    pub const fn new(sink: W) -> Self {
+        Self {
+            sink,
+            stage: Stage::Start,
+            summary: StreamSummary {
+                paragraphs: 0,
+                utterances: 0,
+                words: 0,
+            },
+        }
+    }
+
+    /// Writes the opening `<TEI>` tag and the `<teiHeader>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TeiError::Xml`] when the header was already written, when it
+    /// contains characters XML 1.0 forbids, or when the sink fails.
+    pub fn write_header(&mut self, header: &TeiHeader) -> Result<(), TeiError> {
+        if self.stage != Stage::Start {
+            return Err(TeiError::xml("the TEI header has already been written"));
+        }
+
+        let markup = emit_header(header)?;
+        self.emit(&format!("<TEI>{markup}"))?;
+        self.stage = Stage::AfterHeader;
+        Ok(())
+    }
+
+    /// Writes a single `<p>` or `<u>` block.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TeiError::Xml`] when the header has not been written yet,
+    /// when the block contains characters XML 1.0 forbids, or when the sink
+    /// fails.
+    pub fn write_block(&mut self, block: &BodyBlock) -> Result<(), TeiError> {
+        if self.stage == Stage::Start {
+            return Err(TeiError::xml(
+                "the TEI header must be written before body blocks",
+            ));
+        }
+
+        let markup = emit_fragment(block)?;
+
+        if self.stage == Stage::AfterHeader {
+            self.emit(&format!("<text><body>{markup}"))?;
+            self.stage = Stage::InBody;
+        } else {
+            self.emit(&markup)?;
+        }
+        self.summary.record(block);
+        Ok(())
+    }
+
+    /// Returns the counts of the blocks written so far.
+    #[must_use]
+    pub const fn summary(&self) -> StreamSummary {
+        self.summary
+    }
+
+    /// Closes the body and document, then returns the sink.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TeiError::Xml`] when no header was written or the sink
+    /// fails.
+    pub fn finish(self) -> Result<W, TeiError> {
+        self.finish_with_summary().map(|(sink, _)| sink)
+    }
+
+    /// Closes the body and document, then returns the sink with the counts
+    /// of the blocks written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TeiError::Xml`] when no header was written or the sink
+    /// fails.
+    pub fn finish_with_summary(mut self) -> Result<(W, StreamSummary), TeiError> {
+        match self.stage {
+            Stage::Start => return Err(TeiError::xml("missing field `teiHeader`")),
+            Stage::AfterHeader => self.emit("<text><body/></text></TEI>")?,
+            Stage::InBody => self.emit("</body></text></TEI>")?,
+        }
+        Ok((self.sink, self.summary))
+    }
+
+    fn emit(&mut self, markup: &str) -> Result<(), TeiError> {
+        self.sink
+            .write_all(markup.as_bytes())
+            .and_then(|()| self.sink.flush())
+            .map_err(|error| TeiError::xml(format!("failed to write TEI output: {error}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{emit_xml, parse_xml};
+    use tei_core::{FileDesc, P, TeiDocument, TeiText, Utterance};
+
+    fn header() -> TeiHeader {
+        TeiHeader::new(FileDesc::from_title_str("Wolf 359").expect("valid title"))
+    }
+
+    fn write_all(blocks: &[BodyBlock]) -> String {
+        let mut writer = TeiWriter::new(Vec::new());
+        writer.write_header(&header()).expect("header should write");
+        for block in blocks {
+            writer.write_block(block).expect("block should write");
+        }
+        String::from_utf8(writer.finish().expect("document should close")).expect("UTF-8 output")
+    }
+
+    #[test]
+    fn matches_emit_xml_output() {
+        let blocks = [
+            BodyBlock::Paragraph(P::from_text_segments(["Previously."]).expect("valid paragraph")),
+            BodyBlock::Utterance(
+                Utterance::from_text_segments(Some("eiffel"), ["Day 1 & counting."])
+                    .expect("valid utterance"),
+            ),
+        ];
+        let mut text = TeiText::empty();
+        text.body_mut().extend(blocks.clone());
+        let document = TeiDocument::new(header(), text);
+
+        let streamed = write_all(&blocks);
+
+        assert_eq!(streamed, emit_xml(&document).expect("document should emit"));
+        assert_eq!(parse_xml(&streamed).expect("output should parse"), document);
+    }
+
+    #[test]
+    fn empty_body_matches_emit_xml_output() {
+        let document = TeiDocument::new(header(), TeiText::empty());
+
+        assert_eq!(
+            write_all(&[]),
+            emit_xml(&document).expect("document should emit")
+        );
+    }
+
+    #[test]
+    fn rejects_blocks_before_the_header() {
+        let mut writer = TeiWriter::new(Vec::new());
+        let block =
+            BodyBlock::Paragraph(P::from_text_segments(["Too early."]).expect("valid paragraph"));
+
+        assert!(writer.write_block(&block).is_err());
+        assert!(writer.finish().is_err());
+    }
+
+    #[test]
+    fn summary_refreshes_the_header_as_the_document_would() {
+        let blocks = [
+            BodyBlock::Paragraph(P::from_text_segments(["Previously."]).expect("valid paragraph")),
+            BodyBlock::Utterance(
+                Utterance::from_text_segments(Some("eiffel"), ["Day 1 & counting."])
+                    .expect("valid utterance"),
+            ),
+        ];
+        let mut writer = TeiWriter::new(Vec::new());
+        writer.write_header(&header()).expect("header should write");
+        for block in &blocks {
+            writer.write_block(block).expect("block should write");
+        }
+        assert_eq!(writer.summary().utterance_count(), 1);
+        let (_, summary) = writer.finish_with_summary().expect("document should close");
+
+        let mut streamed = header();
+        summary.refresh_extent(&mut streamed);
+        let mut text = TeiText::empty();
+        text.body_mut().extend(blocks);
+        let mut document = TeiDocument::new(header(), text);
+        document.refresh_extent();
+
+        assert_eq!(summary.paragraph_count(), 1);
+        assert_eq!(summary.word_count(), 5);
+        assert_eq!(&streamed, document.header());
+        assert_eq!(
+            emit_header(&streamed).expect("header should emit"),
+            emit_header(document.header()).expect("header should emit")
+        );
+    }
+
+    #[test]
+    fn rejects_forbidden_characters() {
+        let mut writer = TeiWriter::new(Vec::new());
+        writer.write_header(&header()).expect("header should write");
+        let block = BodyBlock::Paragraph(
+            P::from_text_segments(["bell\u{7}"]).expect("control characters still build"),
+        );
+
+        let error = writer
+            .write_block(&block)
+            .expect_err("control characters must fail");
+
+        assert!(error.to_string().contains("U+0007"), "found {error}");
+    }
+}
@@ -0,0 +1,91 @@
+//! Property tests proving attribute values cannot break well-formedness.
+
+use std::borrow::Cow;
+
+use proptest::prelude::*;
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+use tei_core::{TeiDocument, TeiText, Utterance};
+use tei_xml::{EmitOptions, emit_xml, emit_xml_with_options, escape_xml_attr, parse_xml};
+
+/// Characters XML 1.0 allows, weighted towards those that need escaping.
+fn xml_char() -> impl Strategy<Value = char> {
+    prop_oneof![
+        3 => prop::sample::select(vec!['&', '<', '>', '"', '\'', '\t', '\n', '\r', ' ', ';', '#']),
+        2 => proptest::char::range(' ', '~'),
+        1 => proptest::char::range('\u{A0}', '\u{D7FF}'),
+        1 => proptest::char::range('\u{E000}', '\u{FDCF}'),
+        1 => proptest::char::range('\u{1_0000}', '\u{1_FFFD}'),
+    ]
+}
+
+fn xml_string() -> impl Strategy<Value = String> {
+    prop::collection::vec(xml_char(), 0..24).prop_map(|characters| characters.into_iter().collect())
+}
+
+/// Returns the value of the first attribute on the root element of `markup`.
+fn first_attribute(markup: &str) -> Option<String> {
+    let mut reader = Reader::from_str(markup);
+    loop {
+        match reader.read_event().ok()? {
+            Event::Start(start) | Event::Empty(start) => {
+                let attribute = start.attributes().next()?.ok()?;
+                return attribute.unescape_value().ok().map(Cow::into_owned);
+            }
+            Event::Eof => return None,
+            _ => {}
+        }
+    }
+}
+
+/// Returns the start tag of the first `<u>` in `markup`.
+fn utterance_tag(markup: &str) -> &str {
+    markup
+        .split_once("<u ")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map_or("", |(tag, _)| tag)
+}
+
+proptest! {
+    #[test]
+    fn escaped_values_read_back_in_either_quote(value in xml_string()) {
+        let escaped = escape_xml_attr(&value);
+
+        prop_assert!(!escaped.contains(['"', '\'', '<', '>', '\t', '\n', '\r']));
+        for quote in ['"', '\''] {
+            let markup = format!("<e a={quote}{escaped}{quote}/>");
+            prop_assert_eq!(first_attribute(&markup), Some(value.clone()));
+        }
+    }
+
+    #[test]
+    fn serialised_speakers_round_trip(speaker in xml_string(), words in "[a-z]{1,8}") {
+        prop_assume!(!speaker.trim().is_empty());
+        let Ok(utterance) = Utterance::from_text_segments(Some(speaker), [words]) else {
+            return Err(TestCaseError::fail("visible speakers should build"));
+        };
+        let mut document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        let mut text = TeiText::empty();
+        text.push_utterance(utterance);
+        *document.text_mut() = text;
+
+        let xml = emit_xml(&document).expect("utterances should emit");
+
+        prop_assert!(!utterance_tag(&xml).contains(['\t', '\n', '\r']), "{}", xml);
+        prop_assert_eq!(parse_xml(&xml).expect("emitted markup should parse"), document);
+    }
+
+    #[test]
+    fn schema_locations_stay_inside_their_attribute(location in xml_string()) {
+        let document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+        let options = EmitOptions::new().with_no_namespace_schema_location(location.clone());
+
+        let xml = emit_xml_with_options(&document, options).expect("document should emit");
+
+        prop_assert!(parse_xml(&xml).is_ok(), "{}", xml);
+        let declared = xml
+            .split_once("xsi:noNamespaceSchemaLocation=")
+            .and_then(|(_, rest)| first_attribute(&format!("<e a={rest}")));
+        prop_assert_eq!(declared, Some(location));
+    }
+}
@@ -0,0 +1,154 @@
+//! Data-driven conformance suite for TEI parsing and emission.
+//!
+//! Every `conformance/valid/*.xml` fixture must parse and emit its canonical
+//! form, read from a sibling `*.canonical.xml` file when present and otherwise
+//! identical to the input, must stream through `TeiReader` to the same
+//! document, must import through `import_xml` to that document too, and must
+//! survive a round trip through the JSON mapping. Every `conformance/invalid/*.xml` fixture must fail
+//! to parse with an error mentioning the text in its sibling `*.diagnostic`
+//! file. New cases need no code: add the files to the matching directory.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail, ensure};
+use tei_core::{TeiBody, TeiDocument, TeiText, from_json, to_json};
+use tei_xml::reader::TeiReader;
+use tei_xml::{emit_xml, import_xml, parse_xml};
+
+const CANONICAL_SUFFIX: &str = ".canonical.xml";
+
+fn fixture_inputs(kind: &str) -> Result<Vec<PathBuf>> {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/conformance")
+        .join(kind);
+    let mut inputs = Vec::new();
+
+    for entry in
+        fs::read_dir(&directory).with_context(|| format!("reading {}", directory.display()))?
+    {
+        let path = entry?.path();
+        if is_fixture_input(&path) {
+            inputs.push(path);
+        }
+    }
+
+    inputs.sort();
+    ensure!(
+        !inputs.is_empty(),
+        "no fixtures found in {}",
+        directory.display()
+    );
+    Ok(inputs)
+}
+
+fn is_fixture_input(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+
+    path.extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("xml"))
+        && !name.ends_with(CANONICAL_SUFFIX)
+}
+
+fn check_valid(path: &Path) -> Result<()> {
+    let source = fs::read_to_string(path)?;
+    let canonical_path = path.with_extension("canonical.xml");
+    let expected = if canonical_path.exists() {
+        fs::read_to_string(&canonical_path)?
+    } else {
+        source.clone()
+    };
+    let expected_markup = expected.trim_end();
+
+    let document = parse_xml(&source).context("fixture should parse")?;
+    let emitted = emit_xml(&document).context("fixture should emit")?;
+    ensure!(
+        emitted == expected_markup,
+        "emitted markup differs from the canonical form\nexpected: {expected_markup}\n  actual: {emitted}"
+    );
+
+    let reparsed = parse_xml(&emitted).context("canonical output should parse")?;
+    ensure!(
+        reparsed == document,
+        "canonical output does not parse back to the same document"
+    );
+
+    let streamed = stream_document(&source).context("fixture should stream")?;
+    ensure!(
+        streamed == document,
+        "streaming reader disagrees with parse_xml"
+    );
+
+    let imported = import_xml(&source).context("fixture should import")?;
+    ensure!(
+        imported.document() == &document,
+        "import_xml disagrees with parse_xml"
+    );
+
+    let json = to_json(&document).context("fixture should convert to JSON")?;
+    ensure!(
+        from_json(&json).context("JSON should convert back")? == document,
+        "JSON round trip changes the document
+  json: {json}"
+    );
+    Ok(())
+}
+
+fn stream_document(source: &str) -> Result<TeiDocument> {
+    let mut reader = TeiReader::new(source.as_bytes());
+    let header = reader.header()?.clone();
+    let blocks = reader.by_ref().collect::<Result<Vec<_>, _>>()?;
+    let mut text = TeiText::new(TeiBody::new(blocks));
+    if let Some(timeline) = reader.timeline() {
+        text.set_timeline(timeline.clone());
+    }
+    Ok(TeiDocument::new(header, text))
+}
+
+fn check_invalid(path: &Path) -> Result<()> {
+    let source = fs::read_to_string(path)?;
+    let diagnostic_path = path.with_extension("diagnostic");
+    let diagnostic = fs::read_to_string(&diagnostic_path)
+        .with_context(|| format!("reading {}", diagnostic_path.display()))?;
+    let expected = diagnostic.trim();
+
+    match parse_xml(&source) {
+        Ok(_) => bail!("parsing succeeded but should fail mentioning `{expected}`"),
+        Err(error) => ensure!(
+            error.to_string().contains(expected),
+            "error `{error}` does not mention `{expected}`"
+        ),
+    }
+    Ok(())
+}
+
+fn run_suite(kind: &str, check: fn(&Path) -> Result<()>) -> Result<()> {
+    let failures: Vec<String> = fixture_inputs(kind)?
+        .iter()
+        .filter_map(|path| {
+            check(path)
+                .err()
+                .map(|error| format!("{}: {error:#}", path.display()))
+        })
+        .collect();
+
+    ensure!(
+        failures.is_empty(),
+        "{} {kind} conformance case(s) failed:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+    Ok(())
+}
+
+#[test]
+fn valid_fixtures_emit_canonical_markup() -> Result<()> {
+    run_suite("valid", check_valid)
+}
+
+#[test]
+fn invalid_fixtures_report_expected_diagnostics() -> Result<()> {
+    run_suite("invalid", check_invalid)
+}
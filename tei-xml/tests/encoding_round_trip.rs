@@ -0,0 +1,312 @@
+//! Integration tests covering parse/emit round trips of encoding metadata.
+
+use tei_core::{
+    Hyphenation, HyphenationEol, NormalizationMethod, Quotation, QuotationMarks, RenditionScheme,
+};
+use tei_xml::{emit_xml, parse_xml};
+
+fn document_with_encoding_desc(encoding_desc_children: &str) -> String {
+    format!(
+        concat!(
+            "<TEI>",
+            "<teiHeader>",
+            "<fileDesc>",
+            "<title>Wolf 359</title>",
+            "</fileDesc>",
+            "<encodingDesc>",
+            "{}",
+            "</encodingDesc>",
+            "</teiHeader>",
+            "<text>",
+            "<body/>",
+            "</text>",
+            "</TEI>",
+        ),
+        encoding_desc_children
+    )
+}
+
+#[test]
+fn preserves_application_details() {
+    let source = document_with_encoding_desc(concat!(
+        "<appInfo>",
+        "<application ident=\"whisper\" version=\"large-v3\" when=\"2024-05-01\">",
+        "<desc>Automatic transcription</desc>",
+        "</application>",
+        "<application ident=\"tei-rapporteur\" version=\"0.3\"/>",
+        "</appInfo>",
+    ));
+    let document = parse_xml(&source).expect("application details should parse");
+    let applications = document
+        .header()
+        .encoding_desc()
+        .and_then(|encoding| encoding.app_info())
+        .expect("appInfo should be recorded")
+        .applications();
+
+    assert_eq!(applications.len(), 2);
+    let whisper = applications.first().expect("first application");
+    assert_eq!(whisper.ident(), "whisper");
+    assert_eq!(whisper.version(), "large-v3");
+    assert_eq!(whisper.date(), Some("2024-05-01"));
+    assert_eq!(whisper.notes(), ["Automatic transcription"]);
+
+    let emitted = emit_xml(&document).expect("application details should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn rejects_applications_without_versions() {
+    let source = document_with_encoding_desc(concat!(
+        "<appInfo>",
+        "<application ident=\"whisper\" version=\" \"/>",
+        "</appInfo>",
+    ));
+    let error = parse_xml(&source).expect_err("blank versions should be rejected");
+
+    assert!(
+        error
+            .to_string()
+            .contains("application version may not be empty"),
+        "unexpected error: {error}"
+    );
+}
+
+#[test]
+fn preserves_editorial_declaration() {
+    let source = document_with_encoding_desc(concat!(
+        "<editorialDecl>",
+        "<normalization method=\"silent\">",
+        "<p>Filler words were removed.</p>",
+        "</normalization>",
+        "<quotation marks=\"none\"/>",
+        "<hyphenation eol=\"hard\"/>",
+        "<interpretation>",
+        "<p>Laughter is marked as an incident.</p>",
+        "</interpretation>",
+        "</editorialDecl>",
+    ));
+    let document = parse_xml(&source).expect("editorial declaration should parse");
+    let editorial = document
+        .header()
+        .encoding_desc()
+        .and_then(|encoding| encoding.editorial_decl())
+        .expect("editorialDecl should be recorded");
+
+    let normalization = editorial.normalization().expect("normalization policy");
+    assert_eq!(normalization.method(), NormalizationMethod::Silent);
+    assert_eq!(normalization.notes(), ["Filler words were removed."]);
+    assert_eq!(
+        editorial.quotation().map(Quotation::marks),
+        Some(QuotationMarks::None)
+    );
+    assert_eq!(
+        editorial.hyphenation().map(Hyphenation::eol),
+        Some(HyphenationEol::Hard)
+    );
+
+    let emitted = emit_xml(&document).expect("editorial declaration should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn rejects_unknown_normalization_methods() {
+    let source = document_with_encoding_desc(
+        "<editorialDecl><normalization method=\"loud\"/></editorialDecl>",
+    );
+
+    assert!(
+        parse_xml(&source).is_err(),
+        "unknown methods should be rejected"
+    );
+}
+
+#[test]
+fn preserves_project_description() {
+    let source = document_with_encoding_desc(concat!(
+        "<projectDesc>",
+        "<p>Transcripts collected for the fan archive.</p>",
+        "<p>Episodes are proofread twice.</p>",
+        "</projectDesc>",
+    ));
+    let document = parse_xml(&source).expect("project description should parse");
+    let project = document
+        .header()
+        .encoding_desc()
+        .and_then(|encoding| encoding.project_desc())
+        .expect("projectDesc should be recorded");
+
+    assert_eq!(project.paragraphs().len(), 2);
+
+    let emitted = emit_xml(&document).expect("project description should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn rejects_empty_project_descriptions() {
+    let source = document_with_encoding_desc("<projectDesc/>");
+    let error = parse_xml(&source).expect_err("empty projectDesc should be rejected");
+
+    assert!(
+        error
+            .to_string()
+            .contains("project description may not be empty"),
+        "unexpected error: {error}"
+    );
+}
+
+#[test]
+fn preserves_sampling_declaration() {
+    let source = document_with_encoding_desc(concat!(
+        "<samplingDecl>",
+        "<p>Only the first ten minutes of each episode were transcribed.</p>",
+        "</samplingDecl>",
+    ));
+    let document = parse_xml(&source).expect("sampling declaration should parse");
+    let sampling = document
+        .header()
+        .encoding_desc()
+        .and_then(|encoding| encoding.sampling_decl())
+        .expect("samplingDecl should be recorded");
+
+    assert_eq!(sampling.paragraphs().len(), 1);
+
+    let emitted = emit_xml(&document).expect("sampling declaration should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn preserves_rendition_defaults() {
+    let source = document_with_encoding_desc(concat!(
+        "<tagsDecl>",
+        "<rendition xml:id=\"stress\" scheme=\"css\">font-weight: bold;</rendition>",
+        "<rendition xml:id=\"hi-default\" selector=\"hi\">font-style: italic;</rendition>",
+        "</tagsDecl>",
+    ));
+    let document = parse_xml(&source).expect("tagging declaration should parse");
+    let tags = document
+        .header()
+        .encoding_desc()
+        .and_then(|encoding| encoding.tags_decl())
+        .expect("tagsDecl should be recorded");
+
+    let stress = tags.rendition("stress").expect("stress rendition");
+    assert_eq!(stress.scheme(), Some(RenditionScheme::Css));
+    assert_eq!(stress.content(), "font-weight: bold;");
+    assert_eq!(
+        tags.default_rendition("hi")
+            .map(|rendition| rendition.id().as_str()),
+        Some("hi-default")
+    );
+
+    let emitted = emit_xml(&document).expect("tagging declaration should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn preserves_taxonomies_and_resolves_category_references() {
+    let source = concat!(
+        "<TEI>",
+        "<teiHeader>",
+        "<fileDesc>",
+        "<title>Wolf 359</title>",
+        "</fileDesc>",
+        "<profileDesc>",
+        "<textClass>",
+        "<catRef scheme=\"#genre\" target=\"#horror\"/>",
+        "</textClass>",
+        "</profileDesc>",
+        "<encodingDesc>",
+        "<classDecl>",
+        "<taxonomy xml:id=\"genre\">",
+        "<category xml:id=\"drama\">",
+        "<catDesc>Audio drama</catDesc>",
+        "<category xml:id=\"horror\"/>",
+        "</category>",
+        "</taxonomy>",
+        "</classDecl>",
+        "</encodingDesc>",
+        "</teiHeader>",
+        "<text>",
+        "<body/>",
+        "</text>",
+        "</TEI>",
+    );
+    let document = parse_xml(source).expect("classification declaration should parse");
+    let class_decl = document
+        .header()
+        .encoding_desc()
+        .and_then(|encoding| encoding.class_decl())
+        .expect("classDecl should be recorded");
+
+    assert_eq!(
+        class_decl
+            .category("#drama")
+            .and_then(|category| category.description()),
+        Some("Audio drama")
+    );
+    assert!(document.header().check_cat_refs().is_ok());
+
+    let emitted = emit_xml(&document).expect("classification declaration should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn reports_unresolved_category_references() {
+    let source = concat!(
+        "<TEI>",
+        "<teiHeader>",
+        "<fileDesc>",
+        "<title>Wolf 359</title>",
+        "</fileDesc>",
+        "<profileDesc>",
+        "<textClass>",
+        "<catRef target=\"#comedy\"/>",
+        "</textClass>",
+        "</profileDesc>",
+        "</teiHeader>",
+        "<text>",
+        "<body/>",
+        "</text>",
+        "</TEI>",
+    );
+    let document = parse_xml(source).expect("dangling pointers still parse");
+    let error = document
+        .header()
+        .check_cat_refs()
+        .expect_err("undeclared categories should be reported");
+
+    assert!(
+        error.to_string().contains("`#comedy`"),
+        "unexpected error: {error}"
+    );
+}
+
+#[test]
+fn preserves_citation_structure() {
+    let source = document_with_encoding_desc(concat!(
+        "<refsDecl>",
+        "<citeStructure unit=\"episode\" match=\"/TEI\" use=\"@n\">",
+        "<citeStructure unit=\"turn\" match=\"text/body/u\" use=\"position()\" delim=\".\"/>",
+        "</citeStructure>",
+        "</refsDecl>",
+    ));
+    let document = parse_xml(&source).expect("reference declaration should parse");
+    let refs = document
+        .header()
+        .encoding_desc()
+        .and_then(|encoding| encoding.refs_decl())
+        .expect("refsDecl should be recorded");
+
+    assert_eq!(refs.citation(&["12", "34"]), Some("12.34".to_owned()));
+
+    let emitted = emit_xml(&document).expect("reference declaration should emit");
+
+    assert_eq!(emitted, source);
+}
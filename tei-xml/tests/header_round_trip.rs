@@ -0,0 +1,377 @@
+//! Integration tests covering parse/emit round trips of header metadata.
+
+use std::time::Duration;
+
+use tei_core::{Idno, IdnoType, TeiDocument, Utterance};
+use tei_xml::{emit_xml, parse_xml};
+
+fn document_with_file_desc(file_desc_children: &str) -> String {
+    format!(
+        concat!(
+            "<TEI>",
+            "<teiHeader>",
+            "<fileDesc>",
+            "<title>Wolf 359</title>",
+            "{}",
+            "</fileDesc>",
+            "</teiHeader>",
+            "<text>",
+            "<body/>",
+            "</text>",
+            "</TEI>",
+        ),
+        file_desc_children
+    )
+}
+
+#[test]
+fn preserves_publication_statement() {
+    let source = document_with_file_desc(concat!(
+        "<publicationStmt>",
+        "<publisher>Kinda Evil Genius Productions</publisher>",
+        "<date>2016-08-01</date>",
+        "<availability>",
+        "<licence target=\"https://creativecommons.org/licenses/by/4.0/\">CC BY 4.0</licence>",
+        "</availability>",
+        "</publicationStmt>",
+    ));
+    let document = parse_xml(&source).expect("publication statement should parse");
+    let publication = document
+        .header()
+        .file_desc()
+        .publication_stmt()
+        .expect("publication statement should be recorded");
+
+    assert_eq!(publication.publisher(), "Kinda Evil Genius Productions");
+    assert_eq!(
+        publication
+            .availability()
+            .and_then(|availability| availability.licence())
+            .and_then(|licence| licence.text()),
+        Some("CC BY 4.0")
+    );
+
+    let emitted = emit_xml(&document).expect("publication statement should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn rejects_blank_publishers() {
+    let source =
+        document_with_file_desc("<publicationStmt><publisher>   </publisher></publicationStmt>");
+    let error = parse_xml(&source).expect_err("blank publisher should fail");
+
+    assert!(
+        error.to_string().contains("publisher may not be empty"),
+        "unexpected error: {error}"
+    );
+}
+
+#[test]
+fn preserves_responsibility_statements() {
+    let source = document_with_file_desc(concat!(
+        "<respStmt><resp>transcriber</resp><name>Jane Doe</name></respStmt>",
+        "<respStmt><resp>editor</resp><name>Sam Roe</name></respStmt>",
+    ));
+    let document = parse_xml(&source).expect("responsibility statements should parse");
+    let roles: Vec<_> = document
+        .header()
+        .file_desc()
+        .resp_stmts()
+        .iter()
+        .map(|resp_stmt| (resp_stmt.role(), resp_stmt.name().as_str()))
+        .collect();
+
+    assert_eq!(roles, [("transcriber", "Jane Doe"), ("editor", "Sam Roe")]);
+
+    let emitted = emit_xml(&document).expect("responsibility statements should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn preserves_edition_statement() {
+    let source = document_with_file_desc(concat!(
+        "<respStmt><resp>transcriber</resp><name>Jane Doe</name></respStmt>",
+        "<editionStmt>",
+        "<edition>Second edition</edition>",
+        "<note>Corrected speaker attributions</note>",
+        "</editionStmt>",
+    ));
+    let document = parse_xml(&source).expect("edition statement should parse");
+    let edition = document
+        .header()
+        .file_desc()
+        .edition_stmt()
+        .expect("edition statement should be recorded");
+
+    assert_eq!(edition.edition(), "Second edition");
+    assert_eq!(edition.notes(), ["Corrected speaker attributions"]);
+
+    let emitted = emit_xml(&document).expect("edition statement should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn preserves_extent_measures() {
+    let source = document_with_file_desc(concat!(
+        "<extent>",
+        "<measure unit=\"seconds\" quantity=\"2712\"/>",
+        "<measure unit=\"utterances\" quantity=\"48\"/>",
+        "<measure unit=\"words\" quantity=\"5310\"/>",
+        "</extent>",
+    ));
+    let document = parse_xml(&source).expect("extent should parse");
+    let extent = document
+        .header()
+        .file_desc()
+        .extent()
+        .expect("extent should be recorded");
+
+    assert_eq!(extent.duration(), Some(Duration::from_secs(2712)));
+    assert_eq!(extent.utterance_count(), Some(48));
+    assert_eq!(extent.word_count(), Some(5310));
+
+    let emitted = emit_xml(&document).expect("extent should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn refreshed_extent_is_emitted_before_publication() {
+    let mut document = TeiDocument::from_title_str("Wolf 359").expect("valid title");
+    document.text_mut().push_utterance(
+        Utterance::from_text_segments(Some("eiffel"), ["Day 1 of the mission."])
+            .expect("valid utterance"),
+    );
+    document.refresh_extent();
+
+    let emitted = emit_xml(&document).expect("extent should emit");
+
+    assert!(emitted.contains(concat!(
+        "<extent>",
+        "<measure unit=\"utterances\" quantity=\"1\"/>",
+        "<measure unit=\"words\" quantity=\"5\"/>",
+        "</extent>",
+    )));
+}
+
+#[test]
+fn preserves_series_statement() {
+    let source = document_with_file_desc(concat!(
+        "<seriesStmt>",
+        "<title>Wolf 359</title>",
+        "<idno type=\"url\">https://feeds.example.org/wolf-359</idno>",
+        "<biblScope unit=\"season\">2</biblScope>",
+        "<biblScope unit=\"episode\">12</biblScope>",
+        "</seriesStmt>",
+    ));
+    let document = parse_xml(&source).expect("series statement should parse");
+    let series = document
+        .header()
+        .file_desc()
+        .series_stmt()
+        .expect("series statement should be recorded");
+
+    assert_eq!(series.title(), "Wolf 359");
+    assert_eq!(
+        series.idnos().first().map(Idno::value),
+        Some("https://feeds.example.org/wolf-359")
+    );
+    assert_eq!(series.season_number(), Some(2));
+    assert_eq!(series.episode_number(), Some(12));
+    assert_eq!(document.season_number(), Some(2));
+    assert_eq!(document.episode_number(), Some(12));
+
+    let emitted = emit_xml(&document).expect("series statement should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn preserves_notes_statement() {
+    let source = document_with_file_desc(concat!(
+        "<notesStmt>",
+        "<note>Audio drops out between 12:03 and 12:41.</note>",
+        "<note>Music cues are not transcribed.</note>",
+        "</notesStmt>",
+    ));
+    let document = parse_xml(&source).expect("notes statement should parse");
+    let notes = document
+        .header()
+        .file_desc()
+        .notes_stmt()
+        .expect("notes statement should be recorded");
+
+    assert_eq!(notes.notes().len(), 2);
+
+    let emitted = emit_xml(&document).expect("notes statement should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn rejects_blank_header_notes() {
+    let source = document_with_file_desc("<notesStmt><note>  </note></notesStmt>");
+    let error = parse_xml(&source).expect_err("blank note should fail");
+
+    assert!(
+        error.to_string().contains("note may not be empty"),
+        "unexpected error: {error}"
+    );
+}
+
+#[test]
+fn preserves_typed_identifiers() {
+    let source = document_with_file_desc(concat!(
+        "<idno type=\"doi\">10.5281/zenodo.1234</idno>",
+        "<idno type=\"guid\">wolf-359-episode-12</idno>",
+    ));
+    let document = parse_xml(&source).expect("identifiers should parse");
+    let idnos: Vec<_> = document
+        .header()
+        .file_desc()
+        .idnos()
+        .iter()
+        .map(|idno| (idno.idno_type(), idno.value()))
+        .collect();
+
+    assert_eq!(
+        idnos,
+        [
+            (Some(IdnoType::Doi), "10.5281/zenodo.1234"),
+            (Some(IdnoType::Guid), "wolf-359-episode-12"),
+        ]
+    );
+
+    let emitted = emit_xml(&document).expect("identifiers should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn rejects_malformed_dois() {
+    let source = document_with_file_desc("<idno type=\"doi\">zenodo.1234</idno>");
+    let error = parse_xml(&source).expect_err("malformed DOI should fail");
+
+    assert!(
+        error
+            .to_string()
+            .contains("doi identifier `zenodo.1234` is not valid"),
+        "unexpected error: {error}"
+    );
+}
+
+#[test]
+fn preserves_project_agencies() {
+    let source = document_with_file_desc(concat!(
+        "<sponsor>Regional Heritage Trust</sponsor>",
+        "<funder>National Lottery Heritage Fund</funder>",
+        "<principal>Dr Ada Jones</principal>",
+    ));
+    let document = parse_xml(&source).expect("agencies should parse");
+    let file_desc = document.header().file_desc();
+
+    assert_eq!(file_desc.sponsors(), ["Regional Heritage Trust"]);
+    assert_eq!(file_desc.funders(), ["National Lottery Heritage Fund"]);
+    assert_eq!(file_desc.principals(), ["Dr Ada Jones"]);
+
+    let emitted = emit_xml(&document).expect("agencies should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn rejects_blank_principals() {
+    let source = document_with_file_desc("<principal> </principal>");
+    let error = parse_xml(&source).expect_err("blank principal should fail");
+
+    assert!(
+        error.to_string().contains("principal may not be empty"),
+        "unexpected error: {error}"
+    );
+}
+
+#[test]
+fn preserves_foreign_metadata_verbatim() {
+    let source = concat!(
+        "<TEI>",
+        "<teiHeader>",
+        "<fileDesc>",
+        "<title>Wolf 359</title>",
+        "</fileDesc>",
+        "<xenoData type=\"rss\">&lt;item&gt;&lt;guid&gt;ep-12&lt;/guid&gt;&lt;/item&gt;</xenoData>",
+        "<xenoData type=\"application/json\">{\"explicit\": false}</xenoData>",
+        "</teiHeader>",
+        "<text>",
+        "<body/>",
+        "</text>",
+        "</TEI>",
+    );
+    let document = parse_xml(source).expect("foreign metadata should parse");
+    let payloads: Vec<_> = document
+        .header()
+        .xeno_data()
+        .iter()
+        .map(|xeno| (xeno.data_type(), xeno.payload()))
+        .collect();
+
+    assert_eq!(
+        payloads,
+        [
+            (Some("rss"), "<item><guid>ep-12</guid></item>"),
+            (Some("application/json"), "{\"explicit\": false}"),
+        ]
+    );
+
+    let emitted = emit_xml(&document).expect("foreign metadata should emit");
+    let reparsed = parse_xml(&emitted).expect("emitted metadata should parse");
+
+    assert_eq!(reparsed, document);
+}
+
+#[test]
+fn preserves_source_media() {
+    let source = document_with_file_desc(concat!(
+        "<sourceDesc>",
+        "<recordingStmt>",
+        "<recording>",
+        "<media xml:id=\"ep12-audio\" url=\"https://example.org/ep12.mp3\" ",
+        "mimeType=\"audio/mpeg\" dur=\"PT1H2M3.5S\"/>",
+        "</recording>",
+        "</recordingStmt>",
+        "</sourceDesc>",
+    ));
+    let document = parse_xml(&source).expect("source media should parse");
+    let media = document
+        .header()
+        .file_desc()
+        .source_desc()
+        .and_then(|source_desc| source_desc.find_media("#ep12-audio"))
+        .expect("media should be recorded");
+
+    assert_eq!(media.url(), "https://example.org/ep12.mp3");
+    assert_eq!(media.mime_type(), "audio/mpeg");
+    assert_eq!(media.duration(), Some(Duration::from_millis(3_723_500)));
+
+    let emitted = emit_xml(&document).expect("source media should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn rejects_unparseable_media_durations() {
+    let source = document_with_file_desc(concat!(
+        "<sourceDesc><recordingStmt><recording>",
+        "<media url=\"ep12.mp3\" mimeType=\"audio/mpeg\" dur=\"an hour\"/>",
+        "</recording></recordingStmt></sourceDesc>",
+    ));
+    let error = parse_xml(&source).expect_err("invalid durations should be rejected");
+
+    assert!(
+        error.to_string().contains("duration `an hour`"),
+        "unexpected error: {error}"
+    );
+}
@@ -0,0 +1,125 @@
+//! Imports of TEI P5 produced outside the Episodic profile.
+
+use tei_core::{SourceLocation, TeiError, TeiPath};
+use tei_xml::{LenientParse, import_xml, parse_xml};
+
+/// A transcript in the shape Transcriber-style tools export.
+const TRANSCRIBED: &str = r##"<TEI xmlns="http://www.tei-c.org/ns/1.0">
+  <teiHeader>
+    <fileDesc>
+      <titleStmt><title>Interview 12</title></titleStmt>
+      <publicationStmt><publisher>Oral History Unit</publisher></publicationStmt>
+    </fileDesc>
+  </teiHeader>
+  <text>
+    <body>
+      <u who="#spk1" start="#T0" end="#T1"><anchor synch="#T0"/>Well, <vocal><desc>laughs</desc></vocal>it began <pause dur="PT1S"/>in <emph>spring</emph>.</u>
+    </body>
+  </text>
+</TEI>"##;
+
+/// A document in the shape converters from word-processor files produce.
+const CONVERTED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<TEI xmlns="http://www.tei-c.org/ns/1.0" xml:lang="en">
+  <teiHeader>
+    <fileDesc>
+      <titleStmt>
+        <title>Succulent</title>
+        <author>Gabriel Urbina</author>
+      </titleStmt>
+      <publicationStmt><p>Unpublished draft.</p></publicationStmt>
+    </fileDesc>
+  </teiHeader>
+  <text>
+    <front><docTitle><titlePart>Succulent</titlePart></docTitle></front>
+    <body>
+      <div type="scene">
+        <head>Scene one</head>
+        <p rend="Body Text">Eiffel <emph rend="italic">waters</emph> the plant.</p>
+      </div>
+    </body>
+  </text>
+</TEI>"#;
+
+fn paths(imported: &LenientParse) -> Vec<String> {
+    imported
+        .problems()
+        .iter()
+        .filter_map(TeiError::location)
+        .filter_map(SourceLocation::path)
+        .map(TeiPath::to_string)
+        .collect()
+}
+
+#[test]
+fn imports_transcriber_style_utterances() {
+    let imported = import_xml(TRANSCRIBED).expect("transcript should import");
+
+    let expected = parse_xml(concat!(
+        "<TEI><teiHeader><fileDesc><title>Interview 12</title>",
+        "<publicationStmt><publisher>Oral History Unit</publisher></publicationStmt>",
+        "</fileDesc></teiHeader><text><body>",
+        "<u who=\"#spk1\" start=\"#T0\" end=\"#T1\">Well, it began <pause dur=\"PT1S\"/>in spring.</u>",
+        "</body></text></TEI>",
+    ))
+    .expect("expected document should parse");
+    assert_eq!(imported.document(), &expected);
+    assert_eq!(
+        paths(&imported),
+        [
+            "/TEI/teiHeader[1]/fileDesc[1]/titleStmt[1]",
+            "/TEI/text[1]/body[1]/u[1]/anchor[1]",
+            "/TEI/text[1]/body[1]/u[1]/vocal[1]",
+            "/TEI/text[1]/body[1]/u[1]/emph[1]",
+        ]
+    );
+}
+
+#[test]
+fn imports_converted_documents() {
+    let imported = import_xml(CONVERTED).expect("converted document should import");
+
+    let expected = parse_xml(concat!(
+        "<TEI><teiHeader><fileDesc><title>Succulent</title></fileDesc></teiHeader>",
+        "<text><body><p>Eiffel waters the plant.</p></body></text></TEI>",
+    ))
+    .expect("expected document should parse");
+    assert_eq!(imported.document(), &expected);
+    assert_eq!(
+        paths(&imported),
+        [
+            "/TEI/@xml:lang",
+            "/TEI/teiHeader[1]/fileDesc[1]/titleStmt[1]",
+            "/TEI/teiHeader[1]/fileDesc[1]/titleStmt[1]/author[1]",
+            "/TEI/teiHeader[1]/fileDesc[1]/publicationStmt[1]",
+            "/TEI/text[1]/front[1]",
+            "/TEI/text[1]/body[1]/div[1]",
+            "/TEI/text[1]/body[1]/div[1]/head[1]",
+            "/TEI/text[1]/body[1]/div[1]/p[1]/@rend",
+            "/TEI/text[1]/body[1]/div[1]/p[1]/emph[1]",
+        ]
+    );
+    assert!(
+        imported.problems().iter().any(|problem| problem
+            .to_string()
+            .contains("skipped <publicationStmt> without a <publisher>")),
+        "found {:?}",
+        imported.problems()
+    );
+}
+
+#[test]
+fn profile_documents_import_cleanly() {
+    let xml = concat!(
+        "<TEI><teiHeader><fileDesc><title>Wolf 359</title></fileDesc></teiHeader>",
+        "<text><body><u who=\"eiffel\" xml:id=\"u1\">Day <pause dur=\"PT1S\"/>1.</u></body></text></TEI>",
+    );
+
+    let imported = import_xml(xml).expect("profile documents should import");
+
+    assert!(imported.is_clean(), "found {:?}", imported.problems());
+    assert_eq!(
+        imported.document(),
+        &parse_xml(xml).expect("document should parse")
+    );
+}
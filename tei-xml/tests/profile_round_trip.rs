@@ -0,0 +1,139 @@
+//! Integration tests covering parse/emit round trips of profile metadata.
+
+use tei_core::{CatRef, Keywords, SpeakerName, SpeakerRole};
+use tei_xml::{emit_xml, parse_xml};
+
+fn document_with_profile_desc(profile_desc_children: &str) -> String {
+    format!(
+        concat!(
+            "<TEI>",
+            "<teiHeader>",
+            "<fileDesc>",
+            "<title>Wolf 359</title>",
+            "</fileDesc>",
+            "<profileDesc>",
+            "{}",
+            "</profileDesc>",
+            "</teiHeader>",
+            "<text>",
+            "<body/>",
+            "</text>",
+            "</TEI>",
+        ),
+        profile_desc_children
+    )
+}
+
+#[test]
+fn preserves_text_classification() {
+    let source = document_with_profile_desc(concat!(
+        "<textClass>",
+        "<keywords scheme=\"#lcsh\"><term>Science fiction</term><term>Space</term></keywords>",
+        "<catRef scheme=\"#genre\" target=\"#drama #comedy\"/>",
+        "</textClass>",
+    ));
+    let document = parse_xml(&source).expect("text classification should parse");
+    let text_class = document
+        .header()
+        .profile_desc()
+        .and_then(|profile| profile.text_class())
+        .expect("text classification should be recorded");
+
+    assert_eq!(
+        text_class.keywords().first().map(Keywords::terms),
+        Some(["Science fiction".to_owned(), "Space".to_owned()].as_slice())
+    );
+    assert_eq!(
+        text_class.cat_refs().first().map(CatRef::targets),
+        Some(["#drama".to_owned(), "#comedy".to_owned()].as_slice())
+    );
+
+    let emitted = emit_xml(&document).expect("text classification should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn preserves_abstract_paragraphs() {
+    let source = document_with_profile_desc(concat!(
+        "<abstract>",
+        "<p>Eiffel begins his log.</p>",
+        "<p>Hera reports a fault.</p>",
+        "</abstract>",
+    ));
+    let document = parse_xml(&source).expect("abstract should parse");
+    let summary = document
+        .header()
+        .profile_desc()
+        .and_then(|profile| profile.abstract_text())
+        .expect("abstract should be recorded");
+
+    assert_eq!(summary.paragraphs().len(), 2);
+
+    let emitted = emit_xml(&document).expect("abstract should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn rejects_empty_abstracts() {
+    let source = document_with_profile_desc("<abstract/>");
+    let error = parse_xml(&source).expect_err("empty abstract should fail");
+
+    assert!(
+        error.to_string().contains("abstract may not be empty"),
+        "unexpected error: {error}"
+    );
+}
+
+#[test]
+fn preserves_creation_details() {
+    let source = document_with_profile_desc(concat!(
+        "<creation>",
+        "<date when=\"2024-05-01\"/>",
+        "<placeName>Leeds</placeName>",
+        "</creation>",
+    ));
+    let document = parse_xml(&source).expect("creation should parse");
+    let creation = document
+        .header()
+        .profile_desc()
+        .and_then(|profile| profile.creation())
+        .expect("creation should be recorded");
+
+    assert_eq!(creation.date(), Some("2024-05-01"));
+    assert_eq!(creation.place(), Some("Leeds"));
+
+    let emitted = emit_xml(&document).expect("creation should emit");
+
+    assert_eq!(emitted, source);
+}
+
+#[test]
+fn preserves_speaker_roles() {
+    let source = document_with_profile_desc(concat!(
+        "<speaker role=\"host\">Cecil Palmer</speaker>",
+        "<speaker>Carlos</speaker>",
+        "<speaker role=\"sound designer\">Disparition</speaker>",
+    ));
+    let document = parse_xml(&source).expect("speaker roles should parse");
+    let profile = document
+        .header()
+        .profile_desc()
+        .expect("profile should be recorded");
+
+    let hosts: Vec<_> = profile
+        .speakers_with_role(&SpeakerRole::Host)
+        .map(SpeakerName::as_str)
+        .collect();
+    assert_eq!(hosts, ["Cecil Palmer"]);
+    assert_eq!(
+        profile.speaker_role("Disparition"),
+        Some(&SpeakerRole::Other("sound designer".to_owned()))
+    );
+    assert!(profile.speaker_role("Carlos").is_none());
+
+    let emitted = emit_xml(&document).expect("speaker roles should emit");
+
+    assert_eq!(emitted, source);
+}
@@ -1,8 +1,8 @@
 //! Integration tests covering parse/emit round trips.
 
 use serde::Deserialize;
-use tei_core::BodyBlock;
-use tei_xml::{emit_xml, parse_xml};
+use tei_core::{BodyBlock, DocumentTitle};
+use tei_xml::{ParseOptions, UnknownElements, emit_xml, parse_xml, parse_xml_with_options};
 
 const PRETTY_MINIMAL_TEI: &str = concat!(
     "<TEI>\n",
@@ -74,8 +74,7 @@ fn preserves_xml_id_namespace_attributes() {
     match document
         .text()
         .body()
-        .blocks()
-        .first()
+        .block(0)
         .expect("document should have at least one block")
     {
         BodyBlock::Utterance(utterance) => {
@@ -85,6 +84,7 @@ fn preserves_xml_id_namespace_attributes() {
             );
         }
         BodyBlock::Paragraph(_) => panic!("expected utterance block, found paragraph"),
+        BodyBlock::Raw(_) => panic!("expected utterance block, found raw markup"),
     }
     let emitted = emit_xml(&document).expect("namespaced TEI should emit");
 
@@ -103,3 +103,127 @@ fn quick_xml_deserializes_xml_id_attributes() {
         .expect("xml:id attribute should deserialize");
     assert_eq!(carrier.identifier, "n1");
 }
+
+const MULTILINGUAL_SOURCE: &str = concat!(
+    "<TEI>",
+    "<teiHeader>",
+    "<fileDesc>",
+    "<title>Wolf 359</title>",
+    "</fileDesc>",
+    "</teiHeader>",
+    "<text>",
+    "<body>",
+    "<p xml:lang=\"cy\">Bore da</p>",
+    "<u xml:lang=\"fr\" who=\"host\">Bonjour</u>",
+    "</body>",
+    "</text>",
+    "</TEI>",
+);
+
+#[test]
+fn preserves_xml_lang_on_blocks() {
+    let document = parse_xml(MULTILINGUAL_SOURCE).expect("multilingual TEI should parse");
+    let languages: Vec<_> = document
+        .text()
+        .body()
+        .blocks()
+        .map(|block| block.lang().map(|lang| lang.as_str().to_owned()))
+        .collect();
+    assert_eq!(languages, [Some("cy".to_owned()), Some("fr".to_owned())]);
+
+    let emitted = emit_xml(&document).expect("multilingual TEI should emit");
+
+    assert_eq!(emitted, MULTILINGUAL_SOURCE);
+}
+
+const TYPED_TITLES_SOURCE: &str = concat!(
+    "<TEI>",
+    "<teiHeader>",
+    "<fileDesc>",
+    "<title>Wolf 359</title>",
+    "<title type=\"sub\">Box of Sand</title>",
+    "<title type=\"alt\">Hephaestus Logs</title>",
+    "</fileDesc>",
+    "</teiHeader>",
+    "<text>",
+    "<body/>",
+    "</text>",
+    "</TEI>",
+);
+
+#[test]
+fn preserves_typed_titles() {
+    let document = parse_xml(TYPED_TITLES_SOURCE).expect("typed titles should parse");
+    let file_desc = document.header().file_desc();
+
+    assert_eq!(document.title().as_str(), "Wolf 359");
+    assert_eq!(
+        file_desc.subtitle().map(DocumentTitle::as_str),
+        Some("Box of Sand")
+    );
+    assert_eq!(file_desc.alternative_titles().len(), 1);
+
+    let emitted = emit_xml(&document).expect("typed titles should emit");
+
+    assert_eq!(emitted, TYPED_TITLES_SOURCE);
+}
+
+#[test]
+fn rejects_file_descriptions_without_a_main_title() {
+    let source = TYPED_TITLES_SOURCE.replace("<title>Wolf 359</title>", "");
+    let error = parse_xml(&source).expect_err("missing main title should fail");
+
+    assert!(
+        error
+            .to_string()
+            .contains("file description must include a main title"),
+        "unexpected error: {error}"
+    );
+}
+
+#[test]
+fn preserves_unknown_elements_verbatim() {
+    let xml = concat!(
+        "<TEI><teiHeader><fileDesc><title>Wolf 359</title></fileDesc></teiHeader>",
+        "<text><body>",
+        "<div type=\"scene\"><head>Scene one</head></div>",
+        "<u who=\"eiffel\">Day <gap reason=\"inaudible\" extent=\"2 words\"/>1.</u>",
+        "<p>Fish <foreign xml:lang=\"fr\">&amp; frites</foreign>.</p>",
+        "</body></text></TEI>",
+    );
+    let options = ParseOptions::new()
+        .with_preserved_whitespace(true)
+        .with_unknown_elements(UnknownElements::Preserve);
+
+    let document = parse_xml_with_options(xml, options).expect("unknown elements should be kept");
+
+    assert!(matches!(
+        document.text().body().block(0),
+        Some(BodyBlock::Raw(raw)) if raw.as_str() == "<div type=\"scene\"><head>Scene one</head></div>"
+    ));
+    assert_eq!(emit_xml(&document).expect("document should emit"), xml);
+    assert!(parse_xml(xml).is_err(), "unknown elements fail by default");
+}
+
+#[test]
+fn never_reads_raw_elements_in_the_input_as_placeholders() {
+    let xml = concat!(
+        "<TEI><teiHeader><fileDesc><title>Wolf 359</title></fileDesc></teiHeader>",
+        "<text><body><p>a <raw markup=\"&lt;script&gt;x&lt;/script&gt;\"/></p></body></text></TEI>",
+    );
+
+    let error = parse_xml(xml).expect_err("raw elements in the input must be rejected");
+    assert!(
+        error.to_string().contains("reserved"),
+        "unexpected error: {error}"
+    );
+
+    let options = ParseOptions::new().with_unknown_elements(UnknownElements::Preserve);
+    let document = parse_xml_with_options(xml, options).expect("unknown elements should be kept");
+    let emitted = emit_xml(&document).expect("document should emit");
+    assert!(
+        !emitted.contains("<script>"),
+        "markup was injected: {emitted}"
+    );
+    assert!(emitted.contains("<raw markup=\"&lt;script&gt;x&lt;/script&gt;\"/>"));
+}
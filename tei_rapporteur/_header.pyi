@@ -0,0 +1,246 @@
+"""Type stubs for the header classes of the `tei_rapporteur` extension module.
+
+The classes are defined by the compiled module itself; this stub-only module
+keeps `tei_rapporteur/__init__.pyi` under the repository's file length limit,
+and that stub re-exports every name declared here.
+"""
+
+from collections.abc import Mapping, Sequence
+from typing import Any, Literal, final
+
+from tei_rapporteur import P
+
+@final
+class Idno:
+    def __init__(
+        self, value: str, idno_type: Literal["guid", "doi", "url"] | None = None
+    ) -> None: ...
+    @property
+    def idno_type(self) -> Literal["guid", "doi", "url"] | None: ...
+    @property
+    def value(self) -> str: ...
+
+@final
+class PublicationStmt:
+    def __init__(self, publisher: str) -> None: ...
+    def with_date(self, date: str) -> PublicationStmt: ...
+    @property
+    def publisher(self) -> str: ...
+    @property
+    def date(self) -> str | None: ...
+
+@final
+class SeriesStmt:
+    def __init__(self, title: str) -> None: ...
+    def with_season_number(self, season: int) -> SeriesStmt: ...
+    def with_episode_number(self, episode: int) -> SeriesStmt: ...
+    def add_idno(self, idno: Idno) -> None: ...
+    @property
+    def title(self) -> str: ...
+    @property
+    def season_number(self) -> int | None: ...
+    @property
+    def episode_number(self) -> int | None: ...
+    @property
+    def idnos(self) -> list[Idno]: ...
+
+@final
+class RespStmt:
+    def __init__(self, role: str, name: str) -> None: ...
+    @property
+    def role(self) -> str: ...
+    @property
+    def name(self) -> str: ...
+
+@final
+class Extent:
+    def __init__(
+        self,
+        duration: float | None = None,
+        utterance_count: int | None = None,
+        word_count: int | None = None,
+    ) -> None: ...
+    @property
+    def duration(self) -> float | None: ...
+    @property
+    def utterance_count(self) -> int | None: ...
+    @property
+    def word_count(self) -> int | None: ...
+
+@final
+class Media:
+    def __init__(self, url: str, mime_type: str, duration: float | None = None) -> None: ...
+    @property
+    def url(self) -> str: ...
+    @property
+    def mime_type(self) -> str: ...
+    @property
+    def duration(self) -> float | None: ...
+
+@final
+class SourceDesc:
+    def __init__(self) -> None: ...
+    def add_media(self, media: Media) -> None: ...
+    @property
+    def media(self) -> list[Media]: ...
+
+@final
+class FileDesc:
+    def __init__(self, title: str) -> None: ...
+    def with_publication_stmt(self, publication: PublicationStmt) -> FileDesc: ...
+    def with_series_stmt(self, series: SeriesStmt) -> FileDesc: ...
+    def with_extent(self, extent: Extent) -> FileDesc: ...
+    def with_source_desc(self, source: SourceDesc) -> FileDesc: ...
+    def with_synopsis(self, synopsis: str) -> FileDesc: ...
+    def with_subtitle(self, subtitle: str) -> FileDesc: ...
+    def with_notes(self, notes: Sequence[str]) -> FileDesc: ...
+    def add_alternative_title(self, title: str) -> None: ...
+    def add_idno(self, idno: Idno) -> None: ...
+    def add_resp_stmt(self, resp_stmt: RespStmt) -> None: ...
+    @property
+    def title(self) -> str: ...
+    @property
+    def subtitle(self) -> str | None: ...
+    @property
+    def alternative_titles(self) -> list[str]: ...
+    @property
+    def publication_stmt(self) -> PublicationStmt | None: ...
+    @property
+    def series_stmt(self) -> SeriesStmt | None: ...
+    @property
+    def extent(self) -> Extent | None: ...
+    @property
+    def source_desc(self) -> SourceDesc | None: ...
+    @property
+    def idnos(self) -> list[Idno]: ...
+    @property
+    def resp_stmts(self) -> list[RespStmt]: ...
+    @property
+    def notes(self) -> list[str]: ...
+    @property
+    def synopsis(self) -> str | None: ...
+
+@final
+class Creation:
+    def __init__(self, date: str | None = None, place: str | None = None) -> None: ...
+    @property
+    def date(self) -> str | None: ...
+    @property
+    def place(self) -> str | None: ...
+
+@final
+class Keywords:
+    def __init__(self, terms: Sequence[str], scheme: str | None = None) -> None: ...
+    @property
+    def terms(self) -> list[str]: ...
+    @property
+    def scheme(self) -> str | None: ...
+
+@final
+class CatRef:
+    def __init__(self, targets: Sequence[str], scheme: str | None = None) -> None: ...
+    @property
+    def targets(self) -> list[str]: ...
+    @property
+    def scheme(self) -> str | None: ...
+
+@final
+class TextClass:
+    def __init__(self) -> None: ...
+    def add_keywords(self, keywords: Keywords) -> None: ...
+    def add_cat_ref(self, cat_ref: CatRef) -> None: ...
+    @property
+    def keywords(self) -> list[Keywords]: ...
+    @property
+    def cat_refs(self) -> list[CatRef]: ...
+
+@final
+class ProfileDesc:
+    def __init__(self) -> None: ...
+    def add_speaker(self, speaker: str, role: str | None = None) -> None: ...
+    def add_language(self, language: str) -> None: ...
+    def with_synopsis(self, synopsis: str) -> ProfileDesc: ...
+    def with_creation(self, creation: Creation) -> ProfileDesc: ...
+    def with_text_class(self, text_class: TextClass) -> ProfileDesc: ...
+    def speaker_role(self, speaker: str) -> str | None: ...
+    @property
+    def speakers(self) -> list[str]: ...
+    @property
+    def languages(self) -> list[str]: ...
+    @property
+    def synopsis(self) -> str | None: ...
+    @property
+    def creation(self) -> Creation | None: ...
+    @property
+    def text_class(self) -> TextClass | None: ...
+
+@final
+class AnnotationSystem:
+    def __init__(self, identifier: str, description: str = "") -> None: ...
+    @property
+    def identifier(self) -> str: ...
+    @property
+    def description(self) -> str | None: ...
+
+@final
+class Application:
+    def __init__(self, ident: str, version: str) -> None: ...
+    def with_date(self, date: str) -> Application: ...
+    def with_note(self, note: str) -> Application: ...
+    @property
+    def ident(self) -> str: ...
+    @property
+    def version(self) -> str: ...
+    @property
+    def date(self) -> str | None: ...
+    @property
+    def notes(self) -> list[str]: ...
+
+@final
+class EncodingDesc:
+    def __init__(self) -> None: ...
+    def add_annotation_system(self, system: AnnotationSystem) -> None: ...
+    def with_applications(self, applications: Sequence[Application]) -> EncodingDesc: ...
+    def with_project_desc(self, paragraphs: Sequence[P]) -> EncodingDesc: ...
+    def with_sampling_decl(self, paragraphs: Sequence[P]) -> EncodingDesc: ...
+    @property
+    def annotation_systems(self) -> list[AnnotationSystem]: ...
+    @property
+    def applications(self) -> list[Application]: ...
+    @property
+    def project_desc(self) -> list[P]: ...
+    @property
+    def sampling_decl(self) -> list[P]: ...
+
+@final
+class RevisionChange:
+    def __init__(self, description: str, resp: str = "") -> None: ...
+    @property
+    def description(self) -> str: ...
+    @property
+    def resp(self) -> str | None: ...
+
+@final
+class RevisionDesc:
+    def __init__(self) -> None: ...
+    def add_change(self, change: RevisionChange) -> None: ...
+    @property
+    def changes(self) -> list[RevisionChange]: ...
+
+@final
+class TeiHeader:
+    def __init__(self, file_desc: FileDesc) -> None: ...
+    def with_profile_desc(self, profile_desc: ProfileDesc) -> TeiHeader: ...
+    def with_encoding_desc(self, encoding_desc: EncodingDesc) -> TeiHeader: ...
+    def with_revision_desc(self, revision_desc: RevisionDesc) -> TeiHeader: ...
+    @property
+    def file_desc(self) -> FileDesc: ...
+    @property
+    def profile_desc(self) -> ProfileDesc | None: ...
+    @property
+    def encoding_desc(self) -> EncodingDesc | None: ...
+    @property
+    def revision_desc(self) -> RevisionDesc | None: ...
+    def to_dict(self) -> dict[str, Any]: ...
+    @staticmethod
+    def from_dict(data: Mapping[str, Any]) -> TeiHeader: ...